@@ -0,0 +1,378 @@
+use crate::float;
+use crate::types::hex::Hex;
+use crate::types::linalg::Matrix;
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Color, Plane, Quaternion, Rect2, Rect2i, Transform2D, Transform3D, AABB};
+use crate::utils::{int, CMP_EPSILON};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Arguments, Debug, Write};
+
+/// Approximate equality with a tolerance chosen per call.
+///
+/// Every method compares each component of the two values, and returns `true` only if all of them are approximately equal. Values that are exactly equal (including infinities) are always approximately equal, while `NaN` is never approximately equal to anything.
+///
+/// The inherent `is_equal_approx` methods are roughly `relative_eq(other, CMP_EPSILON, CMP_EPSILON)`. See [`AbsDiff`], [`Relative`] and [`Ulps`] for comparisons with default tolerances, and [`assert_approx_eq!`](crate::assert_approx_eq) for assertions.
+pub trait ApproxEq {
+    /// Returns `true` if the absolute difference between each pair of components is at most `epsilon`.
+    ///
+    /// This is the right comparison for values close to zero, but too strict for large values.
+    fn abs_diff_eq(&self, other: &Self, epsilon: float!()) -> bool;
+
+    /// Returns `true` if the absolute difference between each pair of components is at most `epsilon`, or at most `max_relative` times the largest of the two components.
+    ///
+    /// The tolerance scales with the magnitude of the values, while `epsilon` still handles the values close to zero.
+    fn relative_eq(&self, other: &Self, epsilon: float!(), max_relative: float!()) -> bool;
+
+    /// Returns `true` if the absolute difference between each pair of components is at most `epsilon`, or if they are at most `max_ulps` representable floating-point values apart.
+    ///
+    /// Components with different signs are only equal if they pass the `epsilon` check.
+    fn ulps_eq(&self, other: &Self, epsilon: float!(), max_ulps: u32) -> bool;
+}
+
+#[cfg(not(feature = "double-precision-float"))]
+fn ulps_between(a: f32, b: f32) -> u64 {
+    u64::from(a.to_bits().abs_diff(b.to_bits()))
+}
+
+#[cfg(feature = "double-precision-float")]
+fn ulps_between(a: f64, b: f64) -> u64 {
+    a.to_bits().abs_diff(b.to_bits())
+}
+
+impl ApproxEq for float!() {
+    fn abs_diff_eq(&self, other: &Self, epsilon: float!()) -> bool {
+        // Check for exact equality first, required to handle "infinity" values.
+        self == other || (self - other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: float!(), max_relative: float!()) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+        (self - other).abs() <= self.abs().max(other.abs()) * max_relative
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: float!(), max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_nan()
+            || other.is_nan()
+            || self.is_sign_negative() != other.is_sign_negative()
+        {
+            return false;
+        }
+        ulps_between(*self, *other) <= u64::from(max_ulps)
+    }
+}
+
+impl ApproxEq for int!() {
+    fn abs_diff_eq(&self, other: &Self, epsilon: float!()) -> bool {
+        (*self as float!()).abs_diff_eq(&(*other as float!()), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: float!(), max_relative: float!()) -> bool {
+        (*self as float!()).relative_eq(&(*other as float!()), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: float!(), max_ulps: u32) -> bool {
+        (*self as float!()).ulps_eq(&(*other as float!()), epsilon, max_ulps)
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($type:ty, $($component:ident $(($($args:tt)*))?),+) => {
+        impl ApproxEq for $type {
+            fn abs_diff_eq(&self, other: &Self, epsilon: float!()) -> bool {
+                $(self.$component$(($($args)*))?.abs_diff_eq(&other.$component$(($($args)*))?, epsilon))&&+
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: float!(), max_relative: float!()) -> bool {
+                $(self.$component$(($($args)*))?.relative_eq(&other.$component$(($($args)*))?, epsilon, max_relative))&&+
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: float!(), max_ulps: u32) -> bool {
+                $(self.$component$(($($args)*))?.ulps_eq(&other.$component$(($($args)*))?, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+impl_approx_eq!(Vector2, x, y);
+impl_approx_eq!(Vector3, x, y, z);
+impl_approx_eq!(Vector4, x, y, z, w);
+impl_approx_eq!(Vector2i, x, y);
+impl_approx_eq!(Vector3i, x, y, z);
+impl_approx_eq!(Vector4i, x, y, z, w);
+impl_approx_eq!(Quaternion, x, y, z, w);
+impl_approx_eq!(Basis, x, y, z);
+impl_approx_eq!(Transform2D, x, y, origin);
+impl_approx_eq!(Transform3D, basis, origin);
+impl_approx_eq!(Plane, normal, d);
+impl_approx_eq!(Rect2, position(), size());
+impl_approx_eq!(Rect2i, position(), size());
+impl_approx_eq!(AABB, position(), size());
+impl_approx_eq!(Color, r(), g(), b(), a());
+impl_approx_eq!(Hex, q, r);
+
+impl<const R: usize, const C: usize> ApproxEq for Matrix<R, C> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: float!()) -> bool {
+        let mut pairs = self.rows().iter().flatten().zip(other.rows().iter().flatten());
+        pairs.all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: float!(), max_relative: float!()) -> bool {
+        let mut pairs = self.rows().iter().flatten().zip(other.rows().iter().flatten());
+        pairs.all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: float!(), max_ulps: u32) -> bool {
+        let mut pairs = self.rows().iter().flatten().zip(other.rows().iter().flatten());
+        pairs.all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+/// An [`ApproxEq::abs_diff_eq`] comparison, with `epsilon` defaulting to [`CMP_EPSILON`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AbsDiff {
+    pub epsilon: float!(),
+}
+
+impl AbsDiff {
+    /// Returns a copy of the comparison with the given `epsilon`.
+    pub const fn epsilon(self, epsilon: float!()) -> Self {
+        Self { epsilon }
+    }
+
+    /// Compares `a` and `b` with [`ApproxEq::abs_diff_eq`].
+    pub fn eq<T: ApproxEq + ?Sized>(&self, a: &T, b: &T) -> bool {
+        a.abs_diff_eq(b, self.epsilon)
+    }
+}
+
+impl Default for AbsDiff {
+    fn default() -> Self {
+        Self {
+            epsilon: CMP_EPSILON,
+        }
+    }
+}
+
+/// An [`ApproxEq::relative_eq`] comparison, with `epsilon` and `max_relative` defaulting to [`CMP_EPSILON`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Relative {
+    pub epsilon: float!(),
+    pub max_relative: float!(),
+}
+
+impl Relative {
+    /// Returns a copy of the comparison with the given `epsilon`.
+    pub const fn epsilon(self, epsilon: float!()) -> Self {
+        Self { epsilon, ..self }
+    }
+
+    /// Returns a copy of the comparison with the given `max_relative`.
+    pub const fn max_relative(self, max_relative: float!()) -> Self {
+        Self {
+            max_relative,
+            ..self
+        }
+    }
+
+    /// Compares `a` and `b` with [`ApproxEq::relative_eq`].
+    pub fn eq<T: ApproxEq + ?Sized>(&self, a: &T, b: &T) -> bool {
+        a.relative_eq(b, self.epsilon, self.max_relative)
+    }
+}
+
+impl Default for Relative {
+    fn default() -> Self {
+        Self {
+            epsilon: CMP_EPSILON,
+            max_relative: CMP_EPSILON,
+        }
+    }
+}
+
+/// An [`ApproxEq::ulps_eq`] comparison, with `epsilon` defaulting to [`CMP_EPSILON`] and `max_ulps` to `4`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ulps {
+    pub epsilon: float!(),
+    pub max_ulps: u32,
+}
+
+impl Ulps {
+    /// Returns a copy of the comparison with the given `epsilon`.
+    pub const fn epsilon(self, epsilon: float!()) -> Self {
+        Self { epsilon, ..self }
+    }
+
+    /// Returns a copy of the comparison with the given `max_ulps`.
+    pub const fn max_ulps(self, max_ulps: u32) -> Self {
+        Self { max_ulps, ..self }
+    }
+
+    /// Compares `a` and `b` with [`ApproxEq::ulps_eq`].
+    pub fn eq<T: ApproxEq + ?Sized>(&self, a: &T, b: &T) -> bool {
+        a.ulps_eq(b, self.epsilon, self.max_ulps)
+    }
+}
+
+impl Default for Ulps {
+    fn default() -> Self {
+        Self {
+            epsilon: CMP_EPSILON,
+            max_ulps: 4,
+        }
+    }
+}
+
+/// Panics with a line-by-line diff of the pretty-printed `left` and `right`. Used by the `assert_*_eq!` macros.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_failed<T: Debug + ?Sized>(
+    comparison: &dyn Debug,
+    left: &T,
+    right: &T,
+    message: Option<Arguments<'_>>,
+) -> ! {
+    let left = format!("{:#?}", left);
+    let right = format!("{:#?}", right);
+    let mut report = String::from("assertion `left ≈ right` failed");
+    if let Some(message) = message {
+        let _ = write!(report, ": {}", message);
+    }
+    let _ = write!(report, "\ncomparison: {:?}\ndiff (- left, + right):", comparison);
+
+    let left_lines: Vec<&str> = left.lines().collect();
+    let right_lines: Vec<&str> = right.lines().collect();
+    if left_lines.len() == right_lines.len() {
+        for (l, r) in left_lines.iter().zip(&right_lines) {
+            if l == r {
+                let _ = write!(report, "\n  {}", l);
+            } else {
+                let _ = write!(report, "\n- {}\n+ {}", l, r);
+            }
+        }
+    } else {
+        for l in &left_lines {
+            let _ = write!(report, "\n- {}", l);
+        }
+        for r in &right_lines {
+            let _ = write!(report, "\n+ {}", r);
+        }
+    }
+    panic!("{}", report)
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx_eq {
+    ($comparison:ident, $left:expr, $right:expr, [$($param:ident = $value:expr),*]) => {
+        $crate::__assert_approx_eq!(@check $comparison, $left, $right, [$($param = $value),*], ::core::option::Option::None)
+    };
+    ($comparison:ident, $left:expr, $right:expr, [$($param:ident = $value:expr),*], $($message:tt)+) => {
+        $crate::__assert_approx_eq!(@check $comparison, $left, $right, [$($param = $value),*], ::core::option::Option::Some(::core::format_args!($($message)+)))
+    };
+    (@check $comparison:ident, $left:expr, $right:expr, [$($param:ident = $value:expr),*], $message:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let comparison = $crate::approx::$comparison::default()$(.$param($value))*;
+                if !comparison.eq(left, right) {
+                    $crate::approx::assert_failed(&comparison, left, right, $message);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are approximately equal with [`ApproxEq::abs_diff_eq`], and prints a diff of the values otherwise.
+///
+/// The tolerance can be set with `epsilon = ...`, and a custom message can follow, like with [`assert_eq!`].
+///
+/// ```
+/// # use huginn::assert_abs_diff_eq;
+/// # use huginn::types::vectors::Vector2;
+/// assert_abs_diff_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.05), epsilon = 0.1);
+/// assert_abs_diff_eq!(Vector2::ONE, Vector2::ONE * 1.000001, "{} should be close to one", 1.000001);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx_eq!(AbsDiff, $left, $right, [])
+    };
+    ($left:expr, $right:expr, $($param:ident = $value:expr),+ $(,)?) => {
+        $crate::__assert_approx_eq!(AbsDiff, $left, $right, [$($param = $value),+])
+    };
+    ($left:expr, $right:expr, $($param:ident = $value:expr,)* $message:literal $($arg:tt)*) => {
+        $crate::__assert_approx_eq!(AbsDiff, $left, $right, [$($param = $value),*], $message $($arg)*)
+    };
+}
+
+/// Asserts that two values are approximately equal with [`ApproxEq::relative_eq`], and prints a diff of the values otherwise.
+///
+/// The tolerances can be set with `epsilon = ...` and `max_relative = ...`, and a custom message can follow, like with [`assert_eq!`].
+///
+/// ```
+/// # use huginn::assert_relative_eq;
+/// # use huginn::types::vectors::Vector2;
+/// assert_relative_eq!(Vector2::new(1000.0, 0.0), Vector2::new(1001.0, 0.0), max_relative = 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx_eq!(Relative, $left, $right, [])
+    };
+    ($left:expr, $right:expr, $($param:ident = $value:expr),+ $(,)?) => {
+        $crate::__assert_approx_eq!(Relative, $left, $right, [$($param = $value),+])
+    };
+    ($left:expr, $right:expr, $($param:ident = $value:expr,)* $message:literal $($arg:tt)*) => {
+        $crate::__assert_approx_eq!(Relative, $left, $right, [$($param = $value),*], $message $($arg)*)
+    };
+}
+
+/// Asserts that two values are approximately equal with [`ApproxEq::ulps_eq`], and prints a diff of the values otherwise.
+///
+/// The tolerances can be set with `epsilon = ...` and `max_ulps = ...`, and a custom message can follow, like with [`assert_eq!`].
+///
+/// ```
+/// # use huginn::assert_ulps_eq;
+/// let sum: huginn::float!() = 0.1 + 0.2;
+/// assert_ulps_eq!(sum, 0.3, epsilon = 0.0, max_ulps = 1);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx_eq!(Ulps, $left, $right, [])
+    };
+    ($left:expr, $right:expr, $($param:ident = $value:expr),+ $(,)?) => {
+        $crate::__assert_approx_eq!(Ulps, $left, $right, [$($param = $value),+])
+    };
+    ($left:expr, $right:expr, $($param:ident = $value:expr,)* $message:literal $($arg:tt)*) => {
+        $crate::__assert_approx_eq!(Ulps, $left, $right, [$($param = $value),*], $message $($arg)*)
+    };
+}
+
+/// Asserts that two values are approximately equal, with the same default tolerances as the `is_equal_approx` methods. This is [`assert_relative_eq!`] with its default `epsilon` and `max_relative`.
+///
+/// ```
+/// # use huginn::assert_approx_eq;
+/// # use huginn::types::vectors::Vector3;
+/// # use huginn::types::Basis;
+/// let basis = Basis::from_euler(&Vector3::new(0.3, 0.2, 0.1), None);
+/// assert_approx_eq!(basis * basis.inverse(), Basis::IDENTITY, "A basis times its inverse should be the identity.");
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx_eq!(Relative, $left, $right, [])
+    };
+    ($left:expr, $right:expr, $($message:tt)+) => {
+        $crate::__assert_approx_eq!(Relative, $left, $right, [], $($message)+)
+    };
+}
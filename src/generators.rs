@@ -0,0 +1,10 @@
+use crate::utils::{float, int};
+
+/// The largest absolute value of the generated float components, such as positions and sizes.
+pub const COMPONENT_LIMIT: float!() = 1.0e4;
+
+/// The largest absolute value of the integer components of rects and hexes, small enough that their sums don't overflow.
+pub const INT_COMPONENT_LIMIT: int!() = 1 << 20;
+
+/// The largest magnitude of the scale factors of the generated transforms.
+pub const SCALE_LIMIT: float!() = 10.0;
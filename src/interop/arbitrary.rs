@@ -0,0 +1,177 @@
+use crate::generators::{COMPONENT_LIMIT, INT_COMPONENT_LIMIT, SCALE_LIMIT};
+use crate::types::hex::Hex;
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Color, Plane, Quaternion, Rect2, Rect2i, Transform2D, Transform3D, AABB};
+use crate::utils::{float, float_consts, int};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+// The values respect the same invariants and bounds as the strategies of `huginn::proptest`: float components are finite and bounded, rotations are normalized, transforms are invertible, sizes are non-negative and colors are in `0..=1`.
+
+/// Returns a float in `min..=max`.
+fn float_in(u: &mut Unstructured<'_>, min: float!(), max: float!()) -> Result<float!()> {
+    let t = u.arbitrary::<u32>()? as float!() / u32::MAX as float!();
+    Ok(min + (max - min) * t)
+}
+
+fn component(u: &mut Unstructured<'_>) -> Result<float!()> {
+    float_in(u, -COMPONENT_LIMIT, COMPONENT_LIMIT)
+}
+
+fn scale_factor(u: &mut Unstructured<'_>) -> Result<float!()> {
+    let scale = float_in(u, 0.1, SCALE_LIMIT)?;
+    Ok(if u.arbitrary()? { -scale } else { scale })
+}
+
+fn int_component(u: &mut Unstructured<'_>) -> Result<int!()> {
+    u.int_in_range(-INT_COMPONENT_LIMIT..=INT_COMPONENT_LIMIT)
+}
+
+impl<'a> Arbitrary<'a> for Vector2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(component(u)?, component(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vector3 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(component(u)?, component(u)?, component(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vector4 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(
+            component(u)?,
+            component(u)?,
+            component(u)?,
+            component(u)?,
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vector2i {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(<[int!(); 2]>::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vector3i {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(<[int!(); 3]>::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vector4i {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(<[int!(); 4]>::arbitrary(u)?))
+    }
+}
+
+/// Always a unit quaternion.
+impl<'a> Arbitrary<'a> for Quaternion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let q = Self::new(
+            float_in(u, -1.0, 1.0)?,
+            float_in(u, -1.0, 1.0)?,
+            float_in(u, -1.0, 1.0)?,
+            float_in(u, -1.0, 1.0)?,
+        );
+        if q.length_squared() < 0.01 {
+            return Ok(Self::IDENTITY);
+        }
+        Ok(q.normalized())
+    }
+}
+
+/// Always an orthonormal basis without reflection, i.e. a pure rotation.
+impl<'a> Arbitrary<'a> for Basis {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(&Quaternion::arbitrary(u)?))
+    }
+}
+
+/// Always invertible: a rotation, a non-zero scale, a skew in `-1.0..=1.0` radians and a translation.
+impl<'a> Arbitrary<'a> for Transform2D {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let rotation = float_in(u, -float_consts::PI, float_consts::PI)?;
+        let scale = Vector2::new(scale_factor(u)?, scale_factor(u)?);
+        let skew = float_in(u, -1.0, 1.0)?;
+        Ok(Self::from((rotation, scale, skew, Vector2::arbitrary(u)?)))
+    }
+}
+
+/// Always invertible: a rotation, a non-zero scale and a translation.
+impl<'a> Arbitrary<'a> for Transform3D {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let rotation = Quaternion::arbitrary(u)?;
+        let scale = Vector3::new(scale_factor(u)?, scale_factor(u)?, scale_factor(u)?);
+        Ok(Self::new(
+            Basis::from((&rotation, &scale)),
+            Vector3::arbitrary(u)?,
+        ))
+    }
+}
+
+/// Always with a non-negative size.
+impl<'a> Arbitrary<'a> for Rect2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let position = Vector2::arbitrary(u)?;
+        let size = Vector2::new(
+            float_in(u, 0.0, COMPONENT_LIMIT)?,
+            float_in(u, 0.0, COMPONENT_LIMIT)?,
+        );
+        Ok(Self::new(position, size))
+    }
+}
+
+/// Always with a non-negative size, and small enough components that the end doesn't overflow.
+impl<'a> Arbitrary<'a> for Rect2i {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new_from_dimension(
+            int_component(u)?,
+            int_component(u)?,
+            u.int_in_range(0..=INT_COMPONENT_LIMIT)?,
+            u.int_in_range(0..=INT_COMPONENT_LIMIT)?,
+        ))
+    }
+}
+
+/// Always with a non-negative size.
+impl<'a> Arbitrary<'a> for AABB {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let position = Vector3::arbitrary(u)?;
+        let size = Vector3::new(
+            float_in(u, 0.0, COMPONENT_LIMIT)?,
+            float_in(u, 0.0, COMPONENT_LIMIT)?,
+            float_in(u, 0.0, COMPONENT_LIMIT)?,
+        );
+        Ok(Self::new(position, size))
+    }
+}
+
+/// Always with a normalized normal.
+impl<'a> Arbitrary<'a> for Plane {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let normal = Quaternion::arbitrary(u)?.xform(&Vector3::UP);
+        Ok(Self::new(normal, component(u)?))
+    }
+}
+
+/// Always with every component, including alpha, in `0.0..=1.0`.
+impl<'a> Arbitrary<'a> for Color {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::rgba(
+            float_in(u, 0.0, 1.0)?,
+            float_in(u, 0.0, 1.0)?,
+            float_in(u, 0.0, 1.0)?,
+            float_in(u, 0.0, 1.0)?,
+        ))
+    }
+}
+
+/// Always with coordinates small enough that their sums don't overflow.
+impl<'a> Arbitrary<'a> for Hex {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(int_component(u)?, int_component(u)?))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Quaternion, Transform2D, Transform3D};
+
+#[cfg(not(feature = "double-precision-float"))]
+use ::glam::{Affine2, Affine3A as Affine3, Mat3, Quat, Vec2, Vec3, Vec4};
+#[cfg(feature = "double-precision-float")]
+use ::glam::{
+    DAffine2 as Affine2, DAffine3 as Affine3, DMat3 as Mat3, DQuat as Quat, DVec2 as Vec2,
+    DVec3 as Vec3, DVec4 as Vec4,
+};
+
+#[cfg(not(feature = "double-precision-int"))]
+use ::glam::{IVec2, IVec3, IVec4};
+#[cfg(feature = "double-precision-int")]
+use ::glam::{I64Vec2 as IVec2, I64Vec3 as IVec3, I64Vec4 as IVec4};
+
+macro_rules! impl_vector_conversions {
+    ($vector:ident, $glam:ident, $($component:ident),+) => {
+        impl From<$glam> for $vector {
+            fn from(value: $glam) -> Self {
+                Self::new($(value.$component),+)
+            }
+        }
+
+        impl From<$vector> for $glam {
+            fn from(value: $vector) -> Self {
+                Self::new($(value.$component),+)
+            }
+        }
+    };
+}
+
+impl_vector_conversions!(Vector2, Vec2, x, y);
+impl_vector_conversions!(Vector3, Vec3, x, y, z);
+impl_vector_conversions!(Vector4, Vec4, x, y, z, w);
+impl_vector_conversions!(Vector2i, IVec2, x, y);
+impl_vector_conversions!(Vector3i, IVec3, x, y, z);
+impl_vector_conversions!(Vector4i, IVec4, x, y, z, w);
+
+impl From<Quat> for Quaternion {
+    fn from(value: Quat) -> Self {
+        Self::new(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<Quaternion> for Quat {
+    fn from(value: Quaternion) -> Self {
+        Self::from_xyzw(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<Mat3> for Basis {
+    fn from(value: Mat3) -> Self {
+        Self::new(
+            value.x_axis.into(),
+            value.y_axis.into(),
+            value.z_axis.into(),
+        )
+    }
+}
+
+impl From<Basis> for Mat3 {
+    fn from(value: Basis) -> Self {
+        Self::from_cols(value.x().into(), value.y().into(), value.z().into())
+    }
+}
+
+impl From<Affine2> for Transform2D {
+    fn from(value: Affine2) -> Self {
+        Self::new(
+            value.matrix2.x_axis.into(),
+            value.matrix2.y_axis.into(),
+            value.translation.into(),
+        )
+    }
+}
+
+impl From<Transform2D> for Affine2 {
+    fn from(value: Transform2D) -> Self {
+        Self::from_cols(value.x.into(), value.y.into(), value.origin.into())
+    }
+}
+
+impl From<Affine3> for Transform3D {
+    // `Affine3A` stores SIMD-aligned `Mat3A` and `Vec3A`, while `DAffine3` already stores `DMat3` and `DVec3`.
+    #[allow(clippy::useless_conversion)]
+    fn from(value: Affine3) -> Self {
+        Self::new(
+            Mat3::from(value.matrix3).into(),
+            Vec3::from(value.translation).into(),
+        )
+    }
+}
+
+impl From<Transform3D> for Affine3 {
+    fn from(value: Transform3D) -> Self {
+        Self::from_mat3_translation(value.basis.into(), value.origin.into())
+    }
+}
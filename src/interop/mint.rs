@@ -0,0 +1,103 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Quaternion, Transform2D, Transform3D};
+use crate::utils::{float, int};
+
+macro_rules! impl_vector_conversions {
+    ($vector:ident, $mint:ident<$scalar:ty>, $($component:ident),+) => {
+        impl From<::mint::$mint<$scalar>> for $vector {
+            fn from(value: ::mint::$mint<$scalar>) -> Self {
+                Self::new($(value.$component),+)
+            }
+        }
+
+        impl From<$vector> for ::mint::$mint<$scalar> {
+            fn from(value: $vector) -> Self {
+                Self {
+                    $($component: value.$component),+
+                }
+            }
+        }
+    };
+}
+
+impl_vector_conversions!(Vector2, Vector2<float!()>, x, y);
+impl_vector_conversions!(Vector2, Point2<float!()>, x, y);
+impl_vector_conversions!(Vector3, Vector3<float!()>, x, y, z);
+impl_vector_conversions!(Vector3, Point3<float!()>, x, y, z);
+impl_vector_conversions!(Vector4, Vector4<float!()>, x, y, z, w);
+impl_vector_conversions!(Vector2i, Vector2<int!()>, x, y);
+impl_vector_conversions!(Vector2i, Point2<int!()>, x, y);
+impl_vector_conversions!(Vector3i, Vector3<int!()>, x, y, z);
+impl_vector_conversions!(Vector3i, Point3<int!()>, x, y, z);
+impl_vector_conversions!(Vector4i, Vector4<int!()>, x, y, z, w);
+
+impl From<::mint::Quaternion<float!()>> for Quaternion {
+    fn from(value: ::mint::Quaternion<float!()>) -> Self {
+        Self::new(value.v.x, value.v.y, value.v.z, value.s)
+    }
+}
+
+impl From<Quaternion> for ::mint::Quaternion<float!()> {
+    fn from(value: Quaternion) -> Self {
+        Self {
+            v: ::mint::Vector3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            s: value.w,
+        }
+    }
+}
+
+impl From<::mint::ColumnMatrix3<float!()>> for Basis {
+    fn from(value: ::mint::ColumnMatrix3<float!()>) -> Self {
+        Self::new(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl From<Basis> for ::mint::ColumnMatrix3<float!()> {
+    fn from(value: Basis) -> Self {
+        Self {
+            x: value.x().into(),
+            y: value.y().into(),
+            z: value.z().into(),
+        }
+    }
+}
+
+impl From<::mint::ColumnMatrix2x3<float!()>> for Transform2D {
+    fn from(value: ::mint::ColumnMatrix2x3<float!()>) -> Self {
+        Self::new(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl From<Transform2D> for ::mint::ColumnMatrix2x3<float!()> {
+    fn from(value: Transform2D) -> Self {
+        Self {
+            x: value.x.into(),
+            y: value.y.into(),
+            z: value.origin.into(),
+        }
+    }
+}
+
+impl From<::mint::ColumnMatrix3x4<float!()>> for Transform3D {
+    fn from(value: ::mint::ColumnMatrix3x4<float!()>) -> Self {
+        Self::new(
+            Basis::new(value.x.into(), value.y.into(), value.z.into()),
+            value.w.into(),
+        )
+    }
+}
+
+impl From<Transform3D> for ::mint::ColumnMatrix3x4<float!()> {
+    fn from(value: Transform3D) -> Self {
+        Self {
+            x: value.basis.x().into(),
+            y: value.basis.y().into(),
+            z: value.basis.z().into(),
+            w: value.origin.into(),
+        }
+    }
+}
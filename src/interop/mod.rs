@@ -0,0 +1,12 @@
+// Conversions and trait implementations between the huginn types and other crates, each behind the feature flag named after the crate.
+//
+// The float types map to the `f64` flavour of the other crate when `double-precision-float` is enabled, and the integer vectors to the `i64` flavour when `double-precision-int` is enabled.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,133 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Quaternion, Transform2D, Transform3D};
+use crate::utils::{float, int};
+use ::nalgebra as na;
+
+macro_rules! impl_vector_conversions {
+    ($vector:ident, $na:ident<$scalar:ty>, $($component:ident),+) => {
+        impl From<na::$na<$scalar>> for $vector {
+            fn from(value: na::$na<$scalar>) -> Self {
+                Self::new($(value.$component),+)
+            }
+        }
+
+        impl From<$vector> for na::$na<$scalar> {
+            fn from(value: $vector) -> Self {
+                Self::new($(value.$component),+)
+            }
+        }
+    };
+}
+
+impl_vector_conversions!(Vector2, Vector2<float!()>, x, y);
+impl_vector_conversions!(Vector2, Point2<float!()>, x, y);
+impl_vector_conversions!(Vector3, Vector3<float!()>, x, y, z);
+impl_vector_conversions!(Vector3, Point3<float!()>, x, y, z);
+impl_vector_conversions!(Vector4, Vector4<float!()>, x, y, z, w);
+impl_vector_conversions!(Vector2i, Vector2<int!()>, x, y);
+impl_vector_conversions!(Vector2i, Point2<int!()>, x, y);
+impl_vector_conversions!(Vector3i, Vector3<int!()>, x, y, z);
+impl_vector_conversions!(Vector3i, Point3<int!()>, x, y, z);
+impl_vector_conversions!(Vector4i, Vector4<int!()>, x, y, z, w);
+
+impl From<na::Quaternion<float!()>> for Quaternion {
+    fn from(value: na::Quaternion<float!()>) -> Self {
+        Self::new(value.i, value.j, value.k, value.w)
+    }
+}
+
+impl From<Quaternion> for na::Quaternion<float!()> {
+    fn from(value: Quaternion) -> Self {
+        Self::new(value.w, value.x, value.y, value.z)
+    }
+}
+
+impl From<na::UnitQuaternion<float!()>> for Quaternion {
+    fn from(value: na::UnitQuaternion<float!()>) -> Self {
+        value.into_inner().into()
+    }
+}
+
+/// Normalizes the quaternion, as required by [`na::UnitQuaternion`].
+impl From<Quaternion> for na::UnitQuaternion<float!()> {
+    fn from(value: Quaternion) -> Self {
+        Self::new_normalize(value.into())
+    }
+}
+
+impl From<na::Matrix3<float!()>> for Basis {
+    fn from(value: na::Matrix3<float!()>) -> Self {
+        Self::new_from_floats(
+            value.m11, value.m12, value.m13, value.m21, value.m22, value.m23, value.m31,
+            value.m32, value.m33,
+        )
+    }
+}
+
+impl From<Basis> for na::Matrix3<float!()> {
+    fn from(value: Basis) -> Self {
+        Self::from_columns(&[value.x().into(), value.y().into(), value.z().into()])
+    }
+}
+
+/// Reads the top two rows of the homogeneous matrix, the bottom row is ignored.
+impl From<na::Matrix3<float!()>> for Transform2D {
+    fn from(value: na::Matrix3<float!()>) -> Self {
+        Self::new(
+            Vector2::new(value.m11, value.m21),
+            Vector2::new(value.m12, value.m22),
+            Vector2::new(value.m13, value.m23),
+        )
+    }
+}
+
+impl From<Transform2D> for na::Matrix3<float!()> {
+    fn from(value: Transform2D) -> Self {
+        Self::new(
+            value.x.x, value.y.x, value.origin.x, value.x.y, value.y.y, value.origin.y, 0.0, 0.0,
+            1.0,
+        )
+    }
+}
+
+impl From<na::Affine2<float!()>> for Transform2D {
+    fn from(value: na::Affine2<float!()>) -> Self {
+        value.into_inner().into()
+    }
+}
+
+impl From<Transform2D> for na::Affine2<float!()> {
+    fn from(value: Transform2D) -> Self {
+        Self::from_matrix_unchecked(value.into())
+    }
+}
+
+/// Reads the top three rows of the homogeneous matrix, the bottom row is ignored.
+impl From<na::Matrix4<float!()>> for Transform3D {
+    fn from(value: na::Matrix4<float!()>) -> Self {
+        Self::new(
+            value.fixed_view::<3, 3>(0, 0).into_owned().into(),
+            Vector3::new(value.m14, value.m24, value.m34),
+        )
+    }
+}
+
+impl From<Transform3D> for na::Matrix4<float!()> {
+    fn from(value: Transform3D) -> Self {
+        na::Matrix3::from(value.basis)
+            .to_homogeneous()
+            .append_translation(&value.origin.into())
+    }
+}
+
+impl From<na::Affine3<float!()>> for Transform3D {
+    fn from(value: na::Affine3<float!()>) -> Self {
+        value.into_inner().into()
+    }
+}
+
+impl From<Transform3D> for na::Affine3<float!()> {
+    fn from(value: Transform3D) -> Self {
+        Self::from_matrix_unchecked(value.into())
+    }
+}
@@ -1,2 +1,29 @@
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+// The operator macros of `auto_ops` expand to `::std::ops` paths, which `core` provides just as well.
+#[cfg(not(feature = "std"))]
+extern crate core as std;
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("huginn needs either the `std` feature or, for `no_std` targets, the `libm` feature for its float math.");
+
+/// A module containing traits and assertion macros for approximate equality with custom tolerances.
+pub mod approx;
+/// The bounds shared by the `arbitrary` and `proptest` generators, so both produce the same range of values.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod generators;
+mod interop;
+/// A module containing the encoding and decoding of huginn types in the binary format of Godot's `var_to_bytes` and `bytes_to_var`.
+pub mod marshalls;
+/// Float math that can be made bit-identical across platforms. Every transcendental function huginn uses goes through this module, and huginn never calls `mul_add`, so with the `deterministic` feature the same inputs give the same bits everywhere.
+mod math;
+/// A module containing [`proptest`](::proptest) strategies for every huginn type, which respect the invariants of the type they generate: normalized vectors have a length of `1`, bases are rotations, transforms are invertible, rects have a non-negative size and colors are in the `0..=1` range. The types also implement [`Arbitrary`](::proptest::arbitrary::Arbitrary) with these strategies.
+#[cfg(feature = "proptest")]
+pub mod proptest;
 pub mod types;
 pub mod utils;
+/// A module containing the formatting and parsing of huginn types in Godot's text format, used by `var_to_str`, `str_to_var` and the `.tscn` and `.tres` files.
+pub mod variant_parser;
@@ -0,0 +1,413 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Color, Quaternion, Rect2, Rect2i, Transform2D, Transform3D};
+use crate::utils::{float, int};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// The header flag that marks the floats of a value as 64-bit. Godot sets it when it's built with double precision.
+pub const ENCODE_FLAG_64: u32 = 1 << 16;
+
+const HEADER_TYPE_MASK: u32 = 0xFF;
+const HEADER_SIZE: usize = 4;
+
+/// The reasons why a buffer can't be decoded with [`Variant::decode_var`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The buffer ends before the value does. `needed` is the number of bytes the value takes, header included, and `available` is the length of the buffer.
+    Truncated { needed: usize, available: usize },
+    /// The header holds the Variant type `found` instead of the type `expected` by the decoded type (see [`Variant::TYPE`]).
+    UnexpectedType { expected: u32, found: u32 },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Truncated { needed, available } => write!(
+                f,
+                "Truncated buffer: the value needs {} bytes, but only {} are available.",
+                needed, available
+            ),
+            Self::UnexpectedType { expected, found } => write!(
+                f,
+                "Unexpected Variant type: expected {}, found {}.",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// A type that can be encoded to and decoded from the binary format of Godot's `encode_variant` and `decode_variant`, which `var_to_bytes`, `bytes_to_var` and `PacketPeer` use.
+///
+/// Every value starts with a 4-byte little-endian header holding its Variant type (see [`Variant::TYPE`]), followed by its components in little-endian order. Float types are encoded with 64-bit floats and the [`ENCODE_FLAG_64`] flag with the `double-precision-float` feature, and with 32-bit floats otherwise. Both widths are decoded in every build. [`Color`] always uses 32-bit floats, like in Godot.
+///
+/// Integer types are always encoded with 32-bit integers, since Godot's integer vectors are 32-bit. With the `double-precision-int` feature, components outside of the `i32` range are clamped.
+pub trait Variant: Sized {
+    /// The Variant type id that Godot writes in the low byte of the header, for example `5` for [`Vector2`].
+    const TYPE: u32;
+
+    /// Appends this value, header included, to `buffer`.
+    fn encode_var(&self, buffer: &mut Vec<u8>);
+
+    /// Decodes a value from the start of `bytes`, and returns it with the number of bytes it took. The remaining bytes are ignored.
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError>;
+}
+
+/// Returns `value` encoded with [`Variant::encode_var`], like Godot's `var_to_bytes`.
+pub fn var_to_bytes<T: Variant>(value: &T) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    value.encode_var(&mut buffer);
+    buffer
+}
+
+/// Decodes a value from the start of `bytes` with [`Variant::decode_var`], like Godot's `bytes_to_var`.
+pub fn bytes_to_var<T: Variant>(bytes: &[u8]) -> Result<T, DecodeError> {
+    T::decode_var(bytes).map(|(value, _)| value)
+}
+
+/// Reads the header at the start of `bytes`, and returns whether the value has the [`ENCODE_FLAG_64`] flag.
+fn decode_header(bytes: &[u8], expected: u32) -> Result<bool, DecodeError> {
+    check_length(bytes, HEADER_SIZE)?;
+    let header = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let found = header & HEADER_TYPE_MASK;
+    if found != expected {
+        return Err(DecodeError::UnexpectedType { expected, found });
+    }
+    Ok(header & ENCODE_FLAG_64 != 0)
+}
+
+fn check_length(bytes: &[u8], needed: usize) -> Result<(), DecodeError> {
+    if bytes.len() < needed {
+        Err(DecodeError::Truncated {
+            needed,
+            available: bytes.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "double-precision-float"))]
+fn real_from_f32(value: f32) -> float!() {
+    value
+}
+
+#[cfg(feature = "double-precision-float")]
+fn real_from_f32(value: f32) -> float!() {
+    value.into()
+}
+
+#[cfg(not(feature = "double-precision-float"))]
+fn real_from_f64(value: f64) -> float!() {
+    value as f32
+}
+
+#[cfg(feature = "double-precision-float")]
+fn real_from_f64(value: f64) -> float!() {
+    value
+}
+
+#[cfg(not(feature = "double-precision-float"))]
+fn real_to_f32(value: float!()) -> f32 {
+    value
+}
+
+#[cfg(feature = "double-precision-float")]
+fn real_to_f32(value: float!()) -> f32 {
+    value as f32
+}
+
+#[cfg(not(feature = "double-precision-int"))]
+fn int_from_i32(value: i32) -> int!() {
+    value
+}
+
+#[cfg(feature = "double-precision-int")]
+fn int_from_i32(value: i32) -> int!() {
+    value.into()
+}
+
+#[cfg(not(feature = "double-precision-int"))]
+fn int_to_i32(value: int!()) -> i32 {
+    value
+}
+
+/// Clamps `value` to the `i32` range of Godot's integer vectors.
+#[cfg(feature = "double-precision-int")]
+fn int_to_i32(value: int!()) -> i32 {
+    value.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+}
+
+fn encode_reals(buffer: &mut Vec<u8>, variant_type: u32, values: &[float!()]) {
+    #[cfg(feature = "double-precision-float")]
+    let header = variant_type | ENCODE_FLAG_64;
+    #[cfg(not(feature = "double-precision-float"))]
+    let header = variant_type;
+    buffer.extend_from_slice(&header.to_le_bytes());
+    for value in values {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn decode_reals<const N: usize>(
+    bytes: &[u8],
+    variant_type: u32,
+) -> Result<([float!(); N], usize), DecodeError> {
+    let is_64 = decode_header(bytes, variant_type)?;
+    let size = if is_64 { 8 } else { 4 };
+    let needed = HEADER_SIZE + N * size;
+    check_length(bytes, needed)?;
+
+    let mut values = [0.0; N];
+    for (value, chunk) in values
+        .iter_mut()
+        .zip(bytes[HEADER_SIZE..needed].chunks_exact(size))
+    {
+        *value = if is_64 {
+            real_from_f64(f64::from_le_bytes(chunk.try_into().unwrap()))
+        } else {
+            real_from_f32(f32::from_le_bytes(chunk.try_into().unwrap()))
+        };
+    }
+    Ok((values, needed))
+}
+
+fn encode_ints(buffer: &mut Vec<u8>, variant_type: u32, values: &[int!()]) {
+    buffer.extend_from_slice(&variant_type.to_le_bytes());
+    for &value in values {
+        buffer.extend_from_slice(&int_to_i32(value).to_le_bytes());
+    }
+}
+
+fn decode_ints<const N: usize>(
+    bytes: &[u8],
+    variant_type: u32,
+) -> Result<([int!(); N], usize), DecodeError> {
+    decode_header(bytes, variant_type)?;
+    let needed = HEADER_SIZE + N * 4;
+    check_length(bytes, needed)?;
+
+    let mut values = [0; N];
+    for (value, chunk) in values
+        .iter_mut()
+        .zip(bytes[HEADER_SIZE..needed].chunks_exact(4))
+    {
+        *value = int_from_i32(i32::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    Ok((values, needed))
+}
+
+impl Variant for Vector2 {
+    const TYPE: u32 = 5;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_reals(buffer, Self::TYPE, &[self.x, self.y]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y], length) = decode_reals(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y), length))
+    }
+}
+
+impl Variant for Vector2i {
+    const TYPE: u32 = 6;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_ints(buffer, Self::TYPE, &[self.x, self.y]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y], length) = decode_ints(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y), length))
+    }
+}
+
+impl Variant for Vector3 {
+    const TYPE: u32 = 9;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_reals(buffer, Self::TYPE, &[self.x, self.y, self.z]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, z], length) = decode_reals(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y, z), length))
+    }
+}
+
+impl Variant for Vector3i {
+    const TYPE: u32 = 10;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_ints(buffer, Self::TYPE, &[self.x, self.y, self.z]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, z], length) = decode_ints(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y, z), length))
+    }
+}
+
+impl Variant for Vector4 {
+    const TYPE: u32 = 12;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_reals(buffer, Self::TYPE, &[self.x, self.y, self.z, self.w]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, z, w], length) = decode_reals(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y, z, w), length))
+    }
+}
+
+impl Variant for Vector4i {
+    const TYPE: u32 = 13;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_ints(buffer, Self::TYPE, &[self.x, self.y, self.z, self.w]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, z, w], length) = decode_ints(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y, z, w), length))
+    }
+}
+
+impl Variant for Rect2 {
+    const TYPE: u32 = 7;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        let (position, size) = (self.position(), self.size());
+        encode_reals(buffer, Self::TYPE, &[position.x, position.y, size.x, size.y]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, width, height], length) = decode_reals(bytes, Self::TYPE)?;
+        Ok((Self::new(Vector2::new(x, y), Vector2::new(width, height)), length))
+    }
+}
+
+impl Variant for Rect2i {
+    const TYPE: u32 = 8;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        let (position, size) = (self.position(), self.size());
+        encode_ints(buffer, Self::TYPE, &[position.x, position.y, size.x, size.y]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, width, height], length) = decode_ints(bytes, Self::TYPE)?;
+        Ok((Self::new_from_dimension(x, y, width, height), length))
+    }
+}
+
+impl Variant for Transform2D {
+    const TYPE: u32 = 11;
+
+    /// The columns are encoded in order: [`Transform2D::x`], [`Transform2D::y`] and [`Transform2D::origin`].
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_reals(
+            buffer,
+            Self::TYPE,
+            &[self.x.x, self.x.y, self.y.x, self.y.y, self.origin.x, self.origin.y],
+        );
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([xx, xy, yx, yy, ox, oy], length) = decode_reals(bytes, Self::TYPE)?;
+        Ok((Self::new_from_floats(xx, xy, yx, yy, ox, oy), length))
+    }
+}
+
+/// Returns the rows of `basis`, in the order Godot encodes them.
+fn basis_values(basis: &Basis) -> [float!(); 9] {
+    [
+        basis.x.x, basis.x.y, basis.x.z, basis.y.x, basis.y.y, basis.y.z, basis.z.x, basis.z.y,
+        basis.z.z,
+    ]
+}
+
+fn basis_from_values(values: &[float!()]) -> Basis {
+    Basis::new_rows(
+        Vector3::new(values[0], values[1], values[2]),
+        Vector3::new(values[3], values[4], values[5]),
+        Vector3::new(values[6], values[7], values[8]),
+    )
+}
+
+impl Variant for Basis {
+    const TYPE: u32 = 17;
+
+    /// The rows are encoded in order, like in Godot.
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_reals(buffer, Self::TYPE, &basis_values(self));
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (values, length) = decode_reals::<9>(bytes, Self::TYPE)?;
+        Ok((basis_from_values(&values), length))
+    }
+}
+
+impl Variant for Transform3D {
+    const TYPE: u32 = 18;
+
+    /// The rows of the basis are encoded first, then the origin.
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        let mut values = [0.0; 12];
+        values[..9].copy_from_slice(&basis_values(&self.basis));
+        values[9..].copy_from_slice(&[self.origin.x, self.origin.y, self.origin.z]);
+        encode_reals(buffer, Self::TYPE, &values);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (values, length) = decode_reals::<12>(bytes, Self::TYPE)?;
+        let origin = Vector3::new(values[9], values[10], values[11]);
+        Ok((Self::new(basis_from_values(&values), origin), length))
+    }
+}
+
+impl Variant for Quaternion {
+    const TYPE: u32 = 15;
+
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        encode_reals(buffer, Self::TYPE, &[self.x, self.y, self.z, self.w]);
+    }
+
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let ([x, y, z, w], length) = decode_reals(bytes, Self::TYPE)?;
+        Ok((Self::new(x, y, z, w), length))
+    }
+}
+
+impl Variant for Color {
+    const TYPE: u32 = 20;
+
+    /// The components are always encoded with 32-bit floats, without the [`ENCODE_FLAG_64`] flag.
+    fn encode_var(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&Self::TYPE.to_le_bytes());
+        for value in [self.r(), self.g(), self.b(), self.a()] {
+            buffer.extend_from_slice(&real_to_f32(value).to_le_bytes());
+        }
+    }
+
+    /// The components are always decoded as 32-bit floats, whatever the flags of the header.
+    fn decode_var(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        decode_header(bytes, Self::TYPE)?;
+        let needed = HEADER_SIZE + 4 * 4;
+        check_length(bytes, needed)?;
+
+        let mut values = [0.0; 4];
+        for (value, chunk) in values
+            .iter_mut()
+            .zip(bytes[HEADER_SIZE..needed].chunks_exact(4))
+        {
+            *value = real_from_f32(f32::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let [r, g, b, a] = values;
+        Ok((Self::rgba(r, g, b, a), needed))
+    }
+}
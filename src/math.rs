@@ -0,0 +1,128 @@
+/// The float methods that `core` doesn't provide without `std`, backed by `libm`.
+///
+/// Import it with `#[cfg(not(any(feature = "std", test)))]` where these methods are used: whenever `std` is linked, including by the test harness, the inherent methods take precedence. They are exact, so they don't depend on the platform.
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) trait FloatMath {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+}
+
+#[cfg(not(any(feature = "std", test)))]
+macro_rules! impl_float_math {
+    ($type:ty) => {
+        impl FloatMath for $type {
+            fn floor(self) -> Self {
+                libm::Libm::<$type>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                libm::Libm::<$type>::ceil(self)
+            }
+
+            fn round(self) -> Self {
+                libm::Libm::<$type>::round(self)
+            }
+
+            fn trunc(self) -> Self {
+                libm::Libm::<$type>::trunc(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(any(feature = "std", test)))]
+impl_float_math!(f32);
+#[cfg(not(any(feature = "std", test)))]
+impl_float_math!(f64);
+
+/// The float types the functions of this module accept.
+///
+/// Without `std`, or with the `deterministic` feature, the functions call `libm`, a pure-software implementation that gives the same bits on every target. Otherwise they call the `std` methods, which may use platform intrinsics whose last bits differ between targets.
+pub(crate) trait Real: Copy {
+    fn sqrt(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($type:ty, $($method:ident($($arg:ident),*) => $libm:ident),+ $(,)?) => {
+        impl Real for $type {
+            $(
+                #[inline]
+                fn $method(self $(, $arg: Self)*) -> Self {
+                    #[cfg(any(feature = "deterministic", not(feature = "std")))]
+                    {
+                        libm::Libm::<$type>::$libm(self $(, $arg)*)
+                    }
+                    #[cfg(all(feature = "std", not(feature = "deterministic")))]
+                    {
+                        <$type>::$method(self $(, $arg)*)
+                    }
+                }
+            )+
+        }
+    };
+}
+
+impl_real!(
+    f32,
+    sqrt() => sqrt,
+    powf(n) => pow,
+    sin() => sin,
+    cos() => cos,
+    asin() => asin,
+    acos() => acos,
+    atan2(other) => atan2,
+);
+
+impl_real!(
+    f64,
+    sqrt() => sqrt,
+    powf(n) => pow,
+    sin() => sin,
+    cos() => cos,
+    asin() => asin,
+    acos() => acos,
+    atan2(other) => atan2,
+);
+
+/// Returns the square root of `x`.
+pub(crate) fn sqrt<T: Real>(x: T) -> T {
+    x.sqrt()
+}
+
+/// Returns `x` raised to the power of `n`.
+pub(crate) fn powf<T: Real>(x: T, n: T) -> T {
+    x.powf(n)
+}
+
+/// Returns the sine of `x`, in radians.
+pub(crate) fn sin<T: Real>(x: T) -> T {
+    x.sin()
+}
+
+/// Returns the cosine of `x`, in radians.
+pub(crate) fn cos<T: Real>(x: T) -> T {
+    x.cos()
+}
+
+/// Returns the arc sine of `x`, in radians.
+pub(crate) fn asin<T: Real>(x: T) -> T {
+    x.asin()
+}
+
+/// Returns the arc cosine of `x`, in radians.
+pub(crate) fn acos<T: Real>(x: T) -> T {
+    x.acos()
+}
+
+/// Returns the arc tangent of `y / x`, in radians, using the signs of both to find the quadrant.
+pub(crate) fn atan2<T: Real>(y: T, x: T) -> T {
+    y.atan2(x)
+}
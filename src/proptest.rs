@@ -0,0 +1,202 @@
+use crate::types::hex::Hex;
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{Basis, Color, Plane, Quaternion, Rect2, Rect2i, Transform2D, Transform3D, AABB};
+use crate::utils::{float, float_consts, int};
+use ::proptest::arbitrary::Arbitrary;
+use ::proptest::prelude::*;
+use ::proptest::strategy::BoxedStrategy;
+use core::ops::RangeInclusive;
+
+pub use crate::generators::{COMPONENT_LIMIT, INT_COMPONENT_LIMIT, SCALE_LIMIT};
+
+fn unit() -> RangeInclusive<float!()> {
+    0.0..=1.0
+}
+
+fn signed_unit() -> RangeInclusive<float!()> {
+    -1.0..=1.0
+}
+
+/// Generates a float in `-COMPONENT_LIMIT..=COMPONENT_LIMIT`.
+pub fn component() -> impl Strategy<Value = float!()> {
+    -COMPONENT_LIMIT..=COMPONENT_LIMIT
+}
+
+/// Generates a scale factor, with a magnitude in `0.1..=SCALE_LIMIT` and either sign, so it never collapses an axis.
+pub fn scale_factor() -> impl Strategy<Value = float!()> {
+    (0.1..=SCALE_LIMIT, any::<bool>()).prop_map(|(scale, negative)| {
+        if negative {
+            -scale
+        } else {
+            scale
+        }
+    })
+}
+
+/// Generates a [`Vector2`] with components in `-COMPONENT_LIMIT..=COMPONENT_LIMIT`.
+pub fn vector2() -> impl Strategy<Value = Vector2> {
+    (component(), component()).prop_map(|(x, y)| Vector2::new(x, y))
+}
+
+/// Generates a [`Vector3`] with components in `-COMPONENT_LIMIT..=COMPONENT_LIMIT`.
+pub fn vector3() -> impl Strategy<Value = Vector3> {
+    (component(), component(), component()).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+/// Generates a [`Vector4`] with components in `-COMPONENT_LIMIT..=COMPONENT_LIMIT`.
+pub fn vector4() -> impl Strategy<Value = Vector4> {
+    (component(), component(), component(), component())
+        .prop_map(|(x, y, z, w)| Vector4::new(x, y, z, w))
+}
+
+/// Generates a [`Vector2i`] with any components.
+pub fn vector2i() -> impl Strategy<Value = Vector2i> {
+    any::<[int!(); 2]>().prop_map(Vector2i::from)
+}
+
+/// Generates a [`Vector3i`] with any components.
+pub fn vector3i() -> impl Strategy<Value = Vector3i> {
+    any::<[int!(); 3]>().prop_map(Vector3i::from)
+}
+
+/// Generates a [`Vector4i`] with any components.
+pub fn vector4i() -> impl Strategy<Value = Vector4i> {
+    any::<[int!(); 4]>().prop_map(Vector4i::from)
+}
+
+/// Generates a [`Vector2`] of length `1`, uniformly distributed on the unit circle.
+pub fn normalized_vector2() -> impl Strategy<Value = Vector2> {
+    (-float_consts::PI..float_consts::PI).prop_map(Vector2::from_angle)
+}
+
+/// Generates a [`Vector3`] of length `1`, uniformly distributed on the unit sphere.
+pub fn normalized_vector3() -> impl Strategy<Value = Vector3> {
+    (signed_unit(), signed_unit(), signed_unit())
+        .prop_map(|(x, y, z)| Vector3::new(x, y, z))
+        .prop_filter("the vector must be inside the unit ball", |v| {
+            (0.01..=1.0).contains(&v.length_squared())
+        })
+        .prop_map(|v| v.normalized())
+}
+
+/// Generates a unit [`Quaternion`], uniformly distributed over the rotations.
+pub fn unit_quaternion() -> impl Strategy<Value = Quaternion> {
+    (signed_unit(), signed_unit(), signed_unit(), signed_unit())
+        .prop_map(|(x, y, z, w)| Quaternion::new(x, y, z, w))
+        .prop_filter("the quaternion must be inside the unit ball", |q| {
+            (0.01..=1.0).contains(&q.length_squared())
+        })
+        .prop_map(|q| q.normalized())
+}
+
+/// Generates an orthonormal [`Basis`] without reflection, i.e. a pure rotation.
+pub fn orthonormal_basis() -> impl Strategy<Value = Basis> {
+    unit_quaternion().prop_map(|q| Basis::from(&q))
+}
+
+/// Generates an invertible [`Transform2D`], combining a rotation, a non-zero scale, a skew in `-1.0..=1.0` radians and a translation.
+pub fn invertible_transform2d() -> impl Strategy<Value = Transform2D> {
+    (
+        -float_consts::PI..float_consts::PI,
+        (scale_factor(), scale_factor()),
+        signed_unit(),
+        vector2(),
+    )
+        .prop_map(|(rotation, (sx, sy), skew, origin)| {
+            Transform2D::from((rotation, Vector2::new(sx, sy), skew, origin))
+        })
+}
+
+/// Generates an invertible [`Transform3D`], combining a rotation, a non-zero scale and a translation.
+pub fn invertible_transform3d() -> impl Strategy<Value = Transform3D> {
+    (
+        unit_quaternion(),
+        (scale_factor(), scale_factor(), scale_factor()),
+        vector3(),
+    )
+        .prop_map(|(rotation, (sx, sy, sz), origin)| {
+            Transform3D::new(Basis::from((&rotation, &Vector3::new(sx, sy, sz))), origin)
+        })
+}
+
+/// Generates a [`Rect2`] with a non-negative size.
+pub fn rect2() -> impl Strategy<Value = Rect2> {
+    (vector2(), 0.0..=COMPONENT_LIMIT, 0.0..=COMPONENT_LIMIT)
+        .prop_map(|(position, w, h)| Rect2::new(position, Vector2::new(w, h)))
+}
+
+/// Generates a [`Rect2i`] with a non-negative size, with components bounded by [`INT_COMPONENT_LIMIT`].
+pub fn rect2i() -> impl Strategy<Value = Rect2i> {
+    (
+        -INT_COMPONENT_LIMIT..=INT_COMPONENT_LIMIT,
+        -INT_COMPONENT_LIMIT..=INT_COMPONENT_LIMIT,
+        0..=INT_COMPONENT_LIMIT,
+        0..=INT_COMPONENT_LIMIT,
+    )
+        .prop_map(|(x, y, w, h)| Rect2i::new_from_dimension(x, y, w, h))
+}
+
+/// Generates an [`AABB`] with a non-negative size.
+pub fn aabb() -> impl Strategy<Value = AABB> {
+    (
+        vector3(),
+        0.0..=COMPONENT_LIMIT,
+        0.0..=COMPONENT_LIMIT,
+        0.0..=COMPONENT_LIMIT,
+    )
+        .prop_map(|(position, w, h, d)| AABB::new(position, Vector3::new(w, h, d)))
+}
+
+/// Generates a [`Plane`] with a normalized normal.
+pub fn plane() -> impl Strategy<Value = Plane> {
+    (normalized_vector3(), component()).prop_map(|(normal, d)| Plane::new(normal, d))
+}
+
+/// Generates a [`Color`] with every component, including alpha, in `0.0..=1.0`.
+pub fn color() -> impl Strategy<Value = Color> {
+    (unit(), unit(), unit(), unit())
+        .prop_map(|(r, g, b, a)| Color::rgba(r, g, b, a))
+}
+
+/// Generates a [`Hex`] with coordinates bounded by [`INT_COMPONENT_LIMIT`].
+pub fn hex() -> impl Strategy<Value = Hex> {
+    (
+        -INT_COMPONENT_LIMIT..=INT_COMPONENT_LIMIT,
+        -INT_COMPONENT_LIMIT..=INT_COMPONENT_LIMIT,
+    )
+        .prop_map(|(q, r)| Hex::new(q, r))
+}
+
+macro_rules! impl_arbitrary {
+    ($($type:ty => $strategy:ident),+ $(,)?) => {
+        $(
+            impl Arbitrary for $type {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    $strategy().boxed()
+                }
+            }
+        )+
+    };
+}
+
+impl_arbitrary!(
+    Vector2 => vector2,
+    Vector3 => vector3,
+    Vector4 => vector4,
+    Vector2i => vector2i,
+    Vector3i => vector3i,
+    Vector4i => vector4i,
+    Quaternion => unit_quaternion,
+    Basis => orthonormal_basis,
+    Transform2D => invertible_transform2d,
+    Transform3D => invertible_transform3d,
+    Rect2 => rect2,
+    Rect2i => rect2i,
+    AABB => aabb,
+    Plane => plane,
+    Color => color,
+    Hex => hex,
+);
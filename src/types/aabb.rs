@@ -0,0 +1,391 @@
+use crate::float;
+use crate::types::vectors::Vector3;
+use crate::types::Plane;
+use core::fmt::{Display, Formatter};
+use core::mem::swap;
+use core::ops::Not;
+
+/// A 3D axis-aligned bounding box.
+///
+/// **AABB** represents an axis-aligned bounding box in a 3D space. It is defined by its `position` and `size`, which are [`Vector3`]. It is frequently used for fast overlap tests (see [`intersects`](AABB::intersects)). Although **AABB** itself is axis-aligned, it can be combined with [`Transform3D`](crate::types::Transform3D) to represent a rotated or skewed bounding box.
+///
+/// The 2D equivalent to **AABB** is [`Rect2`](crate::types::Rect2).
+///
+/// **Note:** Negative values for `size` are not supported. With negative size, most **AABB** methods do not work correctly. Use [`abs`](AABB::abs) to get an equivalent **AABB** with a non-negative size.
+///
+/// **Note:** In a boolean context, an **AABB** evaluates to `false` if both `position` and `size` are zero (equal to [`Vector3::ZERO`]). Otherwise, it always evaluates to `true`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AABB {
+    position: Vector3,
+    size: Vector3,
+}
+
+impl AABB {
+    /// Constructs an **AABB** by `position` and `size`.
+    pub const fn new(position: Vector3, size: Vector3) -> Self {
+        Self { position, size }
+    }
+
+    /// Returns an **AABB** equivalent to this bounding box, with its width, height, and depth modified to be non-negative values.
+    ///
+    /// ```
+    /// # use huginn::types::AABB;
+    /// # use huginn::types::vectors::Vector3;
+    /// let box_ = AABB::new(Vector3::new(5.0, 0.0, 5.0), Vector3::new(-20.0, -10.0, -5.0));
+    /// let absolute = box_.abs();
+    ///
+    /// assert_eq!(absolute.position(), Vector3::new(-15.0, -10.0, 0.0));
+    /// assert_eq!(absolute.size(), Vector3::new(20.0, 10.0, 5.0));
+    /// ```
+    ///
+    /// **Note:** It's recommended to use this method when `size` is negative, as most other methods assume that the `size`'s components are greater than `0`.
+    pub fn abs(&self) -> Self {
+        Self::new(self.position + self.size.min_f(0.0), self.size.abs())
+    }
+
+    /// Returns `true` if this bounding box *completely* encloses the `b` box. The edges of both boxes are included.
+    pub fn encloses(&self, b: &Self) -> bool {
+        let src_min = self.position;
+        let src_max = self.end();
+        let dst_min = b.position;
+        let dst_max = b.end();
+
+        src_min.x <= dst_min.x
+            && src_max.x >= dst_max.x
+            && src_min.y <= dst_min.y
+            && src_max.y >= dst_max.y
+            && src_min.z <= dst_min.z
+            && src_max.z >= dst_max.z
+    }
+
+    pub(crate) fn expand_to(&mut self, to: &Vector3) {
+        let begin = self.position.min(to);
+        let end = self.end().max(to);
+        self.position = begin;
+        self.size = end - begin;
+    }
+
+    /// Returns a copy of this bounding box expanded to align the edges with the given `to` point, if necessary.
+    pub fn expand(&self, to: &Vector3) -> Self {
+        let mut aabb = *self;
+        aabb.expand_to(to);
+        aabb
+    }
+
+    /// Returns the center point of the bounding box. This is the same as `position + (size / 2.0)`.
+    pub fn get_center(&self) -> Vector3 {
+        self.position + (self.size / 2.0)
+    }
+
+    /// Returns the position of one of the 8 vertices that compose this bounding box. With an `idx` of `0` this is the same as `position`, and an `idx` of `7` is the same as `end`.
+    ///
+    /// Bit `2` of `idx` selects the X coordinate, bit `1` the Y coordinate and bit `0` the Z coordinate, each taken from `end` if set and from `position` otherwise.
+    pub fn get_endpoint(&self, idx: usize) -> Vector3 {
+        if idx > 7 {
+            panic!("Invalid index");
+        }
+        let end = self.end();
+        Vector3::new(
+            if idx & 4 != 0 { end.x } else { self.position.x },
+            if idx & 2 != 0 { end.y } else { self.position.y },
+            if idx & 1 != 0 { end.z } else { self.position.z },
+        )
+    }
+
+    /// Returns the longest normalized axis of this bounding box's `size`, as a [`Vector3`] ([`Vector3::RIGHT`], [`Vector3::UP`], or [`Vector3::BACK`]). See also [`get_longest_axis_size`](AABB::get_longest_axis_size).
+    pub fn get_longest_axis(&self) -> Vector3 {
+        let mut axis = Vector3::RIGHT;
+        let mut max_size = self.size.x;
+        if self.size.y > max_size {
+            axis = Vector3::UP;
+            max_size = self.size.y;
+        }
+        if self.size.z > max_size {
+            axis = Vector3::BACK;
+        }
+        axis
+    }
+
+    /// Returns the longest dimension of this bounding box's `size`.
+    pub fn get_longest_axis_size(&self) -> float!() {
+        self.size.x.max(self.size.y).max(self.size.z)
+    }
+
+    /// Returns the shortest normalized axis of this bounding box's `size`, as a [`Vector3`] ([`Vector3::RIGHT`], [`Vector3::UP`], or [`Vector3::BACK`]). See also [`get_shortest_axis_size`](AABB::get_shortest_axis_size).
+    pub fn get_shortest_axis(&self) -> Vector3 {
+        let mut axis = Vector3::RIGHT;
+        let mut min_size = self.size.x;
+        if self.size.y < min_size {
+            axis = Vector3::UP;
+            min_size = self.size.y;
+        }
+        if self.size.z < min_size {
+            axis = Vector3::BACK;
+        }
+        axis
+    }
+
+    /// Returns the shortest dimension of this bounding box's `size`.
+    pub fn get_shortest_axis_size(&self) -> float!() {
+        self.size.x.min(self.size.y).min(self.size.z)
+    }
+
+    /// Returns the vertex's position of this bounding box that's the farthest in the given direction. This point is commonly known as the support point in collision detection algorithms.
+    pub fn get_support(&self, direction: &Vector3) -> Vector3 {
+        let mut support = self.position;
+        if direction.x > 0.0 {
+            support.x += self.size.x;
+        }
+        if direction.y > 0.0 {
+            support.y += self.size.y;
+        }
+        if direction.z > 0.0 {
+            support.z += self.size.z;
+        }
+        support
+    }
+
+    /// Returns the bounding box's volume. This is equivalent to `size.x * size.y * size.z`. See also [`has_volume`](AABB::has_volume).
+    pub fn get_volume(&self) -> float!() {
+        self.size.x * self.size.y * self.size.z
+    }
+
+    /// Returns a copy of this bounding box extended on all sides by the given `amount`. A negative `amount` shrinks the box instead.
+    pub fn grow(&self, amount: float!()) -> Self {
+        Self::new(
+            self.position - Vector3::new(amount, amount, amount),
+            self.size + Vector3::new(amount, amount, amount) * 2.0,
+        )
+    }
+
+    /// Returns `true` if the bounding box contains the given `point`. By convention, points exactly on the right, top, and front sides are **not** included.
+    ///
+    /// **Note:** This method is not reliable for **AABB** with a *negative* `size`. Use [`abs`](AABB::abs) first to get a valid bounding box.
+    pub fn has_point(&self, point: &Vector3) -> bool {
+        let end = self.end();
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.z >= self.position.z
+            && point.x < end.x
+            && point.y < end.y
+            && point.z < end.z
+    }
+
+    /// Returns `true` if this bounding box has a surface or a length, that is, at least one component of `size` is greater than `0`. Otherwise, returns `false`.
+    pub fn has_surface(&self) -> bool {
+        self.size.x > 0.0 || self.size.y > 0.0 || self.size.z > 0.0
+    }
+
+    /// Returns `true` if this bounding box's width, height, and depth are all positive. See also [`get_volume`](AABB::get_volume).
+    pub fn has_volume(&self) -> bool {
+        self.size.x > 0.0 && self.size.y > 0.0 && self.size.z > 0.0
+    }
+
+    /// Returns the intersection between this bounding box and `with`. If the boxes do not intersect, returns an empty **AABB**. If the boxes intersect at the edge, returns a flat **AABB** with no volume (see [`has_surface`](AABB::has_surface) and [`has_volume`](AABB::has_volume)).
+    ///
+    /// **Note:** If you only need to know whether two bounding boxes are intersecting, use [`intersects`](AABB::intersects), instead.
+    pub fn intersection(&self, with: &Self) -> Self {
+        let src_min = self.position;
+        let src_max = self.end();
+        let dst_min = with.position;
+        let dst_max = with.end();
+
+        if src_min.x > dst_max.x
+            || src_max.x < dst_min.x
+            || src_min.y > dst_max.y
+            || src_max.y < dst_min.y
+            || src_min.z > dst_max.z
+            || src_max.z < dst_min.z
+        {
+            return Self::default();
+        }
+
+        let min = src_min.max(&dst_min);
+        let max = src_max.min(&dst_max);
+        Self::new(min, max - min)
+    }
+
+    /// Returns `true` if this bounding box overlaps with the box `with`. The edges of both boxes are *always* excluded.
+    pub fn intersects(&self, with: &Self) -> bool {
+        let end = self.end();
+        let with_end = with.end();
+        !(self.position.x >= with_end.x
+            || end.x <= with.position.x
+            || self.position.y >= with_end.y
+            || end.y <= with.position.y
+            || self.position.z >= with_end.z
+            || end.z <= with.position.z)
+    }
+
+    /// Returns `true` if this bounding box is on both sides of the given `plane`.
+    pub fn intersects_plane(&self, plane: &Plane) -> bool {
+        let mut over = false;
+        let mut under = false;
+        for i in 0..8 {
+            if plane.distance_to(&self.get_endpoint(i)) > 0.0 {
+                over = true;
+            } else {
+                under = true;
+            }
+        }
+        under && over
+    }
+
+    /// Returns the first point where this bounding box and the given ray intersect, as a [`Vector3`]. If no intersection occurs, returns [`None`].
+    ///
+    /// The ray begins at `from`, faces `dir` and extends towards infinity. If the ray starts inside the box, `from` is returned.
+    pub fn intersects_ray(&self, from: &Vector3, dir: &Vector3) -> Option<Vector3> {
+        let end = self.end();
+        let mut tmin: float!() = -1e20;
+        let mut tmax: float!() = 1e20;
+
+        for i in 0..3 {
+            if dir.get(i) == 0.0 {
+                if from.get(i) < self.position.get(i) || from.get(i) > end.get(i) {
+                    return None;
+                }
+            } else {
+                // The ray is not parallel to the planes in this direction.
+                let mut t1 = (self.position.get(i) - from.get(i)) / dir.get(i);
+                let mut t2 = (end.get(i) - from.get(i)) / dir.get(i);
+                if t1 > t2 {
+                    swap(&mut t1, &mut t2);
+                }
+                if t1 >= tmin {
+                    tmin = t1;
+                }
+                if t2 < tmax {
+                    if t2 < 0.0 {
+                        return None;
+                    }
+                    tmax = t2;
+                }
+                if tmin > tmax {
+                    return None;
+                }
+            }
+        }
+
+        // When starting inside the box the entry point is behind the ray, so the ray origin is returned instead.
+        if tmin < 0.0 {
+            Some(*from)
+        } else {
+            Some(from + dir * tmin)
+        }
+    }
+
+    /// Returns the first point where this bounding box and the given segment intersect, as a [`Vector3`]. If no intersection occurs, returns [`None`].
+    ///
+    /// The segment begins at `from` and ends at `to`.
+    pub fn intersects_segment(&self, from: &Vector3, to: &Vector3) -> Option<Vector3> {
+        let mut min: float!() = 0.0;
+        let mut max: float!() = 1.0;
+
+        for i in 0..3 {
+            let seg_from = from.get(i);
+            let seg_to = to.get(i);
+            let box_begin = self.position.get(i);
+            let box_end = box_begin + self.size.get(i);
+            let length = seg_to - seg_from;
+
+            let (cmin, cmax) = if seg_from < seg_to {
+                if seg_from > box_end || seg_to < box_begin {
+                    return None;
+                }
+                (
+                    if seg_from < box_begin { (box_begin - seg_from) / length } else { 0.0 },
+                    if seg_to > box_end { (box_end - seg_from) / length } else { 1.0 },
+                )
+            } else {
+                if seg_to > box_end || seg_from < box_begin {
+                    return None;
+                }
+                (
+                    if seg_from > box_end { (box_end - seg_from) / length } else { 0.0 },
+                    if seg_to < box_begin { (box_begin - seg_from) / length } else { 1.0 },
+                )
+            };
+
+            min = min.max(cmin);
+            max = max.min(cmax);
+            if max < min {
+                return None;
+            }
+        }
+
+        Some(from + (to - from) * min)
+    }
+
+    /// Returns `true` if this bounding box and `aabb` are approximately equal, by calling [`Vector3::is_equal_approx`] on the `position` and the `size`.
+    pub fn is_equal_approx(&self, aabb: &Self) -> bool {
+        self.position.is_equal_approx(&aabb.position) && self.size.is_equal_approx(&aabb.size)
+    }
+
+    /// Returns `true` if this bounding box's values are finite, by calling [`Vector3::is_finite`] on the `position` and the `size`.
+    pub fn is_finite(&self) -> bool {
+        self.position.is_finite() && self.size.is_finite()
+    }
+
+    /// Returns an **AABB** that encloses both this bounding box and `with` around the edges. See also [`encloses`](AABB::encloses).
+    pub fn merge(&self, with: &Self) -> Self {
+        let min = self.position.min(&with.position);
+        let max = self.end().max(&with.end());
+        Self::new(min, max - min)
+    }
+
+    /// The ending point. This is usually the corner on the top-right and back of the bounding box, and is equivalent to `position + size`. Setting this point affects the `size`.
+    pub fn end(&self) -> Vector3 {
+        self.position + self.size
+    }
+
+    pub fn set_end(&mut self, end: Vector3) {
+        self.size = end - self.position;
+    }
+
+    /// The origin point. This is usually the corner on the bottom-left and forward of the bounding box.
+    pub fn position(&self) -> Vector3 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: Vector3) {
+        self.position = position;
+    }
+
+    /// The bounding box's width, height, and depth starting from `position`. Setting this value also affects the `end` point.
+    ///
+    /// **Note:** It's recommended setting the width, height, and depth to non-negative values. This is because most methods assume that the `position` is the bottom-left-forward corner, and the `end` is the top-right-back corner. To get an equivalent bounding box with non-negative size, use [`abs`](AABB::abs).
+    pub fn size(&self) -> Vector3 {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: Vector3) {
+        self.size = size;
+    }
+}
+
+impl PartialEq for AABB {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.size == other.size
+    }
+}
+
+impl Display for AABB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "[P: ({}, {}, {}), S: ({}, {}, {})]",
+            self.position.x,
+            self.position.y,
+            self.position.z,
+            self.size.x,
+            self.size.y,
+            self.size.z
+        ))
+    }
+}
+
+impl Not for AABB {
+    type Output = bool;
+    fn not(self) -> Self::Output {
+        self.position == Vector3::ZERO && self.size == Vector3::ZERO
+    }
+}
@@ -1,3 +1,4 @@
+use crate::math;
 use crate::types::vectors::Vector3;
 use crate::types::EulerOrder;
 use crate::types::Quaternion;
@@ -6,7 +7,7 @@ use crate::utils::{
     FloatExt, CMP_EPSILON, UNIT_EPSILON,
 };
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::mem::swap;
+use core::mem::swap;
 
 /// A 3×3 matrix for representing 3D rotation and scale.
 ///
@@ -101,7 +102,7 @@ impl Basis {
                         float_consts::FRAC_1_SQRT_2,
                     )
                 } else {
-                    let x = xx.sqrt();
+                    let x = math::sqrt(xx);
                     (x, xy / x, xz / x)
                 }
             } else if yy > zz {
@@ -113,7 +114,7 @@ impl Basis {
                         float_consts::FRAC_1_SQRT_2,
                     )
                 } else {
-                    let y = yy.sqrt();
+                    let y = math::sqrt(yy);
                     (xy / y, y, yz / y)
                 }
             } else {
@@ -125,7 +126,7 @@ impl Basis {
                         0.0,
                     )
                 } else {
-                    let z = zz.sqrt();
+                    let z = math::sqrt(zz);
                     (xz / z, yz / z, z)
                 }
             };
@@ -133,10 +134,11 @@ impl Basis {
             return;
         }
         // As we have reached here there are no singularities so we can handle normally.
-        let mut s = ((self.z.y - self.y.z) * (self.z.y - self.y.z)
-            + (self.x.z - self.z.x) * (self.x.z - self.z.x)
-            + (self.y.x - self.x.y) * (self.y.x - self.x.y))
-            .sqrt(); // Used to normalize.
+        let mut s = math::sqrt(
+            (self.z.y - self.y.z) * (self.z.y - self.y.z)
+                + (self.x.z - self.z.x) * (self.x.z - self.z.x)
+                + (self.y.x - self.x.y) * (self.y.x - self.x.y),
+        ); // Used to normalize.
 
         if s.abs() < CMP_EPSILON {
             // Prevent divide by zero, should not happen if matrix is orthogonal and should be caught by singularity test above.
@@ -149,12 +151,6 @@ impl Basis {
 
         // safe_acos does clamping.
         *angle = ((self.x.x + self.y.y + self.z.z - 1.0) / 2.0).safe_acos();
-        if angle.is_nan() {
-            println!(
-                "!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!{}!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!",
-                angle
-            );
-        }
     }
 
     fn is_diagonal(&self) -> bool {
@@ -297,16 +293,16 @@ impl Basis {
     }
 
     fn set_euler(&mut self, euler: &Vector3, order: EulerOrder) {
-        let mut c = euler.x.cos();
-        let mut s = euler.x.sin();
+        let mut c = math::cos(euler.x);
+        let mut s = math::sin(euler.x);
         let x_mat = Self::new_from_floats(1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c);
 
-        c = euler.y.cos();
-        s = euler.y.sin();
+        c = math::cos(euler.y);
+        s = math::sin(euler.y);
         let y_mat = Self::new_from_floats(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c);
 
-        c = euler.z.cos();
-        s = euler.z.sin();
+        c = math::cos(euler.z);
+        s = math::sin(euler.z);
         let z_mat = Self::new_from_floats(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0);
 
         let basis = match order {
@@ -382,19 +378,19 @@ impl Basis {
                             && self.y.y == 1.0
                         {
                             // return the simplest form (human friendlier in editor and scripts)
-                            Vector3::new(0.0, self.x.z.atan2(self.x.x), 0.0)
+                            Vector3::new(0.0, math::atan2(self.x.z, self.x.x), 0.0)
                         } else {
                             Vector3::new(
-                                (-self.y.z).atan2(self.z.z),
-                                sy.asin(),
-                                (-self.x.y).atan2(self.x.x),
+                                math::atan2(-self.y.z, self.z.z),
+                                math::asin(sy),
+                                math::atan2(-self.x.y, self.x.x),
                             )
                         }
                     } else {
-                        Vector3::new(self.z.y.atan2(self.y.y), -float_consts::PI / 2.0, 0.0)
+                        Vector3::new(math::atan2(self.z.y, self.y.y), -float_consts::PI / 2.0, 0.0)
                     }
                 } else {
-                    Vector3::new(self.z.y.atan2(self.y.y), float_consts::PI / 2.0, 0.0)
+                    Vector3::new(math::atan2(self.z.y, self.y.y), float_consts::PI / 2.0, 0.0)
                 }
             }
             EulerOrder::XZY => {
@@ -409,17 +405,17 @@ impl Basis {
                 if sz < (1.0 - CMP_EPSILON) {
                     if sz > -(1.0 - CMP_EPSILON) {
                         Vector3::new(
-                            self.z.y.atan2(self.y.y),
-                            self.x.z.atan2(self.x.x),
-                            (-sz).asin(),
+                            math::atan2(self.z.y, self.y.y),
+                            math::atan2(self.x.z, self.x.x),
+                            math::asin(-sz),
                         )
                     } else {
                         // It's -1
-                        Vector3::new(-self.y.z.atan2(self.z.z), 0.0, float_consts::PI / 2.0)
+                        Vector3::new(-math::atan2(self.y.z, self.z.z), 0.0, float_consts::PI / 2.0)
                     }
                 } else {
                     // It's 1
-                    Vector3::new(-self.y.z.atan2(self.z.z), 0.0, -float_consts::PI / 2.0)
+                    Vector3::new(-math::atan2(self.y.z, self.z.z), 0.0, -float_consts::PI / 2.0)
                 }
             }
             EulerOrder::YXZ => {
@@ -442,21 +438,21 @@ impl Basis {
                             && self.x.x == 1.0
                         {
                             // return the simplest form (human friendlier in editor and scripts)
-                            Vector3::new((-m12).atan2(self.y.y), 0.0, 0.0)
+                            Vector3::new(math::atan2(-m12, self.y.y), 0.0, 0.0)
                         } else {
                             Vector3::new(
-                                (-m12).asin(),
-                                self.x.z.atan2(self.z.z),
-                                self.y.x.atan2(self.y.y),
+                                math::asin(-m12),
+                                math::atan2(self.x.z, self.z.z),
+                                math::atan2(self.y.x, self.y.y),
                             )
                         }
                     } else {
                         // It's -1
-                        Vector3::new(float_consts::PI / 2.0, self.x.y.atan2(self.x.x), 0.0)
+                        Vector3::new(float_consts::PI / 2.0, math::atan2(self.x.y, self.x.x), 0.0)
                     }
                 } else {
                     // It's 1
-                    Vector3::new(-float_consts::PI / 2.0, -self.x.y.atan2(self.x.x), 0.0)
+                    Vector3::new(-float_consts::PI / 2.0, -math::atan2(self.x.y, self.x.x), 0.0)
                 }
             }
             EulerOrder::YZX => {
@@ -471,17 +467,17 @@ impl Basis {
                 if sz < (1.0 - CMP_EPSILON) {
                     if sz > -(1.0 - CMP_EPSILON) {
                         Vector3::new(
-                            (-self.y.z).atan2(self.y.y),
-                            (-self.z.x).atan2(self.x.x),
-                            sz.asin(),
+                            math::atan2(-self.y.z, self.y.y),
+                            math::atan2(-self.z.x, self.x.x),
+                            math::asin(sz),
                         )
                     } else {
                         // It's -1
-                        Vector3::new(self.z.y.atan2(self.z.z), 0.0, -float_consts::PI / 2.0)
+                        Vector3::new(math::atan2(self.z.y, self.z.z), 0.0, -float_consts::PI / 2.0)
                     }
                 } else {
                     // It's 1
-                    Vector3::new(self.z.y.atan2(self.z.z), 0.0, float_consts::PI / 2.0)
+                    Vector3::new(math::atan2(self.z.y, self.z.z), 0.0, float_consts::PI / 2.0)
                 }
             }
             EulerOrder::ZXY => {
@@ -496,17 +492,17 @@ impl Basis {
                 if sx < (1.0 - CMP_EPSILON) {
                     if sx > -(1.0 - CMP_EPSILON) {
                         Vector3::new(
-                            sx.asin(),
-                            (-self.z.x).atan2(self.z.z),
-                            (-self.x.y).atan2(self.y.y),
+                            math::asin(sx),
+                            math::atan2(-self.z.x, self.z.z),
+                            math::atan2(-self.x.y, self.y.y),
                         )
                     } else {
                         // It's -1
-                        Vector3::new(-float_consts::PI / 2.0, self.x.z.atan2(self.x.x), 0.0)
+                        Vector3::new(-float_consts::PI / 2.0, math::atan2(self.x.z, self.x.x), 0.0)
                     }
                 } else {
                     // It's 1
-                    Vector3::new(float_consts::PI / 2.0, self.x.z.atan2(self.x.x), 0.0)
+                    Vector3::new(float_consts::PI / 2.0, math::atan2(self.x.z, self.x.x), 0.0)
                 }
             }
             EulerOrder::ZYX => {
@@ -521,17 +517,17 @@ impl Basis {
                 if sy < (1.0 - CMP_EPSILON) {
                     if sy > -(1.0 - CMP_EPSILON) {
                         Vector3::new(
-                            self.z.y.atan2(self.z.z),
-                            (-sy).asin(),
-                            self.y.x.atan2(self.x.x),
+                            math::atan2(self.z.y, self.z.z),
+                            math::asin(-sy),
+                            math::atan2(self.y.x, self.x.x),
                         )
                     } else {
                         // It's -1
-                        Vector3::new(0.0, float_consts::PI / 2.0, -self.x.y.atan2(self.y.y))
+                        Vector3::new(0.0, float_consts::PI / 2.0, -math::atan2(self.x.y, self.y.y))
                     }
                 } else {
                     // It's 1
-                    Vector3::new(0.0, -float_consts::PI / 2.0, -self.x.y.atan2(self.y.y))
+                    Vector3::new(0.0, -float_consts::PI / 2.0, -math::atan2(self.x.y, self.y.y))
                 }
             }
         }
@@ -544,7 +540,7 @@ impl Basis {
         let mut temp = [0.0; 4];
 
         if trace > 0.0 {
-            let mut s = (trace + 1.0).sqrt();
+            let mut s = math::sqrt(trace + 1.0);
             temp[3] = s * 0.5;
             s = 0.5 / s;
 
@@ -567,7 +563,7 @@ impl Basis {
             let k = (i + 2) % 3;
 
             let mut s =
-                (m.get_row(i).get(i) - m.get_row(j).get(j) - m.get_row(k).get(k) + 1.0).sqrt();
+                math::sqrt(m.get_row(i).get(i) - m.get_row(j).get(j) - m.get_row(k).get(k) + 1.0);
             temp[i] = s * 0.5;
             s = 0.5 / s;
 
@@ -911,12 +907,12 @@ impl Basis {
 
     fn set_axis_angle(&mut self, axis: &Vector3, angle: float!()) {
         let axis_sq = Vector3::new(axis.x * axis.x, axis.y * axis.y, axis.z * axis.z);
-        let cosine = angle.cos();
+        let cosine = math::cos(angle);
         self.x.x = axis_sq.x + cosine * (1.0 - axis_sq.x);
         self.y.y = axis_sq.y + cosine * (1.0 - axis_sq.y);
         self.z.z = axis_sq.z + cosine * (1.0 - axis_sq.z);
 
-        let sine = angle.sin();
+        let sine = math::sin(angle);
         let t = 1.0 - cosine;
 
         let mut xyzt = axis.x * axis.y * t;
@@ -968,6 +964,157 @@ impl Basis {
     }
 }
 
+impl Basis {
+    // Row-major copy of the matrix, easier to index in the decomposition below.
+    fn to_array(self) -> [[float!(); 3]; 3] {
+        [
+            [self.x.x, self.x.y, self.x.z],
+            [self.y.x, self.y.y, self.y.z],
+            [self.z.x, self.z.y, self.z.z],
+        ]
+    }
+
+    /// Returns the eigenvalues and eigenvectors of this basis, which must be symmetric (equal to its [`Basis::transposed`]), such as an inertia tensor or a covariance matrix. Only the upper triangle of the matrix is read.
+    ///
+    /// The eigenvalues are returned as a [`Vector3`] sorted from largest to smallest. The matching eigenvectors are the columns (`x`, `y`, and `z`) of the returned basis, which is always a rotation (orthonormal with a determinant of `1`). For an inertia tensor, these are the principal moments and the principal axes.
+    ///
+    /// The result is computed with cyclic [Jacobi rotations](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm), which stay accurate even when eigenvalues are repeated.
+    /// ```
+    /// # use huginn::types::Basis;
+    /// # use huginn::types::vectors::Vector3;
+    /// let tensor = Basis::new_from_floats(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0);
+    /// let (values, vectors) = tensor.symmetric_eigen();
+    ///
+    /// assert!(values.is_equal_approx(&Vector3::new(5.0, 3.0, 1.0)));
+    /// assert!((tensor.xform(&vectors.y()) - vectors.y() * values.y).is_zero_approx());
+    /// ```
+    pub fn symmetric_eigen(&self) -> (Vector3, Basis) {
+        const MAX_SWEEPS: usize = 32;
+
+        let mut a = self.to_array();
+        a[1][0] = a[0][1];
+        a[2][0] = a[0][2];
+        a[2][1] = a[1][2];
+        let mut v = Basis::IDENTITY.to_array();
+
+        let norm_2: float!() = a.iter().flatten().map(|e| e * e).sum();
+        let tolerance = norm_2 * <float!()>::EPSILON * <float!()>::EPSILON;
+
+        for _ in 0..MAX_SWEEPS {
+            let off_2 = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off_2 <= tolerance {
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // Rotation in the (p, q) plane that zeroes a[p][q], using the numerically stable smaller root.
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + math::sqrt(theta * theta + 1.0));
+                let c = 1.0 / math::sqrt(t * t + 1.0);
+                let s = t * c;
+
+                // A = J^T * A * J
+                for row in a.iter_mut() {
+                    let (rp, rq) = (row[p], row[q]);
+                    row[p] = c * rp - s * rq;
+                    row[q] = s * rp + c * rq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = core::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = core::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                // V = V * J
+                for row in v.iter_mut() {
+                    let (rp, rq) = (row[p], row[q]);
+                    row[p] = c * rp - s * rq;
+                    row[q] = s * rp + c * rq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| a[j][j].total_cmp(&a[i][i]));
+
+        let values = Vector3::new(a[order[0]][order[0]], a[order[1]][order[1]], a[order[2]][order[2]]);
+        let mut vectors = Basis::IDENTITY;
+        for (column, &i) in order.iter().enumerate() {
+            vectors.set_column(column, &Vector3::new(v[0][i], v[1][i], v[2][i]));
+        }
+        if vectors.determinant() < 0.0 {
+            vectors.set_column(2, &-vectors.get_column(2));
+        }
+        (values, vectors)
+    }
+
+    /// Diagonalizes this basis, which must be symmetric (see [`Basis::symmetric_eigen`]). Returns the diagonal basis `d` and the rotation `r` such that `self == r * d * r.transposed()`, approximately.
+    ///
+    /// The diagonal of `d` holds the eigenvalues from largest to smallest, and the columns of `r` are the matching eigenvectors.
+    pub fn diagonalize(&self) -> (Basis, Basis) {
+        let (values, vectors) = self.symmetric_eigen();
+        (Basis::from_scale(&values), vectors)
+    }
+
+    /// Returns the [singular value decomposition](https://en.wikipedia.org/wiki/Singular_value_decomposition) of this basis as `(u, sigma, v)`, such that `self == u * Basis::from_scale(&sigma) * v.transposed()`, approximately.
+    ///
+    /// Both `u` and `v` are rotations (orthonormal with a determinant of `1`). The singular values in `sigma` are sorted by decreasing magnitude; if this basis contains a reflection (its [`Basis::determinant`] is negative), the last one is negative so that no reflection has to be stored in `u` or `v`.
+    ///
+    /// This works on any basis, including sheared, reflected and degenerate ones.
+    pub fn svd(&self) -> (Basis, Vector3, Basis) {
+        // The right singular vectors are the eigenvectors of the symmetric M^T * M.
+        let (_, v) = (self.transposed() * self).symmetric_eigen();
+
+        // The columns of B = M * V are orthogonal, with the singular values as lengths. Orthonormalize them (a QR decomposition) to get U without dividing by tiny singular values.
+        let b0 = self.xform(&v.get_column(0));
+        let b1 = self.xform(&v.get_column(1));
+        let b2 = self.xform(&v.get_column(2));
+
+        let u0 = if b0.is_zero_approx() {
+            Vector3::RIGHT
+        } else {
+            b0.normalized()
+        };
+        let mut u1 = b1 - u0 * u0.dot(&b1);
+        if u1.is_zero_approx() {
+            // Any direction perpendicular to u0 will do.
+            u1 = if u0.x.abs() < 0.9 {
+                Vector3::RIGHT
+            } else {
+                Vector3::UP
+            };
+            u1 -= u0 * u0.dot(&u1);
+        }
+        let u1 = u1.normalized();
+        let u2 = u0.cross(&u1);
+
+        let sigma = Vector3::new(u0.dot(&b0), u1.dot(&b1), u2.dot(&b2));
+        let mut u = Basis::IDENTITY;
+        u.set_columns(&u0, &u1, &u2);
+        (u, sigma, v)
+    }
+
+    /// Returns the [polar decomposition](https://en.wikipedia.org/wiki/Polar_decomposition) of this basis as `(rotation, stretch)`, such that `self == rotation * stretch`, approximately.
+    ///
+    /// `rotation` is the closest rotation to this basis (orthonormal with a determinant of `1`), and `stretch` is a symmetric basis holding the scale and shear, expressed in the local frame. If this basis contains a reflection, it is kept in `stretch`, which then has a negative determinant.
+    ///
+    /// Unlike [`Basis::orthonormalized`] and [`Basis::get_rotation_quaternion`], the result does not depend on the order of the axes, and is reliable on sheared and reflected bases.
+    /// ```
+    /// # use huginn::types::Basis;
+    /// # use huginn::types::vectors::Vector3;
+    /// let rotation = Basis::from((&Vector3::UP, 0.5));
+    /// let shear = Basis::new_from_floats(2.0, 0.5, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 3.0);
+    /// let (r, s) = (rotation * shear).polar_decompose();
+    ///
+    /// assert!(r.is_equal_approx(&rotation));
+    /// assert!(s.is_equal_approx(&shear));
+    /// ```
+    pub fn polar_decompose(&self) -> (Basis, Basis) {
+        let (u, sigma, v) = self.svd();
+        let v_t = v.transposed();
+        (u * v_t, v * Basis::from_scale(&sigma) * v_t)
+    }
+}
+
 impl PartialEq for Basis {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y && self.z == other.z
@@ -977,8 +1124,7 @@ impl PartialEq for Basis {
 impl Eq for Basis {}
 
 impl_op_ex!(*|a: &Basis, b: &Basis| -> Basis {
-    println!("basis before: {:?}", a);
-    let basis = Basis::new_from_floats(
+    Basis::new_from_floats(
         b.t_dot_x(&a.x),
         b.t_dot_y(&a.x),
         b.t_dot_z(&a.x),
@@ -988,17 +1134,13 @@ impl_op_ex!(*|a: &Basis, b: &Basis| -> Basis {
         b.t_dot_x(&a.z),
         b.t_dot_y(&a.z),
         b.t_dot_z(&a.z),
-    );
-    println!("basis: {:?}", basis);
-    basis
+    )
 });
 impl_op_ex!(*= |a: &mut Basis, b: &Basis| {
-    println!("basis before: {:?}", a);
     //a.x.x = b.t_dot_x(&a.x); a.x.y = b.t_dot_y(&a.x); a.x.z = b.t_dot_z(&a.x);
     //a.y.x = b.t_dot_x(&a.y); a.y.y = b.t_dot_y(&a.y); a.y.z = b.t_dot_z(&a.y);
     //a.z.x = b.t_dot_x(&a.z); a.z.y = b.t_dot_y(&a.z); a.z.z = b.t_dot_z(&a.z);
     *a = *a * b;
-    println!("basis: {:?}", a);
 });
 //TODO: impl_op_ex_commutative!(*|a: &Basis, b: &Vector3| -> Vector3 { todo!() });
 impl_op_ex_commutative!(*|a: &Basis, b: &float!()| -> Basis {
@@ -0,0 +1,578 @@
+use crate::types::vectors::Vector2;
+use crate::types::{Rect2, Transform2D};
+use crate::utils::{float, CMP_EPSILON};
+use alloc::vec::Vec;
+
+/// The smallest alignment between a face normal and the contact normal for the face to be used as a reference face of a two-point manifold.
+const FACE_ALIGNMENT: float!() = 0.999;
+
+/// The distance under which a shape cast considers the shapes to be touching.
+const CAST_TOLERANCE: float!() = 0.0001;
+
+/// The maximum number of conservative advancement steps of a shape cast.
+const CAST_ITERATIONS: usize = 32;
+
+/// A 2D shape that can be tested for overlap against other shapes, once placed in the world by a [`Transform2D`].
+///
+/// Every shape is described as a convex *core*, a point, a segment or a convex polygon, expanded in every direction by a *radius*. A circle is a point with a radius, a capsule is a segment with a radius, and polygons and rectangles have a radius of `0.0`. This lets every pair of shapes go through the same separating axis test.
+///
+/// **Note:** Radii are not scaled by the transform. Shapes should be placed with transforms made of rotations and translations only (see [`Transform2D::orthonormalized`]).
+pub trait Shape2D {
+    /// Returns the points of the convex core of the shape, placed by `xform`.
+    fn get_core(&self, xform: &Transform2D) -> Vec<Vector2>;
+
+    /// Returns the distance the core of the shape is expanded by.
+    fn get_radius(&self) -> float!();
+
+    /// Returns how this shape, placed by `xform`, overlaps the `other` shape placed by `other_xform`, or [`None`] if they don't touch. The normal of the [`ContactManifold2D`] points from this shape toward `other`.
+    ///
+    /// ```
+    /// # use huginn::types::collision2d::{Circle, Shape2D};
+    /// # use huginn::types::vectors::Vector2;
+    /// # use huginn::types::Transform2D;
+    /// let circle = Circle::new(1.0);
+    /// let other = Transform2D::IDENTITY.translated(&Vector2::new(1.5, 0.0));
+    /// let contact = circle.collide(&Transform2D::IDENTITY, &circle, &other).unwrap();
+    /// assert_eq!(contact.normal, Vector2::RIGHT);
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert_eq!(contact.points(), [Vector2::new(0.75, 0.0)]);
+    /// ```
+    fn collide(
+        &self,
+        xform: &Transform2D,
+        other: &dyn Shape2D,
+        other_xform: &Transform2D,
+    ) -> Option<ContactManifold2D> {
+        collide(&Core::of(self, xform), &Core::of(other, other_xform))
+    }
+
+    /// Returns `true` if this shape, placed by `xform`, touches the `other` shape placed by `other_xform`. See also [`collide`](Shape2D::collide).
+    fn intersects(
+        &self,
+        xform: &Transform2D,
+        other: &dyn Shape2D,
+        other_xform: &Transform2D,
+    ) -> bool {
+        self.collide(xform, other, other_xform).is_some()
+    }
+
+    /// Moves this shape, placed by `xform`, along `motion`, and returns where it first touches the `other` shape placed by `other_xform`, or [`None`] if it doesn't touch it before the end of the motion.
+    ///
+    /// If the shapes already overlap, the hit is at a [`time`](ShapeCast2D::time) of `0.0`.
+    ///
+    /// ```
+    /// # use huginn::types::collision2d::{Circle, Shape2D};
+    /// # use huginn::types::vectors::Vector2;
+    /// # use huginn::types::{Rect2, Transform2D};
+    /// let wall = Rect2::new_from_dimension(10.0, -5.0, 2.0, 10.0);
+    /// let hit = Circle::new(1.0)
+    ///     .cast(&Transform2D::IDENTITY, &Vector2::new(20.0, 0.0), &wall, &Transform2D::IDENTITY)
+    ///     .unwrap();
+    /// assert_eq!(hit.time, 0.45);
+    /// assert_eq!(hit.normal, Vector2::LEFT);
+    /// ```
+    fn cast(
+        &self,
+        xform: &Transform2D,
+        motion: &Vector2,
+        other: &dyn Shape2D,
+        other_xform: &Transform2D,
+    ) -> Option<ShapeCast2D> {
+        cast(Core::of(self, xform), motion, &Core::of(other, other_xform))
+    }
+}
+
+/// How two overlapping shapes touch, see [`Shape2D::collide`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ContactManifold2D {
+    /// The direction, normalized, in which the second shape must move to stop overlapping the first.
+    pub normal: Vector2,
+    /// The distance the second shape must move along `normal` to stop overlapping the first. It's `0.0` if the shapes only touch.
+    pub depth: float!(),
+    points: [Vector2; 2],
+    point_count: usize,
+}
+
+impl ContactManifold2D {
+    /// Returns the contact points, halfway between the surfaces of the two shapes. There are two of them when an edge of a shape lies against an edge of the other, and one otherwise.
+    pub fn points(&self) -> &[Vector2] {
+        &self.points[..self.point_count]
+    }
+
+    fn push(&mut self, point: Vector2) {
+        self.points[self.point_count] = point;
+        self.point_count += 1;
+    }
+}
+
+/// Where a moving shape first touches another, see [`Shape2D::cast`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShapeCast2D {
+    /// The fraction of the motion travelled when the shapes first touch, between `0.0` and `1.0`.
+    pub time: float!(),
+    /// The point where the shapes touch.
+    pub point: Vector2,
+    /// The normal of the surface of the other shape at `point`, pointing toward the moving shape.
+    pub normal: Vector2,
+}
+
+/// A circle centered on the origin of its transform.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Circle {
+    /// The radius of the circle.
+    pub radius: float!(),
+}
+
+impl Circle {
+    /// Constructs a **Circle** of the given `radius`.
+    pub const fn new(radius: float!()) -> Self {
+        Self { radius }
+    }
+}
+
+impl Shape2D for Circle {
+    fn get_core(&self, xform: &Transform2D) -> Vec<Vector2> {
+        alloc::vec![xform.get_origin()]
+    }
+
+    fn get_radius(&self) -> float!() {
+        self.radius
+    }
+}
+
+/// A capsule centered on the origin of its transform and aligned with its Y axis: a rectangle capped by two half circles.
+///
+/// Like Godot's `CapsuleShape2D`, `height` is the full height of the capsule, caps included. A `height` smaller than twice the `radius` gives a circle.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Capsule2D {
+    /// The radius of the capsule, and of its caps.
+    pub radius: float!(),
+    /// The height of the capsule, caps included.
+    pub height: float!(),
+}
+
+impl Capsule2D {
+    /// Constructs a **Capsule2D** of the given `radius` and `height`.
+    pub const fn new(radius: float!(), height: float!()) -> Self {
+        Self { radius, height }
+    }
+}
+
+impl Shape2D for Capsule2D {
+    fn get_core(&self, xform: &Transform2D) -> Vec<Vector2> {
+        let half = (self.height * 0.5 - self.radius).max(0.0);
+        if half == 0.0 {
+            return alloc::vec![xform.get_origin()];
+        }
+        alloc::vec![
+            xform.xform(&Vector2::new(0.0, -half)),
+            xform.xform(&Vector2::new(0.0, half)),
+        ]
+    }
+
+    fn get_radius(&self) -> float!() {
+        self.radius
+    }
+}
+
+/// A segment between the points `a` and `b`, with no thickness.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Segment2D {
+    /// The first point of the segment.
+    pub a: Vector2,
+    /// The second point of the segment.
+    pub b: Vector2,
+}
+
+impl Segment2D {
+    /// Constructs a **Segment2D** between `a` and `b`.
+    pub const fn new(a: Vector2, b: Vector2) -> Self {
+        Self { a, b }
+    }
+}
+
+impl Shape2D for Segment2D {
+    fn get_core(&self, xform: &Transform2D) -> Vec<Vector2> {
+        alloc::vec![xform.xform(&self.a), xform.xform(&self.b)]
+    }
+
+    fn get_radius(&self) -> float!() {
+        0.0
+    }
+}
+
+/// A convex polygon.
+///
+/// The points may be given in either winding order, but must form a convex polygon. Use [`ConvexPolygon2D::from_point_cloud`] to build the convex hull of arbitrary points.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConvexPolygon2D {
+    points: Vec<Vector2>,
+}
+
+impl ConvexPolygon2D {
+    /// Constructs a **ConvexPolygon2D** from the `points` of a convex polygon, in order.
+    pub fn new(points: Vec<Vector2>) -> Self {
+        Self { points }
+    }
+
+    /// Constructs a **ConvexPolygon2D** from the convex hull of `points`, counter-clockwise on screen (Y down). Points inside the hull, and on its edges, are dropped.
+    ///
+    /// ```
+    /// # use huginn::types::collision2d::ConvexPolygon2D;
+    /// # use huginn::types::vectors::Vector2;
+    /// let polygon = ConvexPolygon2D::from_point_cloud(&[
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(2.0, 2.0),
+    ///     Vector2::new(0.0, 2.0),
+    /// ]);
+    /// assert_eq!(polygon.points().len(), 4);
+    /// ```
+    pub fn from_point_cloud(points: &[Vector2]) -> Self {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return Self::new(sorted);
+        }
+
+        // Andrew's monotone chain, building the lower hull and then the upper hull.
+        let mut hull: Vec<Vector2> = Vec::with_capacity(sorted.len() + 1);
+        for pass in 0..2 {
+            let start = hull.len();
+            for &point in sorted.iter() {
+                while hull.len() >= start + 2 {
+                    let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                    if (b - a).cross(&(point - a)) > 0.0 {
+                        break;
+                    }
+                    hull.pop();
+                }
+                hull.push(point);
+            }
+            hull.pop();
+            if pass == 0 {
+                sorted.reverse();
+            }
+        }
+        Self::new(hull)
+    }
+
+    /// Returns the points of the polygon, in order.
+    pub fn points(&self) -> &[Vector2] {
+        &self.points
+    }
+}
+
+impl Shape2D for ConvexPolygon2D {
+    fn get_core(&self, xform: &Transform2D) -> Vec<Vector2> {
+        self.points.iter().map(|point| xform.xform(point)).collect()
+    }
+
+    fn get_radius(&self) -> float!() {
+        0.0
+    }
+}
+
+impl Shape2D for Rect2 {
+    fn get_core(&self, xform: &Transform2D) -> Vec<Vector2> {
+        let (position, end) = (self.position(), self.end());
+        alloc::vec![
+            xform.xform(&position),
+            xform.xform(&Vector2::new(end.x, position.y)),
+            xform.xform(&end),
+            xform.xform(&Vector2::new(position.x, end.y)),
+        ]
+    }
+
+    fn get_radius(&self) -> float!() {
+        0.0
+    }
+}
+
+/// A face of a core, with its outward normal.
+#[derive(Copy, Clone)]
+struct Face {
+    from: Vector2,
+    to: Vector2,
+    normal: Vector2,
+}
+
+/// The core of a placed shape and its radius.
+struct Core {
+    points: Vec<Vector2>,
+    center: Vector2,
+    radius: float!(),
+}
+
+impl Core {
+    fn of<S: Shape2D + ?Sized>(shape: &S, xform: &Transform2D) -> Self {
+        let mut points = shape.get_core(xform);
+        points.dedup();
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        let center = points.iter().fold(Vector2::ZERO, |sum, point| sum + point) / points.len().max(1) as float!();
+        Self {
+            points,
+            center,
+            radius: shape.get_radius().max(0.0),
+        }
+    }
+
+    fn translated(&self, offset: &Vector2) -> Self {
+        Self {
+            points: self.points.iter().map(|point| point + offset).collect(),
+            center: self.center + offset,
+            radius: self.radius,
+        }
+    }
+
+    /// Returns the faces of the core, with zero-length edges left out. A segment has two faces, one on each side, and a point has none.
+    fn faces(&self) -> impl Iterator<Item = Face> + '_ {
+        let count = match self.points.len() {
+            0 | 1 => 0,
+            2 => 2,
+            n => n,
+        };
+        (0..count).filter_map(move |i| {
+            let from = self.points[i];
+            let to = self.points[(i + 1) % self.points.len()];
+            let mut normal = (to - from).orthogonal().normalized();
+            if normal == Vector2::ZERO {
+                return None;
+            }
+            if self.points.len() == 2 {
+                // The second face of a segment is the same edge, seen from the other side.
+                normal = if i == 0 { normal } else { -normal };
+            } else if normal.dot(&(self.center - from)) > 0.0 {
+                normal = -normal;
+            }
+            Some(Face { from, to, normal })
+        })
+    }
+
+    /// Returns the edges of the core, as segments. A point is a single segment of length zero.
+    fn edges(&self) -> impl Iterator<Item = (Vector2, Vector2)> + '_ {
+        let count = match self.points.len() {
+            1 | 2 => 1,
+            n => n,
+        };
+        (0..count).map(move |i| (self.points[i], self.points[(i + 1) % self.points.len()]))
+    }
+
+    fn project(&self, axis: &Vector2) -> (float!(), float!()) {
+        self.points.iter().fold((<float!()>::INFINITY, <float!()>::NEG_INFINITY), |(min, max), point| {
+            let d = point.dot(axis);
+            (min.min(d), max.max(d))
+        })
+    }
+
+    fn support(&self, direction: &Vector2) -> Vector2 {
+        self.points.iter().copied().fold(self.points[0], |best, point| {
+            if point.dot(direction) > best.dot(direction) {
+                point
+            } else {
+                best
+            }
+        })
+    }
+
+    fn best_face(&self, direction: &Vector2) -> Option<Face> {
+        self.faces().fold(None, |best: Option<Face>, face| match best {
+            Some(best) if best.normal.dot(direction) >= face.normal.dot(direction) => Some(best),
+            _ => Some(face),
+        })
+    }
+}
+
+/// Returns the normal, from `a` toward `b`, and depth of the smallest overlap of the cores over the separating axes, or [`None`] if an axis separates them or if both are points.
+fn penetration(a: &Core, b: &Core) -> Option<(Vector2, float!())> {
+    // The face normals are enough for polygons, but segments also need their own direction, which separates collinear segments.
+    let directions = [a, b]
+        .into_iter()
+        .filter(|core| core.points.len() == 2)
+        .map(|core| (core.points[1] - core.points[0]).normalized());
+    let axes = a.faces().chain(b.faces()).map(|face| face.normal).chain(directions);
+
+    let mut best: Option<(Vector2, float!())> = None;
+    for axis in axes {
+        let (a_min, a_max) = a.project(&axis);
+        let (b_min, b_max) = b.project(&axis);
+        let forward = a_max - b_min;
+        let backward = b_max - a_min;
+        if forward < 0.0 || backward < 0.0 {
+            return None;
+        }
+        let candidate = if forward <= backward {
+            (axis, forward)
+        } else {
+            (-axis, backward)
+        };
+        if best.is_none_or(|(_, depth)| candidate.1 < depth) {
+            best = Some(candidate);
+        }
+    }
+    best
+}
+
+fn closest_on_segment(point: &Vector2, from: &Vector2, to: &Vector2) -> Vector2 {
+    let edge = to - from;
+    let length_squared = edge.length_squared();
+    if length_squared == 0.0 {
+        return *from;
+    }
+    let t = ((point - from).dot(&edge) / length_squared).clamp(0.0, 1.0);
+    from + edge * t
+}
+
+/// Returns the closest points of two disjoint cores, the first on `a` and the second on `b`.
+fn closest_points(a: &Core, b: &Core) -> (Vector2, Vector2) {
+    let mut best = (a.points[0], b.points[0]);
+    let mut best_distance = best.0.distance_squared_to(&best.1);
+    let mut consider = |pa: Vector2, pb: Vector2| {
+        let distance = pa.distance_squared_to(&pb);
+        if distance < best_distance {
+            best = (pa, pb);
+            best_distance = distance;
+        }
+    };
+    for (from, to) in b.edges() {
+        for point in &a.points {
+            consider(*point, closest_on_segment(point, &from, &to));
+        }
+    }
+    for (from, to) in a.edges() {
+        for point in &b.points {
+            consider(closest_on_segment(point, &from, &to), *point);
+        }
+    }
+    best
+}
+
+fn collide(a: &Core, b: &Core) -> Option<ContactManifold2D> {
+    if a.points.is_empty() || b.points.is_empty() {
+        return None;
+    }
+    let radius = a.radius + b.radius;
+    if let Some((normal, depth)) = penetration(a, b) {
+        return Some(manifold(a, b, normal, depth + radius, None));
+    }
+
+    let (pa, pb) = closest_points(a, b);
+    let distance = pa.distance_to(&pb);
+    if distance > radius {
+        return None;
+    }
+    let normal = if distance > CMP_EPSILON {
+        (pb - pa) / distance
+    } else {
+        // Both cores are the same point, any direction separates them.
+        Vector2::RIGHT
+    };
+    Some(manifold(a, b, normal, radius - distance, Some((pa, pb))))
+}
+
+/// Builds the manifold of overlapping cores, by clipping the incident face against the reference face when two faces lie against each other. `closest` holds the closest points of the cores if they are disjoint.
+fn manifold(
+    a: &Core,
+    b: &Core,
+    normal: Vector2,
+    depth: float!(),
+    closest: Option<(Vector2, Vector2)>,
+) -> ContactManifold2D {
+    let mut contact = ContactManifold2D {
+        normal,
+        depth,
+        points: [Vector2::ZERO; 2],
+        point_count: 0,
+    };
+
+    if let (Some(face_a), Some(face_b)) = (a.best_face(&normal), b.best_face(&-normal)) {
+        let (reference, incident, reference_radius, incident_radius) =
+            if face_b.normal.dot(&-normal) > face_a.normal.dot(&normal) + CMP_EPSILON {
+                (face_b, face_a, b.radius, a.radius)
+            } else {
+                (face_a, face_b, a.radius, b.radius)
+            };
+        if reference.normal.dot(&normal).abs() >= FACE_ALIGNMENT {
+            let tangent = (reference.to - reference.from).normalized();
+            let (low, high) = (tangent.dot(&reference.from), tangent.dot(&reference.to));
+            let (from, to) = (tangent.dot(&incident.from), tangent.dot(&incident.to));
+            if from.max(to) >= low && from.min(to) <= high {
+                for (point, along) in [(incident.from, from), (incident.to, to)] {
+                    // Slides the incident point along its face until it's between the sides of the reference face.
+                    let clamped = along.clamp(low, high);
+                    let point = if clamped != along {
+                        incident.from.lerp(&incident.to, (clamped - from) / (to - from))
+                    } else {
+                        point
+                    };
+                    let separation = (point - reference.from).dot(&reference.normal) - reference_radius - incident_radius;
+                    if separation <= CMP_EPSILON {
+                        contact.push(point - reference.normal * (incident_radius + separation * 0.5));
+                    }
+                }
+            }
+            if contact.point_count == 2 && contact.points[0].is_equal_approx(&contact.points[1]) {
+                contact.point_count = 1;
+            }
+        }
+    }
+
+    if contact.point_count == 0 {
+        let point = match closest {
+            Some((pa, pb)) => (pa + normal * a.radius + pb - normal * b.radius) * 0.5,
+            None if a.points.len() == 1 => a.points[0] + normal * (a.radius - depth * 0.5),
+            None => b.support(&-normal) - normal * (b.radius - depth * 0.5),
+        };
+        contact.push(point);
+    }
+    contact
+}
+
+/// Casts `a` along `motion` against `b` by conservative advancement: the cores are moved by the distance between the shapes divided by how fast they approach each other, which never overshoots for convex shapes.
+fn cast(a: Core, motion: &Vector2, b: &Core) -> Option<ShapeCast2D> {
+    if let Some(contact) = collide(&a, b) {
+        return Some(ShapeCast2D {
+            time: 0.0,
+            point: contact.points()[0],
+            normal: -contact.normal,
+        });
+    }
+
+    if a.points.is_empty() || b.points.is_empty() {
+        return None;
+    }
+
+    let radius = a.radius + b.radius;
+    let mut time: float!() = 0.0;
+    let mut normal = Vector2::ZERO;
+    for _ in 0..CAST_ITERATIONS {
+        let moved = a.translated(&(motion * time));
+        let (pa, pb) = closest_points(&moved, b);
+        let distance = pa.distance_to(&pb);
+        // The cores only touch once the shapes do, so the previous normal is kept if they meet exactly.
+        if distance > CMP_EPSILON {
+            normal = (pb - pa) / distance;
+        }
+        let gap = distance - radius;
+        if gap <= CAST_TOLERANCE {
+            return Some(ShapeCast2D {
+                time,
+                point: (pa + normal * a.radius + pb - normal * b.radius) * 0.5,
+                normal: -normal,
+            });
+        }
+        let approach = motion.dot(&normal);
+        if approach <= 0.0 {
+            return None;
+        }
+        time += gap / approach;
+        if time > 1.0 {
+            return None;
+        }
+    }
+    None
+}
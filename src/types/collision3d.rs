@@ -0,0 +1,748 @@
+use crate::types::vectors::Vector3;
+use crate::types::{Transform3D, AABB};
+use crate::utils::{float, CMP_EPSILON, CMP_EPSILON2};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// The maximum number of GJK iterations.
+const GJK_ITERATIONS: usize = 64;
+
+/// The maximum number of EPA iterations.
+const EPA_ITERATIONS: usize = 64;
+
+/// How close EPA has to get to the boundary of the Minkowski difference before it stops expanding.
+const EPA_TOLERANCE: float!() = 0.0001;
+
+/// A convex 3D shape described by its support function, which can be tested for overlap against other convex shapes once placed in the world by a [`Transform3D`].
+///
+/// The shape is the convex *core* described by [`get_support`](ConvexShape3D::get_support), expanded in every direction by [`get_margin`](ConvexShape3D::get_margin). A sphere is a point with a margin and a capsule is a segment with a margin, which keeps their queries exact and fast. Custom shapes only need to implement [`get_support`](ConvexShape3D::get_support), and any convex shape works with any other.
+///
+/// Overlap and distance are found with the Gilbert-Johnson-Keerthi algorithm (GJK), and the penetration of overlapping cores with the Expanding Polytope Algorithm (EPA).
+///
+/// **Note:** Margins are not scaled by the transform. Shapes with a margin should be placed with transforms made of rotations and translations only (see [`Transform3D::orthonormalized`]).
+pub trait ConvexShape3D {
+    /// Returns the point of the core that's the farthest in the given `direction`, in the local space of the shape. This point is commonly known as the support point in collision detection algorithms.
+    fn get_support(&self, direction: &Vector3) -> Vector3;
+
+    /// Returns the distance the core of the shape is expanded by. The default is `0.0`.
+    fn get_margin(&self) -> float!() {
+        0.0
+    }
+
+    /// Returns `true` if this shape, placed by `xform`, touches the `other` shape placed by `other_xform`.
+    fn intersects(
+        &self,
+        xform: &Transform3D,
+        other: &dyn ConvexShape3D,
+        other_xform: &Transform3D,
+    ) -> bool {
+        match gjk(&Placed::new(self, xform), &Placed::new(other, other_xform)) {
+            Gjk::Separated(pa, pb) => pa.distance_to(&pb) <= self.get_margin() + other.get_margin(),
+            Gjk::Overlapping(_) => true,
+        }
+    }
+
+    /// Returns the closest points of this shape, placed by `xform`, and the `other` shape placed by `other_xform`, the first on this shape and the second on `other`. Returns [`None`] if the shapes overlap.
+    fn closest_points(
+        &self,
+        xform: &Transform3D,
+        other: &dyn ConvexShape3D,
+        other_xform: &Transform3D,
+    ) -> Option<(Vector3, Vector3)> {
+        let Gjk::Separated(pa, pb) =
+            gjk(&Placed::new(self, xform), &Placed::new(other, other_xform))
+        else {
+            return None;
+        };
+        let distance = pa.distance_to(&pb);
+        let (margin_a, margin_b) = (self.get_margin(), other.get_margin());
+        if distance <= margin_a + margin_b {
+            return None;
+        }
+        let normal = (pb - pa) / distance;
+        Some((pa + normal * margin_a, pb - normal * margin_b))
+    }
+
+    /// Returns the distance between this shape, placed by `xform`, and the `other` shape placed by `other_xform`, which is `0.0` if they overlap.
+    ///
+    /// ```
+    /// # use huginn::types::collision3d::{BoxShape3D, ConvexShape3D, Sphere};
+    /// # use huginn::types::vectors::Vector3;
+    /// # use huginn::types::Transform3D;
+    /// let cube = BoxShape3D::new(Vector3::ONE);
+    /// let sphere = Transform3D::IDENTITY.translated(&Vector3::new(0.0, 3.0, 0.0));
+    /// assert_eq!(cube.distance_to(&Transform3D::IDENTITY, &Sphere::new(1.0), &sphere), 1.5);
+    /// ```
+    fn distance_to(
+        &self,
+        xform: &Transform3D,
+        other: &dyn ConvexShape3D,
+        other_xform: &Transform3D,
+    ) -> float!() {
+        match gjk(&Placed::new(self, xform), &Placed::new(other, other_xform)) {
+            Gjk::Separated(pa, pb) => {
+                (pa.distance_to(&pb) - self.get_margin() - other.get_margin()).max(0.0)
+            }
+            Gjk::Overlapping(_) => 0.0,
+        }
+    }
+
+    /// Returns how this shape, placed by `xform`, overlaps the `other` shape placed by `other_xform`, or [`None`] if they don't touch. The normal of the [`Contact3D`] points from this shape toward `other`.
+    ///
+    /// ```
+    /// # use huginn::types::collision3d::{BoxShape3D, ConvexShape3D};
+    /// # use huginn::types::vectors::Vector3;
+    /// # use huginn::types::Transform3D;
+    /// let cube = BoxShape3D::new(Vector3::new(2.0, 2.0, 2.0));
+    /// let other = Transform3D::IDENTITY.translated(&Vector3::new(0.0, 1.5, 0.0));
+    /// let contact = cube.collide(&Transform3D::IDENTITY, &cube, &other).unwrap();
+    /// assert!(contact.normal.is_equal_approx(&Vector3::UP));
+    /// assert!((contact.depth - 0.5).abs() < 1e-4);
+    /// ```
+    fn collide(
+        &self,
+        xform: &Transform3D,
+        other: &dyn ConvexShape3D,
+        other_xform: &Transform3D,
+    ) -> Option<Contact3D> {
+        let (a, b) = (Placed::new(self, xform), Placed::new(other, other_xform));
+        let (margin_a, margin_b) = (self.get_margin(), other.get_margin());
+        let (normal, core_depth, pa, pb) = match gjk(&a, &b) {
+            Gjk::Separated(pa, pb) => {
+                let distance = pa.distance_to(&pb);
+                if distance > margin_a + margin_b {
+                    return None;
+                }
+                let normal = if distance > CMP_EPSILON {
+                    (pb - pa) / distance
+                } else {
+                    fallback_normal(&a, &b, None)
+                };
+                (normal, -distance, pa, pb)
+            }
+            Gjk::Overlapping(simplex) => epa(&a, &b, *simplex),
+        };
+        Some(Contact3D {
+            normal,
+            depth: core_depth + margin_a + margin_b,
+            point: (pa + normal * margin_a + pb - normal * margin_b) * 0.5,
+        })
+    }
+}
+
+/// How two overlapping shapes touch, see [`ConvexShape3D::collide`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact3D {
+    /// The direction, normalized, in which the second shape must move to stop overlapping the first.
+    pub normal: Vector3,
+    /// The distance the second shape must move along `normal` to stop overlapping the first. It's `0.0` if the shapes only touch.
+    pub depth: float!(),
+    /// The contact point, halfway between the surfaces of the two shapes.
+    pub point: Vector3,
+}
+
+/// A sphere centered on the origin of its transform.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sphere {
+    /// The radius of the sphere.
+    pub radius: float!(),
+}
+
+impl Sphere {
+    /// Constructs a **Sphere** of the given `radius`.
+    pub const fn new(radius: float!()) -> Self {
+        Self { radius }
+    }
+}
+
+impl ConvexShape3D for Sphere {
+    fn get_support(&self, _direction: &Vector3) -> Vector3 {
+        Vector3::ZERO
+    }
+
+    fn get_margin(&self) -> float!() {
+        self.radius.max(0.0)
+    }
+}
+
+/// A capsule centered on the origin of its transform and aligned with its Y axis: a cylinder capped by two half spheres.
+///
+/// Like Godot's `CapsuleShape3D`, `height` is the full height of the capsule, caps included. A `height` smaller than twice the `radius` gives a sphere.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Capsule3D {
+    /// The radius of the capsule, and of its caps.
+    pub radius: float!(),
+    /// The height of the capsule, caps included.
+    pub height: float!(),
+}
+
+impl Capsule3D {
+    /// Constructs a **Capsule3D** of the given `radius` and `height`.
+    pub const fn new(radius: float!(), height: float!()) -> Self {
+        Self { radius, height }
+    }
+}
+
+impl ConvexShape3D for Capsule3D {
+    fn get_support(&self, direction: &Vector3) -> Vector3 {
+        let half = (self.height * 0.5 - self.radius).max(0.0);
+        Vector3::new(0.0, if direction.y < 0.0 { -half } else { half }, 0.0)
+    }
+
+    fn get_margin(&self) -> float!() {
+        self.radius.max(0.0)
+    }
+}
+
+/// A box centered on the origin of its transform.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BoxShape3D {
+    /// The width, height and depth of the box.
+    pub size: Vector3,
+}
+
+impl BoxShape3D {
+    /// Constructs a **BoxShape3D** of the given `size`.
+    pub const fn new(size: Vector3) -> Self {
+        Self { size }
+    }
+}
+
+impl ConvexShape3D for BoxShape3D {
+    fn get_support(&self, direction: &Vector3) -> Vector3 {
+        let half = self.size * 0.5;
+        Vector3::new(
+            if direction.x < 0.0 { -half.x } else { half.x },
+            if direction.y < 0.0 { -half.y } else { half.y },
+            if direction.z < 0.0 { -half.z } else { half.z },
+        )
+    }
+}
+
+/// The convex hull of a set of points.
+///
+/// The points don't need to be on the hull, or in any order: the support function only ever returns points of the hull.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConvexHull3D {
+    points: Vec<Vector3>,
+}
+
+impl ConvexHull3D {
+    /// Constructs a **ConvexHull3D** enclosing `points`.
+    pub fn new(points: Vec<Vector3>) -> Self {
+        Self { points }
+    }
+
+    /// Returns the points the hull encloses.
+    pub fn points(&self) -> &[Vector3] {
+        &self.points
+    }
+}
+
+impl ConvexShape3D for ConvexHull3D {
+    fn get_support(&self, direction: &Vector3) -> Vector3 {
+        self.points
+            .iter()
+            .copied()
+            .reduce(|best, point| {
+                if point.dot(direction) > best.dot(direction) {
+                    point
+                } else {
+                    best
+                }
+            })
+            .unwrap_or(Vector3::ZERO)
+    }
+}
+
+/// A triangle between the points `a`, `b` and `c`, with no thickness.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Triangle3D {
+    /// The first point of the triangle.
+    pub a: Vector3,
+    /// The second point of the triangle.
+    pub b: Vector3,
+    /// The third point of the triangle.
+    pub c: Vector3,
+}
+
+impl Triangle3D {
+    /// Constructs a **Triangle3D** from its three points.
+    pub const fn new(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the normal of the triangle, `(b - a).cross(c - a)` normalized. It's [`Vector3::ZERO`] if the triangle is degenerate.
+    pub fn get_normal(&self) -> Vector3 {
+        (self.b - self.a).cross(&(self.c - self.a)).normalized()
+    }
+}
+
+impl ConvexShape3D for Triangle3D {
+    fn get_support(&self, direction: &Vector3) -> Vector3 {
+        [self.b, self.c].into_iter().fold(self.a, |best, point| {
+            if point.dot(direction) > best.dot(direction) {
+                point
+            } else {
+                best
+            }
+        })
+    }
+}
+
+impl ConvexShape3D for AABB {
+    fn get_support(&self, direction: &Vector3) -> Vector3 {
+        AABB::get_support(self, direction)
+    }
+}
+
+/// A shape placed in the world.
+struct Placed<'a, S: ?Sized> {
+    shape: &'a S,
+    xform: &'a Transform3D,
+}
+
+impl<'a, S: ConvexShape3D + ?Sized> Placed<'a, S> {
+    fn new(shape: &'a S, xform: &'a Transform3D) -> Self {
+        Self { shape, xform }
+    }
+
+    fn support(&self, direction: &Vector3) -> Vector3 {
+        // The support of a transformed shape is the transformed support in the direction seen through the transposed basis.
+        self.xform.xform(
+            &self
+                .shape
+                .get_support(&self.xform.basis_xform_inv(direction)),
+        )
+    }
+
+    fn center(&self) -> Vector3 {
+        self.xform.origin
+    }
+}
+
+/// A point of the Minkowski difference `a - b`, with the points of `a` and `b` it comes from.
+#[derive(Copy, Clone, Debug)]
+struct SupportPoint {
+    w: Vector3,
+    a: Vector3,
+    b: Vector3,
+}
+
+/// Returns the point of the Minkowski difference of the cores of `a` and `b` that's the farthest in `direction`.
+fn support<A, B>(a: &Placed<A>, b: &Placed<B>, direction: &Vector3) -> SupportPoint
+where
+    A: ConvexShape3D + ?Sized,
+    B: ConvexShape3D + ?Sized,
+{
+    let (pa, pb) = (a.support(direction), b.support(&-*direction));
+    SupportPoint {
+        w: pa - pb,
+        a: pa,
+        b: pb,
+    }
+}
+
+/// A GJK simplex, with the barycentric weights of its point closest to the origin.
+#[derive(Copy, Clone, Debug)]
+struct Simplex {
+    points: [SupportPoint; 4],
+    weights: [float!(); 4],
+    len: usize,
+}
+
+impl Simplex {
+    fn new(point: SupportPoint) -> Self {
+        Self {
+            points: [point; 4],
+            weights: [1.0, 0.0, 0.0, 0.0],
+            len: 1,
+        }
+    }
+
+    fn points(&self) -> &[SupportPoint] {
+        &self.points[..self.len]
+    }
+
+    /// Returns the closest point to the origin and the matching points of both shapes.
+    fn closest(&self) -> (Vector3, Vector3, Vector3) {
+        let mut result = (Vector3::ZERO, Vector3::ZERO, Vector3::ZERO);
+        for (point, weight) in self.points().iter().zip(self.weights) {
+            result.0 += point.w * weight;
+            result.1 += point.a * weight;
+            result.2 += point.b * weight;
+        }
+        result
+    }
+
+    /// Keeps the smallest subset of the simplex whose hull contains its point closest to the origin, and updates the weights. Returns `false` if the simplex is a tetrahedron containing the origin.
+    fn reduce(&mut self) -> bool {
+        let reduced = match self.len {
+            1 => return true,
+            2 => closest_on_segment(self.points[0], self.points[1]),
+            3 => closest_on_triangle(self.points[0], self.points[1], self.points[2]),
+            _ => match closest_on_tetrahedron(&self.points) {
+                Some(reduced) => reduced,
+                None => return false,
+            },
+        };
+        *self = reduced;
+        true
+    }
+}
+
+/// Builds a simplex from points and their weights.
+fn weighted<const N: usize>(points: [(SupportPoint, float!()); N]) -> Simplex {
+    let mut simplex = Simplex::new(points[0].0);
+    simplex.len = N;
+    for (i, (point, weight)) in points.into_iter().enumerate() {
+        simplex.points[i] = point;
+        simplex.weights[i] = weight;
+    }
+    simplex
+}
+
+fn closest_on_segment(a: SupportPoint, b: SupportPoint) -> Simplex {
+    let ab = b.w - a.w;
+    let length_squared = ab.length_squared();
+    let t = if length_squared > 0.0 {
+        -a.w.dot(&ab) / length_squared
+    } else {
+        0.0
+    };
+    if t <= 0.0 {
+        Simplex::new(a)
+    } else if t >= 1.0 {
+        Simplex::new(b)
+    } else {
+        weighted([(a, 1.0 - t), (b, t)])
+    }
+}
+
+// See Christer Ericson's Real-Time Collision Detection, 5.1.5, with the origin as the query point.
+fn closest_on_triangle(a: SupportPoint, b: SupportPoint, c: SupportPoint) -> Simplex {
+    let ab = b.w - a.w;
+    let ac = c.w - a.w;
+    let d1 = -ab.dot(&a.w);
+    let d2 = -ac.dot(&a.w);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return Simplex::new(a);
+    }
+    let d3 = -ab.dot(&b.w);
+    let d4 = -ac.dot(&b.w);
+    if d3 >= 0.0 && d4 <= d3 {
+        return Simplex::new(b);
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return weighted([(a, 1.0 - v), (b, v)]);
+    }
+    let d5 = -ab.dot(&c.w);
+    let d6 = -ac.dot(&c.w);
+    if d6 >= 0.0 && d5 <= d6 {
+        return Simplex::new(c);
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return weighted([(a, 1.0 - w), (c, w)]);
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return weighted([(b, 1.0 - w), (c, w)]);
+    }
+    let denominator = 1.0 / (va + vb + vc);
+    let v = vb * denominator;
+    let w = vc * denominator;
+    weighted([(a, 1.0 - v - w), (b, v), (c, w)])
+}
+
+/// Returns the closest subset of the tetrahedron to the origin, or [`None`] if the origin is inside.
+fn closest_on_tetrahedron(points: &[SupportPoint; 4]) -> Option<Simplex> {
+    let [a, b, c, d] = *points;
+    let volume = (b.w - a.w).cross(&(c.w - a.w)).dot(&(d.w - a.w));
+    let flat = volume.abs() <= CMP_EPSILON2;
+    let mut best: Option<(Simplex, float!())> = None;
+    for (p, q, r, opposite) in [(a, b, c, d), (a, c, d, b), (a, d, b, c), (b, d, c, a)] {
+        let normal = (q.w - p.w).cross(&(r.w - p.w));
+        let origin_side = -p.w.dot(&normal);
+        let opposite_side = (opposite.w - p.w).dot(&normal);
+        // A flat tetrahedron has no inside, so every face is tested.
+        if !flat && origin_side * opposite_side >= 0.0 {
+            continue;
+        }
+        let simplex = closest_on_triangle(p, q, r);
+        let distance = simplex.closest().0.length_squared();
+        if best.is_none_or(|(_, best)| distance < best) {
+            best = Some((simplex, distance));
+        }
+    }
+    best.map(|(simplex, _)| simplex)
+}
+
+enum Gjk {
+    /// The cores are disjoint, with these closest points.
+    Separated(Vector3, Vector3),
+    /// The cores overlap, and the simplex contains the origin.
+    Overlapping(Box<Simplex>),
+}
+
+fn gjk<A, B>(a: &Placed<A>, b: &Placed<B>) -> Gjk
+where
+    A: ConvexShape3D + ?Sized,
+    B: ConvexShape3D + ?Sized,
+{
+    let mut direction = b.center() - a.center();
+    if direction.is_zero_approx() {
+        direction = Vector3::RIGHT;
+    }
+    let mut simplex = Simplex::new(support(a, b, &-direction));
+    let (mut v, mut pa, mut pb) = simplex.closest();
+
+    for _ in 0..GJK_ITERATIONS {
+        let distance_squared = v.length_squared();
+        if distance_squared <= CMP_EPSILON2 {
+            return Gjk::Overlapping(Box::new(simplex));
+        }
+        let w = support(a, b, &-v);
+        // Stops once the new support point can't bring the simplex meaningfully closer to the origin.
+        if distance_squared - v.dot(&w.w) <= CMP_EPSILON * distance_squared
+            || simplex
+                .points()
+                .iter()
+                .any(|point| point.w.is_equal_approx(&w.w))
+        {
+            break;
+        }
+
+        simplex.points[simplex.len] = w;
+        simplex.len += 1;
+        if !simplex.reduce() {
+            return Gjk::Overlapping(Box::new(simplex));
+        }
+        let closest = simplex.closest();
+        if closest.0.length_squared() >= distance_squared {
+            // Rounding errors stopped the progress, the previous closest points are as close as it gets.
+            break;
+        }
+        (v, pa, pb) = closest;
+    }
+    Gjk::Separated(pa, pb)
+}
+
+/// Returns the direction from the center of `a` toward the center of `b`, made perpendicular to `line` if given, for cores that touch without a direction of their own.
+fn fallback_normal<A, B>(a: &Placed<A>, b: &Placed<B>, line: Option<&Vector3>) -> Vector3
+where
+    A: ConvexShape3D + ?Sized,
+    B: ConvexShape3D + ?Sized,
+{
+    let mut normal = b.center() - a.center();
+    if let Some(line) = line {
+        normal -= *line * normal.dot(line);
+    }
+    if normal.is_zero_approx() {
+        normal = match line {
+            Some(line) if line.cross(&Vector3::UP).is_zero_approx() => line.cross(&Vector3::RIGHT),
+            Some(line) => line.cross(&Vector3::UP),
+            None => Vector3::UP,
+        };
+    }
+    normal.normalized()
+}
+
+/// The shape of a Minkowski difference with no volume.
+enum Flat {
+    Point,
+    /// A segment, along this direction.
+    Line(Vector3),
+    /// A polygon, with this normal.
+    Plane(Vector3),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct EpaFace {
+    indices: [usize; 3],
+    normal: Vector3,
+    distance: float!(),
+}
+
+impl EpaFace {
+    /// Builds the face of the polytope with these vertices, in counter-clockwise order seen from outside. Returns [`None`] for a degenerate face.
+    fn new(vertices: &[SupportPoint], indices: [usize; 3]) -> Option<Self> {
+        let [a, b, c] = indices.map(|i| vertices[i].w);
+        let normal = (b - a).cross(&(c - a));
+        if normal.length_squared() <= CMP_EPSILON2 * CMP_EPSILON2 {
+            return None;
+        }
+        let normal = normal.normalized();
+        Some(Self {
+            indices,
+            normal,
+            distance: normal.dot(&a),
+        })
+    }
+}
+
+/// Grows the simplex containing the origin into a tetrahedron, or returns how the Minkowski difference is flat if it has no volume.
+fn blow_up<A, B>(a: &Placed<A>, b: &Placed<B>, simplex: &Simplex) -> Result<[SupportPoint; 4], Flat>
+where
+    A: ConvexShape3D + ?Sized,
+    B: ConvexShape3D + ?Sized,
+{
+    const AXES: [Vector3; 3] = [Vector3::RIGHT, Vector3::UP, Vector3::BACK];
+    let mut points: Vec<SupportPoint> = simplex.points().to_vec();
+
+    if points.len() == 1 {
+        let found = AXES
+            .iter()
+            .flat_map(|axis| [*axis, -*axis])
+            .map(|direction| support(a, b, &direction))
+            .find(|point| !point.w.is_equal_approx(&points[0].w));
+        match found {
+            Some(point) => points.push(point),
+            None => return Err(Flat::Point),
+        }
+    }
+    if points.len() == 2 {
+        let line = (points[1].w - points[0].w).normalized();
+        let axis = AXES
+            .iter()
+            .min_by(|x, y| x.dot(&line).abs().total_cmp(&y.dot(&line).abs()))
+            .unwrap();
+        let first = line.cross(axis).normalized();
+        let second = line.cross(&first);
+        let found = [first, -first, second, -second]
+            .iter()
+            .map(|direction| support(a, b, direction))
+            .find(|point| {
+                let offset = point.w - points[0].w;
+                (offset - line * offset.dot(&line)).length_squared() > CMP_EPSILON2
+            });
+        match found {
+            Some(point) => points.push(point),
+            None => return Err(Flat::Line(line)),
+        }
+    }
+    if points.len() == 3 {
+        let normal = (points[1].w - points[0].w)
+            .cross(&(points[2].w - points[0].w))
+            .normalized();
+        let found = [normal, -normal]
+            .iter()
+            .map(|direction| support(a, b, direction))
+            .find(|point| (point.w - points[0].w).dot(&normal).abs() > CMP_EPSILON);
+        match found {
+            Some(point) => points.push(point),
+            None => return Err(Flat::Plane(normal)),
+        }
+    }
+    Ok([points[0], points[1], points[2], points[3]])
+}
+
+/// Returns the normal, the depth, and the closest points of the overlapping cores of `a` and `b`, with the Expanding Polytope Algorithm.
+fn epa<A, B>(
+    a: &Placed<A>,
+    b: &Placed<B>,
+    simplex: Simplex,
+) -> (Vector3, float!(), Vector3, Vector3)
+where
+    A: ConvexShape3D + ?Sized,
+    B: ConvexShape3D + ?Sized,
+{
+    let tetrahedron = match blow_up(a, b, &simplex) {
+        Ok(tetrahedron) => tetrahedron,
+        Err(flat) => {
+            // The Minkowski difference is flat, so the cores overlap with no depth, along any direction out of it.
+            let (_, pa, pb) = simplex.closest();
+            let normal = match flat {
+                Flat::Point => fallback_normal(a, b, None),
+                Flat::Line(line) => fallback_normal(a, b, Some(&line)),
+                Flat::Plane(normal) if normal.dot(&(b.center() - a.center())) < 0.0 => -normal,
+                Flat::Plane(normal) => normal,
+            };
+            return (normal, 0.0, pa, pb);
+        }
+    };
+
+    let mut vertices: Vec<SupportPoint> = tetrahedron.to_vec();
+    let center = tetrahedron
+        .iter()
+        .fold(Vector3::ZERO, |sum, point| sum + point.w)
+        * 0.25;
+    let mut faces: Vec<EpaFace> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .into_iter()
+        .filter_map(|[i, j, k]| {
+            let face = EpaFace::new(&vertices, [i, j, k])?;
+            if face.normal.dot(&(vertices[i].w - center)) < 0.0 {
+                EpaFace::new(&vertices, [i, k, j])
+            } else {
+                Some(face)
+            }
+        })
+        .collect();
+
+    let mut closest = faces[0];
+    for _ in 0..EPA_ITERATIONS {
+        closest = *faces
+            .iter()
+            .min_by(|x, y| x.distance.total_cmp(&y.distance))
+            .unwrap();
+        let w = support(a, b, &closest.normal);
+        if w.w.dot(&closest.normal) - closest.distance < EPA_TOLERANCE {
+            break;
+        }
+
+        // Removes the faces the new point sees, keeping the edges of the hole they leave.
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            if face.normal.dot(&(w.w - vertices[face.indices[0]].w)) <= 0.0 {
+                return true;
+            }
+            for edge in 0..3 {
+                let (from, to) = (face.indices[edge], face.indices[(edge + 1) % 3]);
+                if let Some(shared) = horizon.iter().position(|&edge| edge == (to, from)) {
+                    horizon.swap_remove(shared);
+                } else {
+                    horizon.push((from, to));
+                }
+            }
+            false
+        });
+        let index = vertices.len();
+        vertices.push(w);
+        faces.extend(
+            horizon
+                .into_iter()
+                .filter_map(|(from, to)| EpaFace::new(&vertices, [from, to, index])),
+        );
+        if faces.is_empty() {
+            break;
+        }
+    }
+
+    // The origin projected on the closest face gives the closest points of the cores.
+    let [p, q, r] = closest.indices.map(|i| vertices[i]);
+    let [u, v, w] = barycentric(&(closest.normal * closest.distance), &p.w, &q.w, &r.w);
+    (
+        closest.normal,
+        closest.distance,
+        p.a * u + q.a * v + r.a * w,
+        p.b * u + q.b * v + r.b * w,
+    )
+}
+
+/// Returns the barycentric coordinates of `point` in the triangle `a`, `b`, `c`.
+fn barycentric(point: &Vector3, a: &Vector3, b: &Vector3, c: &Vector3) -> [float!(); 3] {
+    let (v0, v1, v2) = (b - a, c - a, point - a);
+    let (d00, d01, d11) = (v0.dot(&v0), v0.dot(&v1), v1.dot(&v1));
+    let (d20, d21) = (v2.dot(&v0), v2.dot(&v1));
+    let denominator = d00 * d11 - d01 * d01;
+    if denominator.abs() <= CMP_EPSILON2 {
+        return [1.0, 0.0, 0.0];
+    }
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+    [1.0 - v - w, v, w]
+}
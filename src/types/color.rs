@@ -1,11 +1,16 @@
+use crate::math;
 use crate::utils::{is_equal_approx, FloatExt};
 use crate::{float, int};
+use alloc::format;
+use alloc::string::{String, ToString};
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
 use log::error;
-use okhsl::{oklab_to_linear_srgb, Okhsl, Rgb};
-use std::collections::HashMap;
-use std::fmt::{Display, Formatter};
-use std::ops::{Neg, Not};
+#[cfg(feature = "std")]
+use okhsl::{Okhsl, Rgb};
+use core::fmt::{Display, Formatter};
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 #[derive(Copy, Clone, Debug)]
 pub struct Color {
@@ -571,10 +576,14 @@ impl Color {
     }
 
     /// Constructs a color from an [OK HSL profile](https://bottosson.github.io/posts/colorpicker/). The hue (`h`), saturation (`s`), and lightness (`l`) are typically between `0.0` and `1.0`.
+    ///
+    /// **Note:** Only available with the `std` feature. The conversion uses the math of the `okhsl` crate, so the `deterministic` feature doesn't apply to it.
+    #[cfg(feature = "std")]
     pub fn ok_hsl(h: float!(), s: float!(), l: float!()) -> Self {
         Self::ok_hsla(h, s, l, 1.0)
     }
 
+    #[cfg(feature = "std")]
     fn set_ok_hsla(&mut self, h: float!(), s: float!(), l: float!(), a: float!()) {
         let mut hsl = Okhsl::from(Rgb::new(0, 0, 0));
         hsl.h = h as f64;
@@ -587,11 +596,15 @@ impl Color {
         self.a = a;
     }
 
+    #[cfg(feature = "std")]
     fn get_ok_hsla(&self) -> Okhsl {
         Okhsl::from(Rgb::new(self.r8(), self.g8(), self.b8()))
     }
 
     /// Constructs a color from an [OK HSL profile](https://bottosson.github.io/posts/colorpicker/). The hue (`h`), saturation (`s`), and lightness (`l`) are typically between `0.0` and `1.0`.
+    ///
+    /// **Note:** Only available with the `std` feature. The conversion uses the math of the `okhsl` crate, so the `deterministic` feature doesn't apply to it.
+    #[cfg(feature = "std")]
     pub fn ok_hsla(h: float!(), s: float!(), l: float!(), a: float!()) -> Self {
         let mut c = Color::default();
         c.set_ok_hsla(h, s, l, a);
@@ -604,7 +617,7 @@ impl Color {
         let g = ((rgbe >> 9) & 0x1ff) as float!();
         let b = ((rgbe >> 18) & 0x1ff) as float!();
         let e = (rgbe >> 27) as float!();
-        let m = (2.0 as float!()).powf(e - 15.0 - 9.0);
+        let m = math::powf(2.0 as float!(), e - 15.0 - 9.0);
 
         let rd = r * m;
         let gd = g * m;
@@ -613,13 +626,32 @@ impl Color {
         Self::rgb(rd, gd, bd)
     }
 
+    /// Returns the named color constant matching `string`, such as `"alice blue"` or `"ALICE_BLUE"`. The name is not case-sensitive, and spaces are treated as underscores. Returns `default` (or an empty color) if there is no such color.
+    ///
+    /// The lookup doesn't allocate.
     pub fn named(string: &str, default: Option<&Self>) -> Self {
         let default = if let Some(default) = default {
             default
         } else {
             &Self::default()
         };
-        color_name(string.replace(" ", "_").to_uppercase().as_str(), default)
+
+        // Longer than any color name.
+        let mut buffer = [0u8; 32];
+        if string.len() > buffer.len() || !string.is_ascii() {
+            return *default;
+        }
+        for (normalized, byte) in buffer.iter_mut().zip(string.bytes()) {
+            *normalized = if byte == b' ' {
+                b'_'
+            } else {
+                byte.to_ascii_uppercase()
+            };
+        }
+        match core::str::from_utf8(&buffer[..string.len()]) {
+            Ok(name) => color_name(name, default),
+            Err(_) => *default,
+        }
     }
 
     /// Creates a **Color** from the given string, which can be either an HTML color code or a named color (case-insensitive). Returns `default` if the color cannot be inferred from the string.
@@ -819,17 +851,17 @@ impl Color {
             if self.r < 0.0031308 {
                 12.92 * self.r
             } else {
-                (1.0 + 0.055) * self.r.powf(1.0 / 2.4) - 0.055
+                (1.0 + 0.055) * math::powf(self.r, 1.0 / 2.4) - 0.055
             },
             if self.g < 0.0031308 {
                 12.92 * self.g
             } else {
-                (1.0 + 0.055) * self.g.powf(1.0 / 2.4) - 0.055
+                (1.0 + 0.055) * math::powf(self.g, 1.0 / 2.4) - 0.055
             },
             if self.b < 0.0031308 {
                 12.92 * self.b
             } else {
-                (1.0 + 0.055) * self.b.powf(1.0 / 2.4) - 0.055
+                (1.0 + 0.055) * math::powf(self.b, 1.0 / 2.4) - 0.055
             },
             self.a,
         )
@@ -841,17 +873,17 @@ impl Color {
             if self.r < 0.04045 {
                 self.r * (1.0 / 12.92)
             } else {
-                ((self.r + 0.055) * (1.0 / (1.0 + 0.055))).powf(2.4)
+                math::powf((self.r + 0.055) * (1.0 / (1.0 + 0.055)), 2.4)
             },
             if self.g < 0.04045 {
                 self.g * (1.0 / 12.92)
             } else {
-                ((self.g + 0.055) * (1.0 / (1.0 + 0.055))).powf(2.4)
+                math::powf((self.g + 0.055) * (1.0 / (1.0 + 0.055)), 2.4)
             },
             if self.b < 0.04045 {
                 self.b * (1.0 / 12.92)
             } else {
-                ((self.b + 0.055) * (1.0 / (1.0 + 0.055))).powf(2.4)
+                math::powf((self.b + 0.055) * (1.0 / (1.0 + 0.055)), 2.4)
             },
             self.a,
         )
@@ -1069,10 +1101,12 @@ impl Color {
         self.a = c.a;
     }
 
+    #[cfg(feature = "std")]
     pub fn l(&self) -> float!() {
         self.get_ok_hsla().l as float!()
     }
 
+    #[cfg(feature = "std")]
     pub fn set_l(&mut self, l: float!()) {
         let c = Self::ok_hsla(self.h(), self.s(), l, self.a());
         self.r = c.r;
@@ -1249,7 +1283,7 @@ impl From<(&str, float!())> for Color {
 }
 
 impl Display for Color {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
             "Color({}, {}, {}, {})",
             self.r, self.g, self.b, self.a
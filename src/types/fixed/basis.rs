@@ -0,0 +1,795 @@
+use crate::types::fixed::{Fixed, FixedQuaternion, FixedVector3};
+use crate::types::{Basis, EulerOrder};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+/// A 3×3 matrix using [`Fixed`] components, for deterministic simulations.
+///
+/// It has the same layout, conventions and methods as [`Basis`]: `x`, `y` and `z` are the **rows** of the matrix, while [`FixedBasis::new`] and [`FixedBasis::x`] work with the columns, i.e. the axes. Every result is bit-identical across platforms. Convert with [`From`] in both directions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedBasis {
+    /// The first row of the matrix.
+    pub x: FixedVector3,
+    /// The second row of the matrix.
+    pub y: FixedVector3,
+    /// The third row of the matrix.
+    pub z: FixedVector3,
+}
+
+impl Default for FixedBasis {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// `1 - CMP_EPSILON`, the threshold of the gimbal lock cases in [`FixedBasis::get_euler`].
+const ALMOST_ONE: Fixed = Fixed::from_bits(Fixed::ONE.to_bits() - Fixed::CMP_EPSILON.to_bits());
+
+impl FixedBasis {
+    /// The identity basis. This is a basis with no rotation, no shear, and its scale being `1`.
+    pub const IDENTITY: Self = Self::new(FixedVector3::RIGHT, FixedVector3::UP, FixedVector3::BACK);
+
+    /// When any basis is multiplied by **FLIP_X**, it negates all components of the `x` axis (the X column).
+    pub const FLIP_X: Self = Self::new(FixedVector3::LEFT, FixedVector3::UP, FixedVector3::BACK);
+
+    /// When any basis is multiplied by **FLIP_Y**, it negates all components of the `y` axis (the Y column).
+    pub const FLIP_Y: Self = Self::new(FixedVector3::RIGHT, FixedVector3::DOWN, FixedVector3::BACK);
+
+    /// When any basis is multiplied by **FLIP_Z**, it negates all components of the `z` axis (the Z column).
+    pub const FLIP_Z: Self = Self::new(FixedVector3::RIGHT, FixedVector3::UP, FixedVector3::FORWARD);
+
+    /// Constructs a **FixedBasis** from 3 axis vectors, which are the columns of the matrix.
+    pub const fn new(x: FixedVector3, y: FixedVector3, z: FixedVector3) -> Self {
+        Self::new_rows(
+            FixedVector3::new(x.x, y.x, z.x),
+            FixedVector3::new(x.y, y.y, z.y),
+            FixedVector3::new(x.z, y.z, z.z),
+        )
+    }
+
+    /// Constructs a **FixedBasis** from its 3 rows.
+    pub const fn new_rows(x: FixedVector3, y: FixedVector3, z: FixedVector3) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Constructs a **FixedBasis** from its 9 components, row by row.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new_from_floats(
+        xx: Fixed,
+        xy: Fixed,
+        xz: Fixed,
+        yx: Fixed,
+        yy: Fixed,
+        yz: Fixed,
+        zx: Fixed,
+        zy: Fixed,
+        zz: Fixed,
+    ) -> Self {
+        Self::new_rows(
+            FixedVector3::new(xx, xy, xz),
+            FixedVector3::new(yx, yy, yz),
+            FixedVector3::new(zx, zy, zz),
+        )
+    }
+
+    /// Constructs a pure scale basis matrix with no rotation or shear. The `scale` values are set as the main diagonal of the matrix.
+    pub const fn from_scale(scale: &FixedVector3) -> Self {
+        Self::new_rows(
+            FixedVector3::new(scale.x, Fixed::ZERO, Fixed::ZERO),
+            FixedVector3::new(Fixed::ZERO, scale.y, Fixed::ZERO),
+            FixedVector3::new(Fixed::ZERO, Fixed::ZERO, scale.z),
+        )
+    }
+
+    /// Constructs a pure rotation basis matrix from the given Euler angles (in radians). The order of each consecutive rotation can be changed with `order` (see [`EulerOrder`]), and defaults to [`EulerOrder::YXZ`].
+    pub fn from_euler(euler: &FixedVector3, order: Option<EulerOrder>) -> Self {
+        let (zero, one) = (Fixed::ZERO, Fixed::ONE);
+
+        let (s, c) = euler.x.sin_cos();
+        let x_mat = Self::new_rows(
+            FixedVector3::new(one, zero, zero),
+            FixedVector3::new(zero, c, -s),
+            FixedVector3::new(zero, s, c),
+        );
+
+        let (s, c) = euler.y.sin_cos();
+        let y_mat = Self::new_rows(
+            FixedVector3::new(c, zero, s),
+            FixedVector3::new(zero, one, zero),
+            FixedVector3::new(-s, zero, c),
+        );
+
+        let (s, c) = euler.z.sin_cos();
+        let z_mat = Self::new_rows(
+            FixedVector3::new(c, -s, zero),
+            FixedVector3::new(s, c, zero),
+            FixedVector3::new(zero, zero, one),
+        );
+
+        match order.unwrap_or(EulerOrder::YXZ) {
+            EulerOrder::XYZ => x_mat * (y_mat * z_mat),
+            EulerOrder::XZY => x_mat * z_mat * y_mat,
+            EulerOrder::YXZ => y_mat * x_mat * z_mat,
+            EulerOrder::YZX => y_mat * z_mat * x_mat,
+            EulerOrder::ZXY => z_mat * x_mat * y_mat,
+            EulerOrder::ZYX => z_mat * y_mat * x_mat,
+        }
+    }
+
+    /// Returns the basis's first axis, the X column.
+    pub const fn x(self) -> FixedVector3 {
+        self.get_column(0)
+    }
+
+    /// Sets the basis's first axis, the X column.
+    pub fn set_x(&mut self, vector: &FixedVector3) {
+        self.set_column(0, vector);
+    }
+
+    /// Returns the basis's second axis, the Y column.
+    pub const fn y(self) -> FixedVector3 {
+        self.get_column(1)
+    }
+
+    /// Sets the basis's second axis, the Y column.
+    pub fn set_y(&mut self, vector: &FixedVector3) {
+        self.set_column(1, vector);
+    }
+
+    /// Returns the basis's third axis, the Z column.
+    pub const fn z(self) -> FixedVector3 {
+        self.get_column(2)
+    }
+
+    /// Sets the basis's third axis, the Z column.
+    pub fn set_z(&mut self, vector: &FixedVector3) {
+        self.set_column(2, vector);
+    }
+
+    /// Returns the row at `index`.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub const fn get_row(&self, index: usize) -> FixedVector3 {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Sets the row at `index`.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub fn set_row(&mut self, index: usize, row: &FixedVector3) {
+        match index {
+            0 => self.x = *row,
+            1 => self.y = *row,
+            2 => self.z = *row,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Returns the column at `index`, i.e. the axis.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub const fn get_column(&self, index: usize) -> FixedVector3 {
+        FixedVector3::new(self.x.get(index), self.y.get(index), self.z.get(index))
+    }
+
+    /// Sets the column at `index`, i.e. the axis.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub fn set_column(&mut self, index: usize, column: &FixedVector3) {
+        self.x.set(index, column.x);
+        self.y.set(index, column.y);
+        self.z.set(index, column.z);
+    }
+
+    /// Sets the three columns of the matrix, i.e. the axes.
+    pub fn set_columns(&mut self, column_1: &FixedVector3, column_2: &FixedVector3, column_3: &FixedVector3) {
+        *self = Self::new(*column_1, *column_2, *column_3);
+    }
+
+    /// Returns the [determinant](https://en.wikipedia.org/wiki/Determinant) of this basis's matrix.
+    pub fn determinant(&self) -> Fixed {
+        self.x.x * (self.y.y * self.z.z - self.z.y * self.y.z)
+            - self.y.x * (self.x.y * self.z.z - self.z.y * self.x.z)
+            + self.z.x * (self.x.y * self.y.z - self.y.y * self.x.z)
+    }
+
+    /// Writes the rotation axis and angle of this basis, which must be a rotation, to `axis` and `angle`. The identity gives [`FixedVector3::UP`] and `0`.
+    pub fn get_axis_angle(&self, axis: &mut FixedVector3, angle: &mut Fixed) {
+        // https://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToAngle/index.htm
+        let (zero, one, two) = (Fixed::ZERO, Fixed::ONE, Fixed::TWO);
+        if (self.x.y - self.y.x).is_zero_approx()
+            && (self.x.z - self.z.x).is_zero_approx()
+            && (self.y.z - self.z.y).is_zero_approx()
+        {
+            // Singularity found.
+            // First check for identity matrix which must have +1 for all terms in leading diagonal and zero in other terms.
+            let three = Fixed::from_int(3);
+            if self.is_diagonal() && (self.x.x + self.y.y + self.z.z - three).abs() < three * Fixed::CMP_EPSILON {
+                // This singularity is identity matrix so angle = 0.
+                *axis = FixedVector3::UP;
+                *angle = zero;
+                return;
+            }
+            // Otherwise this singularity is angle = 180.
+            let four = Fixed::from_int(4);
+            let xx = (self.x.x + one) / two;
+            let yy = (self.y.y + one) / two;
+            let zz = (self.z.z + one) / two;
+            let xy = (self.x.y + self.y.x) / four;
+            let xz = (self.x.z + self.z.x) / four;
+            let yz = (self.y.z + self.z.y) / four;
+            let diagonal = Fixed::FRAC_1_SQRT_2;
+
+            *axis = if xx > yy && xx > zz {
+                // self.x.x is the largest diagonal term.
+                if xx < Fixed::CMP_EPSILON {
+                    FixedVector3::new(zero, diagonal, diagonal)
+                } else {
+                    let x = xx.sqrt();
+                    FixedVector3::new(x, xy / x, xz / x)
+                }
+            } else if yy > zz {
+                // self.y.y is the largest diagonal term.
+                if yy < Fixed::CMP_EPSILON {
+                    FixedVector3::new(diagonal, zero, diagonal)
+                } else {
+                    let y = yy.sqrt();
+                    FixedVector3::new(xy / y, y, yz / y)
+                }
+            } else {
+                // self.z.z is the largest diagonal term so base result on this.
+                if zz < Fixed::CMP_EPSILON {
+                    FixedVector3::new(diagonal, diagonal, zero)
+                } else {
+                    let z = zz.sqrt();
+                    FixedVector3::new(xz / z, yz / z, z)
+                }
+            };
+            *angle = Fixed::PI;
+            return;
+        }
+        // As we have reached here there are no singularities so we can handle normally.
+        let v = FixedVector3::new(self.z.y - self.y.z, self.x.z - self.z.x, self.y.x - self.x.y);
+        let mut s = v.length();
+        if s < Fixed::CMP_EPSILON {
+            // Prevent divide by zero, should not happen if matrix is orthogonal and should be caught by singularity test above.
+            s = one;
+        }
+        *axis = v / s;
+        // acos does clamping.
+        *angle = ((self.x.x + self.y.y + self.z.z - one) / two).acos();
+    }
+
+    /// Returns this basis's rotation as a [`FixedVector3`] of Euler angles, in radians. The order of each consecutive rotation can be changed with `order` (see [`EulerOrder`]), and defaults to [`EulerOrder::YXZ`].
+    ///
+    /// The basis must be orthonormal, see [`FixedBasis::orthonormalized`].
+    pub fn get_euler(&self, order: Option<EulerOrder>) -> FixedVector3 {
+        let (zero, one) = (Fixed::ZERO, Fixed::ONE);
+        let half_pi = Fixed::FRAC_PI_2;
+
+        // The matrices of each order are listed in https://en.wikipedia.org/wiki/Euler_angles#Rotation_matrix.
+        match order.unwrap_or(EulerOrder::YXZ) {
+            EulerOrder::XYZ => {
+                let sy = self.x.z;
+                if sy >= ALMOST_ONE {
+                    FixedVector3::new(self.z.y.atan2(self.y.y), half_pi, zero)
+                } else if sy <= -ALMOST_ONE {
+                    FixedVector3::new(self.z.y.atan2(self.y.y), -half_pi, zero)
+                } else if self.y.x == zero
+                    && self.x.y == zero
+                    && self.y.z == zero
+                    && self.z.y == zero
+                    && self.y.y == one
+                {
+                    // A pure Y rotation, return the simplest form.
+                    FixedVector3::new(zero, self.x.z.atan2(self.x.x), zero)
+                } else {
+                    FixedVector3::new(
+                        (-self.y.z).atan2(self.z.z),
+                        sy.asin(),
+                        (-self.x.y).atan2(self.x.x),
+                    )
+                }
+            }
+            EulerOrder::XZY => {
+                let sz = self.x.y;
+                if sz >= ALMOST_ONE {
+                    FixedVector3::new(-self.y.z.atan2(self.z.z), zero, -half_pi)
+                } else if sz <= -ALMOST_ONE {
+                    FixedVector3::new(-self.y.z.atan2(self.z.z), zero, half_pi)
+                } else {
+                    FixedVector3::new(
+                        self.z.y.atan2(self.y.y),
+                        self.x.z.atan2(self.x.x),
+                        (-sz).asin(),
+                    )
+                }
+            }
+            EulerOrder::YXZ => {
+                let m12 = self.y.z;
+                if m12 >= ALMOST_ONE {
+                    FixedVector3::new(-half_pi, -self.x.y.atan2(self.x.x), zero)
+                } else if m12 <= -ALMOST_ONE {
+                    FixedVector3::new(half_pi, self.x.y.atan2(self.x.x), zero)
+                } else if self.y.x == zero
+                    && self.x.y == zero
+                    && self.x.z == zero
+                    && self.z.x == zero
+                    && self.x.x == one
+                {
+                    // A pure X rotation, return the simplest form.
+                    FixedVector3::new((-m12).atan2(self.y.y), zero, zero)
+                } else {
+                    FixedVector3::new(
+                        (-m12).asin(),
+                        self.x.z.atan2(self.z.z),
+                        self.y.x.atan2(self.y.y),
+                    )
+                }
+            }
+            EulerOrder::YZX => {
+                let sz = self.y.x;
+                if sz >= ALMOST_ONE {
+                    FixedVector3::new(self.z.y.atan2(self.z.z), zero, half_pi)
+                } else if sz <= -ALMOST_ONE {
+                    FixedVector3::new(self.z.y.atan2(self.z.z), zero, -half_pi)
+                } else {
+                    FixedVector3::new(
+                        (-self.y.z).atan2(self.y.y),
+                        (-self.z.x).atan2(self.x.x),
+                        sz.asin(),
+                    )
+                }
+            }
+            EulerOrder::ZXY => {
+                let sx = self.z.y;
+                if sx >= ALMOST_ONE {
+                    FixedVector3::new(half_pi, self.x.z.atan2(self.x.x), zero)
+                } else if sx <= -ALMOST_ONE {
+                    FixedVector3::new(-half_pi, self.x.z.atan2(self.x.x), zero)
+                } else {
+                    FixedVector3::new(
+                        sx.asin(),
+                        (-self.z.x).atan2(self.z.z),
+                        (-self.x.y).atan2(self.y.y),
+                    )
+                }
+            }
+            EulerOrder::ZYX => {
+                let sy = self.z.x;
+                if sy >= ALMOST_ONE {
+                    FixedVector3::new(zero, -half_pi, -self.x.y.atan2(self.y.y))
+                } else if sy <= -ALMOST_ONE {
+                    FixedVector3::new(zero, half_pi, -self.x.y.atan2(self.y.y))
+                } else {
+                    FixedVector3::new(
+                        self.z.y.atan2(self.z.z),
+                        (-sy).asin(),
+                        self.y.x.atan2(self.x.x),
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns this basis's rotation as a [`FixedQuaternion`], ignoring its scale and reflection.
+    pub fn get_rotation_quaternion(&self) -> FixedQuaternion {
+        let mut m = self.orthonormalized();
+        if m.determinant().is_negative() {
+            // Ensure a proper rotation, which a quaternion can represent.
+            m *= -Fixed::ONE;
+        }
+        m.get_quaternion()
+    }
+
+    /// Returns the quaternion of an orthonormal basis without reflection.
+    fn get_quaternion(&self) -> FixedQuaternion {
+        let m = self;
+        let trace = m.x.x + m.y.y + m.z.z;
+        let mut temp = [Fixed::ZERO; 4];
+
+        if trace > Fixed::ZERO {
+            let s = (trace + Fixed::ONE).sqrt();
+            temp[3] = s * Fixed::HALF;
+            let s = Fixed::HALF / s;
+
+            temp[0] = (m.z.y - m.y.z) * s;
+            temp[1] = (m.x.z - m.z.x) * s;
+            temp[2] = (m.y.x - m.x.y) * s;
+        } else {
+            let i = if m.x.x < m.y.y {
+                if m.y.y < m.z.z {
+                    2
+                } else {
+                    1
+                }
+            } else if m.x.x < m.z.z {
+                2
+            } else {
+                0
+            };
+            let j = (i + 1) % 3;
+            let k = (i + 2) % 3;
+
+            let s = (m.get_row(i).get(i) - m.get_row(j).get(j) - m.get_row(k).get(k) + Fixed::ONE)
+                .sqrt();
+            temp[i] = s * Fixed::HALF;
+            let s = Fixed::HALF / s;
+
+            temp[3] = (m.get_row(k).get(j) - m.get_row(j).get(k)) * s;
+            temp[j] = (m.get_row(j).get(i) + m.get_row(i).get(j)) * s;
+            temp[k] = (m.get_row(k).get(i) + m.get_row(i).get(k)) * s;
+        }
+
+        FixedQuaternion::new(temp[0], temp[1], temp[2], temp[3])
+    }
+
+    /// Returns the length of each axis of this basis, as a [`FixedVector3`]. If the basis is not sheared, this is the scaling factor. It is not affected by rotation. If the determinant is negative, the scale is also negative.
+    pub fn get_scale(&self) -> FixedVector3 {
+        let scale = FixedVector3::new(
+            self.get_column(0).length(),
+            self.get_column(1).length(),
+            self.get_column(2).length(),
+        );
+        scale * self.determinant().sign()
+    }
+
+    /// Returns the [inverse of this basis's matrix](https://en.wikipedia.org/wiki/Invertible_matrix).
+    ///
+    /// **Panics** if the determinant is zero.
+    pub fn inverse(&self) -> Self {
+        macro_rules! cofac {
+            ($row1:ident, $col1:ident, $row2:ident, $col2:ident) => {
+                self.$row1.$col1 * self.$row2.$col2 - self.$row1.$col2 * self.$row2.$col1
+            };
+        }
+        let co = [cofac!(y, y, z, z), cofac!(y, z, z, x), cofac!(y, x, z, y)];
+        let det = self.x.x * co[0] + self.x.y * co[1] + self.x.z * co[2];
+
+        Self::new_rows(
+            FixedVector3::new(co[0], cofac!(x, z, z, y), cofac!(x, y, y, z)),
+            FixedVector3::new(co[1], cofac!(x, x, z, z), cofac!(x, z, y, x)),
+            FixedVector3::new(co[2], cofac!(x, y, z, x), cofac!(x, x, y, y)),
+        ) / det
+    }
+
+    /// Returns `true` if this basis is conformal. A conformal basis is both orthogonal (the axes are perpendicular to each other) and uniform (the axes share the same length).
+    pub fn is_conformal(&self) -> bool {
+        let x = self.get_column(0);
+        let y = self.get_column(1);
+        let z = self.get_column(2);
+        let x_len_sq = x.length_squared();
+        x_len_sq.is_equal_approx(y.length_squared())
+            && x_len_sq.is_equal_approx(z.length_squared())
+            && x.dot(&y).is_zero_approx()
+            && x.dot(&z).is_zero_approx()
+            && y.dot(&z).is_zero_approx()
+    }
+
+    fn is_diagonal(&self) -> bool {
+        [self.x.y, self.x.z, self.y.x, self.y.z, self.z.x, self.z.y]
+            .iter()
+            .all(|e| e.is_zero_approx())
+    }
+
+    /// Returns `true` if this basis and `b` are approximately equal, by calling [`FixedVector3::is_equal_approx`] on all rows.
+    pub fn is_equal_approx(&self, b: &Self) -> bool {
+        self.x.is_equal_approx(&b.x) && self.y.is_equal_approx(&b.y) && self.z.is_equal_approx(&b.z)
+    }
+
+    /// Returns `true` if the axes of this basis are perpendicular to each other.
+    pub fn is_orthogonal(&self) -> bool {
+        let x = self.get_column(0);
+        let y = self.get_column(1);
+        let z = self.get_column(2);
+        x.dot(&y).is_zero_approx() && x.dot(&z).is_zero_approx() && y.dot(&z).is_zero_approx()
+    }
+
+    /// Returns `true` if the axes of this basis are perpendicular to each other and have a length of `1`.
+    pub fn is_orthonormal(&self) -> bool {
+        let x = self.get_column(0);
+        let y = self.get_column(1);
+        let z = self.get_column(2);
+        x.length_squared().is_equal_approx(Fixed::ONE)
+            && y.length_squared().is_equal_approx(Fixed::ONE)
+            && z.length_squared().is_equal_approx(Fixed::ONE)
+            && self.is_orthogonal()
+    }
+
+    /// Returns `true`, since fixed-point numbers can't be infinite or NaN. It exists for parity with [`Basis::is_finite`].
+    pub const fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Returns `true` if this basis is a pure rotation: conformal with a determinant of `1`.
+    pub fn is_rotation(&self) -> bool {
+        self.is_conformal()
+            && self
+                .determinant()
+                .is_equal_approx_with_tolerance(Fixed::ONE, Fixed::UNIT_EPSILON)
+    }
+
+    /// Creates a new basis with a rotation such that the forward axis (-Z) points towards the `target` position. The up axis (+Y) points as close to the `up` vector as possible while staying perpendicular to the forward axis, and defaults to [`FixedVector3::UP`]. If `use_model_front` is `true`, the +Z axis (asset front) is treated as forward instead.
+    pub fn looking_at(target: &FixedVector3, up: Option<&FixedVector3>, use_model_front: bool) -> Self {
+        let up = up.unwrap_or(&FixedVector3::UP);
+        let mut v_z = target.normalized();
+        if !use_model_front {
+            v_z = -v_z;
+        }
+        let v_x = up.cross(&v_z).normalized();
+        let v_y = v_z.cross(&v_x);
+        Self::new(v_x, v_y, v_z)
+    }
+
+    /// Returns the orthonormalized version of this basis. An orthonormal basis is both orthogonal (the axes are perpendicular to each other) and normalized (the axes have a length of `1`), which also means it can only represent rotation.
+    pub fn orthonormalized(&self) -> Self {
+        let x = self.get_column(0).normalized();
+        let y = self.get_column(1);
+        let y = (y - x * x.dot(&y)).normalized();
+        let z = self.get_column(2);
+        let z = (z - x * x.dot(&z) - y * y.dot(&z)).normalized();
+        Self::new(x, y, z)
+    }
+
+    /// Returns this basis rotated around the given `axis` by `angle` (in radians). The `axis` must be a normalized vector.
+    pub fn rotated(&self, axis: &FixedVector3, angle: Fixed) -> Self {
+        Self::from((axis, angle)) * self
+    }
+
+    /// Returns this basis with each axis's components scaled by the given `scale`'s components. The matrix's rows are multiplied by `scale`, which is a global scale.
+    pub fn scaled(&self, scale: &FixedVector3) -> Self {
+        Self::new_rows(self.x * scale.x, self.y * scale.y, self.z * scale.z)
+    }
+
+    /// Returns this basis with each axis scaled by the corresponding component in the given `scale`. The matrix's columns are multiplied by `scale`, which is a local scale.
+    pub fn scaled_local(&self, scale: &FixedVector3) -> Self {
+        self * Self::from_scale(scale)
+    }
+
+    /// Sets this basis to the scale `scale` followed by the rotation `quaternion`, as the product of [`FixedBasis::from_scale`] and the rotation.
+    pub fn set_quaternion_scale(&mut self, quaternion: &FixedQuaternion, scale: &FixedVector3) {
+        *self = Self::from_scale(scale) * Self::from(quaternion);
+    }
+
+    /// Performs a spherical-linear interpolation with the `to` basis, given a `weight`. Both this basis and `to` should represent a rotation.
+    pub fn slerp(&self, to: &Self, weight: Fixed) -> Self {
+        let from = self.get_rotation_quaternion();
+        let to_q = to.get_rotation_quaternion();
+
+        let b = Self::from(&from.slerp(&to_q, weight));
+        Self::new_rows(
+            b.x * self.x.length().lerp(to.x.length(), weight),
+            b.y * self.y.length().lerp(to.y.length(), weight),
+            b.z * self.z.length().lerp(to.z.length(), weight),
+        )
+    }
+
+    /// Returns the transposed version of this basis. This turns the basis's columns into rows, and its rows into columns.
+    pub const fn transposed(&self) -> Self {
+        Self::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the transposed dot product between `with` and the `x` axis, equivalent to `basis.get_column(0).dot(with)`.
+    pub fn t_dot_x(&self, with: &FixedVector3) -> Fixed {
+        self.x.x * with.x + self.y.x * with.y + self.z.x * with.z
+    }
+
+    /// Returns the transposed dot product between `with` and the `y` axis, equivalent to `basis.get_column(1).dot(with)`.
+    pub fn t_dot_y(&self, with: &FixedVector3) -> Fixed {
+        self.x.y * with.x + self.y.y * with.y + self.z.y * with.z
+    }
+
+    /// Returns the transposed dot product between `with` and the `z` axis, equivalent to `basis.get_column(2).dot(with)`.
+    pub fn t_dot_z(&self, with: &FixedVector3) -> Fixed {
+        self.x.z * with.x + self.y.z * with.y + self.z.z * with.z
+    }
+
+    /// Transforms `vector` by this basis.
+    pub fn xform(&self, vector: &FixedVector3) -> FixedVector3 {
+        FixedVector3::new(self.x.dot(vector), self.y.dot(vector), self.z.dot(vector))
+    }
+
+    /// Transforms `vector` by the transpose of this basis, which is its inverse if the basis is orthonormal.
+    pub fn xform_inv(&self, vector: &FixedVector3) -> FixedVector3 {
+        self.transposed().xform(vector)
+    }
+
+    // Row-major copy of the matrix, easier to index in the decomposition below.
+    fn to_array(self) -> [[Fixed; 3]; 3] {
+        [
+            [self.x.x, self.x.y, self.x.z],
+            [self.y.x, self.y.y, self.y.z],
+            [self.z.x, self.z.y, self.z.z],
+        ]
+    }
+
+    /// Returns the eigenvalues and eigenvectors of this basis, which must be symmetric. Only the upper triangle of the matrix is read.
+    ///
+    /// Like [`Basis::symmetric_eigen`], the eigenvalues are sorted from largest to smallest, and the matching eigenvectors are the columns of the returned rotation. The cyclic Jacobi rotations stop once the off-diagonal terms are zero at the resolution of [`Fixed`], or after a fixed number of sweeps.
+    pub fn symmetric_eigen(&self) -> (FixedVector3, Self) {
+        const MAX_SWEEPS: usize = 32;
+        // Beyond this ratio between the diagonal difference and the off-diagonal term, `theta²` would overflow.
+        const LARGE_THETA: Fixed = Fixed::from_int(1 << 15);
+
+        let (zero, one) = (Fixed::ZERO, Fixed::ONE);
+        let mut a = self.to_array();
+        a[1][0] = a[0][1];
+        a[2][0] = a[0][2];
+        a[2][1] = a[1][2];
+        let mut v = Self::IDENTITY.to_array();
+
+        for _ in 0..MAX_SWEEPS {
+            if a[0][1] == zero && a[0][2] == zero && a[1][2] == zero {
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == zero {
+                    continue;
+                }
+                // Rotation in the (p, q) plane that zeroes a[p][q], using the numerically stable smaller root.
+                let difference = a[q][q] - a[p][p];
+                let two_pq = Fixed::TWO * a[p][q];
+                let t = if difference.abs() / LARGE_THETA > two_pq.abs() {
+                    // `1 / (2 * theta)`, the limit of the root for large `theta`.
+                    a[p][q] / difference
+                } else {
+                    let theta = difference / two_pq;
+                    let sign = if theta.is_negative() { -one } else { one };
+                    sign / (theta.abs() + (theta * theta + one).sqrt())
+                };
+                let c = one / (t * t + one).sqrt();
+                let s = t * c;
+
+                // A = J^T * A * J
+                for row in a.iter_mut() {
+                    let (rp, rq) = (row[p], row[q]);
+                    row[p] = c * rp - s * rq;
+                    row[q] = s * rp + c * rq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = core::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = core::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                // The rotation zeroes the term exactly; rounding would leave a few units of the resolution.
+                a[p][q] = zero;
+                a[q][p] = zero;
+                // V = V * J
+                for row in v.iter_mut() {
+                    let (rp, rq) = (row[p], row[q]);
+                    row[p] = c * rp - s * rq;
+                    row[q] = s * rp + c * rq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| a[j][j].cmp(&a[i][i]));
+
+        let values = FixedVector3::new(a[order[0]][order[0]], a[order[1]][order[1]], a[order[2]][order[2]]);
+        let mut vectors = Self::IDENTITY;
+        for (column, &i) in order.iter().enumerate() {
+            vectors.set_column(column, &FixedVector3::new(v[0][i], v[1][i], v[2][i]));
+        }
+        if vectors.determinant().is_negative() {
+            vectors.set_column(2, &-vectors.get_column(2));
+        }
+        (values, vectors)
+    }
+
+    /// Diagonalizes this basis, which must be symmetric (see [`FixedBasis::symmetric_eigen`]). Returns the diagonal basis `d` and the rotation `r` such that `self == r * d * r.transposed()`, approximately.
+    pub fn diagonalize(&self) -> (Self, Self) {
+        let (values, vectors) = self.symmetric_eigen();
+        (Self::from_scale(&values), vectors)
+    }
+
+    /// Returns the singular value decomposition of this basis as `(u, sigma, v)`, such that `self == u * FixedBasis::from_scale(&sigma) * v.transposed()`, approximately. See [`Basis::svd`].
+    ///
+    /// **Note:** The decomposition goes through `self.transposed() * self`, so it overflows for components larger than about `32768`.
+    pub fn svd(&self) -> (Self, FixedVector3, Self) {
+        // The right singular vectors are the eigenvectors of the symmetric M^T * M.
+        let (_, v) = (self.transposed() * self).symmetric_eigen();
+
+        // The columns of B = M * V are orthogonal, with the singular values as lengths. Orthonormalize them to get U without dividing by tiny singular values.
+        let b0 = self.xform(&v.get_column(0));
+        let b1 = self.xform(&v.get_column(1));
+        let b2 = self.xform(&v.get_column(2));
+
+        let u0 = if b0.is_zero_approx() { FixedVector3::RIGHT } else { b0.normalized() };
+        let mut u1 = b1 - u0 * u0.dot(&b1);
+        if u1.is_zero_approx() {
+            // Any direction perpendicular to u0 will do.
+            u1 = if u0.x.abs() < Fixed::HALF { FixedVector3::RIGHT } else { FixedVector3::UP };
+            u1 -= u0 * u0.dot(&u1);
+        }
+        let u1 = u1.normalized();
+        let u2 = u0.cross(&u1);
+
+        let sigma = FixedVector3::new(u0.dot(&b0), u1.dot(&b1), u2.dot(&b2));
+        (Self::new(u0, u1, u2), sigma, v)
+    }
+
+    /// Returns the polar decomposition of this basis as `(rotation, stretch)`, such that `self == rotation * stretch`, approximately. See [`Basis::polar_decompose`].
+    pub fn polar_decompose(&self) -> (Self, Self) {
+        let (u, sigma, v) = self.svd();
+        let v_t = v.transposed();
+        (u * v_t, v * Self::from_scale(&sigma) * v_t)
+    }
+}
+
+impl From<(&FixedVector3, Fixed)> for FixedBasis {
+    /// Constructs a pure rotation basis matrix, rotated around the given axis by the angle (in radians). The axis must be a normalized vector.
+    fn from((axis, angle): (&FixedVector3, Fixed)) -> Self {
+        let one = Fixed::ONE;
+        let (sine, cosine) = angle.sin_cos();
+        let t = one - cosine;
+
+        let xy = axis.x * axis.y * t;
+        let xz = axis.x * axis.z * t;
+        let yz = axis.y * axis.z * t;
+        let (xs, ys, zs) = (axis.x * sine, axis.y * sine, axis.z * sine);
+
+        Self::new_rows(
+            FixedVector3::new(axis.x * axis.x + cosine * (one - axis.x * axis.x), xy - zs, xz + ys),
+            FixedVector3::new(xy + zs, axis.y * axis.y + cosine * (one - axis.y * axis.y), yz - xs),
+            FixedVector3::new(xz - ys, yz + xs, axis.z * axis.z + cosine * (one - axis.z * axis.z)),
+        )
+    }
+}
+
+impl From<&FixedQuaternion> for FixedBasis {
+    /// Constructs a pure rotation basis matrix from the given quaternion.
+    fn from(quaternion: &FixedQuaternion) -> Self {
+        let one = Fixed::ONE;
+        let s = Fixed::TWO / quaternion.length_squared();
+        let xs = quaternion.x * s;
+        let ys = quaternion.y * s;
+        let zs = quaternion.z * s;
+        let wx = quaternion.w * xs;
+        let wy = quaternion.w * ys;
+        let wz = quaternion.w * zs;
+        let xx = quaternion.x * xs;
+        let xy = quaternion.x * ys;
+        let xz = quaternion.x * zs;
+        let yy = quaternion.y * ys;
+        let yz = quaternion.y * zs;
+        let zz = quaternion.z * zs;
+        Self::new_rows(
+            FixedVector3::new(one - (yy + zz), xy - wz, xz + wy),
+            FixedVector3::new(xy + wz, one - (xx + zz), yz - wx),
+            FixedVector3::new(xz - wy, yz + wx, one - (xx + yy)),
+        )
+    }
+}
+
+impl From<Basis> for FixedBasis {
+    /// Converts each component with [`Fixed::from_float`].
+    fn from(value: Basis) -> Self {
+        Self::new_rows(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl From<FixedBasis> for Basis {
+    /// Converts each component with [`Fixed::to_float`].
+    fn from(value: FixedBasis) -> Self {
+        Self::new_rows(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl_op_ex!(*|a: &FixedBasis, b: &FixedBasis| -> FixedBasis {
+    let columns = [b.get_column(0), b.get_column(1), b.get_column(2)];
+    let row = |r: &FixedVector3| FixedVector3::new(r.dot(&columns[0]), r.dot(&columns[1]), r.dot(&columns[2]));
+    FixedBasis::new_rows(row(&a.x), row(&a.y), row(&a.z))
+});
+impl_op_ex!(*= |a: &mut FixedBasis, b: &FixedBasis| { *a = *a * b; });
+impl_op_ex!(*|a: &FixedBasis, b: &FixedVector3| -> FixedVector3 { a.xform(b) });
+impl_op_ex_commutative!(*|a: &FixedBasis, b: &Fixed| -> FixedBasis {
+    FixedBasis::new_rows(a.x * b, a.y * b, a.z * b)
+});
+impl_op_ex!(/ |a: &FixedBasis, b: &Fixed| -> FixedBasis {
+    FixedBasis::new_rows(a.x / b, a.y / b, a.z / b)
+});
+impl_op_ex!(*= |a: &mut FixedBasis, b: &Fixed| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut FixedBasis, b: &Fixed| { *a = *a / b; });
@@ -0,0 +1,13 @@
+mod basis;
+mod quaternion;
+mod scalar;
+mod transform2d;
+mod vector2;
+mod vector3;
+
+pub use basis::FixedBasis;
+pub use quaternion::FixedQuaternion;
+pub use scalar::Fixed;
+pub use transform2d::FixedTransform2D;
+pub use vector2::FixedVector2;
+pub use vector3::FixedVector3;
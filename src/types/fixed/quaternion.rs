@@ -0,0 +1,359 @@
+use crate::types::fixed::{Fixed, FixedBasis, FixedVector3};
+use crate::types::{EulerOrder, Quaternion};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+use core::ops::Neg;
+
+/// A unit quaternion using [`Fixed`] components, for deterministic simulations.
+///
+/// It has the same conventions and methods as [`Quaternion`], but every result is bit-identical across platforms. Convert with [`From`] in both directions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedQuaternion {
+    /// W component of the quaternion. This is the "real" part.
+    pub w: Fixed,
+    /// X component of the quaternion. This is the value along the "imaginary" `i` axis.
+    pub x: Fixed,
+    /// Y component of the quaternion. This is the value along the "imaginary" `j` axis.
+    pub y: Fixed,
+    /// Z component of the quaternion. This is the value along the "imaginary" `k` axis.
+    pub z: Fixed,
+}
+
+impl Default for FixedQuaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl FixedQuaternion {
+    /// The identity quaternion, representing no rotation. This has the same rotation as [`FixedBasis::IDENTITY`].
+    pub const IDENTITY: Self = Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO, Fixed::ONE);
+
+    /// Constructs a **FixedQuaternion** defined by the given values.
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed, w: Fixed) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Returns the angle between this quaternion and `to`. This is the magnitude of the angle you would need to rotate by to get from one to the other.
+    pub fn angle_to(&self, to: &Self) -> Fixed {
+        let d = self.dot(to);
+        // acos does clamping.
+        (d * d * Fixed::TWO - Fixed::ONE).acos()
+    }
+
+    /// Returns the dot product between this quaternion and `with`.
+    pub fn dot(&self, with: &Self) -> Fixed {
+        self.x * with.x + self.y * with.y + self.z * with.z + self.w * with.w
+    }
+
+    /// Returns the exponential of this quaternion. The rotation axis of the result is the normalized vector part of this quaternion, and its angle is the length of the vector part.
+    pub fn exp(&self) -> Self {
+        let v = FixedVector3::new(self.x, self.y, self.z);
+        let theta = v.length();
+        let axis = v.normalized();
+        if theta < Fixed::CMP_EPSILON || !axis.is_normalized() {
+            Self::IDENTITY
+        } else {
+            Self::from((&axis, theta))
+        }
+    }
+
+    /// Constructs a quaternion from the given Euler angles (in radians), in the YXZ order.
+    pub fn from_euler(euler: &FixedVector3) -> Self {
+        // R = Y(a1).X(a2).Z(a3) convention for Euler angles.
+        // Conversion to quaternion as listed in https://ntrs.nasa.gov/archive/nasa/casi.ntrs.nasa.gov/19770024290.pdf (page A-6)
+        let (sin_a1, cos_a1) = (euler.y * Fixed::HALF).sin_cos();
+        let (sin_a2, cos_a2) = (euler.x * Fixed::HALF).sin_cos();
+        let (sin_a3, cos_a3) = (euler.z * Fixed::HALF).sin_cos();
+
+        Self::new(
+            sin_a1 * cos_a2 * sin_a3 + cos_a1 * sin_a2 * cos_a3,
+            sin_a1 * cos_a2 * cos_a3 - cos_a1 * sin_a2 * sin_a3,
+            -sin_a1 * sin_a2 * cos_a3 + cos_a1 * cos_a2 * sin_a3,
+            sin_a1 * sin_a2 * sin_a3 + cos_a1 * cos_a2 * cos_a3,
+        )
+    }
+
+    /// Returns the angle of the rotation represented by this quaternion.
+    ///
+    /// **Note:** The quaternion must be normalized.
+    pub fn get_angle(&self) -> Fixed {
+        Fixed::TWO * self.w.acos()
+    }
+
+    /// Returns the rotation axis of the rotation represented by this quaternion.
+    pub fn get_axis(&self) -> FixedVector3 {
+        let v = FixedVector3::new(self.x, self.y, self.z);
+        if self.w.abs() > Fixed::ONE - Fixed::CMP_EPSILON {
+            v
+        } else {
+            v / (Fixed::ONE - self.w * self.w).sqrt()
+        }
+    }
+
+    /// Returns this quaternion's rotation as a [`FixedVector3`] of Euler angles, in radians. The order of each consecutive rotation can be changed with `order` (see [`EulerOrder`]), and defaults to [`EulerOrder::YXZ`].
+    pub fn get_euler(&self, order: Option<EulerOrder>) -> FixedVector3 {
+        FixedBasis::from(self).get_euler(order)
+    }
+
+    /// Returns the inverse version of this quaternion, inverting the sign of every component except `w`.
+    pub fn inverse(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns `true` if this quaternion and `to` are approximately equal, by calling [`Fixed::is_equal_approx`] on each component.
+    pub fn is_equal_approx(&self, to: &Self) -> bool {
+        self.x.is_equal_approx(to.x)
+            && self.y.is_equal_approx(to.y)
+            && self.z.is_equal_approx(to.z)
+            && self.w.is_equal_approx(to.w)
+    }
+
+    /// Returns `true`, since fixed-point numbers can't be infinite or NaN. It exists for parity with [`Quaternion::is_finite`].
+    pub const fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Returns `true` if this quaternion is normalized.
+    pub fn is_normalized(&self) -> bool {
+        self.length()
+            .is_equal_approx_with_tolerance(Fixed::ONE, Fixed::UNIT_EPSILON)
+    }
+
+    /// Returns this quaternion's length, also called magnitude.
+    pub fn length(&self) -> Fixed {
+        Fixed::hypot(&[self.x, self.y, self.z, self.w])
+    }
+
+    /// Returns this quaternion's length, squared.
+    pub fn length_squared(&self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns the logarithm of this quaternion: its rotation axis multiplied by its rotation angle, in the vector part. The real part `w` is always `0`.
+    pub fn log(&self) -> Self {
+        let v = self.get_axis() * self.get_angle();
+        Self::new(v.x, v.y, v.z, Fixed::ZERO)
+    }
+
+    /// Returns a copy of this quaternion, normalized so that its length is `1`.
+    pub fn normalized(&self) -> Self {
+        self / self.length()
+    }
+
+    /// Performs a spherical-linear interpolation with the `to` quaternion, given a `weight` and returns the result. Both this quaternion and `to` must be normalized.
+    pub fn slerp(&self, to: &Self, weight: Fixed) -> Self {
+        // Adjust the signs to take the shortest path.
+        let mut cosom = self.dot(to);
+        let to1 = if cosom.is_negative() {
+            cosom = -cosom;
+            -*to
+        } else {
+            *to
+        };
+
+        let (scale0, scale1) = if Fixed::ONE - cosom > Fixed::CMP_EPSILON {
+            // Standard case (slerp).
+            let omega = cosom.acos();
+            let sinom = omega.sin();
+            (
+                ((Fixed::ONE - weight) * omega).sin() / sinom,
+                (weight * omega).sin() / sinom,
+            )
+        } else {
+            // The quaternions are very close, so we can do a linear interpolation.
+            (Fixed::ONE - weight, weight)
+        };
+        self * scale0 + to1 * scale1
+    }
+
+    /// Performs a spherical-linear interpolation with the `to` quaternion, given a `weight` and returns the result. Unlike [`FixedQuaternion::slerp`], this method does not check if the rotation path is smaller than 90 degrees. Both this quaternion and `to` must be normalized.
+    pub fn slerp_ni(&self, to: &Self, weight: Fixed) -> Self {
+        let dot = self.dot(to);
+        if dot.abs() > Fixed::ONE - Fixed::CMP_EPSILON {
+            return *self;
+        }
+        let theta = dot.acos();
+        let sin_t = Fixed::ONE / theta.sin();
+        let new_factor = (weight * theta).sin() * sin_t;
+        let inv_factor = ((Fixed::ONE - weight) * theta).sin() * sin_t;
+        self * inv_factor + to * new_factor
+    }
+
+    /// Performs a spherical cubic interpolation between quaternions `pre_a`, this quaternion, `b`, and `post_b`, by the given amount `weight`.
+    pub fn spherical_cubic_interpolate(&self, b: &Self, pre_a: &Self, post_b: &Self, weight: Fixed) -> Self {
+        self.spherical_cubic_interpolate_with(b, pre_a, post_b, weight, |from, to, pre, post| {
+            from.cubic_interpolate(to, pre, post, weight)
+        })
+    }
+
+    /// Performs a spherical cubic interpolation between quaternions `pre_a`, this quaternion, `b`, and `post_b`, by the given amount `weight`.
+    ///
+    /// It can perform smoother interpolation than [`FixedQuaternion::spherical_cubic_interpolate`] by the time values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn spherical_cubic_interpolate_in_time(
+        &self,
+        b: &Self,
+        pre_a: &Self,
+        post_b: &Self,
+        weight: Fixed,
+        b_t: Fixed,
+        pre_a_t: Fixed,
+        post_b_t: Fixed,
+    ) -> Self {
+        self.spherical_cubic_interpolate_with(b, pre_a, post_b, weight, |from, to, pre, post| {
+            from.cubic_interpolate_in_time(to, pre, post, weight, b_t, pre_a_t, post_b_t)
+        })
+    }
+
+    /// Interpolates the logarithms of the four quaternions with `cubic`, in the spaces of both this quaternion and `b`, then blends the two results.
+    fn spherical_cubic_interpolate_with(
+        &self,
+        b: &Self,
+        pre_a: &Self,
+        post_b: &Self,
+        weight: Fixed,
+        cubic: impl Fn(Fixed, Fixed, Fixed, Fixed) -> Fixed,
+    ) -> Self {
+        // Align flip phases.
+        let from_q = FixedBasis::from(self).get_rotation_quaternion();
+        let mut pre_q = FixedBasis::from(pre_a).get_rotation_quaternion();
+        let mut to_q = FixedBasis::from(b).get_rotation_quaternion();
+        let mut post_q = FixedBasis::from(post_b).get_rotation_quaternion();
+
+        // Flip quaternions to the shortest path if necessary.
+        if from_q.dot(&pre_q).is_negative() {
+            pre_q = -pre_q;
+        }
+        let flip2 = from_q.dot(&to_q).is_negative();
+        if flip2 {
+            to_q = -to_q;
+        }
+        let to_post = to_q.dot(&post_q);
+        if to_post.is_negative() || (flip2 && to_post == Fixed::ZERO) {
+            post_q = -post_q;
+        }
+
+        // Calc by Exp map in the space of `space`, where the logarithm of `space` itself is zero.
+        let interpolate = |space: &Self| {
+            let [ln_from, ln_to, ln_pre, ln_post] = [from_q, to_q, pre_q, post_q].map(|q| {
+                if q == *space {
+                    Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO, Fixed::ZERO)
+                } else {
+                    (space.inverse() * q).log()
+                }
+            });
+            let ln = Self::new(
+                cubic(ln_from.x, ln_to.x, ln_pre.x, ln_post.x),
+                cubic(ln_from.y, ln_to.y, ln_pre.y, ln_post.y),
+                cubic(ln_from.z, ln_to.z, ln_pre.z, ln_post.z),
+                Fixed::ZERO,
+            );
+            space * ln.exp()
+        };
+        let q1 = interpolate(&from_q);
+        let q2 = interpolate(&to_q);
+
+        // To cancel error made by Exp map ambiguity, do blending.
+        q1.slerp(&q2, weight)
+    }
+
+    /// Rotates `v` by this quaternion.
+    pub fn xform(&self, v: &FixedVector3) -> FixedVector3 {
+        let u = FixedVector3::new(self.x, self.y, self.z);
+        let uv = u.cross(v);
+        v + ((uv * self.w) + u.cross(&uv)) * Fixed::TWO
+    }
+}
+
+impl From<(&FixedVector3, &FixedVector3)> for FixedQuaternion {
+    /// Constructs a quaternion representing the shortest arc between the two normalized vectors.
+    fn from((arc_from, arc_to): (&FixedVector3, &FixedVector3)) -> Self {
+        let c = arc_from.cross(arc_to);
+        let d = arc_from.dot(arc_to);
+
+        if d < -Fixed::ONE + Fixed::CMP_EPSILON {
+            Self::new(Fixed::ZERO, Fixed::ONE, Fixed::ZERO, Fixed::ZERO)
+        } else {
+            let s = ((Fixed::ONE + d) * Fixed::TWO).sqrt();
+            let rs = Fixed::ONE / s;
+            Self::new(c.x * rs, c.y * rs, c.z * rs, s * Fixed::HALF)
+        }
+    }
+}
+
+impl From<(&FixedVector3, Fixed)> for FixedQuaternion {
+    /// Constructs a quaternion that will rotate around the given axis by the specified angle (in radians). The axis must be a normalized vector.
+    fn from((axis, angle): (&FixedVector3, Fixed)) -> Self {
+        let d = axis.length();
+        if d == Fixed::ZERO {
+            Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO, Fixed::ZERO)
+        } else {
+            let (sin_angle, cos_angle) = (angle * Fixed::HALF).sin_cos();
+            let s = sin_angle / d;
+            Self::new(axis.x * s, axis.y * s, axis.z * s, cos_angle)
+        }
+    }
+}
+
+impl From<&FixedBasis> for FixedQuaternion {
+    /// Constructs a quaternion from the rotation of the given basis, with [`FixedBasis::get_rotation_quaternion`].
+    fn from(basis: &FixedBasis) -> Self {
+        basis.get_rotation_quaternion()
+    }
+}
+
+impl From<Quaternion> for FixedQuaternion {
+    /// Converts each component with [`Fixed::from_float`].
+    fn from(value: Quaternion) -> Self {
+        Self::new(
+            Fixed::from_float(value.x),
+            Fixed::from_float(value.y),
+            Fixed::from_float(value.z),
+            Fixed::from_float(value.w),
+        )
+    }
+}
+
+impl From<FixedQuaternion> for Quaternion {
+    /// Converts each component with [`Fixed::to_float`].
+    fn from(value: FixedQuaternion) -> Self {
+        Self::new(
+            value.x.to_float(),
+            value.y.to_float(),
+            value.z.to_float(),
+            value.w.to_float(),
+        )
+    }
+}
+
+impl_op_ex!(*|lhs: &FixedQuaternion, rhs: &FixedQuaternion| -> FixedQuaternion {
+    FixedQuaternion::new(
+        lhs.w * rhs.x + lhs.x * rhs.w + lhs.y * rhs.z - lhs.z * rhs.y,
+        lhs.w * rhs.y + lhs.y * rhs.w + lhs.z * rhs.x - lhs.x * rhs.z,
+        lhs.w * rhs.z + lhs.z * rhs.w + lhs.x * rhs.y - lhs.y * rhs.x,
+        lhs.w * rhs.w - lhs.x * rhs.x - lhs.y * rhs.y - lhs.z * rhs.z,
+    )
+});
+impl_op_ex!(*= |lhs: &mut FixedQuaternion, rhs: &FixedQuaternion| { *lhs = *lhs * rhs; });
+impl_op_ex!(*|lhs: &FixedQuaternion, rhs: &FixedVector3| -> FixedVector3 { lhs.xform(rhs) });
+impl_op_ex!(+ |lhs: &FixedQuaternion, rhs: &FixedQuaternion| -> FixedQuaternion {
+    FixedQuaternion::new(lhs.x + rhs.x, lhs.y + rhs.y, lhs.z + rhs.z, lhs.w + rhs.w)
+});
+impl_op_ex!(-|lhs: &FixedQuaternion, rhs: &FixedQuaternion| -> FixedQuaternion {
+    FixedQuaternion::new(lhs.x - rhs.x, lhs.y - rhs.y, lhs.z - rhs.z, lhs.w - rhs.w)
+});
+impl_op_ex_commutative!(*|lhs: &FixedQuaternion, rhs: &Fixed| -> FixedQuaternion {
+    FixedQuaternion::new(lhs.x * rhs, lhs.y * rhs, lhs.z * rhs, lhs.w * rhs)
+});
+impl_op_ex!(/ |lhs: &FixedQuaternion, rhs: &Fixed| -> FixedQuaternion {
+    FixedQuaternion::new(lhs.x / rhs, lhs.y / rhs, lhs.z / rhs, lhs.w / rhs)
+});
+impl_op_ex!(*= |lhs: &mut FixedQuaternion, rhs: &Fixed| { *lhs = *lhs * rhs; });
+impl_op_ex!(/= |lhs: &mut FixedQuaternion, rhs: &Fixed| { *lhs = *lhs / rhs; });
+
+impl Neg for FixedQuaternion {
+    type Output = FixedQuaternion;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
@@ -0,0 +1,479 @@
+use crate::utils::{float, int};
+use auto_ops::impl_op_ex;
+use core::fmt::{Display, Formatter};
+use core::ops::Neg;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+
+/// A signed Q32.32 fixed-point number.
+///
+/// The value is stored in an `i64` as a multiple of `2^-32`: 32 integer bits and 32 fractional bits, for a range of about ±2.1 billion and a resolution of about `2.3e-10`.
+///
+/// All of its arithmetic and math functions, including [`Fixed::sqrt`], [`Fixed::sin`] and [`Fixed::atan2`], only use integer operations. Unlike floats, they give bit-identical results on every CPU and with every compiler, which makes them suitable for lockstep simulations and replays.
+///
+/// Multiplication and division compute the exact result in 128 bits, then truncate it to the resolution: multiplication rounds toward negative infinity and division rounds toward zero. Like integer arithmetic, overflow panics in debug builds and wraps in release builds, and dividing by zero always panics.
+///
+/// Convert from and to floats explicitly with [`Fixed::from_float`] and [`Fixed::to_float`], or the matching [`From`] implementations. These conversions are the only places where floats are involved.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i64);
+
+/// The number of fractional bits.
+const FRACTIONAL_BITS: u32 = 32;
+
+/// `2^32` as a float, the scale between a float and the bits of a fixed-point number. Multiplying or dividing by it is exact.
+const SCALE: float!() = (1u64 << FRACTIONAL_BITS) as float!();
+
+/// Returns the fixed-point number closest to `numerator / denominator`, for positive values.
+const fn ratio(numerator: i64, denominator: i64) -> Fixed {
+    Fixed(((numerator << (FRACTIONAL_BITS + 1)) + denominator) / (2 * denominator))
+}
+
+/// The coefficients of the Taylor series of `sin(x) / x`, in `x²`, with alternating signs. Accurate to the resolution in `-PI / 4..=PI / 4`.
+const SIN_COEFFICIENTS: [Fixed; 6] = [
+    Fixed::ONE,
+    ratio(1, 6),
+    ratio(1, 120),
+    ratio(1, 5040),
+    ratio(1, 362880),
+    ratio(1, 39916800),
+];
+
+/// The coefficients of the Taylor series of `cos(x)`, in `x²`, with alternating signs. Accurate to the resolution in `-PI / 4..=PI / 4`.
+const COS_COEFFICIENTS: [Fixed; 7] = [
+    Fixed::ONE,
+    ratio(1, 2),
+    ratio(1, 24),
+    ratio(1, 720),
+    ratio(1, 40320),
+    ratio(1, 3628800),
+    ratio(1, 479001600),
+];
+
+/// The coefficients of the Taylor series of `atan(x) / x`, in `x²`, with alternating signs. Accurate to the resolution in `0..=TAN_FRAC_PI_12`.
+const ATAN_COEFFICIENTS: [Fixed; 8] = [
+    Fixed::ONE,
+    ratio(1, 3),
+    ratio(1, 5),
+    ratio(1, 7),
+    ratio(1, 9),
+    ratio(1, 11),
+    ratio(1, 13),
+    ratio(1, 15),
+];
+
+/// `tan(PI / 12)`, or `2 - sqrt(3)`.
+const TAN_FRAC_PI_12: Fixed = Fixed(1150833018);
+
+/// `sqrt(3)`.
+const SQRT_3: Fixed = Fixed(7439101574);
+
+impl Fixed {
+    /// The number `0`.
+    pub const ZERO: Self = Self(0);
+
+    /// The number `1`.
+    pub const ONE: Self = Self(1 << FRACTIONAL_BITS);
+
+    /// The number `0.5`.
+    pub const HALF: Self = Self(1 << (FRACTIONAL_BITS - 1));
+
+    /// The number `2`.
+    pub const TWO: Self = Self(2 << FRACTIONAL_BITS);
+
+    /// The smallest positive number, `2^-32`.
+    pub const DELTA: Self = Self(1);
+
+    /// The smallest number, about `-2.1e9`.
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// The largest number, about `2.1e9`.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Archimedes' constant, `π`.
+    pub const PI: Self = Self(13493037705);
+
+    /// The full circle constant, `2π`.
+    pub const TAU: Self = Self(26986075409);
+
+    /// `π / 2`.
+    pub const FRAC_PI_2: Self = Self(6746518852);
+
+    /// `π / 4`.
+    pub const FRAC_PI_4: Self = Self(3373259426);
+
+    /// `π / 6`.
+    pub const FRAC_PI_6: Self = Self(2248839617);
+
+    /// `1 / sqrt(2)`.
+    pub const FRAC_1_SQRT_2: Self = Self(3037000500);
+
+    /// The tolerance used by the approximate comparisons, the closest number to `0.00001`, like [`CMP_EPSILON`](crate::utils::CMP_EPSILON).
+    pub const CMP_EPSILON: Self = Self(42950);
+
+    /// The tolerance used to check if vectors and quaternions are normalized, like [`UNIT_EPSILON`](crate::utils::UNIT_EPSILON).
+    pub const UNIT_EPSILON: Self = Self(42950);
+
+    /// Constructs a **Fixed** from its raw representation, a multiple of `2^-32`.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw representation of this number, a multiple of `2^-32`.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Constructs a **Fixed** from an integer. Integers outside of the range of **Fixed** overflow.
+    #[allow(clippy::unnecessary_cast)]
+    pub const fn from_int(value: int!()) -> Self {
+        Self((value as i64) << FRACTIONAL_BITS)
+    }
+
+    /// Returns the integer part of this number, rounded toward negative infinity.
+    #[allow(clippy::unnecessary_cast)]
+    pub const fn to_int(self) -> int!() {
+        (self.0 >> FRACTIONAL_BITS) as int!()
+    }
+
+    /// Constructs the **Fixed** closest to `value`. Values outside of the range saturate to [`Fixed::MIN`] or [`Fixed::MAX`], and `NaN` becomes [`Fixed::ZERO`].
+    pub fn from_float(value: float!()) -> Self {
+        Self((value * SCALE).round() as i64)
+    }
+
+    /// Returns the float closest to this number.
+    pub fn to_float(self) -> float!() {
+        self.0 as float!() / SCALE
+    }
+
+    /// Returns the absolute value of this number.
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns `-1` if this number is negative, `1` if it's positive, and `0` if it's zero.
+    pub const fn sign(self) -> Self {
+        Self(self.0.signum() << FRACTIONAL_BITS)
+    }
+
+    /// Returns `true` if this number is negative.
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the largest integer less than or equal to this number.
+    pub const fn floor(self) -> Self {
+        Self(self.0 & !(Self::ONE.0 - 1))
+    }
+
+    /// Returns the smallest integer greater than or equal to this number.
+    pub const fn ceil(self) -> Self {
+        Self(self.0 + (Self::ONE.0 - 1)).floor()
+    }
+
+    /// Returns the integer closest to this number, rounding half-way cases away from zero.
+    pub const fn round(self) -> Self {
+        if self.0 < 0 {
+            Self(-Self(-self.0 + Self::HALF.0).floor().0)
+        } else {
+            Self(self.0 + Self::HALF.0).floor()
+        }
+    }
+
+    /// Returns the integer part of this number, rounding toward zero.
+    pub const fn trunc(self) -> Self {
+        if self.0 < 0 {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    /// Returns the fractional part of this number, `self - self.floor()`, which is always in `0..1`.
+    pub const fn fract(self) -> Self {
+        Self(self.0 & (Self::ONE.0 - 1))
+    }
+
+    /// Returns the linear interpolation between this number and `to` by the `weight` amount.
+    pub fn lerp(self, to: Self, weight: Self) -> Self {
+        self + (to - self) * weight
+    }
+
+    /// Returns the modulus of this number by `module`, which has the same sign as `module`, unlike the `%` operator.
+    pub fn posmod(self, module: Self) -> Self {
+        let value = self % module;
+        if (value < Self::ZERO && module > Self::ZERO) || (value > Self::ZERO && module < Self::ZERO) {
+            value + module
+        } else {
+            value
+        }
+    }
+
+    /// Returns this number snapped to the nearest multiple of `step`. If `step` is `0`, returns this number unchanged.
+    pub fn snapped(self, step: Self) -> Self {
+        if step != Self::ZERO {
+            (self / step + Self::HALF).floor() * step
+        } else {
+            self
+        }
+    }
+
+    /// Returns the point at the given `t` on the one-dimensional Bézier curve defined by this number and the given `control_1`, `control_2`, and `end` points.
+    pub fn bezier_interpolate(self, control_1: Self, control_2: Self, end: Self, t: Self) -> Self {
+        let three = Self::from_int(3);
+        let omt = Self::ONE - t;
+        let omt2 = omt * omt;
+        let t2 = t * t;
+        self * omt2 * omt + control_1 * omt2 * t * three + control_2 * omt * t2 * three + end * t2 * t
+    }
+
+    /// Returns the derivative at the given `t` on the one-dimensional Bézier curve defined by this number and the given `control_1`, `control_2`, and `end` points.
+    pub fn bezier_derivative(self, control_1: Self, control_2: Self, end: Self, t: Self) -> Self {
+        let three = Self::from_int(3);
+        let omt = Self::ONE - t;
+        (control_1 - self) * three * omt * omt
+            + (control_2 - control_1) * Self::from_int(6) * omt * t
+            + (end - control_2) * three * t * t
+    }
+
+    /// Performs a cubic interpolation between this number and `to` using `pre` and `post` as handles, and returns the result at position `weight`.
+    pub fn cubic_interpolate(self, to: Self, pre: Self, post: Self, weight: Self) -> Self {
+        let three = Self::from_int(3);
+        let weight2 = weight * weight;
+        Self::HALF
+            * (self * Self::TWO
+                + (to - pre) * weight
+                + (Self::TWO * pre - Self::from_int(5) * self + Self::from_int(4) * to - post) * weight2
+                + (three * self - pre - three * to + post) * weight2 * weight)
+    }
+
+    /// Performs a cubic interpolation between this number and `to` using `pre` and `post` as handles, at the times `pre_t`, `to_t` and `post_t` relative to this number, and returns the result at position `weight`.
+    ///
+    /// It can perform smoother interpolation than [`Fixed::cubic_interpolate`] by the time values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn cubic_interpolate_in_time(
+        self,
+        to: Self,
+        pre: Self,
+        post: Self,
+        weight: Self,
+        to_t: Self,
+        pre_t: Self,
+        post_t: Self,
+    ) -> Self {
+        // Barry-Goldman method, with the same fallbacks as the float version when two times are equal.
+        let ratio = |num: Self, den: Self, fallback: Self| if den == Self::ZERO { fallback } else { num / den };
+        let t = Self::ZERO.lerp(to_t, weight);
+        let a1 = pre.lerp(self, ratio(t - pre_t, -pre_t, Self::ZERO));
+        let a2 = self.lerp(to, ratio(t, to_t, Self::HALF));
+        let a3 = to.lerp(post, ratio(t - to_t, post_t - to_t, Self::ONE));
+        let b1 = a1.lerp(a2, ratio(t - pre_t, to_t - pre_t, Self::ZERO));
+        let b2 = a2.lerp(a3, ratio(t, post_t, Self::ONE));
+        b1.lerp(b2, ratio(t, to_t, Self::HALF))
+    }
+
+    /// Returns `true` if this number and `to` are approximately equal, with a tolerance of [`Fixed::CMP_EPSILON`] relative to this number, or at least [`Fixed::CMP_EPSILON`].
+    pub fn is_equal_approx(self, to: Self) -> bool {
+        if self == to {
+            return true;
+        }
+        let tolerance = (Self::CMP_EPSILON * self.abs()).max(Self::CMP_EPSILON);
+        (self - to).abs() < tolerance
+    }
+
+    /// Returns `true` if this number and `to` are approximately equal, within `tolerance`.
+    pub fn is_equal_approx_with_tolerance(self, to: Self, tolerance: Self) -> bool {
+        self == to || (self - to).abs() < tolerance
+    }
+
+    /// Returns `true` if this number is within [`Fixed::CMP_EPSILON`] of zero.
+    pub fn is_zero_approx(self) -> bool {
+        self.abs() < Self::CMP_EPSILON
+    }
+
+    /// Returns the square root of this number, rounded toward zero.
+    ///
+    /// **Note:** There is no `NaN` in fixed point, so negative numbers return `0`.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        Self(((self.0 as u128) << FRACTIONAL_BITS).isqrt() as i64)
+    }
+
+    /// Returns the square root of the sum of the squares of `values`, computed in 128 bits so that the squares don't overflow. Used for the lengths of vectors and quaternions.
+    pub(crate) fn hypot(values: &[Self]) -> Self {
+        let sum = values
+            .iter()
+            .fold(0u128, |sum, value| {
+                let magnitude = value.0.unsigned_abs() as u128;
+                sum.saturating_add(magnitude * magnitude)
+            });
+        Self(sum.isqrt() as i64)
+    }
+
+    /// Returns the sine of this angle, in radians.
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine of this angle, in radians.
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and the cosine of this angle, in radians. This is faster than calling [`Fixed::sin`] and [`Fixed::cos`] separately.
+    pub fn sin_cos(self) -> (Self, Self) {
+        // Reduce the angle to -PI / 4..PI / 4, plus a number of quarter turns.
+        let angle = self.0.rem_euclid(Self::TAU.0);
+        let quadrant = (angle + Self::FRAC_PI_4.0) / Self::FRAC_PI_2.0;
+        let x = Self(angle - quadrant * Self::FRAC_PI_2.0);
+
+        let x2 = x * x;
+        let sin = x * alternating_series(x2, &SIN_COEFFICIENTS);
+        let cos = alternating_series(x2, &COS_COEFFICIENTS);
+        match quadrant {
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            3 => (-cos, sin),
+            _ => (sin, cos),
+        }
+    }
+
+    /// Returns the tangent of this angle, in radians.
+    ///
+    /// **Panics** if the cosine of the angle is exactly zero.
+    pub fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
+    }
+
+    /// Returns the arc sine of this number, in radians, in `-PI / 2..=PI / 2`. The number is clamped to `-1..=1` first.
+    pub fn asin(self) -> Self {
+        let x = self.clamp(-Self::ONE, Self::ONE);
+        x.atan2((Self::ONE - x * x).sqrt())
+    }
+
+    /// Returns the arc cosine of this number, in radians, in `0..=PI`. The number is clamped to `-1..=1` first.
+    pub fn acos(self) -> Self {
+        let x = self.clamp(-Self::ONE, Self::ONE);
+        (Self::ONE - x * x).sqrt().atan2(x)
+    }
+
+    /// Returns the arc tangent of this number, in radians, in `-PI / 2..=PI / 2`.
+    pub fn atan(self) -> Self {
+        let x = self.abs();
+        let angle = if x <= Self::ONE {
+            atan_unit(x)
+        } else {
+            Self::FRAC_PI_2 - atan_unit(Self::ONE / x)
+        };
+        if self.is_negative() {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Returns the arc tangent of `self / x`, in radians, in `-PI..=PI`, using the signs of both numbers to find the quadrant. Returns `0` if both are zero.
+    pub fn atan2(self, x: Self) -> Self {
+        let y = self;
+        if y == Self::ZERO && x == Self::ZERO {
+            return Self::ZERO;
+        }
+        let (ax, ay) = (x.abs(), y.abs());
+        // Divide the smaller magnitude by the larger, so the ratio never overflows.
+        let mut angle = if ay <= ax {
+            atan_unit(ay / ax)
+        } else {
+            Self::FRAC_PI_2 - atan_unit(ax / ay)
+        };
+        if x.is_negative() {
+            angle = Self::PI - angle;
+        }
+        if y.is_negative() {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
+/// Evaluates `c0 - x2 * (c1 - x2 * (c2 - ...))` with Horner's method.
+fn alternating_series(x2: Fixed, coefficients: &[Fixed]) -> Fixed {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fixed::ZERO, |result, &coefficient| coefficient - x2 * result)
+}
+
+/// Returns the arc tangent of `x`, for `x` in `0..=1`.
+fn atan_unit(x: Fixed) -> Fixed {
+    // Reduce the range to 0..=tan(PI / 12), where the series converges quickly, with atan(x) = PI / 6 + atan((x * sqrt(3) - 1) / (sqrt(3) + x)).
+    if x > TAN_FRAC_PI_12 {
+        let reduced = (x * SQRT_3 - Fixed::ONE) / (SQRT_3 + x);
+        Fixed::FRAC_PI_6 + reduced * alternating_series(reduced * reduced, &ATAN_COEFFICIENTS)
+    } else {
+        x * alternating_series(x * x, &ATAN_COEFFICIENTS)
+    }
+}
+
+impl From<int!()> for Fixed {
+    fn from(value: int!()) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<float!()> for Fixed {
+    /// Converts with [`Fixed::from_float`].
+    fn from(value: float!()) -> Self {
+        Self::from_float(value)
+    }
+}
+
+impl From<Fixed> for float!() {
+    /// Converts with [`Fixed::to_float`].
+    fn from(value: Fixed) -> Self {
+        value.to_float()
+    }
+}
+
+impl_op_ex!(+ |a: &Fixed, b: &Fixed| -> Fixed { Fixed(a.0 + b.0) });
+impl_op_ex!(-|a: &Fixed, b: &Fixed| -> Fixed { Fixed(a.0 - b.0) });
+impl_op_ex!(*|a: &Fixed, b: &Fixed| -> Fixed {
+    let product = (a.0 as i128 * b.0 as i128) >> FRACTIONAL_BITS;
+    debug_assert!(
+        i64::try_from(product).is_ok(),
+        "attempt to multiply with overflow"
+    );
+    Fixed(product as i64)
+});
+impl_op_ex!(/ |a: &Fixed, b: &Fixed| -> Fixed {
+    let quotient = ((a.0 as i128) << FRACTIONAL_BITS) / b.0 as i128;
+    debug_assert!(
+        i64::try_from(quotient).is_ok(),
+        "attempt to divide with overflow"
+    );
+    Fixed(quotient as i64)
+});
+impl_op_ex!(% |a: &Fixed, b: &Fixed| -> Fixed { Fixed(a.0 % b.0) });
+
+impl_op_ex!(+= |a: &mut Fixed, b: &Fixed| { *a = *a + b; });
+impl_op_ex!(-= |a: &mut Fixed, b: &Fixed| { *a = *a - b; });
+impl_op_ex!(*= |a: &mut Fixed, b: &Fixed| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut Fixed, b: &Fixed| { *a = *a / b; });
+impl_op_ex!(%= |a: &mut Fixed, b: &Fixed| { *a = *a % b; });
+
+impl Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Self::Output {
+        Fixed(-self.0)
+    }
+}
+
+impl Display for Fixed {
+    /// Prints the value like an `f64`. It is the exact decimal value below `2^21` in magnitude, and is rounded to the 53 significant bits of an `f64` above.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // Every Q32.32 number below 2^21 in magnitude is exactly representable as an f64.
+        write!(f, "{}", self.0 as f64 / (1u64 << FRACTIONAL_BITS) as f64)
+    }
+}
@@ -0,0 +1,283 @@
+use crate::types::fixed::{Fixed, FixedVector2};
+use crate::types::Transform2D;
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+/// A 2×3 matrix representing a 2D transformation using [`Fixed`] components, for deterministic simulations.
+///
+/// It has the same conventions and methods as [`Transform2D`], except for `basis_xfrom`, a misspelled alias of [`FixedTransform2D::basis_xform`]. Every result is bit-identical across platforms. Convert with [`From`] in both directions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedTransform2D {
+    /// The translation offset of this transform, and the column `2` of the matrix.
+    pub origin: FixedVector2,
+    /// The transform basis's X axis, and the column `0` of the matrix.
+    pub x: FixedVector2,
+    /// The transform basis's Y axis, and the column `1` of the matrix.
+    pub y: FixedVector2,
+}
+
+impl Default for FixedTransform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl FixedTransform2D {
+    /// The identity transform, with no translation, rotation or scaling applied.
+    pub const IDENTITY: Self = Self::new(FixedVector2::RIGHT, FixedVector2::DOWN, FixedVector2::ZERO);
+
+    /// When any transform is multiplied by **FLIP_X**, it negates all components of the [`FixedTransform2D::x`] axis (the X column).
+    pub const FLIP_X: Self = Self::new(FixedVector2::LEFT, FixedVector2::DOWN, FixedVector2::ZERO);
+
+    /// When any transform is multiplied by **FLIP_Y**, it negates all components of the [`FixedTransform2D::y`] axis (the Y column).
+    pub const FLIP_Y: Self = Self::new(FixedVector2::RIGHT, FixedVector2::UP, FixedVector2::ZERO);
+
+    /// Constructs a **FixedTransform2D** from 3 [`FixedVector2`] values representing [`FixedTransform2D::x`], [`FixedTransform2D::y`], and the [`FixedTransform2D::origin`] (the three matrix columns).
+    pub const fn new(x: FixedVector2, y: FixedVector2, origin: FixedVector2) -> Self {
+        Self { origin, x, y }
+    }
+
+    /// Constructs a **FixedTransform2D** from the components of its three columns: `x`, `y` and the origin.
+    pub const fn new_from_floats(xx: Fixed, xy: Fixed, yx: Fixed, yy: Fixed, origin_x: Fixed, origin_y: Fixed) -> Self {
+        Self::new(
+            FixedVector2::new(xx, xy),
+            FixedVector2::new(yx, yy),
+            FixedVector2::new(origin_x, origin_y),
+        )
+    }
+
+    /// Returns the inverse of the transform, under the assumption that the basis is invertible (must have non-zero determinant).
+    pub fn affine_inverse(&self) -> Self {
+        let idet = Fixed::ONE / self.determinant();
+        let x = FixedVector2::new(self.y.y * idet, -self.x.y * idet);
+        let y = FixedVector2::new(-self.y.x * idet, self.x.x * idet);
+        let mut inv = Self::new(x, y, FixedVector2::ZERO);
+        inv.origin = inv.basis_xform(&-self.origin);
+        inv
+    }
+
+    /// Returns a vector transformed (multiplied) by the basis matrix. This method does not account for translation (the [`FixedTransform2D::origin`] vector).
+    pub fn basis_xform(&self, v: &FixedVector2) -> FixedVector2 {
+        FixedVector2::new(self.tdotx(v), self.tdoty(v))
+    }
+
+    /// Returns a vector transformed (multiplied) by the inverse basis matrix, under the assumption that the basis is orthonormal (see [`FixedTransform2D::is_conformal`]). This method does not account for translation (the [`FixedTransform2D::origin`] vector).
+    pub fn basis_xform_inv(&self, v: &FixedVector2) -> FixedVector2 {
+        FixedVector2::new(self.x.dot(v), self.y.dot(v))
+    }
+
+    /// Returns the determinant of the basis matrix.
+    pub fn determinant(&self) -> Fixed {
+        self.x.x * self.y.y - self.x.y * self.y.x
+    }
+
+    /// Returns this transform's translation. Equivalent to [`FixedTransform2D::origin`].
+    pub fn get_origin(&self) -> FixedVector2 {
+        self.origin
+    }
+
+    /// Returns this transform's rotation (in radians). This is equivalent to [`FixedTransform2D::x`]'s angle (see [`FixedVector2::angle`]).
+    pub fn get_rotation(&self) -> Fixed {
+        self.x.angle()
+    }
+
+    /// Returns the length of both [`FixedTransform2D::x`] and [`FixedTransform2D::y`], as a [`FixedVector2`]. If this transform's basis is not sheared, this value is the scaling factor.
+    pub fn get_scale(&self) -> FixedVector2 {
+        let det_sign = self.determinant().sign();
+        FixedVector2::new(self.x.length(), det_sign * self.y.length())
+    }
+
+    /// Returns this transform's skew (in radians).
+    pub fn get_skew(&self) -> Fixed {
+        let det_sign = self.determinant().sign();
+        self.x
+            .normalized()
+            .dot(&(det_sign * self.y.normalized()))
+            .acos()
+            - Fixed::FRAC_PI_2
+    }
+
+    /// Sets this transform's skew (in radians), by rotating [`FixedTransform2D::y`] relative to [`FixedTransform2D::x`] while keeping its length.
+    pub fn set_skew(&mut self, angle: Fixed) {
+        let det_sign = self.determinant().sign();
+        self.y = det_sign * self.x.rotated(Fixed::FRAC_PI_2 + angle).normalized() * self.y.length();
+    }
+
+    /// Returns the result of the linear interpolation between this transform and `xform` by the given `weight`.
+    pub fn interpolate_with(&self, xform: &Self, weight: Fixed) -> Self {
+        Self::from((
+            self.get_rotation().lerp(xform.get_rotation(), weight),
+            self.get_scale().lerp(&xform.get_scale(), weight),
+            self.get_skew().lerp(xform.get_skew(), weight),
+            self.get_origin().lerp(&xform.get_origin(), weight),
+        ))
+    }
+
+    /// Returns the inverted version of this transform, under the assumption that the basis is orthonormal (see [`FixedTransform2D::is_conformal`]).
+    pub fn inverse(&self) -> Self {
+        let mut inv = *self;
+        core::mem::swap(&mut inv.x.y, &mut inv.y.x);
+        inv.origin = inv.basis_xform(&-self.origin);
+        inv
+    }
+
+    /// Returns `true` if this transform's basis is conformal, meaning it preserves angles and distance ratios, and may only be composed of rotation and uniform scale.
+    pub fn is_conformal(&self) -> bool {
+        // Non-flipped case.
+        (self.x.x.is_equal_approx(self.y.y) && self.x.y.is_equal_approx(-self.y.x))
+            // Flipped case.
+            || (self.x.x.is_equal_approx(-self.y.y) && self.x.y.is_equal_approx(self.y.x))
+    }
+
+    /// Returns `true`, since fixed-point numbers can't be infinite or NaN. It exists for parity with [`Transform2D::is_finite`].
+    pub const fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Returns `true` if this transform and `xform` are approximately equal, by running [`FixedVector2::is_equal_approx`] on each component.
+    pub fn is_equal_approx(&self, xform: &Self) -> bool {
+        self.x.is_equal_approx(&xform.x)
+            && self.y.is_equal_approx(&xform.y)
+            && self.origin.is_equal_approx(&xform.origin)
+    }
+
+    /// Returns a copy of the transform rotated such that the rotated X-axis points towards the `target` position, in global space.
+    pub fn looking_at(&self, target: &FixedVector2) -> Self {
+        let target_position = self.affine_inverse().xform(target);
+        let rotation = self.get_rotation() + (target_position * self.get_scale()).angle();
+        let mut return_trans = Self::from((rotation, self.get_origin()));
+        return_trans.x *= self.x.length();
+        return_trans.y *= self.y.length();
+        return_trans
+    }
+
+    /// Returns a copy of this transform with its basis orthonormalized. An orthonormalized basis is both orthogonal (the axes are perpendicular to each other) and normalized (the axes have a length of `1`), which also means it can only represent rotation.
+    pub fn orthonormalized(&self) -> Self {
+        // Gram-Schmidt Process
+        let x = self.x.normalized();
+        let y = (self.y - x * x.dot(&self.y)).normalized();
+        Self::new(x, y, self.origin)
+    }
+
+    /// Returns a copy of this transform rotated by the given `angle` (in radians), in the global space (left multiplication).
+    pub fn rotated(&self, angle: Fixed) -> Self {
+        Self::from((angle, FixedVector2::ZERO)) * self
+    }
+
+    /// Returns a copy of this transform rotated by the given `angle` (in radians), in the local space (right multiplication).
+    pub fn rotated_local(&self, angle: Fixed) -> Self {
+        self * Self::from((angle, FixedVector2::ZERO))
+    }
+
+    /// Returns a copy of this transform scaled by the given `scale` factor, in the global space (left multiplication).
+    pub fn scaled(&self, scale: &FixedVector2) -> Self {
+        Self::new(self.x * scale, self.y * scale, self.origin * scale)
+    }
+
+    /// Returns a copy of this transform scaled by the given `scale` factor, in the local space (right multiplication).
+    pub fn scaled_local(&self, scale: &FixedVector2) -> Self {
+        Self::new(self.x * scale.x, self.y * scale.y, self.origin)
+    }
+
+    /// Returns a copy of this transform translated by the given `offset`, in the global space (left multiplication).
+    pub fn translated(&self, offset: &FixedVector2) -> Self {
+        Self::new(self.x, self.y, self.origin + offset)
+    }
+
+    /// Returns a copy of this transform translated by the given `offset`, in the local space (right multiplication).
+    pub fn translated_local(&self, offset: &FixedVector2) -> Self {
+        Self::new(self.x, self.y, self.origin + self.basis_xform(offset))
+    }
+
+    /// Returns the dot product of the first row of the basis with `v`.
+    pub fn tdotx(&self, v: &FixedVector2) -> Fixed {
+        self.x.x * v.x + self.y.x * v.y
+    }
+
+    /// Returns the dot product of the second row of the basis with `v`.
+    pub fn tdoty(&self, v: &FixedVector2) -> Fixed {
+        self.x.y * v.x + self.y.y * v.y
+    }
+
+    /// Returns `v` transformed by this transform, including the translation.
+    pub fn xform(&self, v: &FixedVector2) -> FixedVector2 {
+        self.basis_xform(v) + self.origin
+    }
+
+    /// Returns `v` transformed by the inverse of this transform, under the assumption that the basis is orthonormal.
+    pub fn xform_inv(&self, v: &FixedVector2) -> FixedVector2 {
+        self.basis_xform_inv(&(v - self.origin))
+    }
+
+    /// Returns the column at the given `index`: `0` is [`FixedTransform2D::x`], `1` is [`FixedTransform2D::y`] and `2` is [`FixedTransform2D::origin`].
+    pub fn get(&self, index: usize) -> FixedVector2 {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            2 => self.origin,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Sets the column at the given `index`: `0` is [`FixedTransform2D::x`], `1` is [`FixedTransform2D::y`] and `2` is [`FixedTransform2D::origin`].
+    pub fn set(&mut self, index: usize, value: FixedVector2) {
+        match index {
+            0 => self.x = value,
+            1 => self.y = value,
+            2 => self.origin = value,
+            _ => panic!("Invalid index"),
+        }
+    }
+}
+
+impl From<(Fixed, FixedVector2)> for FixedTransform2D {
+    /// Constructs a transform from a given rotation angle (in radians) and position.
+    fn from((rotation, position): (Fixed, FixedVector2)) -> Self {
+        let (sr, cr) = rotation.sin_cos();
+        Self::new(
+            FixedVector2::new(cr, sr),
+            FixedVector2::new(-sr, cr),
+            position,
+        )
+    }
+}
+
+impl From<(Fixed, FixedVector2, Fixed, FixedVector2)> for FixedTransform2D {
+    /// Constructs a transform from a given rotation angle (in radians), scale, skew angle (in radians) and position.
+    fn from((rotation, scale, skew, position): (Fixed, FixedVector2, Fixed, FixedVector2)) -> Self {
+        let (sr, cr) = rotation.sin_cos();
+        let (srs, crs) = (rotation + skew).sin_cos();
+        Self::new(
+            FixedVector2::new(cr * scale.x, sr * scale.x),
+            FixedVector2::new(-srs * scale.y, crs * scale.y),
+            position,
+        )
+    }
+}
+
+impl From<Transform2D> for FixedTransform2D {
+    /// Converts each column with [`FixedVector2::from`].
+    fn from(value: Transform2D) -> Self {
+        Self::new(value.x.into(), value.y.into(), value.origin.into())
+    }
+}
+
+impl From<FixedTransform2D> for Transform2D {
+    /// Converts each column into a [`crate::types::vectors::Vector2`].
+    fn from(value: FixedTransform2D) -> Self {
+        Self::new(value.x.into(), value.y.into(), value.origin.into())
+    }
+}
+
+impl_op_ex!(*|a: &FixedTransform2D, b: &FixedTransform2D| -> FixedTransform2D {
+    FixedTransform2D::new(a.basis_xform(&b.x), a.basis_xform(&b.y), a.xform(&b.origin))
+});
+impl_op_ex!(*= |a: &mut FixedTransform2D, b: &FixedTransform2D| { *a = *a * b; });
+impl_op_ex!(*|a: &FixedTransform2D, b: &FixedVector2| -> FixedVector2 { a.xform(b) });
+impl_op_ex_commutative!(*|a: &FixedTransform2D, b: &Fixed| -> FixedTransform2D {
+    FixedTransform2D::new(a.x * b, a.y * b, a.origin * b)
+});
+impl_op_ex!(/ |a: &FixedTransform2D, b: &Fixed| -> FixedTransform2D {
+    FixedTransform2D::new(a.x / b, a.y / b, a.origin / b)
+});
+impl_op_ex!(*= |a: &mut FixedTransform2D, b: &Fixed| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut FixedTransform2D, b: &Fixed| { *a = *a / b; });
@@ -0,0 +1,436 @@
+use crate::types::fixed::Fixed;
+use crate::types::vectors::{Vector2, AXIS};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+use core::fmt::Display;
+use core::ops::Neg;
+
+/// A 2D vector using [`Fixed`] coordinates, for deterministic simulations.
+///
+/// It has the same methods as [`Vector2`], with the same conventions, but every result is bit-identical across platforms. Convert with [`From`] in both directions.
+///
+/// **Note:** [`FixedVector2::length_squared`] and [`FixedVector2::dot`] overflow for components larger than about `32768`. [`FixedVector2::length`] and [`FixedVector2::normalized`] don't, since they compute the squares in 128 bits.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedVector2 {
+    /// The vector's X component.
+    pub x: Fixed,
+    /// The vector's Y component.
+    pub y: Fixed,
+}
+
+impl FixedVector2 {
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::new(Fixed::ONE, Fixed::ONE);
+
+    /// Left unit vector. Represents the direction of left.
+    pub const LEFT: Self = Self::new(Fixed::from_int(-1), Fixed::ZERO);
+
+    /// Right unit vector. Represents the direction of right.
+    pub const RIGHT: Self = Self::new(Fixed::ONE, Fixed::ZERO);
+
+    /// Up unit vector. Y is down in 2D, so this vector points -Y.
+    pub const UP: Self = Self::new(Fixed::ZERO, Fixed::from_int(-1));
+
+    /// Down unit vector. Y is down in 2D, so this vector points +Y.
+    pub const DOWN: Self = Self::new(Fixed::ZERO, Fixed::ONE);
+
+    /// Constructs a new **FixedVector2** from the given `x` and `y`.
+    pub const fn new(x: Fixed, y: Fixed) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns a new vector with all components in absolute values (i.e. positive).
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Returns this vector's angle with respect to the positive X axis, or `(1, 0)` vector, in radians.
+    pub fn angle(&self) -> Fixed {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the angle to the given vector, in radians.
+    pub fn angle_to(&self, to: &Self) -> Fixed {
+        self.cross(to).atan2(self.dot(to))
+    }
+
+    /// Returns the angle between the line connecting the two points and the X axis, in radians.
+    pub fn angle_to_point(&self, to: &Self) -> Fixed {
+        (to - self).angle()
+    }
+
+    /// Returns the aspect ratio of this vector, the ratio of `x` to `y`.
+    pub fn aspect(&self) -> Fixed {
+        self.x / self.y
+    }
+
+    /// Returns the derivative at the given `t` on the Bézier curve defined by this vector and the given `control_1`, `control_2`, and `end` points.
+    pub fn bezier_derivation(&self, control_1: &Self, control_2: &Self, end: &Self, t: Fixed) -> Self {
+        Self::new(
+            self.x.bezier_derivative(control_1.x, control_2.x, end.x, t),
+            self.y.bezier_derivative(control_1.y, control_2.y, end.y, t),
+        )
+    }
+
+    /// Returns the point at the given `t` on the Bézier curve defined by this vector and the given `control_1`, `control_2`, and `end` points.
+    pub fn bezier_interpolate(&self, control_1: &Self, control_2: &Self, end: &Self, t: Fixed) -> Self {
+        Self::new(
+            self.x.bezier_interpolate(control_1.x, control_2.x, end.x, t),
+            self.y.bezier_interpolate(control_1.y, control_2.y, end.y, t),
+        )
+    }
+
+    /// Returns the vector "bounced off" from a line defined by the given normal `n` perpendicular to the line.
+    pub fn bounce(&self, n: &Self) -> Self {
+        -self.reflect(n)
+    }
+
+    /// Returns a new vector with all components rounded up (towards positive infinity).
+    pub fn ceil(&self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil())
+    }
+
+    /// Returns a new vector with all components clamped between the components of `min` and `max`.
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
+    }
+
+    /// Returns a new vector with all components clamped between `min` and `max`.
+    pub fn clamp_f(&self, min: Fixed, max: Fixed) -> Self {
+        Self::new(self.x.clamp(min, max), self.y.clamp(min, max))
+    }
+
+    /// Returns the 2D analog of the cross product for this vector and `with`.
+    pub fn cross(&self, with: &Self) -> Fixed {
+        self.x * with.y - self.y * with.x
+    }
+
+    /// Performs a cubic interpolation between this vector and `b` using `pre_a` and `post_b` as handles, and returns the result at position `weight`.
+    pub fn cubic_interpolate(&self, b: &Self, pre_a: &Self, post_b: &Self, weight: Fixed) -> Self {
+        Self::new(
+            self.x.cubic_interpolate(b.x, pre_a.x, post_b.x, weight),
+            self.y.cubic_interpolate(b.y, pre_a.y, post_b.y, weight),
+        )
+    }
+
+    /// Performs a cubic interpolation between this vector and `b` using `pre_a` and `post_b` as handles, and returns the result at position `weight`.
+    ///
+    /// It can perform smoother interpolation than [`FixedVector2::cubic_interpolate`] by the time values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn cubic_interpolate_in_time(
+        &self,
+        b: &Self,
+        pre_a: &Self,
+        post_b: &Self,
+        weight: Fixed,
+        b_t: Fixed,
+        pre_a_t: Fixed,
+        post_b_t: Fixed,
+    ) -> Self {
+        Self::new(
+            self.x.cubic_interpolate_in_time(b.x, pre_a.x, post_b.x, weight, b_t, pre_a_t, post_b_t),
+            self.y.cubic_interpolate_in_time(b.y, pre_a.y, post_b.y, weight, b_t, pre_a_t, post_b_t),
+        )
+    }
+
+    /// Returns the normalized vector pointing from this vector to `to`.
+    pub fn direction_to(&self, to: &Self) -> Self {
+        (to - self).normalized()
+    }
+
+    /// Returns the squared distance between this vector and `to`.
+    pub fn distance_squared_to(&self, to: &Self) -> Fixed {
+        (to - self).length_squared()
+    }
+
+    /// Returns the distance between this vector and `to`.
+    pub fn distance_to(&self, to: &Self) -> Fixed {
+        (to - self).length()
+    }
+
+    /// Returns the dot product of this vector and `with`.
+    pub fn dot(&self, with: &Self) -> Fixed {
+        self.x * with.x + self.y * with.y
+    }
+
+    /// Returns a new vector with all components rounded down (towards negative infinity).
+    pub fn floor(&self) -> Self {
+        Self::new(self.x.floor(), self.y.floor())
+    }
+
+    /// Creates a unit vector rotated to the given `angle` in radians.
+    pub fn from_angle(angle: Fixed) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// Returns the component at the given `index`, `0` for X and `1` for Y.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub const fn get(&self, index: usize) -> Fixed {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Sets the component at the given `index`, `0` for X and `1` for Y.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub fn set(&mut self, index: usize, value: Fixed) {
+        match index {
+            0 => self.x = value,
+            1 => self.y = value,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Returns the component on the given `axis`.
+    ///
+    /// **Panics** if `axis` isn't [`AXIS::X`] or [`AXIS::Y`].
+    pub const fn get_axis(&self, axis: AXIS) -> Fixed {
+        match axis {
+            AXIS::X => self.x,
+            AXIS::Y => self.y,
+            _ => panic!("Invalid axis"),
+        }
+    }
+
+    /// Sets the component on the given `axis`.
+    ///
+    /// **Panics** if `axis` isn't [`AXIS::X`] or [`AXIS::Y`].
+    pub fn set_axis(&mut self, axis: AXIS, value: Fixed) {
+        match axis {
+            AXIS::X => self.x = value,
+            AXIS::Y => self.y = value,
+            _ => panic!("Invalid axis"),
+        }
+    }
+
+    /// Returns `true` if this vector and `to` are approximately equal, by running [`Fixed::is_equal_approx`] on each component.
+    pub fn is_equal_approx(&self, to: &Self) -> bool {
+        self.x.is_equal_approx(to.x) && self.y.is_equal_approx(to.y)
+    }
+
+    /// Returns `true`, since fixed-point numbers can't be infinite or NaN. It exists for parity with [`Vector2::is_finite`].
+    pub const fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Returns `true` if the vector is normalized, i.e. its length is approximately equal to `1`.
+    pub fn is_normalized(&self) -> bool {
+        self.length()
+            .is_equal_approx_with_tolerance(Fixed::ONE, Fixed::UNIT_EPSILON)
+    }
+
+    /// Returns `true` if this vector's values are approximately zero.
+    pub fn is_zero_approx(&self) -> bool {
+        self.x.is_zero_approx() && self.y.is_zero_approx()
+    }
+
+    /// Returns the length (magnitude) of this vector.
+    pub fn length(&self) -> Fixed {
+        Fixed::hypot(&[self.x, self.y])
+    }
+
+    /// Returns the squared length (squared magnitude) of this vector.
+    pub fn length_squared(&self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns the result of the linear interpolation between this vector and `to` by the `weight` amount.
+    pub fn lerp(&self, to: &Self, weight: Fixed) -> Self {
+        Self::new(self.x.lerp(to.x, weight), self.y.lerp(to.y, weight))
+    }
+
+    /// Returns the vector with a maximum length by limiting its length to `length`.
+    pub fn limit_length(&self, length: Fixed) -> Self {
+        let l = self.length();
+        if l > Fixed::ZERO && length < l {
+            self / l * length
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the component-wise maximum of this and `with`.
+    pub fn max(&self, with: &Self) -> Self {
+        Self::new(self.x.max(with.x), self.y.max(with.y))
+    }
+
+    /// Returns the component-wise maximum of this vector and `with`.
+    pub fn max_f(&self, with: Fixed) -> Self {
+        Self::new(self.x.max(with), self.y.max(with))
+    }
+
+    /// Returns the axis of the vector's highest value. If all components are equal, this method returns [`AXIS::X`].
+    pub fn max_axis_index(&self) -> AXIS {
+        if self.x < self.y {
+            AXIS::Y
+        } else {
+            AXIS::X
+        }
+    }
+
+    /// Returns the component-wise minimum of this and `with`.
+    pub fn min(&self, with: &Self) -> Self {
+        Self::new(self.x.min(with.x), self.y.min(with.y))
+    }
+
+    /// Returns the component-wise minimum of this vector and `with`.
+    pub fn min_f(&self, with: Fixed) -> Self {
+        Self::new(self.x.min(with), self.y.min(with))
+    }
+
+    /// Returns the axis of the vector's lowest value. If all components are equal, this method returns [`AXIS::Y`].
+    pub fn min_axis_index(&self) -> AXIS {
+        if self.x < self.y {
+            AXIS::X
+        } else {
+            AXIS::Y
+        }
+    }
+
+    /// Returns a new vector moved toward `to` by the fixed `delta` amount. Will not go past the final value.
+    pub fn move_toward(&self, to: &Self, delta: Fixed) -> Self {
+        let vd = to - self;
+        let len = vd.length();
+        if len <= delta || len < Fixed::CMP_EPSILON {
+            *to
+        } else {
+            self + vd / len * delta
+        }
+    }
+
+    /// Returns the result of scaling the vector to unit length. Equivalent to `v / v.length()`. Returns `(0, 0)` if `v.length() == 0`.
+    pub fn normalized(&self) -> Self {
+        let l = self.length();
+        if l == Fixed::ZERO {
+            *self
+        } else {
+            self / l
+        }
+    }
+
+    /// Returns a perpendicular vector rotated 90 degrees counter-clockwise compared to the original, with the same length.
+    pub fn orthogonal(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Returns a vector composed of the [`Fixed::posmod`] of this vector's components and `module`.
+    pub fn posmod(&self, module: Fixed) -> Self {
+        Self::new(self.x.posmod(module), self.y.posmod(module))
+    }
+
+    /// Returns a vector composed of the [`Fixed::posmod`] of this vector's components and `module_v`'s components.
+    pub fn posmodv(&self, module_v: &Self) -> Self {
+        Self::new(self.x.posmod(module_v.x), self.y.posmod(module_v.y))
+    }
+
+    /// Returns the result of projecting the vector onto the given vector `b`.
+    pub fn project(&self, b: &Self) -> Self {
+        b * (self.dot(b) / b.length_squared())
+    }
+
+    /// Returns the result of reflecting the vector from a line defined by the given direction vector `line`.
+    pub fn reflect(&self, line: &Self) -> Self {
+        line * (Fixed::TWO * self.dot(line)) - self
+    }
+
+    /// Returns the result of rotating this vector by `angle` (in radians).
+    pub fn rotated(&self, angle: Fixed) -> Self {
+        let (sine, cosine) = angle.sin_cos();
+        Self::new(
+            self.x * cosine - self.y * sine,
+            self.x * sine + self.y * cosine,
+        )
+    }
+
+    /// Returns a new vector with all components rounded to the nearest integer, with halfway cases rounded away from zero.
+    pub fn round(&self) -> Self {
+        Self::new(self.x.round(), self.y.round())
+    }
+
+    /// Returns a new vector with each component set to `1` if it's positive, `-1` if it's negative, and `0` if it's zero.
+    pub fn sign(&self) -> Self {
+        Self::new(self.x.sign(), self.y.sign())
+    }
+
+    /// Returns the result of spherical linear interpolation between this vector and `to`, by amount `weight`. This method also handles interpolating the lengths if the input vectors have different lengths.
+    pub fn slerp(&self, to: &Self, weight: Fixed) -> Self {
+        let start_length = self.length();
+        let end_length = to.length();
+        if start_length == Fixed::ZERO || end_length == Fixed::ZERO {
+            // Zero length vectors have no angle, so the best we can do is either lerp or throw an error.
+            return self.lerp(to, weight);
+        }
+        let result_length = start_length.lerp(end_length, weight);
+        let angle = self.angle_to(to);
+        self.rotated(angle * weight) * (result_length / start_length)
+    }
+
+    /// Returns the result of sliding this vector along a line with normal `n`.
+    pub fn slide(&self, n: &Self) -> Self {
+        self - n * self.dot(n)
+    }
+
+    /// Returns a new vector with each component snapped to the nearest multiple of the corresponding component in `step`.
+    pub fn snapped(&self, step: &Self) -> Self {
+        Self::new(self.x.snapped(step.x), self.y.snapped(step.y))
+    }
+
+    /// Returns a new vector with each component snapped to the nearest multiple of `step`.
+    pub fn snapped_f(&self, step: Fixed) -> Self {
+        Self::new(self.x.snapped(step), self.y.snapped(step))
+    }
+
+    /// Returns `vec` projected onto the line with this vector as its normal, at distance `d` from the origin.
+    pub fn plane_project(&self, d: Fixed, vec: &Self) -> Self {
+        vec - self * (self.dot(vec) - d)
+    }
+}
+
+impl From<Vector2> for FixedVector2 {
+    /// Converts each component with [`Fixed::from_float`].
+    fn from(value: Vector2) -> Self {
+        Self::new(Fixed::from_float(value.x), Fixed::from_float(value.y))
+    }
+}
+
+impl From<FixedVector2> for Vector2 {
+    /// Converts each component with [`Fixed::to_float`].
+    fn from(value: FixedVector2) -> Self {
+        Self::new(value.x.to_float(), value.y.to_float())
+    }
+}
+
+impl_op_ex!(+ |a: &FixedVector2, b: &FixedVector2| -> FixedVector2 { FixedVector2::new(a.x + b.x, a.y + b.y) });
+impl_op_ex!(-|a: &FixedVector2, b: &FixedVector2| -> FixedVector2 { FixedVector2::new(a.x - b.x, a.y - b.y) });
+impl_op_ex!(*|a: &FixedVector2, b: &FixedVector2| -> FixedVector2 { FixedVector2::new(a.x * b.x, a.y * b.y) });
+impl_op_ex!(/ |a: &FixedVector2, b: &FixedVector2| -> FixedVector2 { FixedVector2::new(a.x / b.x, a.y / b.y) });
+impl_op_ex_commutative!(*|a: &FixedVector2, b: &Fixed| -> FixedVector2 { FixedVector2::new(a.x * b, a.y * b) });
+impl_op_ex!(/ |a: &FixedVector2, b: &Fixed| -> FixedVector2 { FixedVector2::new(a.x / b, a.y / b) });
+
+impl_op_ex!(+= |a: &mut FixedVector2, b: &FixedVector2| { *a = *a + b; });
+impl_op_ex!(-= |a: &mut FixedVector2, b: &FixedVector2| { *a = *a - b; });
+impl_op_ex!(*= |a: &mut FixedVector2, b: &FixedVector2| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut FixedVector2, b: &FixedVector2| { *a = *a / b; });
+impl_op_ex!(*= |a: &mut FixedVector2, b: &Fixed| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut FixedVector2, b: &Fixed| { *a = *a / b; });
+
+impl Neg for FixedVector2 {
+    type Output = FixedVector2;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Display for FixedVector2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FixedVector2({}, {})", self.x, self.y)
+    }
+}
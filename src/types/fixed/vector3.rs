@@ -0,0 +1,509 @@
+use crate::types::fixed::{Fixed, FixedBasis, FixedVector2};
+use crate::types::vectors::{Vector3, AXIS};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+use core::fmt::Display;
+use core::ops::Neg;
+
+/// A 3D vector using [`Fixed`] coordinates, for deterministic simulations.
+///
+/// It has the same methods as [`Vector3`], with the same conventions, but every result is bit-identical across platforms. Convert with [`From`] in both directions.
+///
+/// **Note:** [`FixedVector3::length_squared`] and [`FixedVector3::dot`] overflow for components larger than about `26000`. [`FixedVector3::length`] and [`FixedVector3::normalized`] don't, since they compute the squares in 128 bits.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedVector3 {
+    /// The vector's X component.
+    pub x: Fixed,
+    /// The vector's Y component.
+    pub y: Fixed,
+    /// The vector's Z component.
+    pub z: Fixed,
+}
+
+impl FixedVector3 {
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::new(Fixed::ONE, Fixed::ONE, Fixed::ONE);
+
+    /// Left unit vector. Represents the local direction of left, and the global direction of west.
+    pub const LEFT: Self = Self::new(Fixed::from_int(-1), Fixed::ZERO, Fixed::ZERO);
+
+    /// Right unit vector. Represents the local direction of right, and the global direction of east.
+    pub const RIGHT: Self = Self::new(Fixed::ONE, Fixed::ZERO, Fixed::ZERO);
+
+    /// Up unit vector.
+    pub const UP: Self = Self::new(Fixed::ZERO, Fixed::ONE, Fixed::ZERO);
+
+    /// Down unit vector.
+    pub const DOWN: Self = Self::new(Fixed::ZERO, Fixed::from_int(-1), Fixed::ZERO);
+
+    /// Forward unit vector. Represents the local direction of forward, and the global direction of north.
+    pub const FORWARD: Self = Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::from_int(-1));
+
+    /// Back unit vector. Represents the local direction of back, and the global direction of south.
+    pub const BACK: Self = Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ONE);
+
+    /// Constructs a new **FixedVector3** from the given `x`, `y` and `z`.
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns a new vector with all components in absolute values (i.e. positive).
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Returns the unsigned minimum angle to the given vector, in radians.
+    pub fn angle_to(&self, to: &Self) -> Fixed {
+        self.cross(to).length().atan2(self.dot(to))
+    }
+
+    /// Returns the derivative at the given `t` on the Bézier curve defined by this vector and the given `control_1`, `control_2`, and `end` points.
+    pub fn bezier_derivative(&self, control_1: &Self, control_2: &Self, end: &Self, t: Fixed) -> Self {
+        Self::new(
+            self.x.bezier_derivative(control_1.x, control_2.x, end.x, t),
+            self.y.bezier_derivative(control_1.y, control_2.y, end.y, t),
+            self.z.bezier_derivative(control_1.z, control_2.z, end.z, t),
+        )
+    }
+
+    /// Returns the point at the given `t` on the Bézier curve defined by this vector and the given `control_1`, `control_2`, and `end` points.
+    pub fn bezier_interpolate(&self, control_1: &Self, control_2: &Self, end: &Self, t: Fixed) -> Self {
+        Self::new(
+            self.x.bezier_interpolate(control_1.x, control_2.x, end.x, t),
+            self.y.bezier_interpolate(control_1.y, control_2.y, end.y, t),
+            self.z.bezier_interpolate(control_1.z, control_2.z, end.z, t),
+        )
+    }
+
+    /// Returns the vector "bounced off" from a plane defined by the given normal `n`.
+    pub fn bounce(&self, n: &Self) -> Self {
+        -self.reflect(n)
+    }
+
+    /// Returns a new vector with all components rounded up (towards positive infinity).
+    pub fn ceil(&self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil(), self.z.ceil())
+    }
+
+    /// Returns a new vector with all components clamped between the components of `min` and `max`.
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        Self::new(
+            self.x.clamp(min.x, max.x),
+            self.y.clamp(min.y, max.y),
+            self.z.clamp(min.z, max.z),
+        )
+    }
+
+    /// Returns a new vector with all components clamped between `min` and `max`.
+    pub fn clamp_f(&self, min: Fixed, max: Fixed) -> Self {
+        Self::new(self.x.clamp(min, max), self.y.clamp(min, max), self.z.clamp(min, max))
+    }
+
+    /// Returns the cross product of this vector and `with`.
+    pub fn cross(&self, with: &Self) -> Self {
+        Self::new(
+            self.y * with.z - self.z * with.y,
+            self.z * with.x - self.x * with.z,
+            self.x * with.y - self.y * with.x,
+        )
+    }
+
+    /// Performs a cubic interpolation between this vector and `b` using `pre_a` and `post_b` as handles, and returns the result at position `weight`.
+    pub fn cubic_interpolate(&self, b: &Self, pre_a: &Self, post_b: &Self, weight: Fixed) -> Self {
+        Self::new(
+            self.x.cubic_interpolate(b.x, pre_a.x, post_b.x, weight),
+            self.y.cubic_interpolate(b.y, pre_a.y, post_b.y, weight),
+            self.z.cubic_interpolate(b.z, pre_a.z, post_b.z, weight),
+        )
+    }
+
+    /// Performs a cubic interpolation between this vector and `b` using `pre_a` and `post_b` as handles, and returns the result at position `weight`.
+    ///
+    /// It can perform smoother interpolation than [`FixedVector3::cubic_interpolate`] by the time values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn cubic_interpolate_in_time(
+        &self,
+        b: &Self,
+        pre_a: &Self,
+        post_b: &Self,
+        weight: Fixed,
+        b_t: Fixed,
+        pre_a_t: Fixed,
+        post_b_t: Fixed,
+    ) -> Self {
+        Self::new(
+            self.x.cubic_interpolate_in_time(b.x, pre_a.x, post_b.x, weight, b_t, pre_a_t, post_b_t),
+            self.y.cubic_interpolate_in_time(b.y, pre_a.y, post_b.y, weight, b_t, pre_a_t, post_b_t),
+            self.z.cubic_interpolate_in_time(b.z, pre_a.z, post_b.z, weight, b_t, pre_a_t, post_b_t),
+        )
+    }
+
+    /// Returns the normalized vector pointing from this vector to `to`.
+    pub fn direction_to(&self, to: &Self) -> Self {
+        (to - self).normalized()
+    }
+
+    /// Returns the squared distance between this vector and `to`.
+    pub fn distance_squared_to(&self, to: &Self) -> Fixed {
+        (to - self).length_squared()
+    }
+
+    /// Returns the distance between this vector and `to`.
+    pub fn distance_to(&self, to: &Self) -> Fixed {
+        (to - self).length()
+    }
+
+    /// Returns the dot product of this vector and `with`.
+    pub fn dot(&self, with: &Self) -> Fixed {
+        self.x * with.x + self.y * with.y + self.z * with.z
+    }
+
+    /// Returns a new vector with all components rounded down (towards negative infinity).
+    pub fn floor(&self) -> Self {
+        Self::new(self.x.floor(), self.y.floor(), self.z.floor())
+    }
+
+    /// Returns the component at the given `index`, `0` for X, `1` for Y and `2` for Z.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub const fn get(&self, index: usize) -> Fixed {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Sets the component at the given `index`, `0` for X, `1` for Y and `2` for Z.
+    ///
+    /// **Panics** if `index` is out of range.
+    pub fn set(&mut self, index: usize, value: Fixed) {
+        match index {
+            0 => self.x = value,
+            1 => self.y = value,
+            2 => self.z = value,
+            _ => panic!("Invalid index"),
+        }
+    }
+
+    /// Returns the component on the given `axis`.
+    ///
+    /// **Panics** if `axis` is [`AXIS::W`].
+    pub const fn get_axis(&self, axis: AXIS) -> Fixed {
+        match axis {
+            AXIS::X => self.x,
+            AXIS::Y => self.y,
+            AXIS::Z => self.z,
+            _ => panic!("Invalid axis"),
+        }
+    }
+
+    /// Sets the component on the given `axis`.
+    ///
+    /// **Panics** if `axis` is [`AXIS::W`].
+    pub fn set_axis(&mut self, axis: AXIS, value: Fixed) {
+        match axis {
+            AXIS::X => self.x = value,
+            AXIS::Y => self.y = value,
+            AXIS::Z => self.z = value,
+            _ => panic!("Invalid axis"),
+        }
+    }
+
+    /// Returns the inverse of the vector. This is the same as `FixedVector3::new(1 / v.x, 1 / v.y, 1 / v.z)`.
+    pub fn inverse(&self) -> Self {
+        Self::new(
+            Fixed::ONE / self.x,
+            Fixed::ONE / self.y,
+            Fixed::ONE / self.z,
+        )
+    }
+
+    /// Returns `true` if this vector and `to` are approximately equal, by running [`Fixed::is_equal_approx`] on each component.
+    pub fn is_equal_approx(&self, to: &Self) -> bool {
+        self.x.is_equal_approx(to.x) && self.y.is_equal_approx(to.y) && self.z.is_equal_approx(to.z)
+    }
+
+    /// Returns `true`, since fixed-point numbers can't be infinite or NaN. It exists for parity with [`Vector3::is_finite`].
+    pub const fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Returns `true` if the vector is normalized, i.e. its length is approximately equal to `1`.
+    pub fn is_normalized(&self) -> bool {
+        self.length()
+            .is_equal_approx_with_tolerance(Fixed::ONE, Fixed::UNIT_EPSILON)
+    }
+
+    /// Returns `true` if this vector's values are approximately zero.
+    pub fn is_zero_approx(&self) -> bool {
+        self.x.is_zero_approx() && self.y.is_zero_approx() && self.z.is_zero_approx()
+    }
+
+    /// Returns the length (magnitude) of this vector.
+    pub fn length(&self) -> Fixed {
+        Fixed::hypot(&[self.x, self.y, self.z])
+    }
+
+    /// Returns the squared length (squared magnitude) of this vector.
+    pub fn length_squared(&self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns the result of the linear interpolation between this vector and `to` by the `weight` amount.
+    pub fn lerp(&self, to: &Self, weight: Fixed) -> Self {
+        Self::new(
+            self.x.lerp(to.x, weight),
+            self.y.lerp(to.y, weight),
+            self.z.lerp(to.z, weight),
+        )
+    }
+
+    /// Returns the vector with a maximum length by limiting its length to `length`.
+    pub fn limit_length(&self, length: Fixed) -> Self {
+        let l = self.length();
+        if l > Fixed::ZERO && length < l {
+            self / l * length
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the component-wise maximum of this and `with`.
+    pub fn max(&self, with: &Self) -> Self {
+        Self::new(self.x.max(with.x), self.y.max(with.y), self.z.max(with.z))
+    }
+
+    /// Returns the component-wise maximum of this vector and `with`.
+    pub fn max_f(&self, with: Fixed) -> Self {
+        Self::new(self.x.max(with), self.y.max(with), self.z.max(with))
+    }
+
+    /// Returns the axis of the vector's highest value. If all components are equal, this method returns [`AXIS::X`].
+    pub fn max_axis_index(&self) -> AXIS {
+        if self.x < self.y {
+            if self.y < self.z {
+                AXIS::Z
+            } else {
+                AXIS::Y
+            }
+        } else if self.x < self.z {
+            AXIS::Z
+        } else {
+            AXIS::X
+        }
+    }
+
+    /// Returns the component-wise minimum of this and `with`.
+    pub fn min(&self, with: &Self) -> Self {
+        Self::new(self.x.min(with.x), self.y.min(with.y), self.z.min(with.z))
+    }
+
+    /// Returns the component-wise minimum of this vector and `with`.
+    pub fn min_f(&self, with: Fixed) -> Self {
+        Self::new(self.x.min(with), self.y.min(with), self.z.min(with))
+    }
+
+    /// Returns the axis of the vector's lowest value. If all components are equal, this method returns [`AXIS::Z`].
+    pub fn min_axis_index(&self) -> AXIS {
+        if self.x < self.y {
+            if self.x < self.z {
+                AXIS::X
+            } else {
+                AXIS::Z
+            }
+        } else if self.y < self.z {
+            AXIS::Y
+        } else {
+            AXIS::Z
+        }
+    }
+
+    /// Returns a new vector moved toward `to` by the fixed `delta` amount. Will not go past the final value.
+    pub fn move_toward(&self, to: &Self, delta: Fixed) -> Self {
+        let vd = to - self;
+        let len = vd.length();
+        if len <= delta || len < Fixed::CMP_EPSILON {
+            *to
+        } else {
+            self + vd / len * delta
+        }
+    }
+
+    /// Returns the result of scaling the vector to unit length. Equivalent to `v / v.length()`. Returns `(0, 0, 0)` if `v.length() == 0`.
+    pub fn normalized(&self) -> Self {
+        let l = self.length();
+        if l == Fixed::ZERO {
+            *self
+        } else {
+            self / l
+        }
+    }
+
+    /// Returns the **FixedVector3** from an octahedral-compressed form created using [`FixedVector3::octahedron_encode`].
+    pub fn octahedron_decode(uv: &FixedVector2) -> Self {
+        let f = FixedVector2::new(uv.x * Fixed::TWO - Fixed::ONE, uv.y * Fixed::TWO - Fixed::ONE);
+        let mut n = Self::new(f.x, f.y, Fixed::ONE - f.x.abs() - f.y.abs());
+        let t = (-n.z).clamp(Fixed::ZERO, Fixed::ONE);
+        n.x += if n.x >= Fixed::ZERO { -t } else { t };
+        n.y += if n.y >= Fixed::ZERO { -t } else { t };
+        n.normalized()
+    }
+
+    /// Returns the octahedral-encoded form of this normalized vector, with both components in `0..=1`. See [`Vector3::octahedron_encode`].
+    pub fn octahedron_encode(&self) -> FixedVector2 {
+        let n = self / (self.x.abs() + self.y.abs() + self.z.abs());
+        let sign = |value: Fixed| if value >= Fixed::ZERO { Fixed::ONE } else { -Fixed::ONE };
+        let o = if n.z >= Fixed::ZERO {
+            FixedVector2::new(n.x, n.y)
+        } else {
+            FixedVector2::new((Fixed::ONE - n.y.abs()) * sign(n.x), (Fixed::ONE - n.x.abs()) * sign(n.y))
+        };
+        FixedVector2::new(o.x * Fixed::HALF + Fixed::HALF, o.y * Fixed::HALF + Fixed::HALF)
+    }
+
+    /// Returns the outer product with `with`.
+    pub fn outer(&self, with: &Self) -> FixedBasis {
+        FixedBasis::new_rows(self * with.x, self * with.y, self * with.z).transposed()
+    }
+
+    /// Returns a vector composed of the [`Fixed::posmod`] of this vector's components and `module`.
+    pub fn posmod(&self, module: Fixed) -> Self {
+        Self::new(
+            self.x.posmod(module),
+            self.y.posmod(module),
+            self.z.posmod(module),
+        )
+    }
+
+    /// Returns a vector composed of the [`Fixed::posmod`] of this vector's components and `module_v`'s components.
+    pub fn posmodv(&self, module_v: &Self) -> Self {
+        Self::new(
+            self.x.posmod(module_v.x),
+            self.y.posmod(module_v.y),
+            self.z.posmod(module_v.z),
+        )
+    }
+
+    /// Returns the result of projecting the vector onto the given vector `b`.
+    pub fn project(&self, b: &Self) -> Self {
+        b * (self.dot(b) / b.length_squared())
+    }
+
+    /// Returns the result of reflecting the vector through a plane defined by the given normal vector `n`.
+    pub fn reflect(&self, n: &Self) -> Self {
+        n * (Fixed::TWO * self.dot(n)) - self
+    }
+
+    /// Returns the result of rotating this vector around a given `axis` by `angle` (in radians). The axis must be a normalized vector.
+    pub fn rotated(&self, axis: &Self, angle: Fixed) -> Self {
+        FixedBasis::from((axis, angle)).xform(self)
+    }
+
+    /// Returns a new vector with all components rounded to the nearest integer, with halfway cases rounded away from zero.
+    pub fn round(&self) -> Self {
+        Self::new(self.x.round(), self.y.round(), self.z.round())
+    }
+
+    /// Returns a new vector with each component set to `1` if it's positive, `-1` if it's negative, and `0` if it's zero.
+    pub fn sign(&self) -> Self {
+        Self::new(self.x.sign(), self.y.sign(), self.z.sign())
+    }
+
+    /// Returns the signed angle to the given vector, in radians. The sign of the angle is positive in a counter-clockwise direction and negative in a clockwise direction when viewed from the side specified by the `axis`.
+    pub fn signed_angle_to(&self, to: &Self, axis: &Self) -> Fixed {
+        let cross_to = self.cross(to);
+        let unsigned_angle = cross_to.length().atan2(self.dot(to));
+        if cross_to.dot(axis).is_negative() {
+            -unsigned_angle
+        } else {
+            unsigned_angle
+        }
+    }
+
+    /// Returns the result of spherical linear interpolation between this vector and `to`, by amount `weight`. This method also handles interpolating the lengths if the input vectors have different lengths.
+    pub fn slerp(&self, to: &Self, weight: Fixed) -> Self {
+        let start_length = self.length();
+        let end_length = to.length();
+        if start_length == Fixed::ZERO || end_length == Fixed::ZERO {
+            // Zero length vectors have no angle, so the best we can do is either lerp or throw an error.
+            return self.lerp(to, weight);
+        }
+        let axis = self.cross(to);
+        let axis_length = axis.length();
+        if axis_length == Fixed::ZERO {
+            // Colinear vectors have no rotation axis or angle between them, so the best we can do is lerp.
+            return self.lerp(to, weight);
+        }
+        let result_length = start_length.lerp(end_length, weight);
+        let angle = self.angle_to(to);
+        self.rotated(&(axis / axis_length), angle * weight) * (result_length / start_length)
+    }
+
+    /// Returns a new vector resulting from sliding this vector along a plane with normal `n`.
+    pub fn slide(&self, n: &Self) -> Self {
+        self - n * self.dot(n)
+    }
+
+    /// Returns a new vector with each component snapped to the nearest multiple of the corresponding component in `step`.
+    pub fn snapped(&self, step: &Self) -> Self {
+        Self::new(
+            self.x.snapped(step.x),
+            self.y.snapped(step.y),
+            self.z.snapped(step.z),
+        )
+    }
+    /// Returns a new vector with each component snapped to the nearest multiple of `step`.
+    pub fn snapped_f(&self, step: Fixed) -> Self {
+        Self::new(self.x.snapped(step), self.y.snapped(step), self.z.snapped(step))
+    }
+}
+
+impl From<Vector3> for FixedVector3 {
+    /// Converts each component with [`Fixed::from_float`].
+    fn from(value: Vector3) -> Self {
+        Self::new(
+            Fixed::from_float(value.x),
+            Fixed::from_float(value.y),
+            Fixed::from_float(value.z),
+        )
+    }
+}
+
+impl From<FixedVector3> for Vector3 {
+    /// Converts each component with [`Fixed::to_float`].
+    fn from(value: FixedVector3) -> Self {
+        Self::new(value.x.to_float(), value.y.to_float(), value.z.to_float())
+    }
+}
+
+impl_op_ex!(+ |a: &FixedVector3, b: &FixedVector3| -> FixedVector3 { FixedVector3::new(a.x + b.x, a.y + b.y, a.z + b.z) });
+impl_op_ex!(-|a: &FixedVector3, b: &FixedVector3| -> FixedVector3 { FixedVector3::new(a.x - b.x, a.y - b.y, a.z - b.z) });
+impl_op_ex!(*|a: &FixedVector3, b: &FixedVector3| -> FixedVector3 { FixedVector3::new(a.x * b.x, a.y * b.y, a.z * b.z) });
+impl_op_ex!(/ |a: &FixedVector3, b: &FixedVector3| -> FixedVector3 { FixedVector3::new(a.x / b.x, a.y / b.y, a.z / b.z) });
+impl_op_ex_commutative!(*|a: &FixedVector3, b: &Fixed| -> FixedVector3 { FixedVector3::new(a.x * b, a.y * b, a.z * b) });
+impl_op_ex!(/ |a: &FixedVector3, b: &Fixed| -> FixedVector3 { FixedVector3::new(a.x / b, a.y / b, a.z / b) });
+
+impl_op_ex!(+= |a: &mut FixedVector3, b: &FixedVector3| { *a = *a + b; });
+impl_op_ex!(-= |a: &mut FixedVector3, b: &FixedVector3| { *a = *a - b; });
+impl_op_ex!(*= |a: &mut FixedVector3, b: &FixedVector3| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut FixedVector3, b: &FixedVector3| { *a = *a / b; });
+impl_op_ex!(*= |a: &mut FixedVector3, b: &Fixed| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut FixedVector3, b: &Fixed| { *a = *a / b; });
+
+impl Neg for FixedVector3 {
+    type Output = FixedVector3;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Display for FixedVector3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FixedVector3({}, {}, {})", self.x, self.y, self.z)
+    }
+}
@@ -0,0 +1,246 @@
+use crate::float;
+use crate::math;
+use crate::types::linalg::MatrixN;
+use crate::types::vectors::Vector3;
+use crate::types::{Plane, Transform3D, AABB};
+use core::fmt::{Display, Formatter};
+
+/// The result of testing a volume against a [`Frustum`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// The volume is completely outside the frustum, and can be culled.
+    Outside,
+    /// The volume crosses at least one of the frustum's planes.
+    Intersecting,
+    /// The volume is completely inside the frustum.
+    Inside,
+}
+
+/// Identifies one of the six planes of a [`Frustum`], in the order they are stored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FrustumPlane {
+    Near,
+    Far,
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+/// A view frustum, the volume a camera can see, bounded by six [`Plane`]s.
+///
+/// The normals of the planes point *outward*, so a point is inside the frustum if it isn't over any of them (see [`Plane::is_point_over`]). The planes are stored in [`FrustumPlane`] order: near, far, left, top, right and bottom.
+///
+/// A **Frustum** can be built for a camera [`Transform3D`] with [`perspective`](Frustum::perspective) or [`orthogonal`](Frustum::orthogonal), or extracted from a view-projection matrix with [`from_view_projection`](Frustum::from_view_projection). Cameras look toward their local [`Vector3::FORWARD`] (-Z) axis, with [`Vector3::UP`] as their up axis.
+///
+/// ```
+/// # use huginn::types::{Containment, Frustum, Transform3D, AABB};
+/// # use huginn::types::vectors::Vector3;
+/// let frustum = Frustum::perspective(&Transform3D::IDENTITY, 1.2, 16.0 / 9.0, 0.1, 100.0);
+///
+/// assert!(frustum.has_point(&Vector3::new(0.0, 0.0, -10.0)));
+/// assert_eq!(frustum.test_aabb(&AABB::new(Vector3::new(-1.0, -1.0, 5.0), Vector3::ONE)), Containment::Outside);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Frustum {
+    planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Constructs a **Frustum** from its six planes, in [`FrustumPlane`] order, with normals pointing outward.
+    pub const fn new(planes: [Plane; 6]) -> Self {
+        Self { planes }
+    }
+
+    /// Constructs a perspective **Frustum** for a camera placed at `camera`.
+    ///
+    /// `fov_y` is the vertical field of view, in radians, and `aspect` is the width of the view divided by its height. `z_near` and `z_far` are the distances to the near and far clipping planes.
+    pub fn perspective(
+        camera: &Transform3D,
+        fov_y: float!(),
+        aspect: float!(),
+        z_near: float!(),
+        z_far: float!(),
+    ) -> Self {
+        let half = fov_y * 0.5;
+        let tan_y = math::sin(half) / math::cos(half);
+        let tan_x = tan_y * aspect;
+        // The side planes go through the eye, so they only need a normal.
+        Self::from_camera_planes(
+            camera,
+            [
+                Plane::new(Vector3::BACK, -z_near),
+                Plane::new(Vector3::FORWARD, z_far),
+                Plane::new(Vector3::new(-1.0, 0.0, tan_x).normalized(), 0.0),
+                Plane::new(Vector3::new(0.0, 1.0, tan_y).normalized(), 0.0),
+                Plane::new(Vector3::new(1.0, 0.0, tan_x).normalized(), 0.0),
+                Plane::new(Vector3::new(0.0, -1.0, tan_y).normalized(), 0.0),
+            ],
+        )
+    }
+
+    /// Constructs an orthogonal **Frustum** for a camera placed at `camera`.
+    ///
+    /// `size` is the height of the view, and `aspect` is its width divided by its height. `z_near` and `z_far` are the distances to the near and far clipping planes.
+    pub fn orthogonal(
+        camera: &Transform3D,
+        size: float!(),
+        aspect: float!(),
+        z_near: float!(),
+        z_far: float!(),
+    ) -> Self {
+        let half_height = size * 0.5;
+        let half_width = half_height * aspect;
+        Self::from_camera_planes(
+            camera,
+            [
+                Plane::new(Vector3::BACK, -z_near),
+                Plane::new(Vector3::FORWARD, z_far),
+                Plane::new(Vector3::LEFT, half_width),
+                Plane::new(Vector3::UP, half_height),
+                Plane::new(Vector3::RIGHT, half_width),
+                Plane::new(Vector3::DOWN, half_height),
+            ],
+        )
+    }
+
+    /// Extracts a **Frustum** from a view-projection `matrix`, the product of a projection matrix and the inverse of the camera transform.
+    ///
+    /// The matrix transforms column vectors, with clip space coordinates between `-w` and `w` on every axis, as in OpenGL. The resulting frustum is in the space the matrix transforms from, usually world space.
+    pub fn from_view_projection(matrix: &MatrixN<4>) -> Self {
+        // Gribb and Hartmann: each clip plane is the sum or difference of the last row and one of the others.
+        let plane = |row: usize, sign: float!()| {
+            let coefficient = |column: usize| matrix[(3, column)] + sign * matrix[(row, column)];
+            // Inside is `ax + by + cz + w >= 0`, so the outward normal is the opposite of `(a, b, c)`.
+            let normal = -Vector3::new(coefficient(0), coefficient(1), coefficient(2));
+            let length = normal.length();
+            Plane::new(normal / length, coefficient(3) / length)
+        };
+        Self::new([
+            plane(2, 1.0),
+            plane(2, -1.0),
+            plane(0, 1.0),
+            plane(1, -1.0),
+            plane(0, -1.0),
+            plane(1, 1.0),
+        ])
+    }
+
+    fn from_camera_planes(camera: &Transform3D, planes: [Plane; 6]) -> Self {
+        Self::new(planes.map(|plane| camera * plane))
+    }
+
+    /// Returns the six planes of this frustum, in [`FrustumPlane`] order.
+    pub const fn planes(&self) -> &[Plane; 6] {
+        &self.planes
+    }
+
+    /// Returns the given plane of this frustum.
+    pub const fn get_plane(&self, plane: FrustumPlane) -> Plane {
+        self.planes[plane as usize]
+    }
+
+    /// Returns the eight corners of this frustum: the top-left, top-right, bottom-right and bottom-left corners of the near plane, followed by the same corners of the far plane.
+    ///
+    /// If some of the planes don't meet at a single point, [`None`] is returned.
+    pub fn get_corners(&self) -> Option<[Vector3; 8]> {
+        let [near, far, left, top, right, bottom] = self.planes;
+        let sides = [(left, top), (right, top), (right, bottom), (left, bottom)];
+        let ends = [near, far];
+        let mut corners = [Vector3::ZERO; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let (a, b) = sides[i % 4];
+            *corner = ends[i / 4].intersect_3(&a, &b)?;
+        }
+        Some(corners)
+    }
+
+    /// Returns `true` if `point` is inside this frustum. Points on the planes are considered inside.
+    pub fn has_point(&self, point: &Vector3) -> bool {
+        self.planes.iter().all(|plane| !plane.is_point_over(point))
+    }
+
+    /// Tests the sphere at `center` with the given `radius` against this frustum.
+    pub fn test_sphere(&self, center: &Vector3, radius: float!()) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.distance_to(center);
+            if distance > radius {
+                return Containment::Outside;
+            }
+            if distance > -radius {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    /// Tests `aabb` against this frustum.
+    ///
+    /// **Note:** The test is conservative: a large box near a corner of the frustum may be reported as [`Containment::Intersecting`] while being outside. It is never reported as [`Containment::Outside`] while being visible.
+    pub fn test_aabb(&self, aabb: &AABB) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in &self.planes {
+            // The corner farthest inside decides if the box is outside, and the farthest outside if it crosses the plane.
+            if plane.is_point_over(&aabb.get_support(&-plane.normal)) {
+                return Containment::Outside;
+            }
+            if plane.is_point_over(&aabb.get_support(&plane.normal)) {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    /// Tests every point of `points` against this frustum, writing whether each is inside to the same index of `results`.
+    ///
+    /// **Note:** Panics if `results` doesn't have the same length as `points`.
+    pub fn test_points(&self, points: &[Vector3], results: &mut [bool]) {
+        assert_eq!(
+            points.len(),
+            results.len(),
+            "There should be one result per point."
+        );
+        for (point, result) in points.iter().zip(results) {
+            *result = self.has_point(point);
+        }
+    }
+
+    /// Tests every sphere of `spheres`, given as a center and a radius, against this frustum, writing the results to the same index of `results`.
+    ///
+    /// **Note:** Panics if `results` doesn't have the same length as `spheres`.
+    pub fn test_spheres(&self, spheres: &[(Vector3, float!())], results: &mut [Containment]) {
+        assert_eq!(
+            spheres.len(),
+            results.len(),
+            "There should be one result per sphere."
+        );
+        for ((center, radius), result) in spheres.iter().zip(results) {
+            *result = self.test_sphere(center, *radius);
+        }
+    }
+
+    /// Tests every box of `aabbs` against this frustum, writing the results to the same index of `results`.
+    ///
+    /// **Note:** Panics if `results` doesn't have the same length as `aabbs`.
+    pub fn test_aabbs(&self, aabbs: &[AABB], results: &mut [Containment]) {
+        assert_eq!(
+            aabbs.len(),
+            results.len(),
+            "There should be one result per box."
+        );
+        for (aabb, result) in aabbs.iter().zip(results) {
+            *result = self.test_aabb(aabb);
+        }
+    }
+}
+
+impl Display for Frustum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let [near, far, left, top, right, bottom] = &self.planes;
+        write!(
+            f,
+            "[N: {near}, F: {far}, L: {left}, T: {top}, R: {right}, B: {bottom}]"
+        )
+    }
+}
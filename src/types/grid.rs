@@ -0,0 +1,646 @@
+use crate::int;
+use crate::math;
+use crate::types::vectors::{Vector2i, Vector3i};
+use crate::types::Rect2i;
+use core::iter::FusedIterator;
+
+/// An iterator over every cell of a [`Rect2i`](crate::types::Rect2i), in row-major order.
+///
+/// Created by [`Rect2i::cells`](crate::types::Rect2i::cells).
+#[derive(Copy, Clone, Debug)]
+pub struct Cells {
+    start_x: int!(),
+    end: Vector2i,
+    current: Vector2i,
+}
+
+impl Cells {
+    pub(crate) fn new(position: Vector2i, size: Vector2i) -> Self {
+        let end = position + size;
+        let current = if size.x > 0 && size.y > 0 {
+            position
+        } else {
+            end
+        };
+        Self {
+            start_x: position.x,
+            end,
+            current,
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        if self.current.y >= self.end.y {
+            return 0;
+        }
+        let width = (self.end.x - self.start_x) as usize;
+        let rows = (self.end.y - self.current.y - 1) as usize;
+        rows * width + (self.end.x - self.current.x) as usize
+    }
+}
+
+impl Iterator for Cells {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.y >= self.end.y {
+            return None;
+        }
+        let cell = self.current;
+        self.current.x += 1;
+        if self.current.x >= self.end.x {
+            self.current.x = self.start_x;
+            self.current.y += 1;
+        }
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Cells {}
+impl FusedIterator for Cells {}
+
+/// An iterator over the cells on the edge of a [`Rect2i`](crate::types::Rect2i), walking clockwise from the top-left corner.
+///
+/// Created by [`Rect2i::border_cells`](crate::types::Rect2i::border_cells).
+#[derive(Copy, Clone, Debug)]
+pub struct BorderCells {
+    position: Vector2i,
+    size: Vector2i,
+    index: int!(),
+    count: int!(),
+}
+
+impl BorderCells {
+    pub(crate) fn new(position: Vector2i, size: Vector2i) -> Self {
+        let count = if size.x <= 0 || size.y <= 0 {
+            0
+        } else if size.x == 1 || size.y == 1 {
+            size.x * size.y
+        } else {
+            2 * size.x + 2 * size.y - 4
+        };
+        Self {
+            position,
+            size,
+            index: 0,
+            count,
+        }
+    }
+}
+
+impl Iterator for BorderCells {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let i = self.index;
+        self.index += 1;
+
+        let (w, h) = (self.size.x, self.size.y);
+        let offset = if h == 1 {
+            Vector2i::new(i, 0)
+        } else if w == 1 {
+            Vector2i::new(0, i)
+        } else if i < w {
+            // Top edge, left to right.
+            Vector2i::new(i, 0)
+        } else if i < w + h - 1 {
+            // Right edge, top to bottom.
+            Vector2i::new(w - 1, i - w + 1)
+        } else if i < 2 * w + h - 2 {
+            // Bottom edge, right to left.
+            Vector2i::new(w - 2 - (i - (w + h - 1)), h - 1)
+        } else {
+            // Left edge, bottom to top.
+            Vector2i::new(0, h - 2 - (i - (2 * w + h - 2)))
+        };
+        Some(self.position + offset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BorderCells {}
+impl FusedIterator for BorderCells {}
+
+/// An iterator over the cells of a [Bresenham line](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm) between two [`Vector2i`], both ends included.
+///
+/// Consecutive cells are 8-connected, and the cells match Godot's `Geometry2D.bresenham_line`. Created by [`Vector2i::line_to`].
+#[derive(Copy, Clone, Debug)]
+pub struct Line {
+    current: Vector2i,
+    step: Vector2i,
+    delta: Vector2i,
+    err: int!(),
+    x_major: bool,
+    remaining: int!(),
+}
+
+impl Line {
+    pub(crate) fn new(from: Vector2i, to: Vector2i) -> Self {
+        let delta = (to - from).abs() * 2;
+        let x_major = delta.x > delta.y;
+        Self {
+            current: from,
+            step: (to - from).sign(),
+            delta,
+            err: if x_major { delta.x / 2 } else { delta.y / 2 },
+            x_major,
+            remaining: delta.x.max(delta.y) / 2 + 1,
+        }
+    }
+}
+
+impl Iterator for Line {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining <= 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let cell = self.current;
+
+        if self.x_major {
+            self.err -= self.delta.y;
+            if self.err < 0 {
+                self.current.y += self.step.y;
+                self.err += self.delta.x;
+            }
+            self.current.x += self.step.x;
+        } else {
+            self.err -= self.delta.x;
+            if self.err < 0 {
+                self.current.x += self.step.x;
+                self.err += self.delta.y;
+            }
+            self.current.y += self.step.y;
+        }
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Line {}
+impl FusedIterator for Line {}
+
+/// An iterator over every cell touched by the segment between the centers of two [`Vector2i`] cells, both ends included.
+///
+/// Every cell shares an edge with a cell yielded before it, so nothing can slip through the line diagonally. Consecutive cells share an edge too, except when the segment passes exactly through a grid corner: both cells sharing that corner are then yielded, one after the other, before the diagonal one, and those two only touch at the corner. Created by [`Vector2i::supercover_line_to`].
+#[derive(Copy, Clone, Debug)]
+pub struct SupercoverLine {
+    current: Vector2i,
+    step: Vector2i,
+    n: Vector2i,
+    i: Vector2i,
+    corner: u8,
+    started: bool,
+}
+
+impl SupercoverLine {
+    pub(crate) fn new(from: Vector2i, to: Vector2i) -> Self {
+        Self {
+            current: from,
+            step: (to - from).sign(),
+            n: (to - from).abs(),
+            i: Vector2i::ZERO,
+            corner: 0,
+            started: false,
+        }
+    }
+}
+
+impl Iterator for SupercoverLine {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.current);
+        }
+        match self.corner {
+            1 => {
+                self.corner = 2;
+                return Some(Vector2i::new(self.current.x, self.current.y + self.step.y));
+            }
+            2 => {
+                self.corner = 0;
+                self.current += self.step;
+                self.i += Vector2i::ONE;
+                return Some(self.current);
+            }
+            _ => {}
+        }
+        if self.i.x >= self.n.x && self.i.y >= self.n.y {
+            return None;
+        }
+
+        let decision = (1 + 2 * self.i.x) * self.n.y - (1 + 2 * self.i.y) * self.n.x;
+        if decision == 0 {
+            // The segment crosses a corner exactly, cover both neighbouring cells.
+            self.corner = 1;
+            Some(Vector2i::new(self.current.x + self.step.x, self.current.y))
+        } else if decision < 0 {
+            self.current.x += self.step.x;
+            self.i.x += 1;
+            Some(self.current)
+        } else {
+            self.current.y += self.step.y;
+            self.i.y += 1;
+            Some(self.current)
+        }
+    }
+}
+
+impl FusedIterator for SupercoverLine {}
+
+/// An iterator over the outline of a circle rasterized with the [midpoint circle algorithm](https://en.wikipedia.org/wiki/Midpoint_circle_algorithm).
+///
+/// Every cell is yielded exactly once, but the octants are interleaved rather than walked in angular order. Created by [`Vector2i::circle_cells`].
+#[derive(Copy, Clone, Debug)]
+pub struct CircleCells {
+    center: Vector2i,
+    x: int!(),
+    y: int!(),
+    err: int!(),
+    octant: u8,
+}
+
+impl CircleCells {
+    pub(crate) fn new(center: Vector2i, radius: int!()) -> Self {
+        Self {
+            center,
+            x: radius,
+            y: 0,
+            err: 1 - radius,
+            octant: 0,
+        }
+    }
+
+    fn is_duplicate(&self, octant: u8) -> bool {
+        if self.x == 0 {
+            octant != 0
+        } else if self.y == 0 {
+            matches!(octant, 2 | 4 | 6 | 7)
+        } else if self.x == self.y {
+            octant % 2 == 1
+        } else {
+            false
+        }
+    }
+
+    fn advance(&mut self) {
+        self.y += 1;
+        if self.err < 0 {
+            self.err += 2 * self.y + 1;
+        } else {
+            self.x -= 1;
+            self.err += 2 * (self.y - self.x) + 1;
+        }
+    }
+}
+
+impl Iterator for CircleCells {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.x < self.y {
+                return None;
+            }
+            if self.octant == 8 {
+                self.octant = 0;
+                self.advance();
+                continue;
+            }
+            let octant = self.octant;
+            self.octant += 1;
+            if self.is_duplicate(octant) {
+                continue;
+            }
+            let (x, y) = (self.x, self.y);
+            let offset = match octant {
+                0 => Vector2i::new(x, y),
+                1 => Vector2i::new(y, x),
+                2 => Vector2i::new(-y, x),
+                3 => Vector2i::new(-x, y),
+                4 => Vector2i::new(-x, -y),
+                5 => Vector2i::new(-y, -x),
+                6 => Vector2i::new(y, -x),
+                _ => Vector2i::new(x, -y),
+            };
+            return Some(self.center + offset);
+        }
+    }
+}
+
+impl FusedIterator for CircleCells {}
+
+/// An iterator over every cell of a filled disk, in row-major order.
+///
+/// A cell at offset `(dx, dy)` from the center is included when `dx * dx + dy * dy <= radius * radius + radius`, which matches a circle of radius `radius + 0.5` and the outline produced by [`CircleCells`]. Created by [`Vector2i::disk_cells`].
+#[derive(Copy, Clone, Debug)]
+pub struct DiskCells {
+    center: Vector2i,
+    radius: int!(),
+    limit: int!(),
+    x: int!(),
+    y: int!(),
+    half_width: int!(),
+}
+
+impl DiskCells {
+    pub(crate) fn new(center: Vector2i, radius: int!()) -> Self {
+        let mut disk = Self {
+            center,
+            radius,
+            limit: radius * radius + radius,
+            x: 0,
+            y: -radius,
+            half_width: 0,
+        };
+        if radius >= 0 {
+            disk.start_row();
+        }
+        disk
+    }
+
+    fn start_row(&mut self) {
+        let available = self.limit - self.y * self.y;
+        let mut half_width = math::sqrt(available as f64) as int!();
+        while half_width * half_width > available {
+            half_width -= 1;
+        }
+        while (half_width + 1) * (half_width + 1) <= available {
+            half_width += 1;
+        }
+        self.half_width = half_width;
+        self.x = -half_width;
+    }
+}
+
+impl Iterator for DiskCells {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y > self.radius {
+            return None;
+        }
+        let cell = self.center + Vector2i::new(self.x, self.y);
+        self.x += 1;
+        if self.x > self.half_width {
+            self.y += 1;
+            if self.y <= self.radius {
+                self.start_row();
+            }
+        }
+        Some(cell)
+    }
+}
+
+impl FusedIterator for DiskCells {}
+
+/// An iterator over every cell of an axis-aligned box of [`Vector3i`], with X varying fastest, then Y, then Z.
+///
+/// Created by [`Vector3i::box_cells`].
+#[derive(Copy, Clone, Debug)]
+pub struct BoxCells {
+    start: Vector3i,
+    end: Vector3i,
+    current: Vector3i,
+}
+
+impl BoxCells {
+    pub(crate) fn new(position: Vector3i, size: Vector3i) -> Self {
+        let end = position + size;
+        let current = if size.x > 0 && size.y > 0 && size.z > 0 {
+            position
+        } else {
+            end
+        };
+        Self {
+            start: position,
+            end,
+            current,
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        if self.current.z >= self.end.z {
+            return 0;
+        }
+        let width = (self.end.x - self.start.x) as usize;
+        let height = (self.end.y - self.start.y) as usize;
+        let layers = (self.end.z - self.current.z - 1) as usize;
+        let rows = (self.end.y - self.current.y - 1) as usize;
+        layers * width * height + rows * width + (self.end.x - self.current.x) as usize
+    }
+}
+
+impl Iterator for BoxCells {
+    type Item = Vector3i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.z >= self.end.z {
+            return None;
+        }
+        let cell = self.current;
+        self.current.x += 1;
+        if self.current.x >= self.end.x {
+            self.current.x = self.start.x;
+            self.current.y += 1;
+            if self.current.y >= self.end.y {
+                self.current.y = self.start.y;
+                self.current.z += 1;
+            }
+        }
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BoxCells {}
+impl FusedIterator for BoxCells {}
+
+const NEIGHBORS_2D: [Vector2i; 8] = [
+    Vector2i::new(1, 0),
+    Vector2i::new(0, 1),
+    Vector2i::new(-1, 0),
+    Vector2i::new(0, -1),
+    Vector2i::new(1, 1),
+    Vector2i::new(-1, 1),
+    Vector2i::new(-1, -1),
+    Vector2i::new(1, -1),
+];
+
+const NEIGHBORS_3D: [Vector3i; 26] = [
+    // Faces.
+    Vector3i::new(1, 0, 0),
+    Vector3i::new(-1, 0, 0),
+    Vector3i::new(0, 1, 0),
+    Vector3i::new(0, -1, 0),
+    Vector3i::new(0, 0, 1),
+    Vector3i::new(0, 0, -1),
+    // Edges.
+    Vector3i::new(1, 1, 0),
+    Vector3i::new(-1, 1, 0),
+    Vector3i::new(1, -1, 0),
+    Vector3i::new(-1, -1, 0),
+    Vector3i::new(1, 0, 1),
+    Vector3i::new(-1, 0, 1),
+    Vector3i::new(1, 0, -1),
+    Vector3i::new(-1, 0, -1),
+    Vector3i::new(0, 1, 1),
+    Vector3i::new(0, -1, 1),
+    Vector3i::new(0, 1, -1),
+    Vector3i::new(0, -1, -1),
+    // Corners.
+    Vector3i::new(1, 1, 1),
+    Vector3i::new(-1, 1, 1),
+    Vector3i::new(1, -1, 1),
+    Vector3i::new(-1, -1, 1),
+    Vector3i::new(1, 1, -1),
+    Vector3i::new(-1, 1, -1),
+    Vector3i::new(1, -1, -1),
+    Vector3i::new(-1, -1, -1),
+];
+
+/// Which cells of a 2D grid count as the neighbors of a cell, see [`Vector2i::neighbors`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Connectivity2D {
+    /// The 4 cells sharing a side with the cell, also known as the von Neumann neighborhood.
+    Four,
+    /// The 8 cells sharing a side or a corner with the cell, also known as the Moore neighborhood.
+    Eight,
+}
+
+impl Connectivity2D {
+    /// Returns the offsets from a cell to its neighbors: the sides clockwise from [`Vector2i::RIGHT`] (Y is down), followed by the corners clockwise from the bottom-right one.
+    pub fn offsets(&self) -> &'static [Vector2i] {
+        match self {
+            Connectivity2D::Four => &NEIGHBORS_2D[..4],
+            Connectivity2D::Eight => &NEIGHBORS_2D,
+        }
+    }
+}
+
+/// Which cells of a 3D grid count as the neighbors of a cell, see [`Vector3i::neighbors`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Connectivity3D {
+    /// The 6 cells sharing a face with the cell.
+    Six,
+    /// The 18 cells sharing a face or an edge with the cell.
+    Eighteen,
+    /// The 26 cells sharing a face, an edge or a corner with the cell.
+    TwentySix,
+}
+
+impl Connectivity3D {
+    /// Returns the offsets from a cell to its neighbors: the faces along +X, -X, +Y, -Y, +Z and -Z, followed by the edges, followed by the corners.
+    pub fn offsets(&self) -> &'static [Vector3i] {
+        match self {
+            Connectivity3D::Six => &NEIGHBORS_3D[..6],
+            Connectivity3D::Eighteen => &NEIGHBORS_3D[..18],
+            Connectivity3D::TwentySix => &NEIGHBORS_3D,
+        }
+    }
+}
+
+/// An iterator over the neighbors of a [`Vector2i`] cell, in the order of [`Connectivity2D::offsets`], optionally skipping the cells outside of a [`Rect2i`].
+///
+/// Created by [`Vector2i::neighbors`] and [`Vector2i::neighbors_in`].
+#[derive(Copy, Clone, Debug)]
+pub struct Neighbors2D {
+    center: Vector2i,
+    offsets: &'static [Vector2i],
+    bounds: Option<Rect2i>,
+}
+
+impl Neighbors2D {
+    pub(crate) fn new(center: Vector2i, connectivity: Connectivity2D, bounds: Option<Rect2i>) -> Self {
+        Self {
+            center,
+            offsets: connectivity.offsets(),
+            bounds,
+        }
+    }
+}
+
+impl Iterator for Neighbors2D {
+    type Item = Vector2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((offset, rest)) = self.offsets.split_first() {
+            self.offsets = rest;
+            let cell = self.center + offset;
+            if self.bounds.is_none_or(|bounds| bounds.has_point(&cell)) {
+                return Some(cell);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.offsets.len();
+        match self.bounds {
+            Some(_) => (0, Some(remaining)),
+            None => (remaining, Some(remaining)),
+        }
+    }
+}
+
+impl FusedIterator for Neighbors2D {}
+
+/// An iterator over the neighbors of a [`Vector3i`] cell, in the order of [`Connectivity3D::offsets`].
+///
+/// Created by [`Vector3i::neighbors`].
+#[derive(Copy, Clone, Debug)]
+pub struct Neighbors3D {
+    center: Vector3i,
+    offsets: &'static [Vector3i],
+}
+
+impl Neighbors3D {
+    pub(crate) fn new(center: Vector3i, connectivity: Connectivity3D) -> Self {
+        Self {
+            center,
+            offsets: connectivity.offsets(),
+        }
+    }
+}
+
+impl Iterator for Neighbors3D {
+    type Item = Vector3i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (offset, rest) = self.offsets.split_first()?;
+        self.offsets = rest;
+        Some(self.center + offset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.offsets.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Neighbors3D {}
+impl FusedIterator for Neighbors3D {}
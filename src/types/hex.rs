@@ -0,0 +1,562 @@
+use crate::math;
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i};
+use crate::utils::{float, float_consts, int};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::Neg;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+
+/// A cell of a hexagonal grid in axial coordinates.
+///
+/// A **Hex** is stored as the axial pair (`q`, `r`). The third cube coordinate `s` is implied by the constraint `q + r + s == 0` (see [`Hex::s`]). Axial coordinates convert losslessly to and from a [`Vector2i`] holding (`q`, `r`), and cube coordinates to and from a [`Vector3i`] holding (`q`, `r`, `s`).
+///
+/// A **Hex** carries no information about its size, position or orientation in the world. Use a [`HexLayout`] to convert between hexes and [`Vector2`] positions, and [`HexOffset`] to convert to and from the offset coordinates commonly used to store hex maps in rectangular arrays.
+///
+/// The conventions follow [Red Blob Games' guide to hexagonal grids](https://www.redblobgames.com/grids/hexagons/).
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Hex {
+    /// The axial `q` coordinate, the column in a pointy-top layout.
+    pub q: int!(),
+    /// The axial `r` coordinate, the row in a pointy-top layout.
+    pub r: int!(),
+}
+
+impl Hex {
+    /// The hex at the origin of the grid.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// The six neighbor offsets, counter-clockwise on screen (Y down) starting from +q. See [`Hex::direction`].
+    pub const DIRECTIONS: [Self; 6] = [
+        Self::new(1, 0),
+        Self::new(1, -1),
+        Self::new(0, -1),
+        Self::new(-1, 0),
+        Self::new(-1, 1),
+        Self::new(0, 1),
+    ];
+
+    /// The six diagonal offsets, each lying between two consecutive [`Hex::DIRECTIONS`]. See [`Hex::diagonal`].
+    pub const DIAGONALS: [Self; 6] = [
+        Self::new(2, -1),
+        Self::new(1, -2),
+        Self::new(-1, -1),
+        Self::new(-2, 1),
+        Self::new(-1, 2),
+        Self::new(1, 1),
+    ];
+
+    /// Constructs a new **Hex** from the axial coordinates `q` and `r`.
+    pub const fn new(q: int!(), r: int!()) -> Self {
+        Self { q, r }
+    }
+
+    /// Constructs a new **Hex** from cube coordinates. Returns [`None`] if `cube.x + cube.y + cube.z` is not `0`.
+    pub const fn from_cube(cube: &Vector3i) -> Option<Self> {
+        if cube.x + cube.y + cube.z == 0 {
+            Some(Self::new(cube.x, cube.y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the third cube coordinate, equal to `-q - r`.
+    pub const fn s(&self) -> int!() {
+        -self.q - self.r
+    }
+
+    /// Returns the axial coordinates as a [`Vector2i`] of (`q`, `r`).
+    pub const fn axial(&self) -> Vector2i {
+        Vector2i::new(self.q, self.r)
+    }
+
+    /// Returns the cube coordinates as a [`Vector3i`] of (`q`, `r`, `s`).
+    pub const fn cube(&self) -> Vector3i {
+        Vector3i::new(self.q, self.r, self.s())
+    }
+
+    /// Returns the unit offset for the given `direction`, wrapped to the range `0..6`. See [`Hex::DIRECTIONS`].
+    pub const fn direction(direction: int!()) -> Self {
+        Self::DIRECTIONS[direction.rem_euclid(6) as usize]
+    }
+
+    /// Returns the diagonal offset for the given `direction`, wrapped to the range `0..6`. See [`Hex::DIAGONALS`].
+    pub const fn diagonal(direction: int!()) -> Self {
+        Self::DIAGONALS[direction.rem_euclid(6) as usize]
+    }
+
+    /// Returns the hex sharing an edge with this one in the given `direction`. See [`Hex::direction`].
+    pub fn neighbor(&self, direction: int!()) -> Self {
+        self + Self::direction(direction)
+    }
+
+    /// Returns the six hexes sharing an edge with this one, in the order of [`Hex::DIRECTIONS`].
+    pub fn neighbors(&self) -> [Self; 6] {
+        Self::DIRECTIONS.map(|d| self + d)
+    }
+
+    /// Returns the hex touching only a corner of this one in the given `direction`. See [`Hex::diagonal`].
+    pub fn diagonal_neighbor(&self, direction: int!()) -> Self {
+        self + Self::diagonal(direction)
+    }
+
+    /// Returns the six hexes touching only a corner of this one, in the order of [`Hex::DIAGONALS`].
+    pub fn diagonals(&self) -> [Self; 6] {
+        Self::DIAGONALS.map(|d| self + d)
+    }
+
+    /// Returns the number of steps between this hex and `to`, moving between neighbors.
+    pub fn distance_to(&self, to: &Self) -> int!() {
+        (to - self).length()
+    }
+
+    /// Returns the number of steps between this hex and [`Hex::ZERO`].
+    pub fn length(&self) -> int!() {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    /// Returns an iterator over the hexes on the straight line from this hex to `to`, both included. The line contains `distance_to(to) + 1` hexes, and consecutive hexes are neighbors.
+    pub fn line_to(&self, to: &Self) -> HexLine {
+        HexLine::new(*self, *to)
+    }
+
+    /// Returns an iterator over the hexes at exactly `radius` steps from this hex. A `radius` of `0` yields this hex, and a negative `radius` yields nothing.
+    ///
+    /// The ring starts at `self + Hex::direction(4) * radius` and walks counter-clockwise.
+    pub fn ring(&self, radius: int!()) -> HexRing {
+        HexRing::new(*self, radius)
+    }
+
+    /// Returns an iterator over the hexes at most `radius` steps from this hex, starting with this hex and then walking each [`Hex::ring`] outwards.
+    pub fn spiral(&self, radius: int!()) -> HexSpiral {
+        HexSpiral::new(*self, radius)
+    }
+
+    /// Returns an iterator over the hexes at most `radius` steps from this hex, ordered by `q` then `r`. This yields the same hexes as [`Hex::spiral`], and is slightly faster when the order does not matter.
+    pub fn range(&self, radius: int!()) -> HexRange {
+        HexRange::new(*self, radius)
+    }
+
+    /// Returns this hex rotated around `center` by `turns` sixths of a full turn. Positive values rotate clockwise on screen (Y down), negative values rotate counter-clockwise.
+    pub fn rotated(&self, center: &Self, turns: int!()) -> Self {
+        let mut v = self - center;
+        for _ in 0..turns.rem_euclid(6) {
+            // (q, r, s) -> (-r, -s, -q)
+            v = Self::new(-v.r, -v.s());
+        }
+        center + v
+    }
+
+    /// Returns the hex closest to the given fractional cube coordinates (`q`, `r`, `s`), which should add up to `0`.
+    ///
+    /// Each component is rounded, then the component with the largest rounding error is recomputed from the other two, so the result always satisfies `q + r + s == 0`.
+    pub fn round(cube: &Vector3) -> Self {
+        let mut q = cube.x.round();
+        let mut r = cube.y.round();
+        let s = cube.z.round();
+
+        let q_diff = (q - cube.x).abs();
+        let r_diff = (r - cube.y).abs();
+        let s_diff = (s - cube.z).abs();
+
+        if q_diff > r_diff && q_diff > s_diff {
+            q = -r - s;
+        } else if r_diff > s_diff {
+            r = -q - s;
+        }
+        Self::new(q as int!(), r as int!())
+    }
+
+    /// Returns the fractional cube coordinates of the point at `weight` along the segment from this hex to `to`.
+    pub fn lerp(&self, to: &Self, weight: float!()) -> Vector3 {
+        Vector3::from(self.cube()).lerp(&Vector3::from(to.cube()), weight)
+    }
+
+    /// Constructs a new **Hex** from `offset` coordinates (column, row) in the given offset `layout`.
+    pub const fn from_offset(offset: &Vector2i, layout: HexOffset) -> Self {
+        let (col, row) = (offset.x, offset.y);
+        match layout {
+            HexOffset::OddR => Self::new(col - (row - (row & 1)) / 2, row),
+            HexOffset::EvenR => Self::new(col - (row + (row & 1)) / 2, row),
+            HexOffset::OddQ => Self::new(col, row - (col - (col & 1)) / 2),
+            HexOffset::EvenQ => Self::new(col, row - (col + (col & 1)) / 2),
+        }
+    }
+
+    /// Returns the offset coordinates (column, row) of this hex in the given offset `layout`.
+    pub const fn to_offset(&self, layout: HexOffset) -> Vector2i {
+        let (q, r) = (self.q, self.r);
+        match layout {
+            HexOffset::OddR => Vector2i::new(q + (r - (r & 1)) / 2, r),
+            HexOffset::EvenR => Vector2i::new(q + (r + (r & 1)) / 2, r),
+            HexOffset::OddQ => Vector2i::new(q, r + (q - (q & 1)) / 2),
+            HexOffset::EvenQ => Vector2i::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+}
+
+impl From<Vector2i> for Hex {
+    /// Constructs a new **Hex** from axial coordinates stored as (`q`, `r`).
+    fn from(value: Vector2i) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<Hex> for Vector2i {
+    /// Returns the axial coordinates of the hex. See [`Hex::axial`].
+    fn from(value: Hex) -> Self {
+        value.axial()
+    }
+}
+
+impl From<Hex> for Vector3i {
+    /// Returns the cube coordinates of the hex. See [`Hex::cube`].
+    fn from(value: Hex) -> Self {
+        value.cube()
+    }
+}
+
+impl PartialEq for Hex {
+    fn eq(&self, other: &Self) -> bool {
+        self.q == other.q && self.r == other.r
+    }
+}
+
+impl Eq for Hex {}
+
+impl Hash for Hex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.q.hash(state);
+        self.r.hash(state);
+    }
+}
+
+impl_op_ex!(+ |a: &Hex, b: &Hex| -> Hex { Hex::new(a.q + b.q, a.r + b.r) });
+
+impl_op_ex!(-|a: &Hex, b: &Hex| -> Hex { Hex::new(a.q - b.q, a.r - b.r) });
+
+impl_op_ex_commutative!(*|a: &Hex, b: &int!()| -> Hex { Hex::new(a.q * b, a.r * b) });
+
+impl_op_ex!(+= |a: &mut Hex, b: &Hex| { a.q += b.q; a.r += b.r; });
+
+impl_op_ex!(-= |a: &mut Hex, b: &Hex| { a.q -= b.q; a.r -= b.r; });
+
+impl_op_ex!(*= |a: &mut Hex, b: &int!()| { a.q *= b; a.r *= b; });
+
+impl Neg for Hex {
+    type Output = Hex;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Hex({}, {})", self.q, self.r)
+    }
+}
+
+/// The way a rectangular array of rows and columns maps onto a hex grid, see [`Hex::from_offset`] and [`Hex::to_offset`].
+///
+/// The `R` variants are used with [`HexOrientation::PointyTop`] and shift every other row, the `Q` variants are used with [`HexOrientation::FlatTop`] and shift every other column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HexOffset {
+    /// Odd rows are shoved right by half a hex.
+    OddR,
+    /// Even rows are shoved right by half a hex.
+    EvenR,
+    /// Odd columns are shoved down by half a hex.
+    OddQ,
+    /// Even columns are shoved down by half a hex.
+    EvenQ,
+}
+
+/// The orientation of the hexes in a [`HexLayout`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HexOrientation {
+    /// Hexes have a corner pointing up, and rows of hexes are horizontal.
+    PointyTop,
+    /// Hexes have an edge on top, and columns of hexes are vertical.
+    FlatTop,
+}
+
+impl HexOrientation {
+    // The hex to world matrix (f0, f1, f2, f3), its inverse (b0, b1, b2, b3), and the angle of the first corner in sixths of a turn.
+    fn matrices(&self) -> ([float!(); 4], [float!(); 4], float!()) {
+        let sqrt_3: float!() = math::sqrt(3.0);
+        match self {
+            HexOrientation::PointyTop => (
+                [sqrt_3, sqrt_3 / 2.0, 0.0, 3.0 / 2.0],
+                [sqrt_3 / 3.0, -1.0 / 3.0, 0.0, 2.0 / 3.0],
+                0.5,
+            ),
+            HexOrientation::FlatTop => (
+                [3.0 / 2.0, 0.0, sqrt_3 / 2.0, sqrt_3],
+                [2.0 / 3.0, 0.0, -1.0 / 3.0, sqrt_3 / 3.0],
+                0.0,
+            ),
+        }
+    }
+}
+
+/// The placement of a hex grid in 2D space.
+///
+/// A **HexLayout** converts between [`Hex`] coordinates and [`Vector2`] positions. The `size` is the distance from the center of a hex to its corners, and can differ on each axis to stretch the grid. The `origin` is the position of the center of [`Hex::ZERO`].
+///
+/// ```
+/// # use huginn::types::hex::{Hex, HexLayout, HexOrientation};
+/// # use huginn::types::vectors::Vector2;
+/// let layout = HexLayout::new(HexOrientation::PointyTop, Vector2::new(32.0, 32.0), Vector2::ZERO);
+/// let hex = Hex::new(3, -1);
+/// assert_eq!(layout.world_to_hex(&layout.hex_to_world(&hex)), hex);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HexLayout {
+    /// Whether the hexes are pointy-top or flat-top.
+    pub orientation: HexOrientation,
+    /// The distance from the center of a hex to its corners on each axis.
+    pub size: Vector2,
+    /// The world position of the center of [`Hex::ZERO`].
+    pub origin: Vector2,
+}
+
+impl HexLayout {
+    /// Constructs a new **HexLayout** from its `orientation`, `size` and `origin`.
+    pub const fn new(orientation: HexOrientation, size: Vector2, origin: Vector2) -> Self {
+        Self {
+            orientation,
+            size,
+            origin,
+        }
+    }
+
+    /// Returns the world position of the center of `hex`.
+    pub fn hex_to_world(&self, hex: &Hex) -> Vector2 {
+        let (f, _, _) = self.orientation.matrices();
+        let q = hex.q as float!();
+        let r = hex.r as float!();
+        Vector2::new(
+            (f[0] * q + f[1] * r) * self.size.x + self.origin.x,
+            (f[2] * q + f[3] * r) * self.size.y + self.origin.y,
+        )
+    }
+
+    /// Returns the fractional cube coordinates (`q`, `r`, `s`) of the world `point`. See [`Hex::round`] to get the hex containing it.
+    pub fn world_to_fractional(&self, point: &Vector2) -> Vector3 {
+        let (_, b, _) = self.orientation.matrices();
+        let pt = (point - self.origin) / self.size;
+        let q = b[0] * pt.x + b[1] * pt.y;
+        let r = b[2] * pt.x + b[3] * pt.y;
+        Vector3::new(q, r, -q - r)
+    }
+
+    /// Returns the hex containing the world `point`.
+    pub fn world_to_hex(&self, point: &Vector2) -> Hex {
+        Hex::round(&self.world_to_fractional(point))
+    }
+
+    /// Returns the world positions of the six corners of `hex`, clockwise on screen (Y down).
+    pub fn corners(&self, hex: &Hex) -> [Vector2; 6] {
+        let (_, _, start_angle) = self.orientation.matrices();
+        let center = self.hex_to_world(hex);
+        core::array::from_fn(|i| {
+            let angle = float_consts::TAU * (start_angle + i as float!()) / 6.0;
+            center + Vector2::new(self.size.x * math::cos(angle), self.size.y * math::sin(angle))
+        })
+    }
+}
+
+/// An iterator over the hexes on a straight line between two [`Hex`], both ends included.
+///
+/// Created by [`Hex::line_to`].
+#[derive(Copy, Clone, Debug)]
+pub struct HexLine {
+    from: Vector3,
+    to: Vector3,
+    step: int!(),
+    distance: int!(),
+}
+
+impl HexLine {
+    fn new(from: Hex, to: Hex) -> Self {
+        // Nudge the endpoints so points exactly on an edge between two hexes always round the same way.
+        let nudge = Vector3::new(1e-6, 2e-6, -3e-6);
+        Self {
+            from: Vector3::from(from.cube()) + nudge,
+            to: Vector3::from(to.cube()) + nudge,
+            step: 0,
+            distance: from.distance_to(&to),
+        }
+    }
+}
+
+impl Iterator for HexLine {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.distance {
+            return None;
+        }
+        let weight = if self.distance == 0 {
+            0.0
+        } else {
+            self.step as float!() / self.distance as float!()
+        };
+        self.step += 1;
+        Some(Hex::round(&self.from.lerp(&self.to, weight)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.distance + 1 - self.step) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for HexLine {}
+impl FusedIterator for HexLine {}
+
+/// An iterator over the hexes at an exact distance from a center [`Hex`].
+///
+/// Created by [`Hex::ring`].
+#[derive(Copy, Clone, Debug)]
+pub struct HexRing {
+    current: Hex,
+    radius: int!(),
+    index: int!(),
+    count: int!(),
+}
+
+impl HexRing {
+    fn new(center: Hex, radius: int!()) -> Self {
+        let count = if radius < 0 {
+            0
+        } else if radius == 0 {
+            1
+        } else {
+            6 * radius
+        };
+        Self {
+            current: center + Hex::direction(4) * radius.max(0),
+            radius,
+            index: 0,
+            count,
+        }
+    }
+}
+
+impl Iterator for HexRing {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let hex = self.current;
+        if self.radius > 0 {
+            self.current += Hex::direction(self.index / self.radius);
+        }
+        self.index += 1;
+        Some(hex)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for HexRing {}
+impl FusedIterator for HexRing {}
+
+/// An iterator over the hexes within a distance of a center [`Hex`], ring by ring.
+///
+/// Created by [`Hex::spiral`].
+#[derive(Copy, Clone, Debug)]
+pub struct HexSpiral {
+    center: Hex,
+    radius: int!(),
+    ring: HexRing,
+}
+
+impl HexSpiral {
+    fn new(center: Hex, radius: int!()) -> Self {
+        Self {
+            center,
+            radius,
+            ring: HexRing::new(center, if radius < 0 { -1 } else { 0 }),
+        }
+    }
+}
+
+impl Iterator for HexSpiral {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(hex) = self.ring.next() {
+                return Some(hex);
+            }
+            if self.ring.radius < 0 || self.ring.radius >= self.radius {
+                return None;
+            }
+            self.ring = HexRing::new(self.center, self.ring.radius + 1);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let outer = if self.ring.radius < 0 {
+            0
+        } else {
+            3 * (self.radius * (self.radius + 1) - self.ring.radius * (self.ring.radius + 1))
+        };
+        let remaining = outer as usize + self.ring.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for HexSpiral {}
+impl FusedIterator for HexSpiral {}
+
+/// An iterator over the hexes within a distance of a center [`Hex`], ordered by `q` then `r`.
+///
+/// Created by [`Hex::range`].
+#[derive(Copy, Clone, Debug)]
+pub struct HexRange {
+    center: Hex,
+    radius: int!(),
+    q: int!(),
+    r: int!(),
+}
+
+impl HexRange {
+    fn new(center: Hex, radius: int!()) -> Self {
+        let q = -radius;
+        Self {
+            center,
+            radius,
+            q,
+            r: (-radius).max(-q - radius),
+        }
+    }
+}
+
+impl Iterator for HexRange {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.q > self.radius {
+            return None;
+        }
+        let hex = self.center + Hex::new(self.q, self.r);
+        self.r += 1;
+        if self.r > self.radius.min(-self.q + self.radius) {
+            self.q += 1;
+            self.r = (-self.radius).max(-self.q - self.radius);
+        }
+        Some(hex)
+    }
+}
+
+impl FusedIterator for HexRange {}
@@ -0,0 +1,589 @@
+use crate::float;
+use crate::math;
+use crate::types::vectors::{Vector2, Vector3};
+use crate::types::{Basis, Plane};
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// A small dense matrix with `R` rows and `C` columns, stored on the stack.
+///
+/// **Matrix** is meant for the small systems that show up in geometry: fitting, calibration and constraint solving, usually between 2×2 and 6×6. Every operation works on fixed-size arrays, so nothing is allocated. Vectors are plain `[float; N]` arrays.
+///
+/// Square matrices can be solved with an [`Lu`] decomposition (see [`Matrix::lu`]), or a [`Cholesky`] decomposition when they are symmetric positive-definite (see [`Matrix::cholesky`]). Overdetermined systems can be solved in the least-squares sense with a [`Qr`] decomposition (see [`Matrix::qr`]), or by accumulating rows in a [`LeastSquares`].
+///
+/// ```
+/// # use huginn::types::linalg::Matrix;
+/// let a = Matrix::new([[2.0, 1.0], [1.0, 3.0]]);
+/// let x = a.solve(&[3.0, 5.0]).unwrap();
+///
+/// assert!((x[0] - 0.8).abs() < 1e-5 && (x[1] - 1.4).abs() < 1e-5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix<const R: usize, const C: usize> {
+    rows: [[float!(); C]; R],
+}
+
+/// A square [`Matrix`] of size `N`.
+pub type MatrixN<const N: usize> = Matrix<N, N>;
+
+impl<const R: usize, const C: usize> Matrix<R, C> {
+    /// A matrix with all its elements set to `0`.
+    pub const ZERO: Self = Self::new([[0.0; C]; R]);
+
+    /// Constructs a **Matrix** from its rows.
+    pub const fn new(rows: [[float!(); C]; R]) -> Self {
+        Self { rows }
+    }
+
+    /// Constructs a **Matrix** by calling `f(row, column)` for every element.
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> float!()) -> Self {
+        Self::new(core::array::from_fn(|r| core::array::from_fn(|c| f(r, c))))
+    }
+
+    /// Returns the rows of this matrix.
+    pub const fn rows(&self) -> &[[float!(); C]; R] {
+        &self.rows
+    }
+
+    /// Returns the row at `index`.
+    pub const fn row(&self, index: usize) -> [float!(); C] {
+        self.rows[index]
+    }
+
+    /// Returns the column at `index`.
+    pub fn column(&self, index: usize) -> [float!(); R] {
+        core::array::from_fn(|r| self.rows[r][index])
+    }
+
+    /// Returns the transposed version of this matrix, turning its rows into columns.
+    pub fn transposed(&self) -> Matrix<C, R> {
+        Matrix::from_fn(|r, c| self.rows[c][r])
+    }
+
+    /// Returns the product of this matrix and the column vector `vector`.
+    pub fn mul_vector(&self, vector: &[float!(); C]) -> [float!(); R] {
+        core::array::from_fn(|r| dot(&self.rows[r], vector))
+    }
+
+    /// Returns `true` if this matrix and `to` are approximately equal, by running [`is_equal_approx`](crate::utils::is_equal_approx) on each element.
+    pub fn is_equal_approx(&self, to: &Self) -> bool {
+        self.rows
+            .iter()
+            .flatten()
+            .zip(to.rows.iter().flatten())
+            .all(|(a, b)| crate::utils::is_equal_approx(*a, *b))
+    }
+
+    /// Returns `true` if every element of this matrix is finite.
+    pub fn is_finite(&self) -> bool {
+        self.rows.iter().flatten().all(|e| e.is_finite())
+    }
+
+    /// Returns the [QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition) of this matrix, computed with Householder reflections. See [`Qr`].
+    ///
+    /// **Note:** The matrix must have at least as many rows as columns.
+    pub fn qr(&self) -> Qr<R, C> {
+        Qr::new(self)
+    }
+
+    /// Returns the vector `x` minimizing the squared length of `self * x - b`, or [`None`] if the columns of this matrix are linearly dependent. This is a shorthand for `self.qr().solve(b)`.
+    ///
+    /// **Note:** The matrix must have at least as many rows as columns.
+    pub fn solve_least_squares(&self, b: &[float!(); R]) -> Option<[float!(); C]> {
+        self.qr().solve(b)
+    }
+}
+
+impl<const N: usize> Matrix<N, N> {
+    /// Returns the identity matrix, with `1` on the diagonal and `0` everywhere else.
+    pub fn identity() -> Self {
+        Self::from_fn(|r, c| if r == c { 1.0 } else { 0.0 })
+    }
+
+    /// Returns the [LU decomposition](https://en.wikipedia.org/wiki/LU_decomposition) of this matrix with partial pivoting, or [`None`] if the matrix is singular. See [`Lu`].
+    pub fn lu(&self) -> Option<Lu<N>> {
+        Lu::new(self)
+    }
+
+    /// Returns the [Cholesky decomposition](https://en.wikipedia.org/wiki/Cholesky_decomposition) of this matrix, or [`None`] if it is not positive-definite. Only the lower triangle of the matrix is read, which is assumed to be symmetric. See [`Cholesky`].
+    pub fn cholesky(&self) -> Option<Cholesky<N>> {
+        Cholesky::new(self)
+    }
+
+    /// Returns the vector `x` such that `self * x == b`, or [`None`] if this matrix is singular. This is a shorthand for `self.lu()?.solve(b)`.
+    pub fn solve(&self, b: &[float!(); N]) -> Option<[float!(); N]> {
+        Some(self.lu()?.solve(b))
+    }
+
+    /// Returns the [determinant](https://en.wikipedia.org/wiki/Determinant) of this matrix.
+    pub fn determinant(&self) -> float!() {
+        self.lu().map_or(0.0, |lu| lu.determinant())
+    }
+
+    /// Returns the inverse of this matrix, or [`None`] if it is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        Some(self.lu()?.inverse())
+    }
+}
+
+impl<const R: usize, const C: usize> Default for Matrix<R, C> {
+    /// Constructs a **Matrix** identical to [`Matrix::ZERO`].
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const R: usize, const C: usize> Index<(usize, usize)> for Matrix<R, C> {
+    type Output = float!();
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.rows[row][column]
+    }
+}
+
+impl<const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<R, C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.rows[row][column]
+    }
+}
+
+impl<const R: usize, const C: usize, const K: usize> Mul<Matrix<C, K>> for Matrix<R, C> {
+    type Output = Matrix<R, K>;
+    fn mul(self, rhs: Matrix<C, K>) -> Self::Output {
+        Matrix::from_fn(|r, k| (0..C).map(|c| self.rows[r][c] * rhs.rows[c][k]).sum())
+    }
+}
+
+impl<const R: usize, const C: usize> Mul<float!()> for Matrix<R, C> {
+    type Output = Self;
+    fn mul(self, rhs: float!()) -> Self::Output {
+        Self::from_fn(|r, c| self.rows[r][c] * rhs)
+    }
+}
+
+impl<const R: usize, const C: usize> Add for Matrix<R, C> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|r, c| self.rows[r][c] + rhs.rows[r][c])
+    }
+}
+
+impl<const R: usize, const C: usize> Sub for Matrix<R, C> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|r, c| self.rows[r][c] - rhs.rows[r][c])
+    }
+}
+
+impl<const R: usize, const C: usize> Neg for Matrix<R, C> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from_fn(|r, c| -self.rows[r][c])
+    }
+}
+
+impl From<Basis> for Matrix<3, 3> {
+    /// Constructs a 3×3 **Matrix** with the same rows as the [`Basis`].
+    fn from(value: Basis) -> Self {
+        Self::new([
+            [value.x.x, value.x.y, value.x.z],
+            [value.y.x, value.y.y, value.y.z],
+            [value.z.x, value.z.y, value.z.z],
+        ])
+    }
+}
+
+impl From<Matrix<3, 3>> for Basis {
+    /// Constructs a [`Basis`] with the same rows as the 3×3 **Matrix**.
+    fn from(value: Matrix<3, 3>) -> Self {
+        let m = value.rows;
+        Basis::new_from_floats(
+            m[0][0], m[0][1], m[0][2], m[1][0], m[1][1], m[1][2], m[2][0], m[2][1], m[2][2],
+        )
+    }
+}
+
+impl<const R: usize, const C: usize> Display for Matrix<R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+        for (r, row) in self.rows.iter().enumerate() {
+            if r > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "(")?;
+            for (c, e) in row.iter().enumerate() {
+                if c > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", e)?;
+            }
+            write!(f, ")")?;
+        }
+        write!(f, "]")
+    }
+}
+
+fn dot<const N: usize>(a: &[float!(); N], b: &[float!(); N]) -> float!() {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+// The pivot threshold below which a matrix is considered singular, relative to its largest element.
+fn singular_tolerance<const R: usize, const C: usize>(m: &[[float!(); C]; R]) -> float!() {
+    let scale = m.iter().flatten().fold(0.0, |max: float!(), e| max.max(e.abs()));
+    scale * <float!()>::EPSILON * (R.max(C) as float!())
+}
+
+/// The LU decomposition of a square [`Matrix`] with partial pivoting, such that `P * A == L * U`.
+///
+/// Created by [`Matrix::lu`]. It can be reused to solve several systems with the same matrix.
+#[derive(Copy, Clone, Debug)]
+pub struct Lu<const N: usize> {
+    // L below the diagonal (with an implied unit diagonal), U on and above it.
+    lu: [[float!(); N]; N],
+    permutation: [usize; N],
+    sign: float!(),
+}
+
+impl<const N: usize> Lu<N> {
+    fn new(matrix: &Matrix<N, N>) -> Option<Self> {
+        let mut lu = matrix.rows;
+        let mut permutation: [usize; N] = core::array::from_fn(|i| i);
+        let mut sign = 1.0;
+        let tolerance = singular_tolerance(&lu);
+
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs()))
+                .unwrap();
+            if lu[pivot][k].abs() <= tolerance {
+                return None;
+            }
+            if pivot != k {
+                lu.swap(pivot, k);
+                permutation.swap(pivot, k);
+                sign = -sign;
+            }
+            let pivot_row = lu[k];
+            for row in lu.iter_mut().skip(k + 1) {
+                row[k] /= pivot_row[k];
+                let factor = row[k];
+                for (e, p) in row.iter_mut().zip(pivot_row.iter()).skip(k + 1) {
+                    *e -= factor * p;
+                }
+            }
+        }
+        Some(Self {
+            lu,
+            permutation,
+            sign,
+        })
+    }
+
+    /// Returns the vector `x` such that `A * x == b`.
+    pub fn solve(&self, b: &[float!(); N]) -> [float!(); N] {
+        let mut x: [float!(); N] = core::array::from_fn(|i| b[self.permutation[i]]);
+        for i in 0..N {
+            let sum = dot_range(&self.lu[i], &x, 0, i);
+            x[i] -= sum;
+        }
+        for i in (0..N).rev() {
+            let sum = dot_range(&self.lu[i], &x, i + 1, N);
+            x[i] = (x[i] - sum) / self.lu[i][i];
+        }
+        x
+    }
+
+    /// Returns the determinant of `A`.
+    pub fn determinant(&self) -> float!() {
+        (0..N).fold(self.sign, |det, i| det * self.lu[i][i])
+    }
+
+    /// Returns the inverse of `A`.
+    pub fn inverse(&self) -> Matrix<N, N> {
+        let mut inverse = Matrix::ZERO;
+        for c in 0..N {
+            let column = self.solve(&core::array::from_fn(|i| if i == c { 1.0 } else { 0.0 }));
+            for (r, e) in column.iter().enumerate() {
+                inverse.rows[r][c] = *e;
+            }
+        }
+        inverse
+    }
+}
+
+fn dot_range<const N: usize>(a: &[float!(); N], b: &[float!(); N], from: usize, to: usize) -> float!() {
+    a[from..to].iter().zip(&b[from..to]).map(|(a, b)| a * b).sum()
+}
+
+/// The Cholesky decomposition of a symmetric positive-definite [`Matrix`], such that `A == L * L^T`.
+///
+/// Created by [`Matrix::cholesky`]. It is about twice as fast as an [`Lu`] decomposition, and is the natural choice for normal equations and other covariance-like matrices.
+#[derive(Copy, Clone, Debug)]
+pub struct Cholesky<const N: usize> {
+    l: [[float!(); N]; N],
+}
+
+impl<const N: usize> Cholesky<N> {
+    fn new(matrix: &Matrix<N, N>) -> Option<Self> {
+        let a = &matrix.rows;
+        let tolerance = singular_tolerance(a);
+        let mut l = [[0.0; N]; N];
+        for i in 0..N {
+            for j in 0..=i {
+                let sum = dot_range(&l[i], &l[j], 0, j);
+                if i == j {
+                    let d = a[i][i] - sum;
+                    if d <= tolerance {
+                        return None;
+                    }
+                    l[i][i] = math::sqrt(d);
+                } else {
+                    l[i][j] = (a[i][j] - sum) / l[j][j];
+                }
+            }
+        }
+        Some(Self { l })
+    }
+
+    /// Returns the lower-triangular factor `L`.
+    pub fn l(&self) -> Matrix<N, N> {
+        Matrix::new(self.l)
+    }
+
+    /// Returns the vector `x` such that `A * x == b`.
+    pub fn solve(&self, b: &[float!(); N]) -> [float!(); N] {
+        let mut x = *b;
+        for i in 0..N {
+            x[i] = (x[i] - dot_range(&self.l[i], &x, 0, i)) / self.l[i][i];
+        }
+        for i in (0..N).rev() {
+            let sum: float!() = (i + 1..N).map(|k| self.l[k][i] * x[k]).sum();
+            x[i] = (x[i] - sum) / self.l[i][i];
+        }
+        x
+    }
+}
+
+/// The thin QR decomposition of a [`Matrix`] with at least as many rows as columns, such that `A == Q * R`, with `Q` having orthonormal columns and `R` being upper-triangular.
+///
+/// Created by [`Matrix::qr`]. It solves overdetermined systems in the least-squares sense without squaring the condition number of the matrix, unlike the normal equations.
+#[derive(Copy, Clone, Debug)]
+pub struct Qr<const R: usize, const C: usize> {
+    q: Matrix<R, C>,
+    r: Matrix<C, C>,
+    tolerance: float!(),
+}
+
+impl<const R: usize, const C: usize> Qr<R, C> {
+    fn new(matrix: &Matrix<R, C>) -> Self {
+        assert!(R >= C, "QR decomposition requires at least as many rows as columns");
+
+        let mut a = matrix.rows;
+        let mut reflectors = [[0.0; R]; C];
+        for (k, v) in reflectors.iter_mut().enumerate() {
+            // Householder vector mapping the column below the diagonal onto the diagonal.
+            let norm = math::sqrt((k..R).map(|i| a[i][k] * a[i][k]).sum::<float!()>());
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = if a[k][k] > 0.0 { -norm } else { norm };
+            for (e, row) in v.iter_mut().zip(a.iter()).skip(k) {
+                *e = row[k];
+            }
+            v[k] -= alpha;
+            let v_norm = math::sqrt(dot(v, v));
+            v.iter_mut().for_each(|e| *e /= v_norm);
+            reflect_columns(&mut a, v, k);
+        }
+
+        let r = Matrix::from_fn(|i, j| if i <= j { a[i][j] } else { 0.0 });
+        let mut q = Matrix::from_fn(|i, j| if i == j { 1.0 } else { 0.0 });
+        for v in reflectors.iter().rev() {
+            reflect_columns(&mut q.rows, v, 0);
+        }
+        Self {
+            q,
+            r,
+            tolerance: singular_tolerance(&matrix.rows),
+        }
+    }
+
+    /// Returns the factor `Q`, whose columns are orthonormal.
+    pub fn q(&self) -> Matrix<R, C> {
+        self.q
+    }
+
+    /// Returns the upper-triangular factor `R`.
+    pub fn r(&self) -> Matrix<C, C> {
+        self.r
+    }
+
+    /// Returns the vector `x` minimizing the squared length of `A * x - b`, or [`None`] if the columns of `A` are linearly dependent.
+    pub fn solve(&self, b: &[float!(); R]) -> Option<[float!(); C]> {
+        let mut x = self.q.transposed().mul_vector(b);
+        for i in (0..C).rev() {
+            let d = self.r.rows[i][i];
+            if d.abs() <= self.tolerance {
+                return None;
+            }
+            x[i] = (x[i] - dot_range(&self.r.rows[i], &x, i + 1, C)) / d;
+        }
+        Some(x)
+    }
+}
+
+// Applies the Householder reflection I - 2 * v * v^T to the columns of `m` from `from` onwards, where `v` is a unit vector that is zero above `from`.
+fn reflect_columns<const R: usize, const C: usize>(
+    m: &mut [[float!(); C]; R],
+    v: &[float!(); R],
+    from: usize,
+) {
+    for j in from..C {
+        let s: float!() = 2.0 * m.iter().zip(v).map(|(row, vi)| vi * row[j]).sum::<float!()>();
+        for (row, vi) in m.iter_mut().zip(v) {
+            row[j] -= s * vi;
+        }
+    }
+}
+
+/// An accumulator for linear least-squares problems with `N` unknowns and any number of equations.
+///
+/// Each call to [`add_row`](LeastSquares::add_row) adds the equation `row · x == rhs`, optionally weighted. The equations are folded into the `N`×`N` normal equations, so the memory used does not depend on their number. [`solve`](LeastSquares::solve) then finds the `x` minimizing the sum of the weighted squared residuals with a [`Cholesky`] decomposition.
+///
+/// ```
+/// # use huginn::types::linalg::LeastSquares;
+/// // Fit y = a + b * x + c * x² to samples of y = 1 - 2x + 0.5x².
+/// let mut fit = LeastSquares::<3>::new();
+/// for i in 0..10 {
+///     let x = i as huginn::float!() * 0.5;
+///     fit.add_row(&[1.0, x, x * x], 1.0 - 2.0 * x + 0.5 * x * x);
+/// }
+/// let [a, b, c] = fit.solve().unwrap();
+///
+/// assert!((a - 1.0).abs() < 1e-3 && (b + 2.0).abs() < 1e-3 && (c - 0.5).abs() < 1e-3);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LeastSquares<const N: usize> {
+    ata: Matrix<N, N>,
+    atb: [float!(); N],
+    count: usize,
+}
+
+impl<const N: usize> LeastSquares<N> {
+    /// Constructs an empty **LeastSquares** problem.
+    pub fn new() -> Self {
+        Self {
+            ata: Matrix::ZERO,
+            atb: [0.0; N],
+            count: 0,
+        }
+    }
+
+    /// Adds the equation `row · x == rhs`.
+    pub fn add_row(&mut self, row: &[float!(); N], rhs: float!()) {
+        self.add_weighted_row(row, rhs, 1.0);
+    }
+
+    /// Adds the equation `row · x == rhs`, whose squared residual is multiplied by `weight` in the minimized sum.
+    pub fn add_weighted_row(&mut self, row: &[float!(); N], rhs: float!(), weight: float!()) {
+        for (i, (ata_row, atb)) in self.ata.rows.iter_mut().zip(&mut self.atb).enumerate() {
+            let wi = weight * row[i];
+            for (e, r) in ata_row.iter_mut().zip(row).take(i + 1) {
+                *e += wi * r;
+            }
+            *atb += wi * rhs;
+        }
+        self.count += 1;
+    }
+
+    /// Returns the number of equations added so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no equation was added yet.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the least-squares solution, or [`None`] if the equations do not determine every unknown.
+    pub fn solve(&self) -> Option<[float!(); N]> {
+        Some(self.ata.cholesky()?.solve(&self.atb))
+    }
+}
+
+impl<const N: usize> Default for LeastSquares<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The centroid and covariance of a point cloud, or None if it is empty.
+fn covariance(points: &[Vector3]) -> Option<(Vector3, Basis)> {
+    if points.is_empty() {
+        return None;
+    }
+    let centroid = points.iter().fold(Vector3::ZERO, |sum, p| sum + p) / points.len() as float!();
+    let mut c: [[float!(); 3]; 3] = [[0.0; 3]; 3];
+    for p in points {
+        let d = p - centroid;
+        let d = [d.x, d.y, d.z];
+        for i in 0..3 {
+            for j in i..3 {
+                c[i][j] += d[i] * d[j];
+            }
+        }
+    }
+    let covariance = Basis::new_from_floats(
+        c[0][0], c[0][1], c[0][2], c[0][1], c[1][1], c[1][2], c[0][2], c[1][2], c[2][2],
+    );
+    Some((centroid, covariance))
+}
+
+/// Returns the [`Plane`] minimizing the sum of the squared distances to `points`, or [`None`] if there are fewer than 3 points or they are all on a line.
+///
+/// The plane goes through the centroid of the points, and its normal is the direction in which they vary the least. See [`Basis::symmetric_eigen`].
+pub fn fit_plane(points: &[Vector3]) -> Option<Plane> {
+    if points.len() < 3 {
+        return None;
+    }
+    let (centroid, covariance) = covariance(points)?;
+    let (values, vectors) = covariance.symmetric_eigen();
+    if values.y <= values.x * crate::utils::CMP_EPSILON {
+        return None;
+    }
+    Some(Plane::new_from_normal_point(&vectors.z(), &centroid))
+}
+
+/// Returns the line minimizing the sum of the squared distances to `points`, as a point on the line and a normalized direction, or [`None`] if there are fewer than 2 distinct points.
+///
+/// The point returned is the centroid of the points, and the direction is the one in which they vary the most. See [`Basis::symmetric_eigen`].
+pub fn fit_line(points: &[Vector3]) -> Option<(Vector3, Vector3)> {
+    let (centroid, covariance) = covariance(points)?;
+    let (values, vectors) = covariance.symmetric_eigen();
+    if values.x <= 0.0 {
+        return None;
+    }
+    Some((centroid, vectors.x()))
+}
+
+/// Returns the circle best fitting `points`, as its center and radius, or [`None`] if there are fewer than 3 points or they are all on a line.
+///
+/// This is the algebraic fit of [Kåsa](https://doi.org/10.1109/TIM.1976.6312298), which minimizes the squared difference between the squared distances to the center and the squared radius. It is exact when the points are on a circle, and slightly biased towards smaller circles when only a short arc is sampled with noise.
+pub fn fit_circle(points: &[Vector2]) -> Option<(Vector2, float!())> {
+    if points.len() < 3 {
+        return None;
+    }
+    // Center the points first, which keeps the normal equations well conditioned far from the origin.
+    let centroid = points.iter().fold(Vector2::ZERO, |sum, p| sum + p) / points.len() as float!();
+    let mut fit = LeastSquares::<3>::new();
+    for p in points {
+        let d = p - centroid;
+        fit.add_row(&[2.0 * d.x, 2.0 * d.y, 1.0], d.length_squared());
+    }
+    let [a, b, c] = fit.solve()?;
+    let radius_squared = c + a * a + b * b;
+    if !radius_squared.is_finite() || radius_squared <= 0.0 {
+        return None;
+    }
+    Some((centroid + Vector2::new(a, b), math::sqrt(radius_squared)))
+}
@@ -0,0 +1,354 @@
+use crate::math;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+use crate::types::vectors::{Vector2, Vector3, Vector4};
+use crate::types::{Color, Transform3D, AABB};
+use crate::utils::{float, CMP_EPSILON};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An indexed triangle mesh, with per-vertex attributes.
+///
+/// Every three elements of `indices` form a triangle, which is front-facing when its vertices are in clockwise order, as with [`Plane::new_from_points`](crate::types::Plane::new_from_points). The attribute arrays, `normals`, `tangents`, `uvs` and `colors`, are either empty, when the mesh doesn't have that attribute, or hold one element per position.
+///
+/// Tangents are stored as a [`Vector4`]: `x`, `y` and `z` are the tangent, and `w` is the sign of the bitangent, which is `normal.cross(tangent) * w`.
+///
+/// ```
+/// # use huginn::types::mesh::TriangleMesh;
+/// # use huginn::types::vectors::Vector3;
+/// let mut mesh = TriangleMesh::new(
+///     vec![Vector3::ZERO, Vector3::FORWARD, Vector3::RIGHT],
+///     vec![0, 1, 2],
+/// );
+/// mesh.generate_flat_normals();
+///
+/// assert_eq!(mesh.normals, vec![Vector3::UP; 3]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TriangleMesh {
+    /// The position of each vertex.
+    pub positions: Vec<Vector3>,
+    /// The normal of each vertex, or empty.
+    pub normals: Vec<Vector3>,
+    /// The tangent of each vertex, with the bitangent sign in `w`, or empty.
+    pub tangents: Vec<Vector4>,
+    /// The texture coordinates of each vertex, or empty.
+    pub uvs: Vec<Vector2>,
+    /// The color of each vertex, or empty.
+    pub colors: Vec<Color>,
+    /// The vertex indices, three per triangle.
+    pub indices: Vec<u32>,
+}
+
+impl TriangleMesh {
+    /// Constructs a **TriangleMesh** from vertex `positions` and triangle `indices`, with no other attribute.
+    ///
+    /// **Note:** Panics if the length of `indices` isn't a multiple of 3.
+    pub fn new(positions: Vec<Vector3>, indices: Vec<u32>) -> Self {
+        assert_eq!(
+            indices.len() % 3,
+            0,
+            "There should be three indices per triangle."
+        );
+        Self {
+            positions,
+            indices,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of vertices of this mesh.
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns the number of triangles of this mesh.
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Returns an iterator over the vertex indices of each triangle.
+    pub fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.indices.chunks_exact(3).map(|triangle| {
+            [
+                triangle[0] as usize,
+                triangle[1] as usize,
+                triangle[2] as usize,
+            ]
+        })
+    }
+
+    /// Returns the smallest [`AABB`] enclosing every vertex of this mesh. It's empty if the mesh has no vertex.
+    pub fn get_aabb(&self) -> AABB {
+        let Some(first) = self.positions.first() else {
+            return AABB::default();
+        };
+        let (min, max) = self
+            .positions
+            .iter()
+            .fold((*first, *first), |(min, max), position| {
+                (min.min(position), max.max(position))
+            });
+        AABB::new(min, max - min)
+    }
+
+    /// Sets the normal of every vertex to the normal of its triangle, so each triangle is shaded flat.
+    ///
+    /// Vertices are duplicated where triangles with different normals meet. The tangents are cleared, since they depend on the normals.
+    pub fn generate_flat_normals(&mut self) {
+        let face_normals = self.face_normals();
+        let corner_normals = (0..self.indices.len())
+            .map(|corner| face_normals[corner / 3])
+            .collect();
+        self.assign_corner_normals(corner_normals);
+    }
+
+    /// Sets the normal of every vertex to the average normal of the triangles around it, so the mesh is shaded smooth.
+    ///
+    /// Triangles meet at a vertex when they have a vertex at the same position, even if it's a different vertex with other attributes, like along UV seams. Two of them are only smoothed together if the angle between their normals is at most `max_angle`, in radians: above it, the edge between them stays sharp and vertices are duplicated. Each triangle is weighted by its angle at the vertex.
+    ///
+    /// The tangents are cleared, since they depend on the normals.
+    pub fn generate_smooth_normals(&mut self, max_angle: float!()) {
+        let face_normals = self.face_normals();
+        let min_dot = math::cos(max_angle) - CMP_EPSILON;
+
+        let mut groups = BTreeMap::new();
+        for (corner, &index) in self.indices.iter().enumerate() {
+            let position = self.positions[index as usize];
+            // Adding zero turns negative zeros into positive ones, so they are grouped together.
+            let key = [
+                (position.x + 0.0).to_bits(),
+                (position.y + 0.0).to_bits(),
+                (position.z + 0.0).to_bits(),
+            ];
+            groups.entry(key).or_insert_with(Vec::new).push(corner);
+        }
+
+        let mut corner_normals = vec![Vector3::ZERO; self.indices.len()];
+        for group in groups.values() {
+            for &corner in group {
+                let normal = face_normals[corner / 3];
+                let sum = group
+                    .iter()
+                    .filter(|&&other| face_normals[other / 3].dot(&normal) >= min_dot)
+                    .fold(Vector3::ZERO, |sum, &other| {
+                        sum + face_normals[other / 3] * self.corner_angle(other)
+                    });
+                corner_normals[corner] = if sum.is_zero_approx() {
+                    normal
+                } else {
+                    sum.normalized()
+                };
+            }
+        }
+        self.assign_corner_normals(corner_normals);
+    }
+
+    /// Generates a tangent for every vertex from its normal and UV, following the MikkTSpace conventions: the tangent follows the U axis, the bitangent is `normal.cross(tangent) * w`, and triangles are weighted by their angle at the vertex.
+    ///
+    /// Returns `false`, leaving the mesh unchanged, if it doesn't have normals or UVs.
+    ///
+    /// **Note:** Unlike the reference MikkTSpace implementation, vertices aren't split where the UV mapping is mirrored. Vertices along such seams should already be distinct, which is the case for most imported meshes.
+    pub fn generate_tangents(&mut self) -> bool {
+        let count = self.vertex_count();
+        if self.normals.len() != count || self.uvs.len() != count {
+            return false;
+        }
+
+        let mut tangents = vec![Vector3::ZERO; count];
+        let mut bitangents = vec![Vector3::ZERO; count];
+        for (triangle, [a, b, c]) in self.triangles().enumerate() {
+            let edge1 = self.positions[b] - self.positions[a];
+            let edge2 = self.positions[c] - self.positions[a];
+            let uv1 = self.uvs[b] - self.uvs[a];
+            let uv2 = self.uvs[c] - self.uvs[a];
+            let det = uv1.x * uv2.y - uv2.x * uv1.y;
+            if det.abs() < CMP_EPSILON {
+                continue;
+            }
+            let tangent = (edge1 * uv2.y - edge2 * uv1.y) / det;
+            let bitangent = (edge2 * uv1.x - edge1 * uv2.x) / det;
+            for (offset, vertex) in [a, b, c].into_iter().enumerate() {
+                let normal = self.normals[vertex];
+                let weight = self.corner_angle(triangle * 3 + offset);
+                tangents[vertex] += (tangent - normal * normal.dot(&tangent)).normalized() * weight;
+                bitangents[vertex] +=
+                    (bitangent - normal * normal.dot(&bitangent)).normalized() * weight;
+            }
+        }
+
+        self.tangents = (0..count)
+            .map(|vertex| {
+                let normal = self.normals[vertex];
+                let mut tangent =
+                    (tangents[vertex] - normal * normal.dot(&tangents[vertex])).normalized();
+                if tangent.is_zero_approx() {
+                    // No triangle gave a usable direction, so any direction on the surface is as good.
+                    let axis = if normal.x.abs() < 0.9 {
+                        Vector3::RIGHT
+                    } else {
+                        Vector3::UP
+                    };
+                    tangent = axis.cross(&normal).normalized();
+                }
+                let sign = if normal.cross(&tangent).dot(&bitangents[vertex]) < 0.0 {
+                    -1.0
+                } else {
+                    1.0
+                };
+                Vector4::new(tangent.x, tangent.y, tangent.z, sign)
+            })
+            .collect();
+        true
+    }
+
+    /// Merges the vertices that are at most `distance` apart and have the same attributes, then removes the triangles that collapsed. The merged vertices keep the position of the first one.
+    ///
+    /// Returns the number of vertices that were removed.
+    pub fn weld(&mut self, distance: float!()) -> usize {
+        let cell_size = distance.max(CMP_EPSILON);
+        let cell = |position: &Vector3| {
+            [position.x, position.y, position.z]
+                .map(|coordinate| (coordinate / cell_size).floor() as i64)
+        };
+
+        let mut grid: BTreeMap<[i64; 3], Vec<usize>> = BTreeMap::new();
+        let mut kept: Vec<usize> = Vec::new();
+        let mut remap = Vec::with_capacity(self.vertex_count());
+        for (vertex, position) in self.positions.iter().enumerate() {
+            let [x, y, z] = cell(position);
+            let neighbours = (-1..=1).flat_map(|dx| {
+                (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| [x + dx, y + dy, z + dz]))
+            });
+            let found = neighbours
+                .filter_map(|key| grid.get(&key))
+                .flatten()
+                .copied()
+                .find(|&other| {
+                    let original = kept[other];
+                    self.positions[original].distance_to(position) <= distance
+                        && self.has_same_attributes(original, vertex)
+                });
+            let index = found.unwrap_or_else(|| {
+                kept.push(vertex);
+                grid.entry([x, y, z]).or_default().push(kept.len() - 1);
+                kept.len() - 1
+            });
+            remap.push(index as u32);
+        }
+
+        let removed = self.vertex_count() - kept.len();
+        fn gather<T: Copy>(values: &mut Vec<T>, kept: &[usize]) {
+            if !values.is_empty() {
+                *values = kept.iter().map(|&vertex| values[vertex]).collect();
+            }
+        }
+        gather(&mut self.positions, &kept);
+        gather(&mut self.normals, &kept);
+        gather(&mut self.tangents, &kept);
+        gather(&mut self.uvs, &kept);
+        gather(&mut self.colors, &kept);
+
+        let mut indices = Vec::with_capacity(self.indices.len());
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| remap[triangle[i] as usize]);
+            if a != b && b != c && c != a {
+                indices.extend([a, b, c]);
+            }
+        }
+        self.indices = indices;
+        removed
+    }
+
+    /// Transforms every vertex of this mesh by `xform`.
+    ///
+    /// Normals are transformed by the inverse transpose of the basis, so they stay perpendicular to the surface under non-uniform scaling. If the basis mirrors the mesh, the winding of the triangles and the sign of the tangents are flipped, so the triangles keep facing out.
+    pub fn transform(&mut self, xform: &Transform3D) {
+        let normal_basis = xform.basis.inverse().transposed();
+        let mirrored = xform.basis.determinant() < 0.0;
+        for position in &mut self.positions {
+            *position = xform.xform(position);
+        }
+        for normal in &mut self.normals {
+            *normal = normal_basis.xform(normal).normalized();
+        }
+        for tangent in &mut self.tangents {
+            let direction = xform
+                .basis
+                .xform(&Vector3::new(tangent.x, tangent.y, tangent.z))
+                .normalized();
+            let sign = if mirrored { -tangent.w } else { tangent.w };
+            *tangent = Vector4::new(direction.x, direction.y, direction.z, sign);
+        }
+        if mirrored {
+            for triangle in self.indices.chunks_exact_mut(3) {
+                triangle.swap(1, 2);
+            }
+        }
+    }
+
+    fn face_normals(&self) -> Vec<Vector3> {
+        self.triangles()
+            .map(|[a, b, c]| {
+                let (a, b, c) = (self.positions[a], self.positions[b], self.positions[c]);
+                (a - c).cross(&(a - b)).normalized()
+            })
+            .collect()
+    }
+
+    /// Returns the angle of the triangle at `corner`, an index into `indices`.
+    fn corner_angle(&self, corner: usize) -> float!() {
+        let triangle = corner - corner % 3;
+        let position =
+            |offset: usize| self.positions[self.indices[triangle + (corner + offset) % 3] as usize];
+        let (origin, next, previous) = (position(0), position(1), position(2));
+        (next - origin).angle_to(&(previous - origin))
+    }
+
+    /// Sets the normal of each corner of each triangle, duplicating the vertices shared by corners with different normals.
+    fn assign_corner_normals(&mut self, corner_normals: Vec<Vector3>) {
+        self.normals = vec![Vector3::ZERO; self.vertex_count()];
+        self.tangents.clear();
+        let mut variants: Vec<Vec<(Vector3, u32)>> = vec![Vec::new(); self.vertex_count()];
+        for (corner, normal) in corner_normals.into_iter().enumerate() {
+            let vertex = self.indices[corner] as usize;
+            let existing = variants[vertex]
+                .iter()
+                .find(|(other, _)| other.is_equal_approx(&normal))
+                .map(|&(_, index)| index);
+            self.indices[corner] = existing.unwrap_or_else(|| {
+                let index = if variants[vertex].is_empty() {
+                    vertex
+                } else {
+                    self.duplicate_vertex(vertex)
+                };
+                self.normals[index] = normal;
+                variants[vertex].push((normal, index as u32));
+                index as u32
+            });
+        }
+    }
+
+    /// Appends a copy of `vertex`, with all its attributes, and returns its index.
+    fn duplicate_vertex(&mut self, vertex: usize) -> usize {
+        fn duplicate<T: Copy>(values: &mut Vec<T>, vertex: usize) {
+            if !values.is_empty() {
+                values.push(values[vertex]);
+            }
+        }
+        duplicate(&mut self.positions, vertex);
+        duplicate(&mut self.normals, vertex);
+        duplicate(&mut self.tangents, vertex);
+        duplicate(&mut self.uvs, vertex);
+        duplicate(&mut self.colors, vertex);
+        self.positions.len() - 1
+    }
+
+    fn has_same_attributes(&self, a: usize, b: usize) -> bool {
+        (self.normals.is_empty() || self.normals[a].is_equal_approx(&self.normals[b]))
+            && (self.tangents.is_empty() || self.tangents[a].is_equal_approx(&self.tangents[b]))
+            && (self.uvs.is_empty() || self.uvs[a].is_equal_approx(&self.uvs[b]))
+            && (self.colors.is_empty() || self.colors[a].is_equal_approx(&self.colors[b]))
+    }
+}
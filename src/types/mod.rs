@@ -1,6 +1,29 @@
+mod aabb;
 mod basis;
 mod color;
+/// A module containing 2D collision shapes, with separating axis overlap tests, contact manifolds and shape casts.
+pub mod collision2d;
+/// A module containing convex 3D collision shapes, with GJK overlap and distance queries and EPA penetration depth.
+pub mod collision3d;
+/// A module containing fixed-point numbers and math types, whose results are bit-identical on every platform, for deterministic simulations.
+pub mod fixed;
+mod frustum;
+/// A module containing iterators that rasterize shapes onto integer grids.
+pub mod grid;
+/// A module containing hexagonal grid coordinates, layouts and iterators.
+pub mod hex;
+/// A module containing small dense matrices, linear solvers and least-squares fitting.
+pub mod linalg;
+/// A module containing an indexed triangle mesh, with normal and tangent generation.
+pub mod mesh;
+/// A module containing rectangle packers that place [`Rect2i`] rectangles into bins, for building texture atlases.
+pub mod packing;
+mod plane;
+/// A module containing procedural primitive meshes, like boxes, spheres and capsules, generated as [`TriangleMesh`](mesh::TriangleMesh)es.
+pub mod primitives;
 mod quaternion;
+/// A module containing 2D and 3D rays, and the [`Raycast`](ray::Raycast) trait to cast them at shapes.
+pub mod ray;
 mod rect2;
 mod transform2d;
 mod transform3d;
@@ -8,10 +31,13 @@ mod transform3d;
 pub mod vectors;
 mod rect2i;
 
+pub use aabb::AABB;
 pub use basis::Basis;
 pub use color::Color;
+pub use frustum::{Containment, Frustum, FrustumPlane};
+pub use plane::Plane;
 pub use quaternion::Quaternion;
-pub use rect2::Rect2;
+pub use rect2::{Rect2, Rect2Intersection};
 pub use rect2i::Rect2i;
 pub use transform2d::Transform2D;
 pub use transform3d::Transform3D;
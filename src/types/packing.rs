@@ -0,0 +1,470 @@
+use crate::math;
+use crate::types::vectors::Vector2i;
+use crate::types::Rect2i;
+use crate::utils::{float, int, wide_int};
+use alloc::vec::Vec;
+
+/// A rectangle placed in a bin by a [`Packer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Placement {
+    /// The area covered by the rectangle, padding excluded. When `rotated` is `true`, its size is the requested size with `x` and `y` swapped.
+    pub rect: Rect2i,
+    /// If `true`, the rectangle was rotated by 90 degrees to fit.
+    pub rotated: bool,
+}
+
+/// The result of packing several rectangles with [`Packer::insert_all`] or [`pack_atlas`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packing {
+    /// The size of the bin, or of the atlas for [`pack_atlas`].
+    pub size: Vector2i,
+    /// The placement of each requested size, in the order they were requested, or `None` if it didn't fit.
+    pub placements: Vec<Option<Placement>>,
+}
+
+impl Packing {
+    /// Returns an iterator over the indices of the requested sizes that didn't fit.
+    pub fn rejected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.placements
+            .iter()
+            .enumerate()
+            .filter_map(|(index, placement)| placement.is_none().then_some(index))
+    }
+
+    /// Returns `true` if every requested size was placed.
+    pub fn is_complete(&self) -> bool {
+        self.placements.iter().all(Option::is_some)
+    }
+
+    /// Returns the smallest size, starting at the origin, that contains every placed rectangle.
+    pub fn extent(&self) -> Vector2i {
+        self.placements
+            .iter()
+            .flatten()
+            .fold(Vector2i::ZERO, |extent, placement| extent.max(&placement.rect.end()))
+    }
+}
+
+/// An algorithm that places rectangles without overlap in a bin of fixed size.
+///
+/// Packers keep track of the free space, so rectangles can be added to an existing bin at any time with [`Packer::insert`]. Rectangles are separated by the packer's padding, but aren't padded from the edges of the bin.
+pub trait Packer {
+    /// Returns the size of the bin.
+    fn size(&self) -> Vector2i;
+
+    /// Places a rectangle of the given `size` and returns where it was placed, or `None` if it doesn't fit. A `size` with a component that isn't positive never fits.
+    fn insert(&mut self, size: Vector2i) -> Option<Placement>;
+
+    /// Returns the fraction of the bin covered by placed rectangles, padding included.
+    fn occupancy(&self) -> float!();
+
+    /// Places every size of `sizes`, largest first, which packs much tighter than inserting them in an arbitrary order.
+    fn insert_all(&mut self, sizes: &[Vector2i]) -> Packing {
+        let mut order: Vec<usize> = (0..sizes.len()).collect();
+        order.sort_by_key(|&i| {
+            let size = sizes[i];
+            core::cmp::Reverse((size.x.max(size.y), size.x.min(size.y)))
+        });
+        let mut placements = alloc::vec![None; sizes.len()];
+        for i in order {
+            placements[i] = self.insert(sizes[i]);
+        }
+        Packing {
+            size: self.size(),
+            placements,
+        }
+    }
+}
+
+/// The rule a [`MaxRectsPacker`] uses to choose where a rectangle goes among the free spaces it fits in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MaxRectsHeuristic {
+    /// Chooses the space where the shorter leftover side is the smallest. This is usually the best choice.
+    BestShortSideFit,
+    /// Chooses the space where the longer leftover side is the smallest.
+    BestLongSideFit,
+    /// Chooses the smallest space.
+    BestAreaFit,
+    /// Chooses the space that puts the bottom of the rectangle the highest (Y is down), like Tetris.
+    BottomLeft,
+}
+
+/// A packer using the MaxRects algorithm, which keeps the list of the largest free rectangles of the bin. It produces the tightest packings, at a cost that grows quadratically with the number of rectangles.
+///
+/// See Jukka Jylänki's [A Thousand Ways to Pack the Bin](https://github.com/juj/RectangleBinPack/blob/master/RectangleBinPack.pdf).
+#[derive(Clone, Debug)]
+pub struct MaxRectsPacker {
+    size: Vector2i,
+    heuristic: MaxRectsHeuristic,
+    padding: int!(),
+    allow_rotation: bool,
+    free: Vec<Rect2i>,
+    used_area: wide_int!(),
+}
+
+impl MaxRectsPacker {
+    /// Constructs an empty **MaxRectsPacker** for a bin of the given `size`. Placed rectangles are separated by `padding`, and are rotated by 90 degrees when it helps if `allow_rotation` is `true`.
+    pub fn new(size: Vector2i, heuristic: MaxRectsHeuristic, padding: int!(), allow_rotation: bool) -> Self {
+        let padding = padding.max(0);
+        let bin = Rect2i::new(Vector2i::ZERO, size + Vector2i::new(padding, padding));
+        Self {
+            size,
+            heuristic,
+            padding,
+            allow_rotation,
+            free: if size.x > 0 && size.y > 0 {
+                alloc::vec![bin]
+            } else {
+                Vec::new()
+            },
+            used_area: 0,
+        }
+    }
+
+    /// Marks `rect` as occupied, so no rectangle will be placed over it. Use it to add rectangles to an atlas packed earlier, by occupying the rectangles it already contains.
+    pub fn occupy(&mut self, rect: &Rect2i) {
+        let padded = Rect2i::new(rect.position(), rect.size() + Vector2i::new(self.padding, self.padding));
+        self.used_area += area(&padded);
+        self.split_free(&padded);
+    }
+
+    // Returns the position and score of the best place for a rectangle of the given padded size, lower scores being better.
+    fn find(&self, size: Vector2i) -> Option<(Vector2i, (wide_int!(), wide_int!()))> {
+        let mut best = None;
+        for free in &self.free {
+            let leftover = free.size() - size;
+            if leftover.x < 0 || leftover.y < 0 {
+                continue;
+            }
+            let short = leftover.x.min(leftover.y) as wide_int!();
+            let long = leftover.x.max(leftover.y) as wide_int!();
+            let score = match self.heuristic {
+                MaxRectsHeuristic::BestShortSideFit => (short, long),
+                MaxRectsHeuristic::BestLongSideFit => (long, short),
+                MaxRectsHeuristic::BestAreaFit => (area(free) - area(&Rect2i::new(Vector2i::ZERO, size)), short),
+                MaxRectsHeuristic::BottomLeft => (
+                    free.position().y as wide_int!() + size.y as wide_int!(),
+                    free.position().x as wide_int!(),
+                ),
+            };
+            if best.is_none_or(|(_, best_score)| score < best_score) {
+                best = Some((free.position(), score));
+            }
+        }
+        best
+    }
+
+    // Removes `used` from the free rectangles, keeping the maximal free rectangles around it.
+    fn split_free(&mut self, used: &Rect2i) {
+        let mut split = Vec::new();
+        self.free.retain(|free| {
+            if !free.intersects(used) {
+                return true;
+            }
+            let (free_end, used_end) = (free.end(), used.end());
+            if used.position().x > free.position().x {
+                split.push(Rect2i::new(
+                    free.position(),
+                    Vector2i::new(used.position().x - free.position().x, free.size().y),
+                ));
+            }
+            if used_end.x < free_end.x {
+                split.push(Rect2i::new(
+                    Vector2i::new(used_end.x, free.position().y),
+                    Vector2i::new(free_end.x - used_end.x, free.size().y),
+                ));
+            }
+            if used.position().y > free.position().y {
+                split.push(Rect2i::new(
+                    free.position(),
+                    Vector2i::new(free.size().x, used.position().y - free.position().y),
+                ));
+            }
+            if used_end.y < free_end.y {
+                split.push(Rect2i::new(
+                    Vector2i::new(free.position().x, used_end.y),
+                    Vector2i::new(free.size().x, free_end.y - used_end.y),
+                ));
+            }
+            false
+        });
+        self.free.extend(split);
+
+        // Drop the free rectangles contained in another one.
+        let mut i = 0;
+        while i < self.free.len() {
+            let rect = self.free[i];
+            let contained = self
+                .free
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != i && other.encloses(&rect) && (*other != rect || j < i));
+            if contained {
+                self.free.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl Packer for MaxRectsPacker {
+    fn size(&self) -> Vector2i {
+        self.size
+    }
+
+    fn insert(&mut self, size: Vector2i) -> Option<Placement> {
+        if size.x <= 0 || size.y <= 0 {
+            return None;
+        }
+        let padded = size + Vector2i::new(self.padding, self.padding);
+        let mut best = self.find(padded).map(|(position, score)| (position, score, false));
+        if self.allow_rotation && size.x != size.y {
+            if let Some((position, score)) = self.find(Vector2i::new(padded.y, padded.x)) {
+                if best.is_none_or(|(_, best_score, _)| score < best_score) {
+                    best = Some((position, score, true));
+                }
+            }
+        }
+
+        let (position, _, rotated) = best?;
+        let size = if rotated { Vector2i::new(size.y, size.x) } else { size };
+        self.occupy(&Rect2i::new(position, size));
+        Some(Placement {
+            rect: Rect2i::new(position, size),
+            rotated,
+        })
+    }
+
+    fn occupancy(&self) -> float!() {
+        occupancy(self.used_area, self.size, self.padding)
+    }
+}
+
+/// A packer using the Skyline algorithm, which only tracks the top outline of the placed rectangles and puts each new rectangle as low as possible (Y is down, so "low" is towards the top of the bin). It is much faster than [`MaxRectsPacker`] but wastes the space hidden under the outline, so it suits rectangles of similar heights, such as glyphs.
+#[derive(Clone, Debug)]
+pub struct SkylinePacker {
+    size: Vector2i,
+    padding: int!(),
+    allow_rotation: bool,
+    // The outline as (x, y, width) segments, sorted by x and covering the whole padded width.
+    skyline: Vec<(int!(), int!(), int!())>,
+    used_area: wide_int!(),
+}
+
+impl SkylinePacker {
+    /// Constructs an empty **SkylinePacker** for a bin of the given `size`. Placed rectangles are separated by `padding`, and are rotated by 90 degrees when it helps if `allow_rotation` is `true`.
+    pub fn new(size: Vector2i, padding: int!(), allow_rotation: bool) -> Self {
+        let padding = padding.max(0);
+        Self {
+            size,
+            padding,
+            allow_rotation,
+            skyline: if size.x > 0 && size.y > 0 {
+                alloc::vec![(0, 0, size.x + padding)]
+            } else {
+                Vec::new()
+            },
+            used_area: 0,
+        }
+    }
+
+    // Returns the height at which a rectangle of the given padded size fits when starting at the segment `index`, if it fits.
+    fn fits(&self, index: usize, size: Vector2i) -> Option<int!()> {
+        let x = self.skyline[index].0;
+        if x + size.x > self.size.x + self.padding {
+            return None;
+        }
+        let mut y = 0;
+        let mut remaining = size.x;
+        for &(_, segment_y, width) in &self.skyline[index..] {
+            if remaining <= 0 {
+                break;
+            }
+            y = y.max(segment_y);
+            remaining -= width;
+        }
+        (y + size.y <= self.size.y + self.padding).then_some(y)
+    }
+
+    // Returns the segment index, height and score of the best place for a rectangle of the given padded size.
+    fn find(&self, size: Vector2i) -> Option<(usize, int!(), (int!(), int!()))> {
+        let mut best = None;
+        for index in 0..self.skyline.len() {
+            if let Some(y) = self.fits(index, size) {
+                let score = (y + size.y, self.skyline[index].0);
+                if best.is_none_or(|(_, _, best_score)| score < best_score) {
+                    best = Some((index, y, score));
+                }
+            }
+        }
+        best
+    }
+
+    fn add_segment(&mut self, index: usize, y: int!(), size: Vector2i) {
+        let x = self.skyline[index].0;
+        self.skyline.insert(index, (x, y + size.y, size.x));
+
+        // Shrink or remove the segments now under the new one.
+        let end = x + size.x;
+        let i = index + 1;
+        while i < self.skyline.len() {
+            let (segment_x, segment_y, width) = self.skyline[i];
+            if segment_x >= end {
+                break;
+            }
+            let covered = end - segment_x;
+            if covered >= width {
+                self.skyline.remove(i);
+            } else {
+                self.skyline[i] = (end, segment_y, width - covered);
+                break;
+            }
+        }
+
+        // Merge the neighbouring segments at the same height.
+        let mut i = 0;
+        while i + 1 < self.skyline.len() {
+            if self.skyline[i].1 == self.skyline[i + 1].1 {
+                self.skyline[i].2 += self.skyline[i + 1].2;
+                self.skyline.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl Packer for SkylinePacker {
+    fn size(&self) -> Vector2i {
+        self.size
+    }
+
+    fn insert(&mut self, size: Vector2i) -> Option<Placement> {
+        if size.x <= 0 || size.y <= 0 {
+            return None;
+        }
+        let padded = size + Vector2i::new(self.padding, self.padding);
+        let mut best = self.find(padded).map(|(index, y, score)| (index, y, score, false));
+        if self.allow_rotation && size.x != size.y {
+            if let Some((index, y, score)) = self.find(Vector2i::new(padded.y, padded.x)) {
+                if best.is_none_or(|(_, _, best_score, _)| score < best_score) {
+                    best = Some((index, y, score, true));
+                }
+            }
+        }
+
+        let (index, y, _, rotated) = best?;
+        let (size, padded) = if rotated {
+            (Vector2i::new(size.y, size.x), Vector2i::new(padded.y, padded.x))
+        } else {
+            (size, padded)
+        };
+        let position = Vector2i::new(self.skyline[index].0, y);
+        self.add_segment(index, y, padded);
+        self.used_area += area(&Rect2i::new(position, padded));
+        Some(Placement {
+            rect: Rect2i::new(position, size),
+            rotated,
+        })
+    }
+
+    fn occupancy(&self) -> float!() {
+        occupancy(self.used_area, self.size, self.padding)
+    }
+}
+
+/// The constraint on the size of an atlas built by [`pack_atlas`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AtlasSize {
+    /// The atlas has any size.
+    Any,
+    /// The width and height of the atlas are powers of two, which some older GPUs and texture compression formats require.
+    PowerOfTwo,
+    /// The atlas is a square.
+    Square,
+    /// The atlas is a square whose side is a power of two.
+    SquarePowerOfTwo,
+}
+
+/// Packs rectangles of the given `sizes` into the smallest atlas found that respects `constraint` and doesn't exceed `max_size`, using the packers created by `new_packer` for a given bin size.
+///
+/// Square bins of increasing size are tried until every rectangle fits, and the atlas is then cropped to the placed rectangles. If some rectangles don't fit in `max_size`, the returned [`Packing`] reports them (see [`Packing::rejected`]). `max_size` should itself respect `constraint`.
+///
+/// ```
+/// # use huginn::types::packing::{pack_atlas, AtlasSize, MaxRectsHeuristic, MaxRectsPacker};
+/// # use huginn::types::vectors::Vector2i;
+/// let sizes = [Vector2i::new(64, 32), Vector2i::new(32, 32), Vector2i::new(16, 32), Vector2i::new(32, 16)];
+/// let atlas = pack_atlas(&sizes, Vector2i::new(1024, 1024), AtlasSize::PowerOfTwo, |size| {
+///     MaxRectsPacker::new(size, MaxRectsHeuristic::BestShortSideFit, 0, true)
+/// });
+/// assert!(atlas.is_complete());
+/// assert_eq!(atlas.size, Vector2i::new(64, 64));
+/// ```
+pub fn pack_atlas<P: Packer>(
+    sizes: &[Vector2i],
+    max_size: Vector2i,
+    constraint: AtlasSize,
+    mut new_packer: impl FnMut(Vector2i) -> P,
+) -> Packing {
+    let valid = sizes.iter().filter(|size| size.x > 0 && size.y > 0);
+    let total_area: wide_int!() = valid.clone().map(|size| size.x as wide_int!() * size.y as wide_int!()).sum();
+    let min_side = valid.map(|size| size.x.min(size.y)).max().unwrap_or(1);
+
+    let mut side = (math::sqrt(total_area as f64) as int!()).max(min_side).max(1);
+    let square = matches!(constraint, AtlasSize::Square | AtlasSize::SquarePowerOfTwo);
+    let power_of_two = matches!(constraint, AtlasSize::PowerOfTwo | AtlasSize::SquarePowerOfTwo);
+    let max_size = if square {
+        let side = max_size.x.min(max_size.y);
+        Vector2i::new(side, side)
+    } else {
+        max_size
+    };
+    if power_of_two {
+        side = next_power_of_two(side);
+    }
+
+    loop {
+        let bin = Vector2i::new(side, side).min(&max_size);
+        let mut packing = new_packer(bin).insert_all(sizes);
+        if packing.is_complete() || bin == max_size {
+            let extent = packing.extent();
+            let size = if square {
+                Vector2i::new(extent.x.max(extent.y), extent.x.max(extent.y))
+            } else {
+                extent
+            };
+            packing.size = if power_of_two {
+                Vector2i::new(next_power_of_two(size.x), next_power_of_two(size.y)).min(&bin)
+            } else {
+                size
+            };
+            return packing;
+        }
+        side = if power_of_two {
+            side.saturating_mul(2)
+        } else {
+            side.saturating_add((side / 16).max(1))
+        };
+    }
+}
+
+fn area(rect: &Rect2i) -> wide_int!() {
+    rect.size().x as wide_int!() * rect.size().y as wide_int!()
+}
+
+fn occupancy(used_area: wide_int!(), size: Vector2i, padding: int!()) -> float!() {
+    let bin = area(&Rect2i::new(Vector2i::ZERO, size + Vector2i::new(padding, padding)));
+    if bin <= 0 {
+        return 0.0;
+    }
+    used_area as float!() / bin as float!()
+}
+
+fn next_power_of_two(value: int!()) -> int!() {
+    let mut power = 1;
+    while power < value {
+        power = power.saturating_mul(2);
+    }
+    power
+}
@@ -0,0 +1,184 @@
+use crate::float;
+use crate::types::vectors::Vector3;
+use crate::utils::{is_equal_approx, is_zero_approx, CMP_EPSILON};
+use core::fmt::{Display, Formatter};
+use core::ops::Neg;
+
+/// A plane in Hessian normal form.
+///
+/// Represents a normalized plane equation. `normal` is the normal of the plane (a, b, c normalized), and `d` is the distance from the origin to the plane (in the direction of `normal`). "Over" or "Above" the plane is considered the side of the plane towards where the normal is pointing.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Plane {
+    /// The normal of the plane, typically a unit vector. Shouldn't be a zero vector as **Plane** with such `normal` does not represent a valid plane.
+    ///
+    /// In the scalar equation of the plane `ax + by + cz = d`, this is the vector `(a, b, c)`, where `d` is the `d` property.
+    pub normal: Vector3,
+    /// The distance from the origin to the plane, expressed in terms of `normal` (according to its direction and magnitude). Actual absolute distance from the origin to the plane can be calculated as `d.abs() / normal.length()` (if `normal` has zero length then this **Plane** does not represent a valid plane).
+    ///
+    /// In the scalar equation of the plane `ax + by + cz = d`, this is `d`, while the `(a, b, c)` coordinates are represented by the `normal` property.
+    pub d: float!(),
+}
+
+impl Plane {
+    /// A plane that extends in the Y and Z axes (normal vector points +X).
+    pub const PLANE_YZ: Self = Self::new(Vector3::new(1.0, 0.0, 0.0), 0.0);
+
+    /// A plane that extends in the X and Z axes (normal vector points +Y).
+    pub const PLANE_XZ: Self = Self::new(Vector3::new(0.0, 1.0, 0.0), 0.0);
+
+    /// A plane that extends in the X and Y axes (normal vector points +Z).
+    pub const PLANE_XY: Self = Self::new(Vector3::new(0.0, 0.0, 1.0), 0.0);
+
+    /// Creates a plane from the `normal` vector and the plane's distance from the origin `d`.
+    ///
+    /// **Note:** The `normal` should be a normalized vector.
+    pub const fn new(normal: Vector3, d: float!()) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates a plane from the four parameters. The three components of the resulting plane's `normal` are `a`, `b` and `c`, and the plane has a distance of `d` from the origin.
+    pub const fn new_from_floats(a: float!(), b: float!(), c: float!(), d: float!()) -> Self {
+        Self::new(Vector3::new(a, b, c), d)
+    }
+
+    /// Creates a plane from the `normal` vector and a `point` on the plane.
+    ///
+    /// **Note:** The `normal` should be a normalized vector.
+    pub fn new_from_normal_point(normal: &Vector3, point: &Vector3) -> Self {
+        Self::new(*normal, normal.dot(point))
+    }
+
+    /// Creates a plane from the three points, given in clockwise order.
+    pub fn new_from_points(point1: &Vector3, point2: &Vector3, point3: &Vector3) -> Self {
+        let normal = (point1 - point3).cross(&(point1 - point2)).normalized();
+        Self::new(normal, normal.dot(point1))
+    }
+
+    /// Returns the shortest distance from the plane to the position `point`. If the point is above the plane, the distance will be positive. If below, the distance will be negative.
+    pub fn distance_to(&self, point: &Vector3) -> float!() {
+        self.normal.dot(point) - self.d
+    }
+
+    /// Returns the center of the plane.
+    pub fn get_center(&self) -> Vector3 {
+        self.normal * self.d
+    }
+
+    /// Returns `true` if `point` is inside the plane. Comparison uses a custom minimum `tolerance` threshold.
+    pub fn has_point(&self, point: &Vector3, tolerance: float!()) -> bool {
+        self.distance_to(point).abs() <= tolerance
+    }
+
+    /// Returns the intersection point of the three planes `b`, `c` and this plane. If no intersection is found, [`None`] is returned.
+    pub fn intersect_3(&self, b: &Self, c: &Self) -> Option<Vector3> {
+        let denom = self.normal.cross(&b.normal).dot(&c.normal);
+        if is_zero_approx(denom) {
+            return None;
+        }
+        Some(
+            ((b.normal.cross(&c.normal) * self.d)
+                + (c.normal.cross(&self.normal) * b.d)
+                + (self.normal.cross(&b.normal) * c.d))
+                / denom,
+        )
+    }
+
+    /// Returns the intersection point of a ray consisting of the position `from` and the direction normal `dir` with this plane. If no intersection is found, [`None`] is returned.
+    pub fn intersects_ray(&self, from: &Vector3, dir: &Vector3) -> Option<Vector3> {
+        let den = self.normal.dot(dir);
+        if is_zero_approx(den) {
+            return None;
+        }
+        let dist = (self.normal.dot(from) - self.d) / den;
+        // This is a ray, anything before the emitting position doesn't exist.
+        if dist > CMP_EPSILON {
+            return None;
+        }
+        Some(from + dir * -dist)
+    }
+
+    /// Returns the intersection point of a segment from position `from` to position `to` with this plane. If no intersection is found, [`None`] is returned.
+    pub fn intersects_segment(&self, from: &Vector3, to: &Vector3) -> Option<Vector3> {
+        let segment = from - to;
+        let den = self.normal.dot(&segment);
+        if is_zero_approx(den) {
+            return None;
+        }
+        let dist = (self.normal.dot(from) - self.d) / den;
+        if !(-CMP_EPSILON..=1.0 + CMP_EPSILON).contains(&dist) {
+            return None;
+        }
+        Some(from + segment * -dist)
+    }
+
+    /// Returns `true` if this plane and `to_plane` are approximately equal, by running [`is_equal_approx`](crate::utils::is_equal_approx) on each component.
+    pub fn is_equal_approx(&self, to_plane: &Self) -> bool {
+        self.normal.is_equal_approx(&to_plane.normal) && is_equal_approx(self.d, to_plane.d)
+    }
+
+    /// Returns `true` if this plane and `to_plane` are approximately equal, or if they describe the same plane with opposite normals.
+    pub fn is_equal_approx_any_side(&self, to_plane: &Self) -> bool {
+        self.is_equal_approx(to_plane) || self.is_equal_approx(&-to_plane)
+    }
+
+    /// Returns `true` if this plane is finite, by calling `is_finite` on each component.
+    pub fn is_finite(&self) -> bool {
+        self.normal.is_finite() && self.d.is_finite()
+    }
+
+    /// Returns `true` if `point` is located above the plane.
+    pub fn is_point_over(&self, point: &Vector3) -> bool {
+        self.normal.dot(point) > self.d
+    }
+
+    fn normalize(&mut self) {
+        let l = self.normal.length();
+        if l == 0.0 {
+            *self = Self::default();
+            return;
+        }
+        self.normal /= l;
+        self.d /= l;
+    }
+
+    /// Returns a copy of the plane, with normalized `normal` (so it's a unit vector). Returns `Plane(0, 0, 0, 0)` if `normal` can't be normalized (it has zero length).
+    pub fn normalized(&self) -> Self {
+        let mut p = *self;
+        p.normalize();
+        p
+    }
+
+    /// Returns the orthogonal projection of `point` into a point in the plane.
+    pub fn project(&self, point: &Vector3) -> Vector3 {
+        point - self.normal * self.distance_to(point)
+    }
+}
+
+impl PartialEq for Plane {
+    fn eq(&self, other: &Self) -> bool {
+        self.normal == other.normal && self.d == other.d
+    }
+}
+
+impl Neg for Plane {
+    type Output = Plane;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.normal, -self.d)
+    }
+}
+
+impl Neg for &Plane {
+    type Output = Plane;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl Display for Plane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "[N: ({}, {}, {}), D: {}]",
+            self.normal.x, self.normal.y, self.normal.z, self.d
+        ))
+    }
+}
@@ -0,0 +1,672 @@
+use crate::math;
+use crate::types::mesh::TriangleMesh;
+use crate::types::vectors::{Vector2, Vector3};
+use crate::utils::float;
+use crate::utils::float_consts::{FRAC_PI_2, PI, TAU};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A mesh generated from a few parameters, with normals and UVs.
+///
+/// Like Godot's **PrimitiveMesh** classes, the primitives are centered on the origin, their triangles are front-facing when their vertices are in clockwise order, and UVs start at the top-left corner of the texture. Tangents can be added with [`TriangleMesh::generate_tangents`].
+///
+/// ```
+/// # use huginn::types::primitives::{PrimitiveMesh, SphereMesh};
+/// let mesh = SphereMesh { radius: 2.0, ..SphereMesh::default() }.generate();
+///
+/// assert!(mesh.positions.iter().all(|position| (position.length() - 2.0).abs() < 1e-5));
+/// ```
+pub trait PrimitiveMesh {
+    /// Generates the vertices and triangles of this primitive.
+    fn generate(&self) -> TriangleMesh;
+}
+
+/// A box, with each face facing one of the axes.
+///
+/// The texture is laid out in a 3×2 grid: the front (+Z), right (+X) and back (-Z) faces on the top row, and the left (-X), top (+Y) and bottom (-Y) faces on the bottom row.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BoxMesh {
+    /// The size of the box. Defaults to `(1, 1, 1)`.
+    pub size: Vector3,
+    /// The number of extra subdivisions along the X axis. Defaults to `0`.
+    pub subdivide_width: usize,
+    /// The number of extra subdivisions along the Y axis. Defaults to `0`.
+    pub subdivide_height: usize,
+    /// The number of extra subdivisions along the Z axis. Defaults to `0`.
+    pub subdivide_depth: usize,
+}
+
+impl Default for BoxMesh {
+    fn default() -> Self {
+        Self {
+            size: Vector3::ONE,
+            subdivide_width: 0,
+            subdivide_height: 0,
+            subdivide_depth: 0,
+        }
+    }
+}
+
+impl PrimitiveMesh for BoxMesh {
+    fn generate(&self) -> TriangleMesh {
+        let half = self.size * 0.5;
+        let (x, y, z) = (half.x, half.y, half.z);
+        let (columns, rows, slices) = (
+            self.subdivide_width + 1,
+            self.subdivide_height + 1,
+            self.subdivide_depth + 1,
+        );
+        let third = 1.0 / 3.0;
+        let mut mesh = TriangleMesh::default();
+        let faces = [
+            (
+                Vector3::new(-x, y, z),
+                Vector3::RIGHT * self.size.x,
+                Vector3::DOWN * self.size.y,
+                (columns, rows),
+                (0.0, 0.0),
+            ),
+            (
+                Vector3::new(x, y, z),
+                Vector3::FORWARD * self.size.z,
+                Vector3::DOWN * self.size.y,
+                (slices, rows),
+                (third, 0.0),
+            ),
+            (
+                Vector3::new(x, y, -z),
+                Vector3::LEFT * self.size.x,
+                Vector3::DOWN * self.size.y,
+                (columns, rows),
+                (2.0 * third, 0.0),
+            ),
+            (
+                Vector3::new(-x, y, -z),
+                Vector3::BACK * self.size.z,
+                Vector3::DOWN * self.size.y,
+                (slices, rows),
+                (0.0, 0.5),
+            ),
+            (
+                Vector3::new(-x, y, -z),
+                Vector3::RIGHT * self.size.x,
+                Vector3::BACK * self.size.z,
+                (columns, slices),
+                (third, 0.5),
+            ),
+            (
+                Vector3::new(-x, -y, z),
+                Vector3::RIGHT * self.size.x,
+                Vector3::FORWARD * self.size.z,
+                (columns, slices),
+                (2.0 * third, 0.5),
+            ),
+        ];
+        for (corner, right, down, (columns, rows), (u, v)) in faces {
+            add_face(
+                &mut mesh,
+                corner,
+                right,
+                down,
+                columns,
+                rows,
+                Vector2::new(u, v),
+                Vector2::new(third, 0.5),
+            );
+        }
+        mesh
+    }
+}
+
+/// A flat rectangle on the XZ plane, facing up (+Y).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlaneMesh {
+    /// The size of the plane, along the X and Z axes. Defaults to `(2, 2)`.
+    pub size: Vector2,
+    /// The number of extra subdivisions along the X axis. Defaults to `0`.
+    pub subdivide_width: usize,
+    /// The number of extra subdivisions along the Z axis. Defaults to `0`.
+    pub subdivide_depth: usize,
+}
+
+impl Default for PlaneMesh {
+    fn default() -> Self {
+        Self {
+            size: Vector2::new(2.0, 2.0),
+            subdivide_width: 0,
+            subdivide_depth: 0,
+        }
+    }
+}
+
+impl PrimitiveMesh for PlaneMesh {
+    fn generate(&self) -> TriangleMesh {
+        let mut mesh = TriangleMesh::default();
+        add_face(
+            &mut mesh,
+            Vector3::new(-self.size.x * 0.5, 0.0, -self.size.y * 0.5),
+            Vector3::RIGHT * self.size.x,
+            Vector3::BACK * self.size.y,
+            self.subdivide_width + 1,
+            self.subdivide_depth + 1,
+            Vector2::ZERO,
+            Vector2::ONE,
+        );
+        mesh
+    }
+}
+
+/// A flat rectangle on the XY plane, facing the camera (+Z).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QuadMesh {
+    /// The size of the quad, along the X and Y axes. Defaults to `(1, 1)`.
+    pub size: Vector2,
+}
+
+impl Default for QuadMesh {
+    fn default() -> Self {
+        Self { size: Vector2::ONE }
+    }
+}
+
+impl PrimitiveMesh for QuadMesh {
+    fn generate(&self) -> TriangleMesh {
+        let mut mesh = TriangleMesh::default();
+        add_face(
+            &mut mesh,
+            Vector3::new(-self.size.x * 0.5, self.size.y * 0.5, 0.0),
+            Vector3::RIGHT * self.size.x,
+            Vector3::DOWN * self.size.y,
+            1,
+            1,
+            Vector2::ZERO,
+            Vector2::ONE,
+        );
+        mesh
+    }
+}
+
+/// A UV sphere, made of rings of vertices between the poles.
+///
+/// The texture is wrapped around the sphere, with an equirectangular projection.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SphereMesh {
+    /// The radius of the sphere. Defaults to `0.5`.
+    pub radius: float!(),
+    /// The number of segments around the Y axis, at least 3. Defaults to `64`.
+    pub radial_segments: usize,
+    /// The number of slices from pole to pole, at least 2. Defaults to `32`.
+    pub rings: usize,
+}
+
+impl Default for SphereMesh {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            radial_segments: 64,
+            rings: 32,
+        }
+    }
+}
+
+impl PrimitiveMesh for SphereMesh {
+    fn generate(&self) -> TriangleMesh {
+        let rings = self.rings.max(2);
+        let profile = (0..=rings).map(|ring| {
+            let v = ring as float!() / rings as float!();
+            let (sin, cos) = (math::sin(v * PI), math::cos(v * PI));
+            ProfilePoint {
+                radius: sin * self.radius,
+                y: cos * self.radius,
+                normal: Vector2::new(sin, cos),
+                v,
+            }
+        });
+        let mut mesh = TriangleMesh::default();
+        add_lathe(&mut mesh, profile, self.radial_segments);
+        mesh
+    }
+}
+
+/// A geodesic sphere, made by subdividing an icosahedron, whose triangles all have about the same size.
+///
+/// The texture is wrapped around the sphere like on a [`SphereMesh`]. Along the seam, the U coordinate goes past `1`, so the texture should repeat.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IcosphereMesh {
+    /// The radius of the sphere. Defaults to `0.5`.
+    pub radius: float!(),
+    /// The number of times every triangle of the icosahedron is split in four. Defaults to `3`.
+    pub subdivisions: usize,
+}
+
+impl Default for IcosphereMesh {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            subdivisions: 3,
+        }
+    }
+}
+
+impl PrimitiveMesh for IcosphereMesh {
+    fn generate(&self) -> TriangleMesh {
+        let t: float!() = (1.0 + math::sqrt(5.0)) * 0.5;
+        let mut normals = vec![
+            Vector3::new(-1.0, t, 0.0),
+            Vector3::new(1.0, t, 0.0),
+            Vector3::new(-1.0, -t, 0.0),
+            Vector3::new(1.0, -t, 0.0),
+            Vector3::new(0.0, -1.0, t),
+            Vector3::new(0.0, 1.0, t),
+            Vector3::new(0.0, -1.0, -t),
+            Vector3::new(0.0, 1.0, -t),
+            Vector3::new(t, 0.0, -1.0),
+            Vector3::new(t, 0.0, 1.0),
+            Vector3::new(-t, 0.0, -1.0),
+            Vector3::new(-t, 0.0, 1.0),
+        ];
+        normals
+            .iter_mut()
+            .for_each(|normal| *normal = normal.normalized());
+        let mut triangles = vec![
+            [0, 5, 11],
+            [0, 1, 5],
+            [0, 7, 1],
+            [0, 10, 7],
+            [0, 11, 10],
+            [1, 9, 5],
+            [5, 4, 11],
+            [11, 2, 10],
+            [10, 6, 7],
+            [7, 8, 1],
+            [3, 4, 9],
+            [3, 2, 4],
+            [3, 6, 2],
+            [3, 8, 6],
+            [3, 9, 8],
+            [4, 5, 9],
+            [2, 11, 4],
+            [6, 10, 2],
+            [8, 7, 6],
+            [9, 1, 8],
+        ];
+
+        for _ in 0..self.subdivisions {
+            let mut midpoints = BTreeMap::new();
+            let mut midpoint = |a: u32, b: u32| {
+                *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                    normals.push((normals[a as usize] + normals[b as usize]).normalized());
+                    normals.len() as u32 - 1
+                })
+            };
+            triangles = triangles
+                .into_iter()
+                .flat_map(|[a, b, c]| {
+                    let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+
+        let mut mesh = TriangleMesh::new(
+            normals.iter().map(|normal| *normal * self.radius).collect(),
+            triangles.concat(),
+        );
+        mesh.uvs = normals
+            .iter()
+            .map(|normal| {
+                let u = math::atan2(normal.x, normal.z) / TAU;
+                Vector2::new(
+                    if u < 0.0 { u + 1.0 } else { u },
+                    math::acos(normal.y.clamp(-1.0, 1.0)) / PI,
+                )
+            })
+            .collect();
+        mesh.normals = normals;
+        fix_uv_seam(&mut mesh);
+        mesh
+    }
+}
+
+/// A capsule along the Y axis: a cylinder with a hemisphere at each end.
+///
+/// The texture is wrapped around the capsule, with the V coordinate following the distance along its outline.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CapsuleMesh {
+    /// The radius of the capsule. Defaults to `0.5`.
+    pub radius: float!(),
+    /// The full height of the capsule, hemispheres included. It's at least twice the radius. Defaults to `2`.
+    pub height: float!(),
+    /// The number of segments around the Y axis, at least 3. Defaults to `64`.
+    pub radial_segments: usize,
+    /// The number of slices of each hemisphere, at least 1. Defaults to `8`.
+    pub rings: usize,
+}
+
+impl Default for CapsuleMesh {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            height: 2.0,
+            radial_segments: 64,
+            rings: 8,
+        }
+    }
+}
+
+impl PrimitiveMesh for CapsuleMesh {
+    fn generate(&self) -> TriangleMesh {
+        let rings = self.rings.max(1);
+        let half_cylinder = (self.height * 0.5 - self.radius).max(0.0);
+        let length = PI * self.radius + 2.0 * half_cylinder;
+        // Each hemisphere goes from its pole to the equator, so the cylinder is the band between the two.
+        let profile = (0..=rings)
+            .chain(rings..=2 * rings)
+            .enumerate()
+            .map(|(row, ring)| {
+                let angle = ring as float!() / rings as float!() * FRAC_PI_2;
+                let (sin, cos) = (math::sin(angle), math::cos(angle));
+                let (y, distance) = if row <= rings {
+                    (half_cylinder + cos * self.radius, angle * self.radius)
+                } else {
+                    (
+                        -half_cylinder + cos * self.radius,
+                        angle * self.radius + 2.0 * half_cylinder,
+                    )
+                };
+                ProfilePoint {
+                    radius: sin * self.radius,
+                    y,
+                    normal: Vector2::new(sin, cos),
+                    v: distance / length,
+                }
+            });
+        let mut mesh = TriangleMesh::default();
+        add_lathe(&mut mesh, profile, self.radial_segments);
+        mesh
+    }
+}
+
+/// A cylinder along the Y axis, or a cone when one of its radii is `0`.
+///
+/// The side covers the top two thirds of the texture. The caps are in the bottom third, the top cap on the left half and the bottom cap on the right half.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CylinderMesh {
+    /// The radius of the top of the cylinder. Defaults to `0.5`.
+    pub top_radius: float!(),
+    /// The radius of the bottom of the cylinder. Defaults to `0.5`.
+    pub bottom_radius: float!(),
+    /// The height of the cylinder. Defaults to `2`.
+    pub height: float!(),
+    /// The number of segments around the Y axis, at least 3. Defaults to `64`.
+    pub radial_segments: usize,
+    /// The number of slices along the height, at least 1. Defaults to `4`.
+    pub rings: usize,
+    /// If `true`, the top is closed by a disc, unless its radius is `0`. Defaults to `true`.
+    pub cap_top: bool,
+    /// If `true`, the bottom is closed by a disc, unless its radius is `0`. Defaults to `true`.
+    pub cap_bottom: bool,
+}
+
+impl Default for CylinderMesh {
+    fn default() -> Self {
+        Self {
+            top_radius: 0.5,
+            bottom_radius: 0.5,
+            height: 2.0,
+            radial_segments: 64,
+            rings: 4,
+            cap_top: true,
+            cap_bottom: true,
+        }
+    }
+}
+
+impl PrimitiveMesh for CylinderMesh {
+    fn generate(&self) -> TriangleMesh {
+        let rings = self.rings.max(1);
+        let half_height = self.height * 0.5;
+        // The side leans in or out, so its normal does too.
+        let normal = Vector2::new(self.height, self.bottom_radius - self.top_radius).normalized();
+        let profile = (0..=rings).map(|ring| {
+            let weight = ring as float!() / rings as float!();
+            ProfilePoint {
+                radius: self.top_radius + (self.bottom_radius - self.top_radius) * weight,
+                y: half_height - self.height * weight,
+                normal,
+                v: weight * 2.0 / 3.0,
+            }
+        });
+        let mut mesh = TriangleMesh::default();
+        add_lathe(&mut mesh, profile, self.radial_segments);
+
+        let segments = self.radial_segments.max(3);
+        let caps = [
+            (
+                self.cap_top,
+                self.top_radius,
+                half_height,
+                Vector3::UP,
+                0.25,
+            ),
+            (
+                self.cap_bottom,
+                self.bottom_radius,
+                -half_height,
+                Vector3::DOWN,
+                0.75,
+            ),
+        ];
+        for (enabled, radius, y, normal, center_u) in caps {
+            if !enabled || radius <= 0.0 {
+                continue;
+            }
+            let center = mesh.positions.len() as u32;
+            mesh.positions.push(Vector3::new(0.0, y, 0.0));
+            mesh.normals.push(normal);
+            mesh.uvs.push(Vector2::new(center_u, 5.0 / 6.0));
+            for segment in 0..=segments {
+                let angle = segment as float!() / segments as float!() * TAU;
+                let (sin, cos) = (math::sin(angle), math::cos(angle));
+                mesh.positions
+                    .push(Vector3::new(sin * radius, y, cos * radius));
+                mesh.normals.push(normal);
+                // Seen from below, +Z is up on the bottom cap.
+                mesh.uvs.push(Vector2::new(
+                    center_u + sin * 0.25,
+                    5.0 / 6.0 + cos * normal.y / 6.0,
+                ));
+            }
+            for segment in 0..segments as u32 {
+                let (current, next) = (center + 1 + segment, center + 2 + segment);
+                if normal.y > 0.0 {
+                    mesh.indices.extend([center, next, current]);
+                } else {
+                    mesh.indices.extend([center, current, next]);
+                }
+            }
+        }
+        mesh
+    }
+}
+
+/// A torus around the Y axis.
+///
+/// The texture is wrapped around the torus: the U coordinate goes around the Y axis, and the V coordinate around the tube, starting from its top.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TorusMesh {
+    /// The distance from the center of the torus to the inside of the tube. Defaults to `0.5`.
+    pub inner_radius: float!(),
+    /// The distance from the center of the torus to the outside of the tube. Defaults to `1`.
+    pub outer_radius: float!(),
+    /// The number of segments around the Y axis, at least 3. Defaults to `64`.
+    pub rings: usize,
+    /// The number of segments around the tube, at least 3. Defaults to `32`.
+    pub ring_segments: usize,
+}
+
+impl Default for TorusMesh {
+    fn default() -> Self {
+        Self {
+            inner_radius: 0.5,
+            outer_radius: 1.0,
+            rings: 64,
+            ring_segments: 32,
+        }
+    }
+}
+
+impl PrimitiveMesh for TorusMesh {
+    fn generate(&self) -> TriangleMesh {
+        let segments = self.ring_segments.max(3);
+        let center = (self.inner_radius + self.outer_radius) * 0.5;
+        let tube = (self.outer_radius - self.inner_radius) * 0.5;
+        let profile = (0..=segments).map(|segment| {
+            let v = segment as float!() / segments as float!();
+            let (sin, cos) = (math::sin(v * TAU), math::cos(v * TAU));
+            ProfilePoint {
+                radius: center + sin * tube,
+                y: cos * tube,
+                normal: Vector2::new(sin, cos),
+                v,
+            }
+        });
+        let mut mesh = TriangleMesh::default();
+        add_lathe(&mut mesh, profile, self.rings);
+        mesh
+    }
+}
+
+/// A point of the outline revolved around the Y axis by [`add_lathe`].
+struct ProfilePoint {
+    /// The distance from the Y axis.
+    radius: float!(),
+    y: float!(),
+    /// The normal, with its component away from the Y axis in `x`, and its vertical component in `y`.
+    normal: Vector2,
+    v: float!(),
+}
+
+/// Appends the surface made by revolving `profile`, ordered from top to bottom, around the Y axis. Each profile point gives a ring of `segments + 1` vertices, the first and last being at the same place with a different U coordinate.
+fn add_lathe(
+    mesh: &mut TriangleMesh,
+    profile: impl Iterator<Item = ProfilePoint>,
+    segments: usize,
+) {
+    let segments = segments.max(3);
+    let first = mesh.positions.len() as u32;
+    let mut rows = 0;
+    for point in profile {
+        for segment in 0..=segments {
+            let u = segment as float!() / segments as float!();
+            // At U = 0 the vertex faces +Z, and U increases toward +X, so it goes right as seen from the front.
+            let (sin, cos) = (math::sin(u * TAU), math::cos(u * TAU));
+            mesh.positions.push(Vector3::new(
+                sin * point.radius,
+                point.y,
+                cos * point.radius,
+            ));
+            mesh.normals.push(
+                Vector3::new(sin * point.normal.x, point.normal.y, cos * point.normal.x)
+                    .normalized(),
+            );
+            mesh.uvs.push(Vector2::new(u, point.v));
+        }
+        rows += 1;
+    }
+    add_grid_indices(mesh, first, segments, rows - 1);
+}
+
+/// Appends a flat grid of `columns` by `rows` quads, from `corner` toward `right` and `down`, which are the full width and height of the face as seen from its front. `uv_position` and `uv_size` give the region of the texture covering it.
+#[allow(clippy::too_many_arguments)]
+fn add_face(
+    mesh: &mut TriangleMesh,
+    corner: Vector3,
+    right: Vector3,
+    down: Vector3,
+    columns: usize,
+    rows: usize,
+    uv_position: Vector2,
+    uv_size: Vector2,
+) {
+    let normal = down.cross(&right).normalized();
+    let first = mesh.positions.len() as u32;
+    for row in 0..=rows {
+        for column in 0..=columns {
+            let (x, y) = (
+                column as float!() / columns as float!(),
+                row as float!() / rows as float!(),
+            );
+            mesh.positions.push(corner + right * x + down * y);
+            mesh.normals.push(normal);
+            mesh.uvs.push(uv_position + Vector2::new(x, y) * uv_size);
+        }
+    }
+    add_grid_indices(mesh, first, columns, rows);
+}
+
+/// Appends the triangles of a grid of `columns` by `rows` quads, whose vertices start at `first` and are laid out row by row, left to right and top to bottom as seen from its front.
+fn add_grid_indices(mesh: &mut TriangleMesh, first: u32, columns: usize, rows: usize) {
+    let stride = columns as u32 + 1;
+    for row in 0..rows as u32 {
+        for column in 0..columns as u32 {
+            let top_left = first + row * stride + column;
+            let bottom_left = top_left + stride;
+            mesh.indices.extend([
+                top_left,
+                top_left + 1,
+                bottom_left + 1,
+                top_left,
+                bottom_left + 1,
+                bottom_left,
+            ]);
+        }
+    }
+}
+
+/// Duplicates the vertices of the triangles that cross the seam of a wrapped texture, at U = 0, so the texture doesn't go back across the whole triangle. Vertices on the poles get a copy for each triangle, under the middle of its other vertices.
+fn fix_uv_seam(mesh: &mut TriangleMesh) {
+    let mut wrapped = BTreeMap::new();
+    let mut placed_poles = BTreeSet::new();
+    for triangle in 0..mesh.triangle_count() {
+        let corners = [0, 1, 2].map(|i| mesh.indices[triangle * 3 + i] as usize);
+        let poles = corners.map(|vertex| {
+            mesh.normals[vertex].x.abs() < 1e-6 && mesh.normals[vertex].z.abs() < 1e-6
+        });
+        let mut us = corners.map(|vertex| mesh.uvs[vertex].x);
+        let others = || (0..3).filter(|&i| !poles[i]);
+        let min = others().map(|i| us[i]).fold(1.0, <float!()>::min);
+        let max = others().map(|i| us[i]).fold(0.0, <float!()>::max);
+
+        if max - min > 0.5 {
+            for i in others().filter(|&i| us[i] < 0.5).collect::<Vec<_>>() {
+                us[i] += 1.0;
+                let vertex = corners[i];
+                mesh.indices[triangle * 3 + i] = *wrapped.entry(vertex).or_insert_with(|| {
+                    duplicate(mesh, vertex);
+                    mesh.uvs.last_mut().unwrap().x += 1.0;
+                    mesh.positions.len() as u32 - 1
+                });
+            }
+        }
+        let middle = others().map(|i| us[i]).sum::<float!()>() / others().count() as float!();
+        for i in (0..3).filter(|&i| poles[i]) {
+            let mut vertex = corners[i];
+            // The first triangle around a pole can keep the original vertex.
+            if !placed_poles.insert(vertex) {
+                duplicate(mesh, vertex);
+                vertex = mesh.positions.len() - 1;
+                mesh.indices[triangle * 3 + i] = vertex as u32;
+            }
+            mesh.uvs[vertex].x = middle;
+        }
+    }
+}
+
+/// Appends a copy of `vertex`, with its normal and UV.
+fn duplicate(mesh: &mut TriangleMesh, vertex: usize) {
+    mesh.positions.push(mesh.positions[vertex]);
+    mesh.normals.push(mesh.normals[vertex]);
+    mesh.uvs.push(mesh.uvs[vertex]);
+}
@@ -1,3 +1,4 @@
+use crate::math;
 use crate::types::vectors::Vector3;
 use crate::types::{Basis, EulerOrder};
 use crate::utils::{
@@ -5,7 +6,7 @@ use crate::utils::{
     is_equal_approx_with_tolerance, CMP_EPSILON, UNIT_EPSILON,
 };
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::ops::Neg;
+use core::ops::Neg;
 
 /// A unit quaternion used for representing 3D rotations.
 ///
@@ -60,7 +61,7 @@ impl Quaternion {
     pub fn angle_to(&self, to: &Self) -> float!() {
         let d = self.dot(to);
         // acos does clamping.
-        (d * d * 2.0 - 1.0).acos()
+        math::acos(d * d * 2.0 - 1.0)
     }
 
     /// Returns the dot product between this quaternion and `with`.
@@ -93,12 +94,12 @@ impl Quaternion {
         // Conversion to quaternion as listed in https://ntrs.nasa.gov/archive/nasa/casi.ntrs.nasa.gov/19770024290.pdf (page A-6)
         // a3 is the angle of the first rotation, following the notation in this reference.
 
-        let cos_a1 = half_a1.cos();
-        let sin_a1 = half_a1.sin();
-        let cos_a2 = half_a2.cos();
-        let sin_a2 = half_a2.sin();
-        let cos_a3 = half_a3.cos();
-        let sin_a3 = half_a3.sin();
+        let cos_a1 = math::cos(half_a1);
+        let sin_a1 = math::sin(half_a1);
+        let cos_a2 = math::cos(half_a2);
+        let sin_a2 = math::sin(half_a2);
+        let cos_a3 = math::cos(half_a3);
+        let sin_a3 = math::sin(half_a3);
 
         Quaternion::new(
             sin_a1 * cos_a2 * sin_a3 + cos_a1 * sin_a2 * cos_a3,
@@ -112,7 +113,7 @@ impl Quaternion {
     ///
     /// **Note:** The quaternion must be normalized.
     pub fn get_angle(&self) -> float!() {
-        2.0 * self.w.acos()
+        2.0 * math::acos(self.w)
     }
 
     /// Returns the rotation axis of the rotation represented by this quaternion.
@@ -120,7 +121,7 @@ impl Quaternion {
         if self.w.abs() > 1.0 - CMP_EPSILON {
             Vector3::new(self.x, self.y, self.z)
         } else {
-            let r = 1.0 / (1.0 - self.w * self.w).sqrt();
+            let r = 1.0 / math::sqrt(1.0 - self.w * self.w);
             Vector3::new(self.x * r, self.y * r, self.z * r)
         }
     }
@@ -164,7 +165,7 @@ impl Quaternion {
 
     /// Returns this quaternion's length, also called magnitude.
     pub fn length(&self) -> float!() {
-        self.length_squared().sqrt()
+        math::sqrt(self.length_squared())
     }
 
     /// Returns this quaternion's length, squared.
@@ -203,11 +204,11 @@ impl Quaternion {
 
         let (scale0, scale1) = if (1.0 - cosom) > CMP_EPSILON {
             // standard case (slerp)
-            let omega = cosom.acos();
-            let sinom = omega.sin();
+            let omega = math::acos(cosom);
+            let sinom = math::sin(omega);
             (
-                ((1.0 - weight) * omega).sin() / sinom,
-                (weight * omega).sin() / sinom,
+                math::sin((1.0 - weight) * omega) / sinom,
+                math::sin(weight * omega) / sinom,
             )
         } else {
             // "from" and "to" quaternions are very close
@@ -232,10 +233,10 @@ impl Quaternion {
         if dot.abs() > 1.0 - CMP_EPSILON / 10.0 {
             from
         } else {
-            let theta = dot.acos();
-            let sin_t = 1.0 / theta.sin();
-            let new_factor = (weight * theta).sin() * sin_t;
-            let inv_factor = ((1.0 - weight) * theta).sin() * sin_t;
+            let theta = math::acos(dot);
+            let sin_t = 1.0 / math::sin(theta);
+            let new_factor = math::sin(weight * theta) * sin_t;
+            let inv_factor = math::sin((1.0 - weight) * theta) * sin_t;
 
             Self::new(
                 inv_factor * from.x + new_factor * to.x,
@@ -393,7 +394,7 @@ impl From<(&Vector3, &Vector3)> for Quaternion {
         if d < -1.0 + CMP_EPSILON {
             Quaternion::new(0.0, 1.0, 0.0, 0.0)
         } else {
-            let s = ((1.0 + d) * 2.0).sqrt();
+            let s = math::sqrt((1.0 + d) * 2.0);
             let rs = 1.0 / s;
             Quaternion::new(c.x * rs, c.y * rs, c.z * rs, s * 0.5)
         }
@@ -407,8 +408,8 @@ impl From<(&Vector3, float!())> for Quaternion {
         if d == 0.0 {
             Quaternion::new(0.0, 0.0, 0.0, 0.0)
         } else {
-            let sin_angle = (v.1 * 0.5).sin();
-            let cos_angle = (v.1 * 0.5).cos();
+            let sin_angle = math::sin(v.1 * 0.5);
+            let cos_angle = math::cos(v.1 * 0.5);
             let s = sin_angle / d;
             Quaternion::new(v.0.x * s, v.0.y * s, v.0.z * s, cos_angle)
         }
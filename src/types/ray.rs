@@ -0,0 +1,398 @@
+use crate::math;
+use crate::types::collision2d::{Circle, Segment2D};
+use crate::types::collision3d::{BoxShape3D, Sphere, Triangle3D};
+use crate::types::vectors::{Vector2, Vector3};
+use crate::types::{Plane, Rect2, Transform2D, Transform3D, AABB};
+use crate::utils::{float, is_zero_approx, CMP_EPSILON};
+use auto_ops::impl_op_ex;
+
+/// A ray that shapes implementing [`Raycast`] can be hit by, see [`Ray2D`] and [`Ray3D`].
+pub trait Ray {
+    /// The vector type of the ray, [`Vector2`] or [`Vector3`].
+    type Vector: Copy;
+
+    /// Returns the point at the distance `t` along the ray.
+    fn at(&self, t: float!()) -> Self::Vector;
+}
+
+/// A shape that can be hit by rays of type `R`.
+///
+/// Rays are cast in the space the shape is defined in. To cast a ray at a shape placed by a transform, transform the ray by the inverse of that transform first, with `ray * xform`, which works with any invertible transform (see [`Transform3D::affine_inverse`]). Hits at a distance larger than `max_distance` are ignored, so an infinite `max_distance` sets no limit.
+///
+/// **Note:** The direction of a transformed ray is normalized again, so if the transform scales, the hit's `t` and `max_distance` are in the units of the shape's space, not the world's. Transform the hit `point` back with `xform * point` to measure the distance in world units.
+///
+/// Solid shapes are hit from the inside at a distance of `0.0`, with a normal of zero. Shapes with no inside, like segments, planes and triangles, can be hit from both sides, and their normal faces the ray.
+pub trait Raycast<R: Ray> {
+    /// Returns where `ray` first hits this shape, or [`None`] if it doesn't hit it within `max_distance`.
+    fn raycast(&self, ray: &R, max_distance: float!()) -> Option<RayHit<R::Vector>>;
+}
+
+// `derive` doesn't accept type macros in the fields of generic structs.
+type Float = float!();
+
+/// Where a ray hits a shape, see [`Raycast`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit<V> {
+    /// The distance from the origin of the ray to the hit.
+    pub t: Float,
+    /// The point where the ray hits the shape.
+    pub point: V,
+    /// The normal of the shape at `point`, or zero if the ray starts inside the shape.
+    pub normal: V,
+}
+
+/// A 2D ray, starting at an `origin` and extending towards infinity in a normalized `direction`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Ray2D {
+    origin: Vector2,
+    direction: Vector2,
+}
+
+impl Ray2D {
+    /// Constructs a **Ray2D** starting at `origin`, towards `direction`, which is normalized.
+    ///
+    /// ```
+    /// # use huginn::types::ray::Ray2D;
+    /// # use huginn::types::vectors::Vector2;
+    /// let ray = Ray2D::new(Vector2::new(1.0, 1.0), Vector2::new(0.0, 5.0));
+    /// assert_eq!(ray.at(2.0), Vector2::new(1.0, 3.0));
+    /// ```
+    pub fn new(origin: Vector2, direction: Vector2) -> Self {
+        Self {
+            origin,
+            direction: direction.normalized(),
+        }
+    }
+
+    /// Returns the point at the distance `t` along the ray.
+    pub fn at(&self, t: float!()) -> Vector2 {
+        self.origin + self.direction * t
+    }
+
+    /// The point the ray starts at.
+    pub fn origin(&self) -> Vector2 {
+        self.origin
+    }
+
+    /// The normalized direction of the ray.
+    pub fn direction(&self) -> Vector2 {
+        self.direction
+    }
+
+    /// Returns the first hit of the ray among `shapes`, with the index of the shape it hits.
+    pub fn cast_first<'a, S: Raycast<Self> + ?Sized + 'a>(
+        &self,
+        shapes: impl IntoIterator<Item = &'a S>,
+        max_distance: float!(),
+    ) -> Option<(usize, RayHit<Vector2>)> {
+        first_hit(self, shapes, max_distance)
+    }
+}
+
+impl Ray for Ray2D {
+    type Vector = Vector2;
+
+    fn at(&self, t: float!()) -> Vector2 {
+        Ray2D::at(self, t)
+    }
+}
+
+/// A 3D ray, starting at an `origin` and extending towards infinity in a normalized `direction`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Ray3D {
+    origin: Vector3,
+    direction: Vector3,
+}
+
+impl Ray3D {
+    /// Constructs a **Ray3D** starting at `origin`, towards `direction`, which is normalized.
+    pub fn new(origin: Vector3, direction: Vector3) -> Self {
+        Self {
+            origin,
+            direction: direction.normalized(),
+        }
+    }
+
+    /// Returns the point at the distance `t` along the ray.
+    pub fn at(&self, t: float!()) -> Vector3 {
+        self.origin + self.direction * t
+    }
+
+    /// The point the ray starts at.
+    pub fn origin(&self) -> Vector3 {
+        self.origin
+    }
+
+    /// The normalized direction of the ray.
+    pub fn direction(&self) -> Vector3 {
+        self.direction
+    }
+
+    /// Returns the first hit of the ray among `shapes`, with the index of the shape it hits.
+    ///
+    /// ```
+    /// # use huginn::types::collision3d::Triangle3D;
+    /// # use huginn::types::ray::Ray3D;
+    /// # use huginn::types::vectors::Vector3;
+    /// let triangles = [
+    ///     Triangle3D::new(Vector3::new(-1.0, -1.0, -5.0), Vector3::new(1.0, -1.0, -5.0), Vector3::new(0.0, 1.0, -5.0)),
+    ///     Triangle3D::new(Vector3::new(-1.0, -1.0, -2.0), Vector3::new(1.0, -1.0, -2.0), Vector3::new(0.0, 1.0, -2.0)),
+    /// ];
+    /// let (index, hit) = Ray3D::new(Vector3::ZERO, Vector3::FORWARD).cast_first(&triangles, 10.0).unwrap();
+    /// assert_eq!(index, 1);
+    /// assert_eq!(hit.t, 2.0);
+    /// assert_eq!(hit.normal, Vector3::BACK);
+    /// ```
+    pub fn cast_first<'a, S: Raycast<Self> + ?Sized + 'a>(
+        &self,
+        shapes: impl IntoIterator<Item = &'a S>,
+        max_distance: float!(),
+    ) -> Option<(usize, RayHit<Vector3>)> {
+        first_hit(self, shapes, max_distance)
+    }
+}
+
+impl Ray for Ray3D {
+    type Vector = Vector3;
+
+    fn at(&self, t: float!()) -> Vector3 {
+        Ray3D::at(self, t)
+    }
+}
+
+fn first_hit<'a, R: Ray, S: Raycast<R> + ?Sized + 'a>(
+    ray: &R,
+    shapes: impl IntoIterator<Item = &'a S>,
+    max_distance: float!(),
+) -> Option<(usize, RayHit<R::Vector>)> {
+    let mut best: Option<(usize, RayHit<R::Vector>)> = None;
+    for (index, shape) in shapes.into_iter().enumerate() {
+        // Each hit shortens the ray, so farther shapes are skipped early.
+        let limit = best.as_ref().map_or(max_distance, |(_, hit)| hit.t);
+        if let Some(hit) = shape.raycast(ray, limit) {
+            if best.as_ref().is_none_or(|(_, best)| hit.t < best.t) {
+                best = Some((index, hit));
+            }
+        }
+    }
+    best
+}
+
+impl Raycast<Ray2D> for Rect2 {
+    fn raycast(&self, ray: &Ray2D, max_distance: float!()) -> Option<RayHit<Vector2>> {
+        let hit = self.intersects_ray(&ray.origin, &ray.direction)?;
+        let t = (hit.entry - ray.origin).dot(&ray.direction).max(0.0);
+        (t <= max_distance).then_some(RayHit {
+            t,
+            point: hit.entry,
+            normal: hit.entry_normal,
+        })
+    }
+}
+
+impl Raycast<Ray2D> for Circle {
+    fn raycast(&self, ray: &Ray2D, max_distance: float!()) -> Option<RayHit<Vector2>> {
+        let t = ray_sphere(
+            ray.origin.dot(&ray.direction),
+            ray.origin.length_squared(),
+            self.radius,
+        )?;
+        if t > max_distance {
+            return None;
+        }
+        let point = ray.at(t);
+        Some(RayHit {
+            t,
+            point,
+            normal: if t == 0.0 {
+                Vector2::ZERO
+            } else {
+                point / self.radius
+            },
+        })
+    }
+}
+
+impl Raycast<Ray2D> for Segment2D {
+    fn raycast(&self, ray: &Ray2D, max_distance: float!()) -> Option<RayHit<Vector2>> {
+        let edge = self.b - self.a;
+        let denominator = ray.direction.cross(&edge);
+        if is_zero_approx(denominator) {
+            // A parallel ray only grazes the segment, which doesn't count as a hit.
+            return None;
+        }
+        let offset = self.a - ray.origin;
+        let t = offset.cross(&edge) / denominator;
+        let along = offset.cross(&ray.direction) / denominator;
+        if t < 0.0 || t > max_distance || !(0.0..=1.0).contains(&along) {
+            return None;
+        }
+        let mut normal = edge.orthogonal().normalized();
+        if normal.dot(&ray.direction) > 0.0 {
+            normal = -normal;
+        }
+        Some(RayHit {
+            t,
+            point: ray.at(t),
+            normal,
+        })
+    }
+}
+
+impl Raycast<Ray3D> for AABB {
+    fn raycast(&self, ray: &Ray3D, max_distance: float!()) -> Option<RayHit<Vector3>> {
+        let end = self.end();
+        let mut t_entry: float!() = 0.0;
+        let mut t_exit = max_distance;
+        let mut normal = Vector3::ZERO;
+
+        for i in 0..3 {
+            let (origin, direction) = (ray.origin.get(i), ray.direction.get(i));
+            if direction == 0.0 {
+                if origin < self.position().get(i) || origin > end.get(i) {
+                    return None;
+                }
+                continue;
+            }
+            let mut near = (self.position().get(i) - origin) / direction;
+            let mut far = (end.get(i) - origin) / direction;
+            let mut side = -1.0;
+            if near > far {
+                core::mem::swap(&mut near, &mut far);
+                side = 1.0;
+            }
+            if near > t_entry {
+                t_entry = near;
+                normal = Vector3::ZERO;
+                normal.set(i, side);
+            }
+            t_exit = t_exit.min(far);
+            if t_entry > t_exit {
+                return None;
+            }
+        }
+
+        Some(RayHit {
+            t: t_entry,
+            point: ray.at(t_entry),
+            normal,
+        })
+    }
+}
+
+impl Raycast<Ray3D> for BoxShape3D {
+    fn raycast(&self, ray: &Ray3D, max_distance: float!()) -> Option<RayHit<Vector3>> {
+        AABB::new(self.size * -0.5, self.size).raycast(ray, max_distance)
+    }
+}
+
+impl Raycast<Ray3D> for Sphere {
+    fn raycast(&self, ray: &Ray3D, max_distance: float!()) -> Option<RayHit<Vector3>> {
+        let t = ray_sphere(
+            ray.origin.dot(&ray.direction),
+            ray.origin.length_squared(),
+            self.radius,
+        )?;
+        if t > max_distance {
+            return None;
+        }
+        let point = ray.at(t);
+        Some(RayHit {
+            t,
+            point,
+            normal: if t == 0.0 {
+                Vector3::ZERO
+            } else {
+                point / self.radius
+            },
+        })
+    }
+}
+
+impl Raycast<Ray3D> for Plane {
+    fn raycast(&self, ray: &Ray3D, max_distance: float!()) -> Option<RayHit<Vector3>> {
+        let denominator = self.normal.dot(&ray.direction);
+        if is_zero_approx(denominator) {
+            return None;
+        }
+        let t = (self.d - self.normal.dot(&ray.origin)) / denominator;
+        if t < 0.0 || t > max_distance {
+            return None;
+        }
+        Some(RayHit {
+            t,
+            point: ray.at(t),
+            normal: if denominator > 0.0 {
+                -self.normal
+            } else {
+                self.normal
+            },
+        })
+    }
+}
+
+impl Raycast<Ray3D> for Triangle3D {
+    // The Möller–Trumbore algorithm.
+    fn raycast(&self, ray: &Ray3D, max_distance: float!()) -> Option<RayHit<Vector3>> {
+        let (edge_1, edge_2) = (self.b - self.a, self.c - self.a);
+        let p = ray.direction.cross(&edge_2);
+        let determinant = edge_1.dot(&p);
+        if determinant.abs() <= CMP_EPSILON * CMP_EPSILON {
+            return None;
+        }
+        let inverse = 1.0 / determinant;
+        let offset = ray.origin - self.a;
+        let u = offset.dot(&p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = offset.cross(&edge_1);
+        let v = ray.direction.dot(&q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = edge_2.dot(&q) * inverse;
+        if t < 0.0 || t > max_distance {
+            return None;
+        }
+        let normal = self.get_normal();
+        Some(RayHit {
+            t,
+            point: ray.at(t),
+            normal: if normal.dot(&ray.direction) > 0.0 {
+                -normal
+            } else {
+                normal
+            },
+        })
+    }
+}
+
+/// Returns the distance along a ray to a sphere centered on the origin, from the dot product of the origin of the ray with its direction and the squared length of its origin. Returns `0.0` if the ray starts inside.
+fn ray_sphere(
+    origin_dot_direction: float!(),
+    origin_length_squared: float!(),
+    radius: float!(),
+) -> Option<float!()> {
+    let c = origin_length_squared - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let discriminant = origin_dot_direction * origin_dot_direction - c;
+    if origin_dot_direction > 0.0 || discriminant < 0.0 {
+        return None;
+    }
+    Some(-origin_dot_direction - math::sqrt(discriminant))
+}
+
+impl_op_ex!(*|a: &Transform2D, b: &Ray2D| -> Ray2D {
+    Ray2D::new(a.xform(&b.origin), a.basis_xform(&b.direction))
+});
+
+impl_op_ex!(*|a: &Ray2D, b: &Transform2D| -> Ray2D { b.affine_inverse() * a });
+
+impl_op_ex!(*|a: &Transform3D, b: &Ray3D| -> Ray3D {
+    Ray3D::new(a.xform(&b.origin), a.basis_xform(&b.direction))
+});
+
+impl_op_ex!(*|a: &Ray3D, b: &Transform3D| -> Ray3D { b.affine_inverse() * a });
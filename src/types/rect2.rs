@@ -1,9 +1,8 @@
 use crate::float;
 use crate::types::vectors::Vector2;
-use crate::types::{Side, Transform2D};
-use auto_ops::impl_op_ex;
-use std::fmt::{Display, Formatter};
-use std::ops::Not;
+use crate::types::Side;
+use core::fmt::{Display, Formatter};
+use core::ops::Not;
 use crate::types::rect2i::Rect2i;
 
 /// A 2D axis-aligned bounding box using floating-point coordinates.
@@ -21,6 +20,19 @@ pub struct Rect2 {
     size: Vector2,
 }
 
+/// The points where a ray or a segment enters and leaves a [`Rect2`], see [`Rect2::intersects_ray`] and [`Rect2::intersects_segment`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rect2Intersection {
+    /// The point where the ray or segment enters the rectangle, or its start if it starts inside.
+    pub entry: Vector2,
+    /// The outward normal of the side crossed at `entry`, or [`Vector2::ZERO`] if the ray or segment starts inside.
+    pub entry_normal: Vector2,
+    /// The point where the ray or segment leaves the rectangle, or the end of the segment if it ends inside.
+    pub exit: Vector2,
+    /// The outward normal of the side crossed at `exit`, or [`Vector2::ZERO`] if the segment ends inside.
+    pub exit_normal: Vector2,
+}
+
 impl Rect2 {
     pub fn get_support(&self, direction: &Vector2) -> Vector2 {
         let mut support = self.position;
@@ -63,6 +75,16 @@ impl Rect2 {
         Self::new(self.position + self.size.min_f(0.0), self.size.abs())
     }
 
+    /// Returns the point of this rectangle closest to `point`, which is `point` itself if it's inside. See also [`distance_to_point`](Rect2::distance_to_point).
+    pub fn closest_point(&self, point: &Vector2) -> Vector2 {
+        point.clamp(&self.position, &self.end())
+    }
+
+    /// Returns the distance between `point` and the closest point of this rectangle, which is `0.0` if `point` is inside. See also [`closest_point`](Rect2::closest_point).
+    pub fn distance_to_point(&self, point: &Vector2) -> float!() {
+        (point - self.closest_point(point)).length()
+    }
+
     /// Returns `true` if this rectangle *completely* encloses the `b` rectangle.
     pub fn encloses(&self, b: &Self) -> bool {
         (b.position.x >= self.position.x)
@@ -230,6 +252,86 @@ impl Rect2 {
         true
     }
 
+    /// Returns where the given ray enters and leaves this rectangle, as a [`Rect2Intersection`]. If no intersection occurs, or `dir` is [`Vector2::ZERO`], returns [`None`].
+    ///
+    /// The ray begins at `from`, faces `dir` and extends towards infinity. If the ray starts inside the rectangle, the entry point is `from`.
+    ///
+    /// ```
+    /// # use huginn::types::Rect2;
+    /// # use huginn::types::vectors::Vector2;
+    /// let rect = Rect2::new_from_dimension(0.0, 0.0, 10.0, 10.0);
+    /// let hit = rect.intersects_ray(&Vector2::new(-5.0, 5.0), &Vector2::RIGHT).unwrap();
+    /// assert_eq!(hit.entry, Vector2::new(0.0, 5.0));
+    /// assert_eq!(hit.entry_normal, Vector2::LEFT);
+    /// assert_eq!(hit.exit, Vector2::new(10.0, 5.0));
+    /// ```
+    pub fn intersects_ray(&self, from: &Vector2, dir: &Vector2) -> Option<Rect2Intersection> {
+        if *dir == Vector2::ZERO {
+            return None;
+        }
+        self.clip_line(from, dir, <float!()>::INFINITY)
+    }
+
+    /// Returns where the given segment enters and leaves this rectangle, as a [`Rect2Intersection`]. If no intersection occurs, returns [`None`].
+    ///
+    /// The segment begins at `from` and ends at `to`. If it starts inside the rectangle, the entry point is `from`, and if it ends inside, the exit point is `to`.
+    pub fn intersects_segment(&self, from: &Vector2, to: &Vector2) -> Option<Rect2Intersection> {
+        self.clip_line(from, &(to - from), 1.0)
+    }
+
+    // Clips the line `from + dir * t` for `t` in `0..=max` against this rectangle, with the slab method.
+    fn clip_line(&self, from: &Vector2, dir: &Vector2, max: float!()) -> Option<Rect2Intersection> {
+        let end = self.end();
+        let (mut t_entry, mut entry_normal) = (<float!()>::NEG_INFINITY, Vector2::ZERO);
+        let (mut t_exit, mut exit_normal) = (<float!()>::INFINITY, Vector2::ZERO);
+
+        for i in 0..2 {
+            if dir.get(i) == 0.0 {
+                // The line is parallel to the sides on this axis.
+                if from.get(i) < self.position.get(i) || from.get(i) > end.get(i) {
+                    return None;
+                }
+                continue;
+            }
+            let mut near = (self.position.get(i) - from.get(i)) / dir.get(i);
+            let mut far = (end.get(i) - from.get(i)) / dir.get(i);
+            let mut normal = Vector2::ZERO;
+            normal.set(i, -1.0);
+            if near > far {
+                core::mem::swap(&mut near, &mut far);
+                normal = -normal;
+            }
+            if near > t_entry {
+                t_entry = near;
+                entry_normal = normal;
+            }
+            if far < t_exit {
+                t_exit = far;
+                exit_normal = -normal;
+            }
+        }
+
+        if t_entry > t_exit || t_exit < 0.0 || t_entry > max {
+            return None;
+        }
+        let (entry, entry_normal) = if t_entry < 0.0 {
+            (*from, Vector2::ZERO)
+        } else {
+            (from + dir * t_entry, entry_normal)
+        };
+        let (exit, exit_normal) = if t_exit > max {
+            (from + dir * max, Vector2::ZERO)
+        } else {
+            (from + dir * t_exit, exit_normal)
+        };
+        Some(Rect2Intersection {
+            entry,
+            entry_normal,
+            exit,
+            exit_normal,
+        })
+    }
+
     /// Returns `true` if this rectangle and `rect` are approximately equal, by calling [`Vector2::is_equal_approx`] on the `position` and the `size`.
     pub fn is_equal_approx(&self, rect: &Self) -> bool {
         self.position.is_equal_approx(&rect.position) && self.size.is_equal_approx(&rect.size)
@@ -294,25 +396,8 @@ impl PartialEq for Rect2 {
     }
 }
 
-impl_op_ex!(*= |a: &mut Rect2, b: &Transform2D| {
-    let x = b.x * a.size.x;
-    let y = b.y * a.size.y;
-    let pos = b.xform(&a.position);
-
-    a.position = pos;
-    a.expand_to(&(pos + x));
-    a.expand_to(&(pos + y));
-    a.expand_to(&(pos + x + y));
-});
-
-impl_op_ex!(*|a: &Rect2, b: &Transform2D| -> Rect2 {
-    let mut r = *a;
-    r *= b;
-    r
-});
-
 impl Display for Rect2 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
             "[P: ({}, {}), S: ({}, {})]",
             self.position.x, self.position.y, self.size.x, self.size.y
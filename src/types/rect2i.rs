@@ -1,6 +1,8 @@
-use std::fmt::{Display, Formatter};
-use std::ops::Not;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::ops::Not;
 use crate::int;
+use crate::types::grid::{BorderCells, Cells};
 use crate::types::{Rect2, Side};
 use crate::types::vectors::{Vector2, Vector2i};
 
@@ -36,6 +38,31 @@ impl Rect2i {
         Self::new(self.position + self.size.min_i(0), self.size.abs())
     }
 
+    /// Returns an iterator over the cells on the edge of this rectangle, walking clockwise from `position`. Each cell is yielded once, and a rectangle without area yields nothing.
+    ///
+    /// ```
+    /// # use huginn::types::Rect2i;
+    /// let border: Vec<_> = Rect2i::new_from_dimension(0, 0, 3, 3).border_cells().collect();
+    /// assert_eq!(border.len(), 8);
+    /// ```
+    pub fn border_cells(&self) -> BorderCells {
+        BorderCells::new(self.position, self.size)
+    }
+
+    /// Returns an iterator over every cell inside this rectangle, in row-major order. Like [`has_point`](Rect2i::has_point), the cells on the `end` edges are excluded.
+    ///
+    /// ```
+    /// # use huginn::types::Rect2i;
+    /// # use huginn::types::vectors::Vector2i;
+    /// let mut cells = Rect2i::new_from_dimension(2, 5, 2, 2).cells();
+    /// assert_eq!(cells.next(), Some(Vector2i::new(2, 5)));
+    /// assert_eq!(cells.next(), Some(Vector2i::new(3, 5)));
+    /// assert_eq!(cells.next(), Some(Vector2i::new(2, 6)));
+    /// ```
+    pub fn cells(&self) -> Cells {
+        Cells::new(self.position, self.size)
+    }
+
     /// Returns `true` if this **Rect2i** completely encloses another one.
     pub fn encloses(&self, b: &Self) -> bool {
         (b.position.x >= self.position.x) && (b.position.y >= self.position.y) &&
@@ -194,6 +221,44 @@ impl Rect2i {
         new_rect
     }
 
+    /// Returns the parts of this rectangle not covered by `b`, as up to four non-overlapping rectangles: the full-width strips above and below `b` first, then the parts left and right of `b`. Returns this rectangle alone if it doesn't intersect `b`, and nothing if `b` encloses it.
+    ///
+    /// ```
+    /// # use huginn::types::Rect2i;
+    /// let rect = Rect2i::new_from_dimension(0, 0, 10, 10);
+    /// let parts = rect.subtract(&Rect2i::new_from_dimension(5, 0, 10, 5));
+    /// assert_eq!(parts, [Rect2i::new_from_dimension(0, 5, 10, 5), Rect2i::new_from_dimension(0, 0, 5, 5)]);
+    /// ```
+    pub fn subtract(&self, b: &Self) -> Vec<Self> {
+        if !self.has_area() {
+            return Vec::new();
+        }
+        if !b.has_area() || !self.intersects(b) {
+            return alloc::vec![*self];
+        }
+        let inner = self.intersection(b);
+        let (end, inner_end) = (self.end(), inner.end());
+        let mut parts = Vec::with_capacity(4);
+        if inner.position.y > self.position.y {
+            parts.push(Self::new(self.position, Vector2i::new(self.size.x, inner.position.y - self.position.y)));
+        }
+        if inner_end.y < end.y {
+            parts.push(Self::new_from_dimension(self.position.x, inner_end.y, self.size.x, end.y - inner_end.y));
+        }
+        if inner.position.x > self.position.x {
+            parts.push(Self::new_from_dimension(
+                self.position.x,
+                inner.position.y,
+                inner.position.x - self.position.x,
+                inner.size.y,
+            ));
+        }
+        if inner_end.x < end.x {
+            parts.push(Self::new_from_dimension(inner_end.x, inner.position.y, end.x - inner_end.x, inner.size.y));
+        }
+        parts
+    }
+
     pub fn end(&self) -> Vector2i {
         self.position + self.size
     }
@@ -230,7 +295,7 @@ impl PartialEq for Rect2i {
 impl Eq for Rect2i {}
 
 impl Display for Rect2i {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
             "[P: ({}, {}), S: ({}, {})]",
             self.position.x, self.position.y, self.size.x, self.size.y
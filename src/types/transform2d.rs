@@ -1,8 +1,11 @@
+use crate::math;
 use crate::types::vectors::Vector2;
+use crate::types::Rect2;
 use crate::utils::{float, float_consts, int, is_equal_approx, FloatExt};
+use alloc::vec::Vec;
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::mem::swap;
-use std::ops::Not;
+use core::mem::swap;
+use core::ops::Not;
 
 /// A 2×3 matrix representing a 2D transformation.
 ///
@@ -11,6 +14,8 @@ use std::ops::Not;
 /// The `x` and `y` axes form a 2×2 matrix, known as the transform's **basis**. The length of each axis ([`Vector2::length`]) influences the transform's scale, while the direction of all axes influence the rotation. Usually, both axes are perpendicular to one another. However, when you rotate one axis individually, the transform becomes skewed. Applying a skewed transform to a 2D sprite will make the sprite appear distorted.
 ///
 /// **Note:** Unlike [`Transform3D`], there is no 2D equivalent to the [`Basis`] type. All mentions of "basis" refer to the `x` and `y` components of **Transform2D**.
+///
+/// **Note:** As in Godot, multiplying with the transform on the right (`vector * transform`, `rect * transform`) applies the *inverse* transform, see [`xform_inv`](Transform2D::xform_inv). `Vector2 * Transform2D` and `Vec<Vector2> * Transform2D` used to apply the forward transform; use `transform * vector` for that.
 #[derive(Copy, Clone, Debug)]
 pub struct Transform2D {
     /// The translation offset of this transform, and the column `2` of the matrix. In 2D space, this can be seen as the position.
@@ -128,8 +133,8 @@ impl Transform2D {
 
     fn set_rotation(&mut self, rot: float!()) {
         let scale = self.get_scale();
-        let cr = rot.cos();
-        let sr = rot.sin();
+        let cr = math::cos(rot);
+        let sr = math::sin(rot);
         self.x.x = cr;
         self.x.y = sr;
         self.y.x = -sr;
@@ -155,10 +160,7 @@ impl Transform2D {
     /// Returns this transform's skew (in radians).
     pub fn get_skew(&self) -> float!() {
         let det = self.determinant();
-        self.x
-            .normalized()
-            .dot(&(det.sign() * self.y.normalized()))
-            .acos()
+        math::acos(self.x.normalized().dot(&(det.sign() * self.y.normalized())))
             - float_consts::PI * 0.5
     }
 
@@ -233,6 +235,35 @@ impl Transform2D {
         Vector2::new(self.tdotx(vec), self.tdoty(vec)) + self.origin
     }
 
+    /// Returns a copy of the `vec` vector, transformed (multiplied) by the inverse transform (see [`Transform2D::inverse`]).
+    ///
+    /// **Note:** This method assumes that this transform's basis is *orthonormal* (see [`Transform2D::orthonormalized`]). If the basis is not orthonormal, `transform.affine_inverse().xform(vec)` should be used instead (see [`Transform2D::affine_inverse`]).
+    pub fn xform_inv(&self, vec: &Vector2) -> Vector2 {
+        self.basis_xform_inv(&(vec - self.origin))
+    }
+
+    /// Returns the smallest [`Rect2`] enclosing `rect` transformed (multiplied) by this transform. This is also what `transform * rect` returns.
+    pub fn xform_rect(&self, rect: &Rect2) -> Rect2 {
+        let x = self.x * rect.size().x;
+        let y = self.y * rect.size().y;
+        let position = self.xform(&rect.position());
+        Rect2::new(position, Vector2::ZERO)
+            .expand(&(position + x))
+            .expand(&(position + y))
+            .expand(&(position + x + y))
+    }
+
+    /// Returns the smallest [`Rect2`] enclosing `rect` transformed (multiplied) by the inverse transform (see [`Transform2D::xform_inv`]). This is also what `rect * transform` returns.
+    ///
+    /// **Note:** Like [`Transform2D::xform_inv`], this method assumes that this transform's basis is *orthonormal*. Otherwise, use `transform.affine_inverse().xform_rect(rect)`.
+    pub fn xform_inv_rect(&self, rect: &Rect2) -> Rect2 {
+        let (position, end) = (rect.position(), rect.end());
+        Rect2::new(self.xform_inv(&position), Vector2::ZERO)
+            .expand(&self.xform_inv(&Vector2::new(position.x, end.y)))
+            .expand(&self.xform_inv(&Vector2::new(end.x, position.y)))
+            .expand(&self.xform_inv(&end))
+    }
+
     /// Returns a copy of the transform rotated such that the rotated X-axis points towards the `target` position, in global space.
     pub fn looking_at(&self, target: &Vector2) -> Self {
         let mut return_trans = Self::from((self.get_rotation(), self.get_origin()));
@@ -359,8 +390,8 @@ impl Transform2D {
 impl From<(float!(), &Vector2)> for Transform2D {
     /// Constructs a **Transform2D** from a given angle (in radians) and position.
     fn from(value: (float!(), &Vector2)) -> Self {
-        let cr = value.0.cos();
-        let sr = value.0.sin();
+        let cr = math::cos(value.0);
+        let sr = math::sin(value.0);
         Self::new_from_floats(cr, sr, -sr, cr, value.1.x, value.1.y)
     }
 }
@@ -375,10 +406,10 @@ impl From<(float!(), Vector2)> for Transform2D {
 impl From<(float!(), &Vector2, float!(), &Vector2)> for Transform2D {
     /// Constructs a **Transform2D** from a given angle (in radians), scale, skew (in radians), and position.
     fn from(value: (float!(), &Vector2, float!(), &Vector2)) -> Self {
-        let xx = value.0.cos() * value.1.x;
-        let yy = (value.0 + value.2).cos() * value.1.y;
-        let yx = -(value.0 + value.2).sin() * value.1.y;
-        let xy = value.0.sin() * value.1.x;
+        let xx = math::cos(value.0) * value.1.x;
+        let yy = math::cos(value.0 + value.2) * value.1.y;
+        let yx = -math::sin(value.0 + value.2) * value.1.y;
+        let xy = math::sin(value.0) * value.1.x;
         Self::new_from_floats(xx, xy, yx, yy, value.3.x, value.3.y)
     }
 }
@@ -412,8 +443,11 @@ impl PartialEq for Transform2D {
 
 impl Eq for Transform2D {}
 
-impl_op_ex_commutative!(*|a: &Transform2D, b: &Vec<Vector2>| -> Vec<Vector2> {
-    b.iter().map(|(&i)| i * a).collect()
+impl_op_ex!(*|a: &Transform2D, b: &Vec<Vector2>| -> Vec<Vector2> {
+    b.iter().map(|i| a.xform(i)).collect()
+});
+impl_op_ex!(*|a: &Vec<Vector2>, b: &Transform2D| -> Vec<Vector2> {
+    a.iter().map(|i| b.xform_inv(i)).collect()
 });
 
 impl_op_ex!(*= |a: &mut Transform2D, b: &Transform2D| {
@@ -435,15 +469,20 @@ impl_op_ex!(*|a: &Transform2D, b: &Transform2D| -> Transform2D {
     t
 });
 
-// TODO: impl Rect2 * Transform2D
+impl_op_ex!(*|a: &Transform2D, b: &Rect2| -> Rect2 { a.xform_rect(b) });
+
+impl_op_ex!(*|a: &Rect2, b: &Transform2D| -> Rect2 { b.xform_inv_rect(a) });
+
+impl_op_ex!(*= |a: &mut Rect2, b: &Transform2D| { *a = b.xform_inv_rect(a) });
 
-impl_op_ex_commutative!(*|a: &Transform2D, b: &Vector2| -> Vector2 {
+impl_op_ex!(*|a: &Transform2D, b: &Vector2| -> Vector2 {
     let mut ret = *b;
     ret.x = b.x * a.x.x + b.y * a.y.x;
     ret.y = b.x * a.x.y + b.y * a.y.y;
     ret += a.origin;
     ret
 });
+impl_op_ex!(*|a: &Vector2, b: &Transform2D| -> Vector2 { b.xform_inv(a) });
 
 impl_op_ex!(*= |a: &mut Transform2D, b: &float!()| {
     a.x *= b;
@@ -1,12 +1,16 @@
 use crate::int;
-use crate::types::vectors::Vector3;
-use crate::types::Basis;
+use crate::types::vectors::{Vector2, Vector3};
+use crate::types::{Basis, Plane, Transform2D, AABB};
 use crate::utils::float;
+use alloc::vec::Vec;
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+use core::fmt::{Display, Formatter};
 
 /// A 3×4 matrix representing a 3D transformation.
 ///
 /// **Transform3D** is a 3×4 matrix representing a transformation in 3D space. It contains a [`Basis`], which on its own can represent rotation, scale, and shear. Additionally, combined with its own `origin`, the transform can also represent a translation.
+///
+/// **Note:** As in Godot, multiplying with the transform on the right (`vector * transform`, `aabb * transform`, `plane * transform`) applies the *inverse* transform, see [`xform_inv`](Transform3D::xform_inv). `Vector3 * Transform3D` and `Vec<Vector3> * Transform3D` used to apply the forward transform; use `transform * vector` for that.
 #[derive(Copy, Clone, Debug, Default)]
 pub struct Transform3D {
     /// The [`Basis`] of this transform. It is composed by 3 axes ([`Basis::x`], [`Basis::y`], and [`Basis::z`]). Together, these represent the transform's rotation, scale, and shear.
@@ -100,7 +104,7 @@ impl Transform3D {
     /// The up axis (+Y) points as close to the `up` vector as possible while staying perpendicular to the forward axis. The resulting transform is orthonormalized. The existing rotation, scale, and skew information from the original transform is discarded. The `target` and `up` vectors cannot be zero, cannot be parallel to each other, and are defined in global/parent space.
     ///
     /// If `use_model_front` is `true`, the +Z axis (asset front) is treated as forward (implies +X is left) and points toward the `target` position. By default, the -Z axis (camera forward) is treated as forward (implies +X is right).
-    fn looking_at(&self, target: &Vector3, up: Option<&Vector3>, use_model_front: bool) -> Self {
+    pub fn looking_at(&self, target: &Vector3, up: Option<&Vector3>, use_model_front: bool) -> Self {
         let mut t = *self;
         t.basis = Basis::looking_at(&(target - self.origin), up, use_model_front);
         t
@@ -183,11 +187,106 @@ impl Transform3D {
         Self::new(self.basis, self.origin + self.basis.xform(&offset))
     }
 
+    /// Returns a copy of the `vec` vector, transformed (multiplied) by this transform. This is equivalent to `transform * vec`.
     pub fn xform(&self, vec: &Vector3) -> Vector3 {
         Vector3::new(
-            self.basis.x.dot(&vec) + self.origin.x,
-            self.basis.y.dot(&vec) + self.origin.y,
-            self.basis.z.dot(&vec) + self.origin.z,
+            self.basis.x.dot(vec) + self.origin.x,
+            self.basis.y.dot(vec) + self.origin.y,
+            self.basis.z.dot(vec) + self.origin.z,
+        )
+    }
+
+    /// Returns a copy of the `vec` vector, transformed (multiplied) by the inverse of this transform. This is equivalent to `vec * transform`.
+    ///
+    /// **Note:** This method assumes that this transform's `basis` is *orthonormal* (see [`orthonormalized`](Transform3D::orthonormalized)). If the basis is not orthonormal, `transform.affine_inverse().xform(vec)` should be used instead (see [`affine_inverse`](Transform3D::affine_inverse)).
+    pub fn xform_inv(&self, vec: &Vector3) -> Vector3 {
+        self.basis_xform_inv(&(vec - self.origin))
+    }
+
+    /// Returns a copy of the `vec` vector, transformed (multiplied) by the `basis` matrix. Unlike the multiplication operator (`*`), this method ignores the `origin`.
+    pub fn basis_xform(&self, vec: &Vector3) -> Vector3 {
+        self.basis.xform(vec)
+    }
+
+    /// Returns a copy of the `vec` vector, transformed (multiplied) by the inverse `basis` matrix (see [`inverse`](Transform3D::inverse)). This method ignores the `origin`.
+    ///
+    /// **Note:** This method assumes that this transform's `basis` is *orthonormal* (see [`orthonormalized`](Transform3D::orthonormalized)). If the basis is not orthonormal, `transform.affine_inverse().basis_xform(vec)` should be used instead (see [`affine_inverse`](Transform3D::affine_inverse)).
+    pub fn basis_xform_inv(&self, vec: &Vector3) -> Vector3 {
+        Vector3::new(
+            self.basis.t_dot_x(vec),
+            self.basis.t_dot_y(vec),
+            self.basis.t_dot_z(vec),
+        )
+    }
+
+    fn xform_plane(&self, plane: &Plane) -> Plane {
+        // Transform a single point on the plane, and use the inverse transpose of the basis for correct normals with non-uniform scaling.
+        let point = self.xform(&(plane.normal * plane.d));
+        let normal = self.basis.inverse().transposed().xform(&plane.normal).normalized();
+        Plane::new(normal, normal.dot(&point))
+    }
+
+    fn xform_inv_plane(&self, plane: &Plane) -> Plane {
+        let point = self.affine_inverse().xform(&(plane.normal * plane.d));
+        let normal = self.basis.transposed().xform(&plane.normal).normalized();
+        Plane::new(normal, normal.dot(&point))
+    }
+
+    fn xform_aabb(&self, aabb: &AABB) -> AABB {
+        // https://dev.theomader.com/transform-bounding-boxes/
+        let min = aabb.position();
+        let max = aabb.end();
+        let mut tmin = self.origin;
+        let mut tmax = self.origin;
+        for i in 0..3 {
+            let row = self.basis.get_row(i);
+            for j in 0..3 {
+                let e = row.get(j) * min.get(j);
+                let f = row.get(j) * max.get(j);
+                if e < f {
+                    tmin.set(i, tmin.get(i) + e);
+                    tmax.set(i, tmax.get(i) + f);
+                } else {
+                    tmin.set(i, tmin.get(i) + f);
+                    tmax.set(i, tmax.get(i) + e);
+                }
+            }
+        }
+        AABB::new(tmin, tmax - tmin)
+    }
+
+    fn xform_inv_aabb(&self, aabb: &AABB) -> AABB {
+        let mut ret = AABB::new(self.xform_inv(&aabb.get_endpoint(0)), Vector3::ZERO);
+        for i in 1..8 {
+            ret.expand_to(&self.xform_inv(&aabb.get_endpoint(i)));
+        }
+        ret
+    }
+}
+
+impl From<Transform2D> for Transform3D {
+    /// Constructs a **Transform3D** from a [`Transform2D`], placing it on the XY plane. The Z axis is left unchanged.
+    fn from(value: Transform2D) -> Self {
+        Self::new(
+            Basis::new(
+                Vector3::new(value.x.x, value.x.y, 0.0),
+                Vector3::new(value.y.x, value.y.y, 0.0),
+                Vector3::BACK,
+            ),
+            Vector3::new(value.origin.x, value.origin.y, 0.0),
+        )
+    }
+}
+
+impl From<Transform3D> for Transform2D {
+    /// Constructs a **Transform2D** from the XY components of a [`Transform3D`]. Everything involving the Z axis is discarded.
+    fn from(value: Transform3D) -> Self {
+        let x = value.basis.x();
+        let y = value.basis.y();
+        Self::new(
+            Vector2::new(x.x, x.y),
+            Vector2::new(y.x, y.y),
+            Vector2::new(value.origin.x, value.origin.y),
         )
     }
 }
@@ -208,18 +307,23 @@ impl_op_ex!(*|a: &Transform3D, b: &Transform3D| -> Transform3D {
     t
 });
 
-impl_op_ex_commutative!(*|a: &Transform3D, b: &Vector3| -> Vector3 {
-    let mut ret = *b;
-    ret.x = b.x * a.basis.x.x + b.y * a.basis.y.x + b.z * a.basis.z.x;
-    ret.y = b.x * a.basis.x.y + b.y * a.basis.y.y + b.z * a.basis.z.y;
-    ret.z = b.x * a.basis.x.z + b.y * a.basis.y.z + b.z * a.basis.z.z;
-    ret += a.origin;
-    ret
-});
+impl Eq for Transform3D {}
+
+impl_op_ex!(*|a: &Transform3D, b: &Vector3| -> Vector3 { a.xform(b) });
+impl_op_ex!(*|a: &Vector3, b: &Transform3D| -> Vector3 { b.xform_inv(a) });
 
-impl_op_ex_commutative!(*|a: &Transform3D, b: &Vec<Vector3>| -> Vec<Vector3> {
-    b.iter().map(|&i| i * a).collect()
+impl_op_ex!(*|a: &Transform3D, b: &Vec<Vector3>| -> Vec<Vector3> {
+    b.iter().map(|i| a.xform(i)).collect()
 });
+impl_op_ex!(*|a: &Vec<Vector3>, b: &Transform3D| -> Vec<Vector3> {
+    a.iter().map(|i| b.xform_inv(i)).collect()
+});
+
+impl_op_ex!(*|a: &Transform3D, b: &Plane| -> Plane { a.xform_plane(b) });
+impl_op_ex!(*|a: &Plane, b: &Transform3D| -> Plane { b.xform_inv_plane(a) });
+
+impl_op_ex!(*|a: &Transform3D, b: &AABB| -> AABB { a.xform_aabb(b) });
+impl_op_ex!(*|a: &AABB, b: &Transform3D| -> AABB { b.xform_inv_aabb(a) });
 
 impl_op_ex!(*= |a: &mut Transform3D, b: &float!()|{
     a.basis *= b;
@@ -256,3 +360,15 @@ impl_op_ex_commutative!(/ |a: &Transform3D, b: int!()| -> Transform3D {
     ret /= b;
     ret
 });
+
+impl Display for Transform3D {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let x = self.basis.x();
+        let y = self.basis.y();
+        let z = self.basis.z();
+        f.write_fmt(format_args!(
+            "[X: ({}, {}, {}), Y: ({}, {}, {}), Z: ({}, {}, {}), O: ({}, {}, {})]",
+            x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z, self.origin.x, self.origin.y, self.origin.z
+        ))
+    }
+}
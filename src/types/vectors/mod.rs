@@ -5,6 +5,8 @@ mod vector3i;
 mod vector4;
 mod vector4i;
 
+mod swizzle;
+mod traits;
 mod utils;
 
 pub use vector2::Vector2;
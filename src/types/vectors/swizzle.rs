@@ -0,0 +1,624 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+
+// Swizzles build a new vector from any combination of the components of another, like in GLSL: `v.zyx()`, `v.xxyy()`, `v.xz()`, and so on. The read swizzles cover every combination of 2 to 4 components, the write swizzles (`set_xz`, ...) every combination without repeated components.
+//
+// `macro_rules!` cannot build identifiers, so the names are listed explicitly, once per source dimension, and shared between the float and integer vectors.
+
+macro_rules! swizzle {
+    ($name:ident -> $out:ident($first:ident $(, $rest:ident)*)) => {
+        #[doc = concat!(
+            "Returns a new [`", stringify!($out), "`] made of the (`", stringify!($first), "`",
+            $(", `", stringify!($rest), "`",)* ") components of this vector."
+        )]
+        pub const fn $name(&self) -> $out {
+            $out::new(self.$first $(, self.$rest)*)
+        }
+    };
+}
+
+macro_rules! swizzle_set {
+    ($name:ident($in:ident: $first:ident = $first_src:ident $(, $rest:ident = $rest_src:ident)*)) => {
+        #[doc = concat!(
+            "Sets the (`", stringify!($first), "`", $(", `", stringify!($rest), "`",)*
+            ") components of this vector to the components of `value`, in order."
+        )]
+        pub fn $name(&mut self, value: &$in) {
+            self.$first = value.$first_src;
+            $(self.$rest = value.$rest_src;)*
+        }
+    };
+}
+
+macro_rules! swizzles_2 {
+    ($v2:ident, $v3:ident, $v4:ident) => {
+        swizzle!(xx -> $v2(x, x));
+        swizzle!(xy -> $v2(x, y));
+        swizzle!(yx -> $v2(y, x));
+        swizzle!(yy -> $v2(y, y));
+        swizzle!(xxx -> $v3(x, x, x));
+        swizzle!(xxy -> $v3(x, x, y));
+        swizzle!(xyx -> $v3(x, y, x));
+        swizzle!(xyy -> $v3(x, y, y));
+        swizzle!(yxx -> $v3(y, x, x));
+        swizzle!(yxy -> $v3(y, x, y));
+        swizzle!(yyx -> $v3(y, y, x));
+        swizzle!(yyy -> $v3(y, y, y));
+        swizzle!(xxxx -> $v4(x, x, x, x));
+        swizzle!(xxxy -> $v4(x, x, x, y));
+        swizzle!(xxyx -> $v4(x, x, y, x));
+        swizzle!(xxyy -> $v4(x, x, y, y));
+        swizzle!(xyxx -> $v4(x, y, x, x));
+        swizzle!(xyxy -> $v4(x, y, x, y));
+        swizzle!(xyyx -> $v4(x, y, y, x));
+        swizzle!(xyyy -> $v4(x, y, y, y));
+        swizzle!(yxxx -> $v4(y, x, x, x));
+        swizzle!(yxxy -> $v4(y, x, x, y));
+        swizzle!(yxyx -> $v4(y, x, y, x));
+        swizzle!(yxyy -> $v4(y, x, y, y));
+        swizzle!(yyxx -> $v4(y, y, x, x));
+        swizzle!(yyxy -> $v4(y, y, x, y));
+        swizzle!(yyyx -> $v4(y, y, y, x));
+        swizzle!(yyyy -> $v4(y, y, y, y));
+        swizzle_set!(set_xy($v2: x = x, y = y));
+        swizzle_set!(set_yx($v2: y = x, x = y));
+    };
+}
+
+macro_rules! swizzles_3 {
+    ($v2:ident, $v3:ident, $v4:ident) => {
+        swizzle!(xx -> $v2(x, x));
+        swizzle!(xy -> $v2(x, y));
+        swizzle!(xz -> $v2(x, z));
+        swizzle!(yx -> $v2(y, x));
+        swizzle!(yy -> $v2(y, y));
+        swizzle!(yz -> $v2(y, z));
+        swizzle!(zx -> $v2(z, x));
+        swizzle!(zy -> $v2(z, y));
+        swizzle!(zz -> $v2(z, z));
+        swizzle!(xxx -> $v3(x, x, x));
+        swizzle!(xxy -> $v3(x, x, y));
+        swizzle!(xxz -> $v3(x, x, z));
+        swizzle!(xyx -> $v3(x, y, x));
+        swizzle!(xyy -> $v3(x, y, y));
+        swizzle!(xyz -> $v3(x, y, z));
+        swizzle!(xzx -> $v3(x, z, x));
+        swizzle!(xzy -> $v3(x, z, y));
+        swizzle!(xzz -> $v3(x, z, z));
+        swizzle!(yxx -> $v3(y, x, x));
+        swizzle!(yxy -> $v3(y, x, y));
+        swizzle!(yxz -> $v3(y, x, z));
+        swizzle!(yyx -> $v3(y, y, x));
+        swizzle!(yyy -> $v3(y, y, y));
+        swizzle!(yyz -> $v3(y, y, z));
+        swizzle!(yzx -> $v3(y, z, x));
+        swizzle!(yzy -> $v3(y, z, y));
+        swizzle!(yzz -> $v3(y, z, z));
+        swizzle!(zxx -> $v3(z, x, x));
+        swizzle!(zxy -> $v3(z, x, y));
+        swizzle!(zxz -> $v3(z, x, z));
+        swizzle!(zyx -> $v3(z, y, x));
+        swizzle!(zyy -> $v3(z, y, y));
+        swizzle!(zyz -> $v3(z, y, z));
+        swizzle!(zzx -> $v3(z, z, x));
+        swizzle!(zzy -> $v3(z, z, y));
+        swizzle!(zzz -> $v3(z, z, z));
+        swizzle!(xxxx -> $v4(x, x, x, x));
+        swizzle!(xxxy -> $v4(x, x, x, y));
+        swizzle!(xxxz -> $v4(x, x, x, z));
+        swizzle!(xxyx -> $v4(x, x, y, x));
+        swizzle!(xxyy -> $v4(x, x, y, y));
+        swizzle!(xxyz -> $v4(x, x, y, z));
+        swizzle!(xxzx -> $v4(x, x, z, x));
+        swizzle!(xxzy -> $v4(x, x, z, y));
+        swizzle!(xxzz -> $v4(x, x, z, z));
+        swizzle!(xyxx -> $v4(x, y, x, x));
+        swizzle!(xyxy -> $v4(x, y, x, y));
+        swizzle!(xyxz -> $v4(x, y, x, z));
+        swizzle!(xyyx -> $v4(x, y, y, x));
+        swizzle!(xyyy -> $v4(x, y, y, y));
+        swizzle!(xyyz -> $v4(x, y, y, z));
+        swizzle!(xyzx -> $v4(x, y, z, x));
+        swizzle!(xyzy -> $v4(x, y, z, y));
+        swizzle!(xyzz -> $v4(x, y, z, z));
+        swizzle!(xzxx -> $v4(x, z, x, x));
+        swizzle!(xzxy -> $v4(x, z, x, y));
+        swizzle!(xzxz -> $v4(x, z, x, z));
+        swizzle!(xzyx -> $v4(x, z, y, x));
+        swizzle!(xzyy -> $v4(x, z, y, y));
+        swizzle!(xzyz -> $v4(x, z, y, z));
+        swizzle!(xzzx -> $v4(x, z, z, x));
+        swizzle!(xzzy -> $v4(x, z, z, y));
+        swizzle!(xzzz -> $v4(x, z, z, z));
+        swizzle!(yxxx -> $v4(y, x, x, x));
+        swizzle!(yxxy -> $v4(y, x, x, y));
+        swizzle!(yxxz -> $v4(y, x, x, z));
+        swizzle!(yxyx -> $v4(y, x, y, x));
+        swizzle!(yxyy -> $v4(y, x, y, y));
+        swizzle!(yxyz -> $v4(y, x, y, z));
+        swizzle!(yxzx -> $v4(y, x, z, x));
+        swizzle!(yxzy -> $v4(y, x, z, y));
+        swizzle!(yxzz -> $v4(y, x, z, z));
+        swizzle!(yyxx -> $v4(y, y, x, x));
+        swizzle!(yyxy -> $v4(y, y, x, y));
+        swizzle!(yyxz -> $v4(y, y, x, z));
+        swizzle!(yyyx -> $v4(y, y, y, x));
+        swizzle!(yyyy -> $v4(y, y, y, y));
+        swizzle!(yyyz -> $v4(y, y, y, z));
+        swizzle!(yyzx -> $v4(y, y, z, x));
+        swizzle!(yyzy -> $v4(y, y, z, y));
+        swizzle!(yyzz -> $v4(y, y, z, z));
+        swizzle!(yzxx -> $v4(y, z, x, x));
+        swizzle!(yzxy -> $v4(y, z, x, y));
+        swizzle!(yzxz -> $v4(y, z, x, z));
+        swizzle!(yzyx -> $v4(y, z, y, x));
+        swizzle!(yzyy -> $v4(y, z, y, y));
+        swizzle!(yzyz -> $v4(y, z, y, z));
+        swizzle!(yzzx -> $v4(y, z, z, x));
+        swizzle!(yzzy -> $v4(y, z, z, y));
+        swizzle!(yzzz -> $v4(y, z, z, z));
+        swizzle!(zxxx -> $v4(z, x, x, x));
+        swizzle!(zxxy -> $v4(z, x, x, y));
+        swizzle!(zxxz -> $v4(z, x, x, z));
+        swizzle!(zxyx -> $v4(z, x, y, x));
+        swizzle!(zxyy -> $v4(z, x, y, y));
+        swizzle!(zxyz -> $v4(z, x, y, z));
+        swizzle!(zxzx -> $v4(z, x, z, x));
+        swizzle!(zxzy -> $v4(z, x, z, y));
+        swizzle!(zxzz -> $v4(z, x, z, z));
+        swizzle!(zyxx -> $v4(z, y, x, x));
+        swizzle!(zyxy -> $v4(z, y, x, y));
+        swizzle!(zyxz -> $v4(z, y, x, z));
+        swizzle!(zyyx -> $v4(z, y, y, x));
+        swizzle!(zyyy -> $v4(z, y, y, y));
+        swizzle!(zyyz -> $v4(z, y, y, z));
+        swizzle!(zyzx -> $v4(z, y, z, x));
+        swizzle!(zyzy -> $v4(z, y, z, y));
+        swizzle!(zyzz -> $v4(z, y, z, z));
+        swizzle!(zzxx -> $v4(z, z, x, x));
+        swizzle!(zzxy -> $v4(z, z, x, y));
+        swizzle!(zzxz -> $v4(z, z, x, z));
+        swizzle!(zzyx -> $v4(z, z, y, x));
+        swizzle!(zzyy -> $v4(z, z, y, y));
+        swizzle!(zzyz -> $v4(z, z, y, z));
+        swizzle!(zzzx -> $v4(z, z, z, x));
+        swizzle!(zzzy -> $v4(z, z, z, y));
+        swizzle!(zzzz -> $v4(z, z, z, z));
+        swizzle_set!(set_xy($v2: x = x, y = y));
+        swizzle_set!(set_xz($v2: x = x, z = y));
+        swizzle_set!(set_yx($v2: y = x, x = y));
+        swizzle_set!(set_yz($v2: y = x, z = y));
+        swizzle_set!(set_zx($v2: z = x, x = y));
+        swizzle_set!(set_zy($v2: z = x, y = y));
+        swizzle_set!(set_xyz($v3: x = x, y = y, z = z));
+        swizzle_set!(set_xzy($v3: x = x, z = y, y = z));
+        swizzle_set!(set_yxz($v3: y = x, x = y, z = z));
+        swizzle_set!(set_yzx($v3: y = x, z = y, x = z));
+        swizzle_set!(set_zxy($v3: z = x, x = y, y = z));
+        swizzle_set!(set_zyx($v3: z = x, y = y, x = z));
+    };
+}
+
+macro_rules! swizzles_4 {
+    ($v2:ident, $v3:ident, $v4:ident) => {
+        swizzle!(xx -> $v2(x, x));
+        swizzle!(xy -> $v2(x, y));
+        swizzle!(xz -> $v2(x, z));
+        swizzle!(xw -> $v2(x, w));
+        swizzle!(yx -> $v2(y, x));
+        swizzle!(yy -> $v2(y, y));
+        swizzle!(yz -> $v2(y, z));
+        swizzle!(yw -> $v2(y, w));
+        swizzle!(zx -> $v2(z, x));
+        swizzle!(zy -> $v2(z, y));
+        swizzle!(zz -> $v2(z, z));
+        swizzle!(zw -> $v2(z, w));
+        swizzle!(wx -> $v2(w, x));
+        swizzle!(wy -> $v2(w, y));
+        swizzle!(wz -> $v2(w, z));
+        swizzle!(ww -> $v2(w, w));
+        swizzle!(xxx -> $v3(x, x, x));
+        swizzle!(xxy -> $v3(x, x, y));
+        swizzle!(xxz -> $v3(x, x, z));
+        swizzle!(xxw -> $v3(x, x, w));
+        swizzle!(xyx -> $v3(x, y, x));
+        swizzle!(xyy -> $v3(x, y, y));
+        swizzle!(xyz -> $v3(x, y, z));
+        swizzle!(xyw -> $v3(x, y, w));
+        swizzle!(xzx -> $v3(x, z, x));
+        swizzle!(xzy -> $v3(x, z, y));
+        swizzle!(xzz -> $v3(x, z, z));
+        swizzle!(xzw -> $v3(x, z, w));
+        swizzle!(xwx -> $v3(x, w, x));
+        swizzle!(xwy -> $v3(x, w, y));
+        swizzle!(xwz -> $v3(x, w, z));
+        swizzle!(xww -> $v3(x, w, w));
+        swizzle!(yxx -> $v3(y, x, x));
+        swizzle!(yxy -> $v3(y, x, y));
+        swizzle!(yxz -> $v3(y, x, z));
+        swizzle!(yxw -> $v3(y, x, w));
+        swizzle!(yyx -> $v3(y, y, x));
+        swizzle!(yyy -> $v3(y, y, y));
+        swizzle!(yyz -> $v3(y, y, z));
+        swizzle!(yyw -> $v3(y, y, w));
+        swizzle!(yzx -> $v3(y, z, x));
+        swizzle!(yzy -> $v3(y, z, y));
+        swizzle!(yzz -> $v3(y, z, z));
+        swizzle!(yzw -> $v3(y, z, w));
+        swizzle!(ywx -> $v3(y, w, x));
+        swizzle!(ywy -> $v3(y, w, y));
+        swizzle!(ywz -> $v3(y, w, z));
+        swizzle!(yww -> $v3(y, w, w));
+        swizzle!(zxx -> $v3(z, x, x));
+        swizzle!(zxy -> $v3(z, x, y));
+        swizzle!(zxz -> $v3(z, x, z));
+        swizzle!(zxw -> $v3(z, x, w));
+        swizzle!(zyx -> $v3(z, y, x));
+        swizzle!(zyy -> $v3(z, y, y));
+        swizzle!(zyz -> $v3(z, y, z));
+        swizzle!(zyw -> $v3(z, y, w));
+        swizzle!(zzx -> $v3(z, z, x));
+        swizzle!(zzy -> $v3(z, z, y));
+        swizzle!(zzz -> $v3(z, z, z));
+        swizzle!(zzw -> $v3(z, z, w));
+        swizzle!(zwx -> $v3(z, w, x));
+        swizzle!(zwy -> $v3(z, w, y));
+        swizzle!(zwz -> $v3(z, w, z));
+        swizzle!(zww -> $v3(z, w, w));
+        swizzle!(wxx -> $v3(w, x, x));
+        swizzle!(wxy -> $v3(w, x, y));
+        swizzle!(wxz -> $v3(w, x, z));
+        swizzle!(wxw -> $v3(w, x, w));
+        swizzle!(wyx -> $v3(w, y, x));
+        swizzle!(wyy -> $v3(w, y, y));
+        swizzle!(wyz -> $v3(w, y, z));
+        swizzle!(wyw -> $v3(w, y, w));
+        swizzle!(wzx -> $v3(w, z, x));
+        swizzle!(wzy -> $v3(w, z, y));
+        swizzle!(wzz -> $v3(w, z, z));
+        swizzle!(wzw -> $v3(w, z, w));
+        swizzle!(wwx -> $v3(w, w, x));
+        swizzle!(wwy -> $v3(w, w, y));
+        swizzle!(wwz -> $v3(w, w, z));
+        swizzle!(www -> $v3(w, w, w));
+        swizzle!(xxxx -> $v4(x, x, x, x));
+        swizzle!(xxxy -> $v4(x, x, x, y));
+        swizzle!(xxxz -> $v4(x, x, x, z));
+        swizzle!(xxxw -> $v4(x, x, x, w));
+        swizzle!(xxyx -> $v4(x, x, y, x));
+        swizzle!(xxyy -> $v4(x, x, y, y));
+        swizzle!(xxyz -> $v4(x, x, y, z));
+        swizzle!(xxyw -> $v4(x, x, y, w));
+        swizzle!(xxzx -> $v4(x, x, z, x));
+        swizzle!(xxzy -> $v4(x, x, z, y));
+        swizzle!(xxzz -> $v4(x, x, z, z));
+        swizzle!(xxzw -> $v4(x, x, z, w));
+        swizzle!(xxwx -> $v4(x, x, w, x));
+        swizzle!(xxwy -> $v4(x, x, w, y));
+        swizzle!(xxwz -> $v4(x, x, w, z));
+        swizzle!(xxww -> $v4(x, x, w, w));
+        swizzle!(xyxx -> $v4(x, y, x, x));
+        swizzle!(xyxy -> $v4(x, y, x, y));
+        swizzle!(xyxz -> $v4(x, y, x, z));
+        swizzle!(xyxw -> $v4(x, y, x, w));
+        swizzle!(xyyx -> $v4(x, y, y, x));
+        swizzle!(xyyy -> $v4(x, y, y, y));
+        swizzle!(xyyz -> $v4(x, y, y, z));
+        swizzle!(xyyw -> $v4(x, y, y, w));
+        swizzle!(xyzx -> $v4(x, y, z, x));
+        swizzle!(xyzy -> $v4(x, y, z, y));
+        swizzle!(xyzz -> $v4(x, y, z, z));
+        swizzle!(xyzw -> $v4(x, y, z, w));
+        swizzle!(xywx -> $v4(x, y, w, x));
+        swizzle!(xywy -> $v4(x, y, w, y));
+        swizzle!(xywz -> $v4(x, y, w, z));
+        swizzle!(xyww -> $v4(x, y, w, w));
+        swizzle!(xzxx -> $v4(x, z, x, x));
+        swizzle!(xzxy -> $v4(x, z, x, y));
+        swizzle!(xzxz -> $v4(x, z, x, z));
+        swizzle!(xzxw -> $v4(x, z, x, w));
+        swizzle!(xzyx -> $v4(x, z, y, x));
+        swizzle!(xzyy -> $v4(x, z, y, y));
+        swizzle!(xzyz -> $v4(x, z, y, z));
+        swizzle!(xzyw -> $v4(x, z, y, w));
+        swizzle!(xzzx -> $v4(x, z, z, x));
+        swizzle!(xzzy -> $v4(x, z, z, y));
+        swizzle!(xzzz -> $v4(x, z, z, z));
+        swizzle!(xzzw -> $v4(x, z, z, w));
+        swizzle!(xzwx -> $v4(x, z, w, x));
+        swizzle!(xzwy -> $v4(x, z, w, y));
+        swizzle!(xzwz -> $v4(x, z, w, z));
+        swizzle!(xzww -> $v4(x, z, w, w));
+        swizzle!(xwxx -> $v4(x, w, x, x));
+        swizzle!(xwxy -> $v4(x, w, x, y));
+        swizzle!(xwxz -> $v4(x, w, x, z));
+        swizzle!(xwxw -> $v4(x, w, x, w));
+        swizzle!(xwyx -> $v4(x, w, y, x));
+        swizzle!(xwyy -> $v4(x, w, y, y));
+        swizzle!(xwyz -> $v4(x, w, y, z));
+        swizzle!(xwyw -> $v4(x, w, y, w));
+        swizzle!(xwzx -> $v4(x, w, z, x));
+        swizzle!(xwzy -> $v4(x, w, z, y));
+        swizzle!(xwzz -> $v4(x, w, z, z));
+        swizzle!(xwzw -> $v4(x, w, z, w));
+        swizzle!(xwwx -> $v4(x, w, w, x));
+        swizzle!(xwwy -> $v4(x, w, w, y));
+        swizzle!(xwwz -> $v4(x, w, w, z));
+        swizzle!(xwww -> $v4(x, w, w, w));
+        swizzle!(yxxx -> $v4(y, x, x, x));
+        swizzle!(yxxy -> $v4(y, x, x, y));
+        swizzle!(yxxz -> $v4(y, x, x, z));
+        swizzle!(yxxw -> $v4(y, x, x, w));
+        swizzle!(yxyx -> $v4(y, x, y, x));
+        swizzle!(yxyy -> $v4(y, x, y, y));
+        swizzle!(yxyz -> $v4(y, x, y, z));
+        swizzle!(yxyw -> $v4(y, x, y, w));
+        swizzle!(yxzx -> $v4(y, x, z, x));
+        swizzle!(yxzy -> $v4(y, x, z, y));
+        swizzle!(yxzz -> $v4(y, x, z, z));
+        swizzle!(yxzw -> $v4(y, x, z, w));
+        swizzle!(yxwx -> $v4(y, x, w, x));
+        swizzle!(yxwy -> $v4(y, x, w, y));
+        swizzle!(yxwz -> $v4(y, x, w, z));
+        swizzle!(yxww -> $v4(y, x, w, w));
+        swizzle!(yyxx -> $v4(y, y, x, x));
+        swizzle!(yyxy -> $v4(y, y, x, y));
+        swizzle!(yyxz -> $v4(y, y, x, z));
+        swizzle!(yyxw -> $v4(y, y, x, w));
+        swizzle!(yyyx -> $v4(y, y, y, x));
+        swizzle!(yyyy -> $v4(y, y, y, y));
+        swizzle!(yyyz -> $v4(y, y, y, z));
+        swizzle!(yyyw -> $v4(y, y, y, w));
+        swizzle!(yyzx -> $v4(y, y, z, x));
+        swizzle!(yyzy -> $v4(y, y, z, y));
+        swizzle!(yyzz -> $v4(y, y, z, z));
+        swizzle!(yyzw -> $v4(y, y, z, w));
+        swizzle!(yywx -> $v4(y, y, w, x));
+        swizzle!(yywy -> $v4(y, y, w, y));
+        swizzle!(yywz -> $v4(y, y, w, z));
+        swizzle!(yyww -> $v4(y, y, w, w));
+        swizzle!(yzxx -> $v4(y, z, x, x));
+        swizzle!(yzxy -> $v4(y, z, x, y));
+        swizzle!(yzxz -> $v4(y, z, x, z));
+        swizzle!(yzxw -> $v4(y, z, x, w));
+        swizzle!(yzyx -> $v4(y, z, y, x));
+        swizzle!(yzyy -> $v4(y, z, y, y));
+        swizzle!(yzyz -> $v4(y, z, y, z));
+        swizzle!(yzyw -> $v4(y, z, y, w));
+        swizzle!(yzzx -> $v4(y, z, z, x));
+        swizzle!(yzzy -> $v4(y, z, z, y));
+        swizzle!(yzzz -> $v4(y, z, z, z));
+        swizzle!(yzzw -> $v4(y, z, z, w));
+        swizzle!(yzwx -> $v4(y, z, w, x));
+        swizzle!(yzwy -> $v4(y, z, w, y));
+        swizzle!(yzwz -> $v4(y, z, w, z));
+        swizzle!(yzww -> $v4(y, z, w, w));
+        swizzle!(ywxx -> $v4(y, w, x, x));
+        swizzle!(ywxy -> $v4(y, w, x, y));
+        swizzle!(ywxz -> $v4(y, w, x, z));
+        swizzle!(ywxw -> $v4(y, w, x, w));
+        swizzle!(ywyx -> $v4(y, w, y, x));
+        swizzle!(ywyy -> $v4(y, w, y, y));
+        swizzle!(ywyz -> $v4(y, w, y, z));
+        swizzle!(ywyw -> $v4(y, w, y, w));
+        swizzle!(ywzx -> $v4(y, w, z, x));
+        swizzle!(ywzy -> $v4(y, w, z, y));
+        swizzle!(ywzz -> $v4(y, w, z, z));
+        swizzle!(ywzw -> $v4(y, w, z, w));
+        swizzle!(ywwx -> $v4(y, w, w, x));
+        swizzle!(ywwy -> $v4(y, w, w, y));
+        swizzle!(ywwz -> $v4(y, w, w, z));
+        swizzle!(ywww -> $v4(y, w, w, w));
+        swizzle!(zxxx -> $v4(z, x, x, x));
+        swizzle!(zxxy -> $v4(z, x, x, y));
+        swizzle!(zxxz -> $v4(z, x, x, z));
+        swizzle!(zxxw -> $v4(z, x, x, w));
+        swizzle!(zxyx -> $v4(z, x, y, x));
+        swizzle!(zxyy -> $v4(z, x, y, y));
+        swizzle!(zxyz -> $v4(z, x, y, z));
+        swizzle!(zxyw -> $v4(z, x, y, w));
+        swizzle!(zxzx -> $v4(z, x, z, x));
+        swizzle!(zxzy -> $v4(z, x, z, y));
+        swizzle!(zxzz -> $v4(z, x, z, z));
+        swizzle!(zxzw -> $v4(z, x, z, w));
+        swizzle!(zxwx -> $v4(z, x, w, x));
+        swizzle!(zxwy -> $v4(z, x, w, y));
+        swizzle!(zxwz -> $v4(z, x, w, z));
+        swizzle!(zxww -> $v4(z, x, w, w));
+        swizzle!(zyxx -> $v4(z, y, x, x));
+        swizzle!(zyxy -> $v4(z, y, x, y));
+        swizzle!(zyxz -> $v4(z, y, x, z));
+        swizzle!(zyxw -> $v4(z, y, x, w));
+        swizzle!(zyyx -> $v4(z, y, y, x));
+        swizzle!(zyyy -> $v4(z, y, y, y));
+        swizzle!(zyyz -> $v4(z, y, y, z));
+        swizzle!(zyyw -> $v4(z, y, y, w));
+        swizzle!(zyzx -> $v4(z, y, z, x));
+        swizzle!(zyzy -> $v4(z, y, z, y));
+        swizzle!(zyzz -> $v4(z, y, z, z));
+        swizzle!(zyzw -> $v4(z, y, z, w));
+        swizzle!(zywx -> $v4(z, y, w, x));
+        swizzle!(zywy -> $v4(z, y, w, y));
+        swizzle!(zywz -> $v4(z, y, w, z));
+        swizzle!(zyww -> $v4(z, y, w, w));
+        swizzle!(zzxx -> $v4(z, z, x, x));
+        swizzle!(zzxy -> $v4(z, z, x, y));
+        swizzle!(zzxz -> $v4(z, z, x, z));
+        swizzle!(zzxw -> $v4(z, z, x, w));
+        swizzle!(zzyx -> $v4(z, z, y, x));
+        swizzle!(zzyy -> $v4(z, z, y, y));
+        swizzle!(zzyz -> $v4(z, z, y, z));
+        swizzle!(zzyw -> $v4(z, z, y, w));
+        swizzle!(zzzx -> $v4(z, z, z, x));
+        swizzle!(zzzy -> $v4(z, z, z, y));
+        swizzle!(zzzz -> $v4(z, z, z, z));
+        swizzle!(zzzw -> $v4(z, z, z, w));
+        swizzle!(zzwx -> $v4(z, z, w, x));
+        swizzle!(zzwy -> $v4(z, z, w, y));
+        swizzle!(zzwz -> $v4(z, z, w, z));
+        swizzle!(zzww -> $v4(z, z, w, w));
+        swizzle!(zwxx -> $v4(z, w, x, x));
+        swizzle!(zwxy -> $v4(z, w, x, y));
+        swizzle!(zwxz -> $v4(z, w, x, z));
+        swizzle!(zwxw -> $v4(z, w, x, w));
+        swizzle!(zwyx -> $v4(z, w, y, x));
+        swizzle!(zwyy -> $v4(z, w, y, y));
+        swizzle!(zwyz -> $v4(z, w, y, z));
+        swizzle!(zwyw -> $v4(z, w, y, w));
+        swizzle!(zwzx -> $v4(z, w, z, x));
+        swizzle!(zwzy -> $v4(z, w, z, y));
+        swizzle!(zwzz -> $v4(z, w, z, z));
+        swizzle!(zwzw -> $v4(z, w, z, w));
+        swizzle!(zwwx -> $v4(z, w, w, x));
+        swizzle!(zwwy -> $v4(z, w, w, y));
+        swizzle!(zwwz -> $v4(z, w, w, z));
+        swizzle!(zwww -> $v4(z, w, w, w));
+        swizzle!(wxxx -> $v4(w, x, x, x));
+        swizzle!(wxxy -> $v4(w, x, x, y));
+        swizzle!(wxxz -> $v4(w, x, x, z));
+        swizzle!(wxxw -> $v4(w, x, x, w));
+        swizzle!(wxyx -> $v4(w, x, y, x));
+        swizzle!(wxyy -> $v4(w, x, y, y));
+        swizzle!(wxyz -> $v4(w, x, y, z));
+        swizzle!(wxyw -> $v4(w, x, y, w));
+        swizzle!(wxzx -> $v4(w, x, z, x));
+        swizzle!(wxzy -> $v4(w, x, z, y));
+        swizzle!(wxzz -> $v4(w, x, z, z));
+        swizzle!(wxzw -> $v4(w, x, z, w));
+        swizzle!(wxwx -> $v4(w, x, w, x));
+        swizzle!(wxwy -> $v4(w, x, w, y));
+        swizzle!(wxwz -> $v4(w, x, w, z));
+        swizzle!(wxww -> $v4(w, x, w, w));
+        swizzle!(wyxx -> $v4(w, y, x, x));
+        swizzle!(wyxy -> $v4(w, y, x, y));
+        swizzle!(wyxz -> $v4(w, y, x, z));
+        swizzle!(wyxw -> $v4(w, y, x, w));
+        swizzle!(wyyx -> $v4(w, y, y, x));
+        swizzle!(wyyy -> $v4(w, y, y, y));
+        swizzle!(wyyz -> $v4(w, y, y, z));
+        swizzle!(wyyw -> $v4(w, y, y, w));
+        swizzle!(wyzx -> $v4(w, y, z, x));
+        swizzle!(wyzy -> $v4(w, y, z, y));
+        swizzle!(wyzz -> $v4(w, y, z, z));
+        swizzle!(wyzw -> $v4(w, y, z, w));
+        swizzle!(wywx -> $v4(w, y, w, x));
+        swizzle!(wywy -> $v4(w, y, w, y));
+        swizzle!(wywz -> $v4(w, y, w, z));
+        swizzle!(wyww -> $v4(w, y, w, w));
+        swizzle!(wzxx -> $v4(w, z, x, x));
+        swizzle!(wzxy -> $v4(w, z, x, y));
+        swizzle!(wzxz -> $v4(w, z, x, z));
+        swizzle!(wzxw -> $v4(w, z, x, w));
+        swizzle!(wzyx -> $v4(w, z, y, x));
+        swizzle!(wzyy -> $v4(w, z, y, y));
+        swizzle!(wzyz -> $v4(w, z, y, z));
+        swizzle!(wzyw -> $v4(w, z, y, w));
+        swizzle!(wzzx -> $v4(w, z, z, x));
+        swizzle!(wzzy -> $v4(w, z, z, y));
+        swizzle!(wzzz -> $v4(w, z, z, z));
+        swizzle!(wzzw -> $v4(w, z, z, w));
+        swizzle!(wzwx -> $v4(w, z, w, x));
+        swizzle!(wzwy -> $v4(w, z, w, y));
+        swizzle!(wzwz -> $v4(w, z, w, z));
+        swizzle!(wzww -> $v4(w, z, w, w));
+        swizzle!(wwxx -> $v4(w, w, x, x));
+        swizzle!(wwxy -> $v4(w, w, x, y));
+        swizzle!(wwxz -> $v4(w, w, x, z));
+        swizzle!(wwxw -> $v4(w, w, x, w));
+        swizzle!(wwyx -> $v4(w, w, y, x));
+        swizzle!(wwyy -> $v4(w, w, y, y));
+        swizzle!(wwyz -> $v4(w, w, y, z));
+        swizzle!(wwyw -> $v4(w, w, y, w));
+        swizzle!(wwzx -> $v4(w, w, z, x));
+        swizzle!(wwzy -> $v4(w, w, z, y));
+        swizzle!(wwzz -> $v4(w, w, z, z));
+        swizzle!(wwzw -> $v4(w, w, z, w));
+        swizzle!(wwwx -> $v4(w, w, w, x));
+        swizzle!(wwwy -> $v4(w, w, w, y));
+        swizzle!(wwwz -> $v4(w, w, w, z));
+        swizzle!(wwww -> $v4(w, w, w, w));
+        swizzle_set!(set_xy($v2: x = x, y = y));
+        swizzle_set!(set_xz($v2: x = x, z = y));
+        swizzle_set!(set_xw($v2: x = x, w = y));
+        swizzle_set!(set_yx($v2: y = x, x = y));
+        swizzle_set!(set_yz($v2: y = x, z = y));
+        swizzle_set!(set_yw($v2: y = x, w = y));
+        swizzle_set!(set_zx($v2: z = x, x = y));
+        swizzle_set!(set_zy($v2: z = x, y = y));
+        swizzle_set!(set_zw($v2: z = x, w = y));
+        swizzle_set!(set_wx($v2: w = x, x = y));
+        swizzle_set!(set_wy($v2: w = x, y = y));
+        swizzle_set!(set_wz($v2: w = x, z = y));
+        swizzle_set!(set_xyz($v3: x = x, y = y, z = z));
+        swizzle_set!(set_xyw($v3: x = x, y = y, w = z));
+        swizzle_set!(set_xzy($v3: x = x, z = y, y = z));
+        swizzle_set!(set_xzw($v3: x = x, z = y, w = z));
+        swizzle_set!(set_xwy($v3: x = x, w = y, y = z));
+        swizzle_set!(set_xwz($v3: x = x, w = y, z = z));
+        swizzle_set!(set_yxz($v3: y = x, x = y, z = z));
+        swizzle_set!(set_yxw($v3: y = x, x = y, w = z));
+        swizzle_set!(set_yzx($v3: y = x, z = y, x = z));
+        swizzle_set!(set_yzw($v3: y = x, z = y, w = z));
+        swizzle_set!(set_ywx($v3: y = x, w = y, x = z));
+        swizzle_set!(set_ywz($v3: y = x, w = y, z = z));
+        swizzle_set!(set_zxy($v3: z = x, x = y, y = z));
+        swizzle_set!(set_zxw($v3: z = x, x = y, w = z));
+        swizzle_set!(set_zyx($v3: z = x, y = y, x = z));
+        swizzle_set!(set_zyw($v3: z = x, y = y, w = z));
+        swizzle_set!(set_zwx($v3: z = x, w = y, x = z));
+        swizzle_set!(set_zwy($v3: z = x, w = y, y = z));
+        swizzle_set!(set_wxy($v3: w = x, x = y, y = z));
+        swizzle_set!(set_wxz($v3: w = x, x = y, z = z));
+        swizzle_set!(set_wyx($v3: w = x, y = y, x = z));
+        swizzle_set!(set_wyz($v3: w = x, y = y, z = z));
+        swizzle_set!(set_wzx($v3: w = x, z = y, x = z));
+        swizzle_set!(set_wzy($v3: w = x, z = y, y = z));
+        swizzle_set!(set_xyzw($v4: x = x, y = y, z = z, w = w));
+        swizzle_set!(set_xywz($v4: x = x, y = y, w = z, z = w));
+        swizzle_set!(set_xzyw($v4: x = x, z = y, y = z, w = w));
+        swizzle_set!(set_xzwy($v4: x = x, z = y, w = z, y = w));
+        swizzle_set!(set_xwyz($v4: x = x, w = y, y = z, z = w));
+        swizzle_set!(set_xwzy($v4: x = x, w = y, z = z, y = w));
+        swizzle_set!(set_yxzw($v4: y = x, x = y, z = z, w = w));
+        swizzle_set!(set_yxwz($v4: y = x, x = y, w = z, z = w));
+        swizzle_set!(set_yzxw($v4: y = x, z = y, x = z, w = w));
+        swizzle_set!(set_yzwx($v4: y = x, z = y, w = z, x = w));
+        swizzle_set!(set_ywxz($v4: y = x, w = y, x = z, z = w));
+        swizzle_set!(set_ywzx($v4: y = x, w = y, z = z, x = w));
+        swizzle_set!(set_zxyw($v4: z = x, x = y, y = z, w = w));
+        swizzle_set!(set_zxwy($v4: z = x, x = y, w = z, y = w));
+        swizzle_set!(set_zyxw($v4: z = x, y = y, x = z, w = w));
+        swizzle_set!(set_zywx($v4: z = x, y = y, w = z, x = w));
+        swizzle_set!(set_zwxy($v4: z = x, w = y, x = z, y = w));
+        swizzle_set!(set_zwyx($v4: z = x, w = y, y = z, x = w));
+        swizzle_set!(set_wxyz($v4: w = x, x = y, y = z, z = w));
+        swizzle_set!(set_wxzy($v4: w = x, x = y, z = z, y = w));
+        swizzle_set!(set_wyxz($v4: w = x, y = y, x = z, z = w));
+        swizzle_set!(set_wyzx($v4: w = x, y = y, z = z, x = w));
+        swizzle_set!(set_wzxy($v4: w = x, z = y, x = z, y = w));
+        swizzle_set!(set_wzyx($v4: w = x, z = y, y = z, x = w));
+    };
+}
+
+impl Vector2 {
+    swizzles_2!(Vector2, Vector3, Vector4);
+}
+
+impl Vector2i {
+    swizzles_2!(Vector2i, Vector3i, Vector4i);
+}
+
+impl Vector3 {
+    swizzles_3!(Vector2, Vector3, Vector4);
+}
+
+impl Vector3i {
+    swizzles_3!(Vector2i, Vector3i, Vector4i);
+}
+
+impl Vector4 {
+    swizzles_4!(Vector2, Vector3, Vector4);
+}
+
+impl Vector4i {
+    swizzles_4!(Vector2i, Vector3i, Vector4i);
+}
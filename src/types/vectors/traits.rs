@@ -0,0 +1,155 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::utils::{float, int};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::ops::{Index, IndexMut};
+
+// Standard library traits shared by every vector type: indexing, conversions to and from arrays and tuples, iteration and folding.
+//
+// All the vectors are `#[repr(C)]` with their components declared in index order, so they have the same layout as the matching array, which `AsRef` and `AsMut` rely on.
+
+macro_rules! impl_vector_traits {
+    ($vector:ident, $scalar:ty, $tuple:ty, $n:literal, $($component:ident => $index:literal),+) => {
+        /// Accesses the components by their index position, the same way as `get()`.
+        ///
+        /// **Panics** if `index` is out of range.
+        impl Index<usize> for $vector {
+            type Output = $scalar;
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    $($index => &self.$component,)+
+                    _ => panic!("Invalid index"),
+                }
+            }
+        }
+
+        /// Accesses the components by their index position, the same way as `set()`.
+        ///
+        /// **Panics** if `index` is out of range.
+        impl IndexMut<usize> for $vector {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    $($index => &mut self.$component,)+
+                    _ => panic!("Invalid index"),
+                }
+            }
+        }
+
+        impl From<[$scalar; $n]> for $vector {
+            fn from(value: [$scalar; $n]) -> Self {
+                let [$($component),+] = value;
+                Self::new($($component),+)
+            }
+        }
+
+        impl From<$vector> for [$scalar; $n] {
+            fn from(value: $vector) -> Self {
+                [$(value.$component),+]
+            }
+        }
+
+        impl From<$tuple> for $vector {
+            fn from(value: $tuple) -> Self {
+                let ($($component),+) = value;
+                Self::new($($component),+)
+            }
+        }
+
+        impl From<$vector> for $tuple {
+            fn from(value: $vector) -> Self {
+                ($(value.$component),+)
+            }
+        }
+
+        impl AsRef<[$scalar; $n]> for $vector {
+            fn as_ref(&self) -> &[$scalar; $n] {
+                // SAFETY: the vector is `#[repr(C)]` and made of exactly `$n` scalars, in index order.
+                unsafe { &*(self as *const Self as *const [$scalar; $n]) }
+            }
+        }
+
+        impl AsMut<[$scalar; $n]> for $vector {
+            fn as_mut(&mut self) -> &mut [$scalar; $n] {
+                // SAFETY: the vector is `#[repr(C)]` and made of exactly `$n` scalars, in index order.
+                unsafe { &mut *(self as *mut Self as *mut [$scalar; $n]) }
+            }
+        }
+
+        impl IntoIterator for $vector {
+            type Item = $scalar;
+            type IntoIter = core::array::IntoIter<$scalar, $n>;
+            fn into_iter(self) -> Self::IntoIter {
+                <[$scalar; $n]>::from(self).into_iter()
+            }
+        }
+
+        /// Collects the components in index order.
+        ///
+        /// **Panics** if the iterator yields fewer components than the vector has. Any extra items are left unconsumed.
+        impl FromIterator<$scalar> for $vector {
+            fn from_iter<I: IntoIterator<Item = $scalar>>(iter: I) -> Self {
+                let mut iter = iter.into_iter();
+                Self {
+                    $($component: iter
+                        .next()
+                        .expect(concat!("Missing the ", stringify!($component), " component")),)+
+                }
+            }
+        }
+
+        /// Adds the vectors component-wise, starting from `ZERO`.
+        impl Sum for $vector {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a + b)
+            }
+        }
+
+        impl<'a> Sum<&'a $vector> for $vector {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a + b)
+            }
+        }
+
+        /// Multiplies the vectors component-wise, starting from `ONE`.
+        impl Product for $vector {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |a, b| a * b)
+            }
+        }
+
+        impl<'a> Product<&'a $vector> for $vector {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |a, b| a * b)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vectori_traits {
+    ($vector:ident, $($component:ident),+) => {
+        impl Hash for $vector {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $(self.$component.hash(state);)+
+            }
+        }
+
+        /// Compares the components in index order, like the `<` and `>` operators.
+        impl Ord for $vector {
+            fn cmp(&self, other: &Self) -> Ordering {
+                Ordering::Equal$(.then(self.$component.cmp(&other.$component)))+
+            }
+        }
+    };
+}
+
+impl_vector_traits!(Vector2, float!(), (float!(), float!()), 2, x => 0, y => 1);
+impl_vector_traits!(Vector3, float!(), (float!(), float!(), float!()), 3, x => 0, y => 1, z => 2);
+impl_vector_traits!(Vector4, float!(), (float!(), float!(), float!(), float!()), 4, x => 0, y => 1, z => 2, w => 3);
+impl_vector_traits!(Vector2i, int!(), (int!(), int!()), 2, x => 0, y => 1);
+impl_vector_traits!(Vector3i, int!(), (int!(), int!(), int!()), 3, x => 0, y => 1, z => 2);
+impl_vector_traits!(Vector4i, int!(), (int!(), int!(), int!(), int!()), 4, x => 0, y => 1, z => 2, w => 3);
+
+impl_vectori_traits!(Vector2i, x, y);
+impl_vectori_traits!(Vector3i, x, y, z);
+impl_vectori_traits!(Vector4i, x, y, z, w);
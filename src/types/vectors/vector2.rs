@@ -1,3 +1,4 @@
+use crate::math;
 use crate::types::vectors::vector2i::Vector2i;
 use crate::types::vectors::AXIS;
 use crate::utils::{
@@ -6,9 +7,11 @@ use crate::utils::{
     FloatExt, CMP_EPSILON, UNIT_EPSILON,
 };
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::ops::{Neg, Not};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 /// A 2D vector using floating-point coordinates.
 ///
@@ -20,6 +23,7 @@ use std::ops::{Neg, Not};
 ///
 /// **Note:** In a boolean context, a Vector2 will evaluate to `false` if it's equal to `Vector2::(0.0, 0.0)`. Otherwise, a Vector2 will always evaluate to `true`.
 #[derive(Copy, Clone, Default, Debug)]
+#[repr(C)]
 pub struct Vector2 {
     /// The vector's X component. Also, accessible by using the index position `vec.get(0)`.
     pub x: float!(),
@@ -80,14 +84,14 @@ impl Vector2 {
     ///
     /// Equivalent to the result of `atan2` when called with the vector's `y` and `x` as parameters: `y.atan2(x)`.
     pub fn angle(&self) -> float!() {
-        self.y.atan2(self.x)
+        math::atan2(self.y, self.x)
     }
 
     /// Returns the angle to the given vector, in radians.
     ///
     /// [Illustration of the returned angle](https://raw.githubusercontent.com/godotengine/godot-docs/master/img/vector2_angle_to.png).
     pub fn angle_to(&self, to: &Self) -> float!() {
-        self.cross(to).atan2(self.dot(to))
+        math::atan2(self.cross(to), self.dot(to))
     }
 
     /// Returns the angle between the line connecting the two points and the X axis, in radians.
@@ -223,7 +227,7 @@ impl Vector2 {
 
     /// Returns the distance between this vector and `to`.
     pub fn distance_to(&self, to: &Self) -> float!() {
-        self.distance_squared_to(to).sqrt()
+        math::sqrt(self.distance_squared_to(to))
     }
 
     /// Returns the dot product of this vector and `with`. This can be used to compare the angle between two vectors. For example, this can be used to determine whether an enemy is facing the player.
@@ -254,7 +258,7 @@ impl Vector2 {
     /// println!("{:?}", Vector2::from_angle(PI / 2.0)); // Prints (0, 1).
     /// ```
     pub fn from_angle(angle: float!()) -> Self {
-        Self::new(angle.cos(), angle.sin())
+        Self::new(math::cos(angle), math::sin(angle))
     }
 
     /// Access vector components using their `index`. `v.get(0)` is equivalent to `v.x`, and `v.get(1)` is equivalent to `v.y`.
@@ -314,7 +318,7 @@ impl Vector2 {
 
     /// Returns the length (magnitude) of this vector.
     pub fn length(&self) -> float!() {
-        (self.x * self.x + self.y * self.y).sqrt()
+        math::sqrt(self.x * self.x + self.y * self.y)
     }
 
     /// Returns the squared length (squared magnitude) of this vector.
@@ -408,7 +412,7 @@ impl Vector2 {
     fn normalize(&mut self) {
         let mut l = self.length_squared();
         if l != 0.0 {
-            l = l.sqrt();
+            l = math::sqrt(l);
             self.x /= l;
             self.y /= l;
         }
@@ -445,8 +449,8 @@ impl Vector2 {
 
     /// Returns the result of rotating this vector by `angle` (in radians).
     pub fn rotated(&self, angle: float!()) -> Self {
-        let sine = angle.sin();
-        let cosine = angle.cos();
+        let sine = math::sin(angle);
+        let cosine = math::cos(angle);
         Self::new(
             self.x * cosine - self.y * sine,
             self.x * sine + self.y * cosine,
@@ -473,8 +477,8 @@ impl Vector2 {
             // Zero length vectors have no angle, so the best we can do is either lerp or throw an error.
             return self.lerp(to, weight);
         }
-        let start_length = start_length_sq.sqrt();
-        let result_length = start_length.lerp(end_length_sq.sqrt(), weight);
+        let start_length = math::sqrt(start_length_sq);
+        let result_length = start_length.lerp(math::sqrt(end_length_sq), weight);
         let angle = self.angle_to(to);
         self.rotated(angle * weight) * (result_length / start_length)
     }
@@ -607,7 +611,7 @@ impl Neg for Vector2 {
 }
 
 impl Display for Vector2 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Vector2({}, {})", self.x, self.y)
     }
 }
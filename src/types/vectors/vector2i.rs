@@ -1,9 +1,14 @@
+use crate::math;
+use crate::types::grid::{CircleCells, Connectivity2D, DiskCells, Line, Neighbors2D, SupercoverLine};
+use crate::types::Rect2i;
 use crate::types::vectors::{Vector2, AXIS};
-use crate::utils::{float, int, snapped_i};
+use crate::utils::{float, float_consts, int, snapped_i, wide_int};
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
-use std::ops::{Neg, Not};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 /// A 2D vector using integer coordinates.
 ///
@@ -13,6 +18,7 @@ use std::ops::{Neg, Not};
 ///
 /// **Note:** In a boolean context, a Vector2i will evaluate to `false` if it's equal to `Vector2i::new(0, 0)`. Otherwise, a Vector2i will always evaluate to `true`.
 #[derive(Copy, Clone, Default, Debug)]
+#[repr(C)]
 pub struct Vector2i {
     /// The vector's X component. Also, accessible by using the index position `vec.get(0)`
     pub x: int!(),
@@ -69,8 +75,35 @@ impl Vector2i {
         self.x as float!() / self.y as float!()
     }
 
+    /// Returns the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance) between this vector and `to`, the number of steps between them when moving to any of the 8 neighbors of a cell, as a wider integer that can't overflow.
+    pub fn chebyshev_distance_to(&self, to: &Self) -> wide_int!() {
+        let x = (to.x as wide_int!() - self.x as wide_int!()).abs();
+        let y = (to.y as wide_int!() - self.y as wide_int!()).abs();
+        x.max(y)
+    }
+
+    /// Returns the component-wise sum of this vector and `with`, or `None` if any component overflows, by running `checked_add` on each component.
+    pub fn checked_add(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(self.x.checked_add(with.x)?, self.y.checked_add(with.y)?))
+    }
+
+    /// Returns the component-wise quotient of this vector and `with`, or `None` if any component overflows or any component of `with` is `0`, by running `checked_div` on each component.
+    pub fn checked_div(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(self.x.checked_div(with.x)?, self.y.checked_div(with.y)?))
+    }
+
+    /// Returns the component-wise product of this vector and `with`, or `None` if any component overflows, by running `checked_mul` on each component.
+    pub fn checked_mul(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(self.x.checked_mul(with.x)?, self.y.checked_mul(with.y)?))
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, or `None` if any component overflows, by running `checked_sub` on each component.
+    pub fn checked_sub(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(self.x.checked_sub(with.x)?, self.y.checked_sub(with.y)?))
+    }
+
     /// Returns a new vector with all components clamped between the components of `min` and `max`, by running `clamp` on each component.
-    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+    pub fn clamp(self, min: &Self, max: &Self) -> Self {
         Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
     }
 
@@ -79,32 +112,71 @@ impl Vector2i {
         Self::new(self.x.clamp(min, max), self.y.clamp(min, max))
     }
 
-    /// Returns the squared distance between this vector and `to`.
+    /// Returns an iterator over the cells of the circle outline centered on this vector with the given `radius`, rasterized with the midpoint circle algorithm. A negative `radius` yields nothing. See also [`Vector2i::disk_cells`].
+    pub fn circle_cells(&self, radius: int!()) -> CircleCells {
+        CircleCells::new(*self, radius)
+    }
+
+    /// Returns an iterator over the cells of the filled disk centered on this vector with the given `radius`, in row-major order. A negative `radius` yields nothing. See also [`Vector2i::circle_cells`].
+    pub fn disk_cells(&self, radius: int!()) -> DiskCells {
+        DiskCells::new(*self, radius)
+    }
+
+    /// Returns the squared distance between this vector and `to`, as a wider integer that can't overflow for any pair of vectors short of saturating at its maximum.
     ///
     /// This method runs faster than [`Vector2i::distance_to`], so prefer it if you need to compare vectors or need the squared distance for some formula.
-    pub fn distance_squared_to(&self, to: &Self) -> int!() {
-        (to - self).length_squared()
+    pub fn distance_squared_to(&self, to: &Self) -> wide_int!() {
+        let x = to.x as wide_int!() - self.x as wide_int!();
+        let y = to.y as wide_int!() - self.y as wide_int!();
+        x.saturating_mul(x).saturating_add(y.saturating_mul(y))
     }
 
-    /// Returns the distance between this vector and to.
+    /// Returns the distance between this vector and `to`.
     pub fn distance_to(&self, to: &Self) -> float!() {
-        (to - self).length()
+        math::sqrt(self.distance_squared_to(to) as float!())
+    }
+
+    /// Returns the component-wise Euclidean quotient of this vector and `with`, rounding towards negative infinity for positive divisors, by running `div_euclid` on each component. This is the cell of a grid of size `with` containing this vector.
+    ///
+    /// **Note:** Panics if any component of `with` is `0`.
+    pub fn div_euclid(&self, with: &Self) -> Self {
+        Self::new(self.x.div_euclid(with.x), self.y.div_euclid(with.y))
     }
 
     /// Returns the length (magnitude) of this vector.
     pub fn length(&self) -> float!() {
-        (self.length_squared() as float!()).sqrt()
+        math::sqrt(self.length_squared() as float!())
     }
 
-    /// Returns the squared length (squared magnitude) of this vector.
+    /// Returns the squared length (squared magnitude) of this vector, as a wider integer that can't overflow short of saturating at its maximum.
     ///
     /// This method runs faster than [`Vector2i::length`], so prefer it if you need to compare vectors or need the squared distance for some formula.
-    pub const fn length_squared(&self) -> int!() {
-        self.x * self.x + self.y * self.y
+    pub const fn length_squared(&self) -> wide_int!() {
+        let x = self.x as wide_int!();
+        let y = self.y as wide_int!();
+        (x * x).saturating_add(y * y)
+    }
+
+    /// Returns an iterator over the cells of the [Bresenham line](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm) from this vector to `to`, both included. Consecutive cells may touch diagonally. See also [`Vector2i::supercover_line_to`].
+    ///
+    /// ```
+    /// # use huginn::types::vectors::Vector2i;
+    /// let line: Vec<_> = Vector2i::new(0, 0).line_to(&Vector2i::new(3, 1)).collect();
+    /// assert_eq!(line, [Vector2i::new(0, 0), Vector2i::new(1, 0), Vector2i::new(2, 1), Vector2i::new(3, 1)]);
+    /// ```
+    pub fn line_to(&self, to: &Self) -> Line {
+        Line::new(*self, *to)
+    }
+
+    /// Returns the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between this vector and `to`, the number of steps between them when moving to any of the 4 neighbors of a cell, as a wider integer that can't overflow.
+    pub fn manhattan_distance_to(&self, to: &Self) -> wide_int!() {
+        let x = (to.x as wide_int!() - self.x as wide_int!()).abs();
+        let y = (to.y as wide_int!() - self.y as wide_int!()).abs();
+        x + y
     }
 
     /// Returns the component-wise maximum of this and `with`, equivalent to `Vector2i::new(x.max(with.x), y.max(with.y))`.
-    pub fn max(&self, with: &Self) -> Self {
+    pub fn max(self, with: &Self) -> Self {
         Self::new(self.x.max(with.x), self.y.max(with.y))
     }
 
@@ -123,7 +195,7 @@ impl Vector2i {
     }
 
     /// Returns the component-wise minimum of this and `with`, equivalent to `Vector2i::new(x.min(with.x), y.min(with.y))`.
-    pub fn min(&self, with: &Self) -> Self {
+    pub fn min(self, with: &Self) -> Self {
         Self::new(self.x.min(with.x), self.y.min(with.y))
     }
 
@@ -141,11 +213,62 @@ impl Vector2i {
         Self::new(self.x.min(with), self.y.min(with))
     }
 
+    /// Returns an iterator over the neighbors of this cell with the given `connectivity`, in the order of [`Connectivity2D::offsets`]. See also [`Vector2i::neighbors_in`].
+    ///
+    /// ```
+    /// # use huginn::types::grid::Connectivity2D;
+    /// # use huginn::types::vectors::Vector2i;
+    /// let neighbors: Vec<_> = Vector2i::new(0, 0).neighbors(Connectivity2D::Four).collect();
+    /// assert_eq!(neighbors, [Vector2i::RIGHT, Vector2i::DOWN, Vector2i::LEFT, Vector2i::UP]);
+    /// ```
+    pub fn neighbors(&self, connectivity: Connectivity2D) -> Neighbors2D {
+        Neighbors2D::new(*self, connectivity, None)
+    }
+
+    /// Returns an iterator over the neighbors of this cell with the given `connectivity` that lie inside `bounds`, in the order of [`Connectivity2D::offsets`]. See also [`Vector2i::neighbors`].
+    pub fn neighbors_in(&self, connectivity: Connectivity2D, bounds: &Rect2i) -> Neighbors2D {
+        Neighbors2D::new(*self, connectivity, Some(*bounds))
+    }
+
+    /// Returns the octile distance between this vector and `to`, the length of the shortest path between them when moving to any of the 8 neighbors of a cell, with diagonal steps costing `sqrt(2)`.
+    pub fn octile_distance_to(&self, to: &Self) -> float!() {
+        let x = (to.x as wide_int!() - self.x as wide_int!()).abs();
+        let y = (to.y as wide_int!() - self.y as wide_int!()).abs();
+        x.max(y) as float!() + (float_consts::SQRT_2 - 1.0) * x.min(y) as float!()
+    }
+
+    /// Returns the component-wise Euclidean remainder of this vector and `with`, which is never negative, by running `rem_euclid` on each component. This is the position of this vector inside its cell of a grid of size `with`.
+    ///
+    /// **Note:** Panics if any component of `with` is `0`.
+    pub fn rem_euclid(&self, with: &Self) -> Self {
+        Self::new(self.x.rem_euclid(with.x), self.y.rem_euclid(with.y))
+    }
+
+    /// Returns the component-wise sum of this vector and `with`, clamping overflowing components to [`Vector2i::MIN`] or [`Vector2i::MAX`], by running `saturating_add` on each component.
+    pub fn saturating_add(&self, with: &Self) -> Self {
+        Self::new(self.x.saturating_add(with.x), self.y.saturating_add(with.y))
+    }
+
+    /// Returns the component-wise product of this vector and `with`, clamping overflowing components to [`Vector2i::MIN`] or [`Vector2i::MAX`], by running `saturating_mul` on each component.
+    pub fn saturating_mul(&self, with: &Self) -> Self {
+        Self::new(self.x.saturating_mul(with.x), self.y.saturating_mul(with.y))
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, clamping overflowing components to [`Vector2i::MIN`] or [`Vector2i::MAX`], by running `saturating_sub` on each component.
+    pub fn saturating_sub(&self, with: &Self) -> Self {
+        Self::new(self.x.saturating_sub(with.x), self.y.saturating_sub(with.y))
+    }
+
     /// Returns a new vector with each component set to `1` if it's positive, `-1` if it's negative, and `0` if it's zero. The result is identical to calling `signum` on each component.
     pub const fn sign(&self) -> Self {
         Self::new(self.x.signum(), self.y.signum())
     }
 
+    /// Returns an iterator over every cell crossed by the segment from the center of this cell to the center of `to`, both included. Unlike [`Vector2i::line_to`], every cell shares an edge with a cell before it, so nothing can slip through the line diagonally. See [`SupercoverLine`] for the order of the cells around a crossed corner.
+    pub fn supercover_line_to(&self, to: &Self) -> SupercoverLine {
+        SupercoverLine::new(*self, *to)
+    }
+
     /// Returns a new vector with each component snapped to the closest multiple of the corresponding component in `step`.
     pub fn snapped(&self, step: &Self) -> Self {
         Self::new(snapped_i(self.x, step.x), snapped_i(self.y, step.y))
@@ -156,6 +279,21 @@ impl Vector2i {
         Self::new(snapped_i(self.x, step), snapped_i(self.y, step))
     }
 
+    /// Returns the component-wise sum of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_add` on each component.
+    pub fn wrapping_add(&self, with: &Self) -> Self {
+        Self::new(self.x.wrapping_add(with.x), self.y.wrapping_add(with.y))
+    }
+
+    /// Returns the component-wise product of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_mul` on each component.
+    pub fn wrapping_mul(&self, with: &Self) -> Self {
+        Self::new(self.x.wrapping_mul(with.x), self.y.wrapping_mul(with.y))
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_sub` on each component.
+    pub fn wrapping_sub(&self, with: &Self) -> Self {
+        Self::new(self.x.wrapping_sub(with.x), self.y.wrapping_sub(with.y))
+    }
+
     pub fn set(&mut self, index: usize, value: int!()) {
         match index {
             0 => self.x = value,
@@ -242,6 +380,96 @@ impl_op_ex!(-= |a: &mut Vector2i, b: &int!()| { a.x = a.x - b; a.y = a.y - b; })
 
 impl_op_ex!(-= |a: &mut Vector2i, b: &Vector2i| { a.x = a.x - b.x; a.y = a.y - b.y; });
 
+impl_op_ex!(& |a: &Vector2i, b: &Vector2i| -> Vector2i {
+    Vector2i::new(a.x & b.x, a.y & b.y)
+});
+
+impl_op_ex!(&= |a: &mut Vector2i, b: &Vector2i| {
+    a.x = a.x & b.x;
+    a.y = a.y & b.y;
+});
+
+impl_op_ex!(& |a: &Vector2i, b: &int!()| -> Vector2i {
+    Vector2i::new(a.x & b, a.y & b)
+});
+
+impl_op_ex!(&= |a: &mut Vector2i, b: &int!()| {
+    a.x = a.x & b;
+    a.y = a.y & b;
+});
+
+impl_op_ex!(| |a: &Vector2i, b: &Vector2i| -> Vector2i {
+    Vector2i::new(a.x | b.x, a.y | b.y)
+});
+
+impl_op_ex!(|= |a: &mut Vector2i, b: &Vector2i| {
+    a.x = a.x | b.x;
+    a.y = a.y | b.y;
+});
+
+impl_op_ex!(| |a: &Vector2i, b: &int!()| -> Vector2i {
+    Vector2i::new(a.x | b, a.y | b)
+});
+
+impl_op_ex!(|= |a: &mut Vector2i, b: &int!()| {
+    a.x = a.x | b;
+    a.y = a.y | b;
+});
+
+impl_op_ex!(^ |a: &Vector2i, b: &Vector2i| -> Vector2i {
+    Vector2i::new(a.x ^ b.x, a.y ^ b.y)
+});
+
+impl_op_ex!(^= |a: &mut Vector2i, b: &Vector2i| {
+    a.x = a.x ^ b.x;
+    a.y = a.y ^ b.y;
+});
+
+impl_op_ex!(^ |a: &Vector2i, b: &int!()| -> Vector2i {
+    Vector2i::new(a.x ^ b, a.y ^ b)
+});
+
+impl_op_ex!(^= |a: &mut Vector2i, b: &int!()| {
+    a.x = a.x ^ b;
+    a.y = a.y ^ b;
+});
+
+impl_op_ex!(<< |a: &Vector2i, b: &Vector2i| -> Vector2i {
+    Vector2i::new(a.x << b.x, a.y << b.y)
+});
+
+impl_op_ex!(<<= |a: &mut Vector2i, b: &Vector2i| {
+    a.x = a.x << b.x;
+    a.y = a.y << b.y;
+});
+
+impl_op_ex!(<< |a: &Vector2i, b: &int!()| -> Vector2i {
+    Vector2i::new(a.x << b, a.y << b)
+});
+
+impl_op_ex!(<<= |a: &mut Vector2i, b: &int!()| {
+    a.x = a.x << b;
+    a.y = a.y << b;
+});
+
+impl_op_ex!(>> |a: &Vector2i, b: &Vector2i| -> Vector2i {
+    Vector2i::new(a.x >> b.x, a.y >> b.y)
+});
+
+impl_op_ex!(>>= |a: &mut Vector2i, b: &Vector2i| {
+    a.x = a.x >> b.x;
+    a.y = a.y >> b.y;
+});
+
+impl_op_ex!(>> |a: &Vector2i, b: &int!()| -> Vector2i {
+    Vector2i::new(a.x >> b, a.y >> b)
+});
+
+impl_op_ex!(>>= |a: &mut Vector2i, b: &int!()| {
+    a.x = a.x >> b;
+    a.y = a.y >> b;
+});
+
 impl Not for Vector2i {
     type Output = bool;
     fn not(self) -> Self::Output {
@@ -251,13 +479,7 @@ impl Not for Vector2i {
 
 impl PartialOrd for Vector2i {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self < other {
-            Some(Ordering::Less)
-        } else if self > other {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Equal)
-        }
+        Some(self.cmp(other))
     }
     fn lt(&self, other: &Self) -> bool {
         if self.x < other.x {
@@ -294,7 +516,7 @@ impl Neg for Vector2i {
 }
 
 impl Display for Vector2i {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!("Vector2i({}, {})", self.x, self.y))
     }
 }
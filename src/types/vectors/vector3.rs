@@ -1,3 +1,4 @@
+use crate::math;
 use crate::types::vectors::{Vector2, Vector3i, AXIS};
 use crate::types::Basis;
 use crate::utils::{
@@ -6,9 +7,11 @@ use crate::utils::{
     FloatExt, CMP_EPSILON, UNIT_EPSILON,
 };
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::ops::{Neg, Not};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 /// A 3D vector using floating-point coordinates.
 ///
@@ -20,6 +23,7 @@ use std::ops::{Neg, Not};
 ///
 /// **Note:** In a boolean context, a Vector3 will evaluate to `false` if it's equal to `Vector3::new(0.0, 0.0, 0.0)`. Otherwise, a Vector3 will always evaluate to `true`.
 #[derive(Copy, Clone, Default, Debug)]
+#[repr(C)]
 pub struct Vector3 {
     /// The vector's X component. Also, accessible by using the index position `v.get(0)`.
     pub x: float!(),
@@ -128,7 +132,7 @@ impl Vector3 {
 
     /// Returns the unsigned minimum angle to the given vector, in radians.
     pub fn angle_to(&self, to: &Self) -> float!() {
-        self.cross(to).length().atan2(self.dot(to))
+        math::atan2(self.cross(to).length(), self.dot(to))
     }
 
     /// Returns the derivative at the given `t` on the [Bézier curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve) defined by this vector and the given `control_1`, `control_2`, and `end` points.
@@ -307,7 +311,7 @@ impl Vector3 {
 
     /// Returns the length (magnitude) of this vector.
     pub fn length(&self) -> float!() {
-        self.length_squared().sqrt()
+        math::sqrt(self.length_squared())
     }
 
     /// Returns the squared length (squared magnitude) of this vector.
@@ -408,7 +412,7 @@ impl Vector3 {
             self.z = 0.0;
             self.y = 0.0;
         } else {
-            let length = length_sq.sqrt();
+            let length = math::sqrt(length_sq);
             self.x /= length;
             self.y /= length;
             self.z /= length;
@@ -520,7 +524,7 @@ impl Vector3 {
     /// Returns the signed angle to the given vector, in radians. The sign of the angle is positive in a counter-clockwise direction and negative in a clockwise direction when viewed from the side specified by the `axis`.
     pub fn signed_angle_to(&self, to: &Self, axis: &Self) -> float!() {
         let cross_to = self.cross(to);
-        let unsigned_angle = cross_to.length().atan2(self.dot(to));
+        let unsigned_angle = math::atan2(cross_to.length(), self.dot(to));
         let sign = cross_to.dot(axis);
         if sign < 0.0 {
             -unsigned_angle
@@ -547,9 +551,9 @@ impl Vector3 {
             // Colinear vectors have no rotation axis or angle between them, so the best we can do is lerp.
             return self.lerp(to, weight);
         }
-        axis /= axis_length_sq.sqrt();
-        let start_length = start_length_sq.sqrt();
-        let result_length = start_length.lerp(end_length_sq.sqrt(), weight);
+        axis /= math::sqrt(axis_length_sq);
+        let start_length = math::sqrt(start_length_sq);
+        let result_length = start_length.lerp(math::sqrt(end_length_sq), weight);
         let angle = self.angle_to(to);
         self.rotated(&axis, angle * weight) * (result_length / start_length)
     }
@@ -727,7 +731,7 @@ impl Neg for Vector3 {
     }
 }
 impl Display for Vector3 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!("Vector3({}, {}, {})", self.x, self.y, self.z))
     }
 }
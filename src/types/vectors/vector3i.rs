@@ -1,10 +1,14 @@
 use crate::float;
+use crate::math;
+use crate::types::grid::{BoxCells, Connectivity3D, Neighbors3D};
 use crate::types::vectors::{Vector2, Vector2i, Vector3, AXIS};
-use crate::utils::{int, snapped_i};
+use crate::utils::{float_consts, int, snapped_i, wide_int};
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::ops::{Neg, Not};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 /// A 3D vector using integer coordinates.
 ///
@@ -14,6 +18,7 @@ use std::ops::{Neg, Not};
 ///
 /// **Note:** In a boolean context, a Vector3i will evaluate to `false` if it's equal to `Vector3i(0, 0, 0)`. Otherwise, a Vector3i will always evaluate to `true`.
 #[derive(Copy, Clone, Default, Debug)]
+#[repr(C)]
 pub struct Vector3i {
     /// The vector's X component. Also, accessible by using the index position `v.get(0)`.
     pub x: int!(),
@@ -64,8 +69,57 @@ impl Vector3i {
         Self::new(self.x.abs(), self.y.abs(), self.z.abs())
     }
 
+    /// Returns an iterator over every cell of the box starting at this vector with the given `size`, with X varying fastest, then Y, then Z. Like [`Rect2i::cells`](crate::types::Rect2i::cells), the cells at `self + size` are excluded, and a box with a non-positive `size` component yields nothing.
+    pub fn box_cells(&self, size: &Self) -> BoxCells {
+        BoxCells::new(*self, *size)
+    }
+
+    /// Returns the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance) between this vector and `to`, the number of steps between them when moving to any of the 26 neighbors of a cell, as a wider integer that can't overflow.
+    pub fn chebyshev_distance_to(&self, to: &Self) -> wide_int!() {
+        let x = (to.x as wide_int!() - self.x as wide_int!()).abs();
+        let y = (to.y as wide_int!() - self.y as wide_int!()).abs();
+        let z = (to.z as wide_int!() - self.z as wide_int!()).abs();
+        x.max(y).max(z)
+    }
+
+    /// Returns the component-wise sum of this vector and `with`, or `None` if any component overflows, by running `checked_add` on each component.
+    pub fn checked_add(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(with.x)?,
+            self.y.checked_add(with.y)?,
+            self.z.checked_add(with.z)?,
+        ))
+    }
+
+    /// Returns the component-wise quotient of this vector and `with`, or `None` if any component overflows or any component of `with` is `0`, by running `checked_div` on each component.
+    pub fn checked_div(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_div(with.x)?,
+            self.y.checked_div(with.y)?,
+            self.z.checked_div(with.z)?,
+        ))
+    }
+
+    /// Returns the component-wise product of this vector and `with`, or `None` if any component overflows, by running `checked_mul` on each component.
+    pub fn checked_mul(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(with.x)?,
+            self.y.checked_mul(with.y)?,
+            self.z.checked_mul(with.z)?,
+        ))
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, or `None` if any component overflows, by running `checked_sub` on each component.
+    pub fn checked_sub(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(with.x)?,
+            self.y.checked_sub(with.y)?,
+            self.z.checked_sub(with.z)?,
+        ))
+    }
+
     /// Returns a new vector with all components clamped between the components of `min` and `max`, by running `clamp` on each component.
-    pub fn clamp(&self, min: &Vector3i, max: &Vector3i) -> Self {
+    pub fn clamp(self, min: &Vector3i, max: &Vector3i) -> Self {
         Self::new(
             self.x.clamp(min.x, max.x),
             self.y.clamp(min.y, max.y),
@@ -82,32 +136,53 @@ impl Vector3i {
         )
     }
 
-    /// Returns the squared distance between this vector and `to`.
+    /// Returns the squared distance between this vector and `to`, as a wider integer that can't overflow for any pair of vectors short of saturating at its maximum.
     ///
     /// This method runs faster than [`Vector3i::distance_to`], so prefer it if you need to compare vectors or need the squared distance for some formula.
-    pub fn distance_squared_to(&self, to: &Self) -> int!() {
-        (to - self).length_squared()
+    pub fn distance_squared_to(&self, to: &Self) -> wide_int!() {
+        let x = to.x as wide_int!() - self.x as wide_int!();
+        let y = to.y as wide_int!() - self.y as wide_int!();
+        let z = to.z as wide_int!() - self.z as wide_int!();
+        x.saturating_mul(x).saturating_add(y.saturating_mul(y)).saturating_add(z.saturating_mul(z))
     }
 
     /// Returns the distance between this vector and `to`.
     pub fn distance_to(&self, to: &Self) -> float!() {
-        (to - self).length()
+        math::sqrt(self.distance_squared_to(to) as float!())
+    }
+
+    /// Returns the component-wise Euclidean quotient of this vector and `with`, rounding towards negative infinity for positive divisors, by running `div_euclid` on each component. This is the cell of a grid of size `with` containing this vector.
+    ///
+    /// **Note:** Panics if any component of `with` is `0`.
+    pub fn div_euclid(&self, with: &Self) -> Self {
+        Self::new(self.x.div_euclid(with.x), self.y.div_euclid(with.y), self.z.div_euclid(with.z))
     }
 
     /// Returns the length (magnitude) of this vector.
     pub fn length(&self) -> float!() {
-        (self.length_squared() as float!()).sqrt()
+        math::sqrt(self.length_squared() as float!())
     }
 
-    /// Returns the squared length (squared magnitude) of this vector.
+    /// Returns the squared length (squared magnitude) of this vector, as a wider integer that can't overflow short of saturating at its maximum.
     ///
     /// This method runs faster than [`Vector3i::length`], so prefer it if you need to compare vectors or need the squared distance for some formula.
-    pub const fn length_squared(&self) -> int!() {
-        self.x * self.x + self.y * self.y + self.z * self.z
+    pub const fn length_squared(&self) -> wide_int!() {
+        let x = self.x as wide_int!();
+        let y = self.y as wide_int!();
+        let z = self.z as wide_int!();
+        (x * x).saturating_add(y * y).saturating_add(z * z)
+    }
+
+    /// Returns the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between this vector and `to`, the number of steps between them when moving to any of the 6 neighbors of a cell, as a wider integer that can't overflow.
+    pub fn manhattan_distance_to(&self, to: &Self) -> wide_int!() {
+        let x = (to.x as wide_int!() - self.x as wide_int!()).abs();
+        let y = (to.y as wide_int!() - self.y as wide_int!()).abs();
+        let z = (to.z as wide_int!() - self.z as wide_int!()).abs();
+        x + y + z
     }
 
     /// Returns the component-wise maximum of this and `with`, equivalent to `Vector3i::new(x.max(with.x), y.max(with.y), z.max(with.z))`.
-    pub fn max(&self, with: &Self) -> Self {
+    pub fn max(self, with: &Self) -> Self {
         Self::new(self.x.max(with.x), self.y.max(with.y), self.z.max(with.z))
     }
 
@@ -132,7 +207,7 @@ impl Vector3i {
     }
 
     /// Returns the component-wise minimum of this and `with`, equivalent to `Vector3i::new(x.min(with.x), y.min(with.y), z.min(with.z))`.
-    pub fn min(&self, with: &Self) -> Self {
+    pub fn min(self, with: &Self) -> Self {
         Self::new(self.x.min(with.x), self.y.min(with.y), self.z.min(with.z))
     }
 
@@ -156,6 +231,58 @@ impl Vector3i {
         Self::new(self.x.min(with), self.y.min(with), self.z.min(with))
     }
 
+    /// Returns an iterator over the neighbors of this cell with the given `connectivity`, in the order of [`Connectivity3D::offsets`].
+    pub fn neighbors(&self, connectivity: Connectivity3D) -> Neighbors3D {
+        Neighbors3D::new(*self, connectivity)
+    }
+
+    /// Returns the octile distance between this vector and `to`, the length of the shortest path between them when moving to any of the 26 neighbors of a cell, with steps across an edge costing `sqrt(2)` and steps across a corner costing `sqrt(3)`.
+    pub fn octile_distance_to(&self, to: &Self) -> float!() {
+        let x = (to.x as wide_int!() - self.x as wide_int!()).abs();
+        let y = (to.y as wide_int!() - self.y as wide_int!()).abs();
+        let z = (to.z as wide_int!() - self.z as wide_int!()).abs();
+        let (low, high) = (x.min(y), x.max(y));
+        let (low, mid, high) = (low.min(z), low.max(z).min(high), high.max(z));
+        let sqrt_3: float!() = math::sqrt(3.0);
+        high as float!()
+            + (float_consts::SQRT_2 - 1.0) * mid as float!()
+            + (sqrt_3 - float_consts::SQRT_2) * low as float!()
+    }
+
+    /// Returns the component-wise Euclidean remainder of this vector and `with`, which is never negative, by running `rem_euclid` on each component. This is the position of this vector inside its cell of a grid of size `with`.
+    ///
+    /// **Note:** Panics if any component of `with` is `0`.
+    pub fn rem_euclid(&self, with: &Self) -> Self {
+        Self::new(self.x.rem_euclid(with.x), self.y.rem_euclid(with.y), self.z.rem_euclid(with.z))
+    }
+
+    /// Returns the component-wise sum of this vector and `with`, clamping overflowing components to [`Vector3i::MIN`] or [`Vector3i::MAX`], by running `saturating_add` on each component.
+    pub fn saturating_add(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.saturating_add(with.x),
+            self.y.saturating_add(with.y),
+            self.z.saturating_add(with.z),
+        )
+    }
+
+    /// Returns the component-wise product of this vector and `with`, clamping overflowing components to [`Vector3i::MIN`] or [`Vector3i::MAX`], by running `saturating_mul` on each component.
+    pub fn saturating_mul(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.saturating_mul(with.x),
+            self.y.saturating_mul(with.y),
+            self.z.saturating_mul(with.z),
+        )
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, clamping overflowing components to [`Vector3i::MIN`] or [`Vector3i::MAX`], by running `saturating_sub` on each component.
+    pub fn saturating_sub(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.saturating_sub(with.x),
+            self.y.saturating_sub(with.y),
+            self.z.saturating_sub(with.z),
+        )
+    }
+
     /// Returns a new vector with each component set to `1` if it's positive, `-1` if it's negative, and `0` if it's zero. The result is identical to calling `sign` on each component.
     pub fn sign(&self) -> Self {
         Self::new(self.x.signum(), self.y.signum(), self.z.signum())
@@ -179,6 +306,33 @@ impl Vector3i {
         )
     }
 
+    /// Returns the component-wise sum of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_add` on each component.
+    pub fn wrapping_add(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.wrapping_add(with.x),
+            self.y.wrapping_add(with.y),
+            self.z.wrapping_add(with.z),
+        )
+    }
+
+    /// Returns the component-wise product of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_mul` on each component.
+    pub fn wrapping_mul(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.wrapping_mul(with.x),
+            self.y.wrapping_mul(with.y),
+            self.z.wrapping_mul(with.z),
+        )
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_sub` on each component.
+    pub fn wrapping_sub(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.wrapping_sub(with.x),
+            self.y.wrapping_sub(with.y),
+            self.z.wrapping_sub(with.z),
+        )
+    }
+
     pub const fn get(&self, index: usize) -> int!() {
         match index {
             0 => self.x,
@@ -234,7 +388,7 @@ impl PartialEq for Vector3i {
 impl Eq for Vector3i {}
 
 impl Display for Vector3i {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!("Vector3i({},{},{})", self.x, self.y, self.z))
     }
 }
@@ -309,13 +463,7 @@ impl_op_ex!(/ |a: &Vector3i, b: &int!()| -> Vector3i {
 
 impl PartialOrd for Vector3i {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self < other {
-            Some(Ordering::Less)
-        } else if self > other {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Equal)
-        }
+        Some(self.cmp(other))
     }
     fn lt(&self, other: &Self) -> bool {
         if self.x < other.x {
@@ -368,6 +516,106 @@ impl From<&Vector3> for Vector3i {
     }
 }
 
+impl_op_ex!(& |a: &Vector3i, b: &Vector3i| -> Vector3i {
+    Vector3i::new(a.x & b.x, a.y & b.y, a.z & b.z)
+});
+
+impl_op_ex!(&= |a: &mut Vector3i, b: &Vector3i| {
+    a.x = a.x & b.x;
+    a.y = a.y & b.y;
+    a.z = a.z & b.z;
+});
+
+impl_op_ex!(& |a: &Vector3i, b: &int!()| -> Vector3i {
+    Vector3i::new(a.x & b, a.y & b, a.z & b)
+});
+
+impl_op_ex!(&= |a: &mut Vector3i, b: &int!()| {
+    a.x = a.x & b;
+    a.y = a.y & b;
+    a.z = a.z & b;
+});
+
+impl_op_ex!(| |a: &Vector3i, b: &Vector3i| -> Vector3i {
+    Vector3i::new(a.x | b.x, a.y | b.y, a.z | b.z)
+});
+
+impl_op_ex!(|= |a: &mut Vector3i, b: &Vector3i| {
+    a.x = a.x | b.x;
+    a.y = a.y | b.y;
+    a.z = a.z | b.z;
+});
+
+impl_op_ex!(| |a: &Vector3i, b: &int!()| -> Vector3i {
+    Vector3i::new(a.x | b, a.y | b, a.z | b)
+});
+
+impl_op_ex!(|= |a: &mut Vector3i, b: &int!()| {
+    a.x = a.x | b;
+    a.y = a.y | b;
+    a.z = a.z | b;
+});
+
+impl_op_ex!(^ |a: &Vector3i, b: &Vector3i| -> Vector3i {
+    Vector3i::new(a.x ^ b.x, a.y ^ b.y, a.z ^ b.z)
+});
+
+impl_op_ex!(^= |a: &mut Vector3i, b: &Vector3i| {
+    a.x = a.x ^ b.x;
+    a.y = a.y ^ b.y;
+    a.z = a.z ^ b.z;
+});
+
+impl_op_ex!(^ |a: &Vector3i, b: &int!()| -> Vector3i {
+    Vector3i::new(a.x ^ b, a.y ^ b, a.z ^ b)
+});
+
+impl_op_ex!(^= |a: &mut Vector3i, b: &int!()| {
+    a.x = a.x ^ b;
+    a.y = a.y ^ b;
+    a.z = a.z ^ b;
+});
+
+impl_op_ex!(<< |a: &Vector3i, b: &Vector3i| -> Vector3i {
+    Vector3i::new(a.x << b.x, a.y << b.y, a.z << b.z)
+});
+
+impl_op_ex!(<<= |a: &mut Vector3i, b: &Vector3i| {
+    a.x = a.x << b.x;
+    a.y = a.y << b.y;
+    a.z = a.z << b.z;
+});
+
+impl_op_ex!(<< |a: &Vector3i, b: &int!()| -> Vector3i {
+    Vector3i::new(a.x << b, a.y << b, a.z << b)
+});
+
+impl_op_ex!(<<= |a: &mut Vector3i, b: &int!()| {
+    a.x = a.x << b;
+    a.y = a.y << b;
+    a.z = a.z << b;
+});
+
+impl_op_ex!(>> |a: &Vector3i, b: &Vector3i| -> Vector3i {
+    Vector3i::new(a.x >> b.x, a.y >> b.y, a.z >> b.z)
+});
+
+impl_op_ex!(>>= |a: &mut Vector3i, b: &Vector3i| {
+    a.x = a.x >> b.x;
+    a.y = a.y >> b.y;
+    a.z = a.z >> b.z;
+});
+
+impl_op_ex!(>> |a: &Vector3i, b: &int!()| -> Vector3i {
+    Vector3i::new(a.x >> b, a.y >> b, a.z >> b)
+});
+
+impl_op_ex!(>>= |a: &mut Vector3i, b: &int!()| {
+    a.x = a.x >> b;
+    a.y = a.y >> b;
+    a.z = a.z >> b;
+});
+
 impl Not for Vector3i {
     type Output = bool;
 
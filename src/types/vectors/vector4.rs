@@ -1,12 +1,15 @@
+use crate::math;
 use crate::types::vectors::{Vector4i, AXIS};
 use crate::utils::{
     cubic_interpolate, cubic_interpolate_in_time, float, int, is_equal_approx,
     is_equal_approx_with_tolerance, is_zero_approx, posmod_f, snapped, FloatExt, UNIT_EPSILON,
 };
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::ops::{Neg, Not};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 /// A 4D vector using floating-point coordinates.
 ///
@@ -18,15 +21,16 @@ use std::ops::{Neg, Not};
 ///
 /// **Note:** In a boolean context, a Vector4 will evaluate to `false` if it's equal to `Vector4(0, 0, 0, 0)`. Otherwise, a Vector4 will always evaluate to `true`.
 #[derive(Copy, Clone, Default, Debug)]
+#[repr(C)]
 pub struct Vector4 {
-    /// The vector's W component. Also, accessible by using the index position `v.get(3)`.
-    pub w: float!(),
     /// The vector's X component. Also, accessible by using the index position `v.get(0)`.
     pub x: float!(),
     /// The vector's Y component. Also, accessible by using the index position `v.get(1)`.
     pub y: float!(),
     /// The vector's Z component. Also, accessible by using the index position `v.get(2)`.
     pub z: float!(),
+    /// The vector's W component. Also, accessible by using the index position `v.get(3)`.
+    pub w: float!(),
 }
 
 impl Vector4 {
@@ -191,7 +195,7 @@ impl Vector4 {
 
     /// Returns the length (magnitude) of this vector.
     pub fn length(&self) -> float!() {
-        self.length_squared().sqrt()
+        math::sqrt(self.length_squared())
     }
 
     /// Returns the squared length (squared magnitude) of this vector.
@@ -313,7 +317,7 @@ impl Vector4 {
             self.z = 0.0;
             self.w = 0.0;
         } else {
-            let length = length_sq.sqrt();
+            let length = math::sqrt(length_sq);
             self.x /= length;
             self.y /= length;
             self.z /= length;
@@ -617,7 +621,7 @@ impl Neg for Vector4 {
     }
 }
 impl Display for Vector4 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
             "Vector4({}, {}, {}, {})",
             self.x, self.y, self.z, self.w
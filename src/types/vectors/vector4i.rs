@@ -1,9 +1,12 @@
+use crate::math;
 use crate::types::vectors::{Vector4, AXIS};
-use crate::utils::{float, int, snapped_i};
+use crate::utils::{float, int, snapped_i, wide_int};
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::ops::{Neg, Not};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Neg, Not};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
 
 /// A 4D vector using integer coordinates.
 ///
@@ -13,15 +16,16 @@ use std::ops::{Neg, Not};
 ///
 /// **Note:** In a boolean context, a Vector4i will evaluate to `false` if it's equal to `Vector4i(0, 0, 0, 0)`. Otherwise, a Vector4i will always evaluate to `true`.
 #[derive(Copy, Clone, Default, Debug)]
+#[repr(C)]
 pub struct Vector4i {
-    /// The vector's W component. Also, accessible by using the index position `v.get(3)`.
-    pub w: int!(),
     /// The vector's X component. Also, accessible by using the index position `v.get(0)`.
     pub x: int!(),
     /// The vector's Y component. Also, accessible by using the index position `v.get(1)`.
     pub y: int!(),
     /// The vector's Z component. Also, accessible by using the index position `v.get(2)`.
     pub z: int!(),
+    /// The vector's W component. Also, accessible by using the index position `v.get(3)`.
+    pub w: int!(),
 }
 
 impl Vector4i {
@@ -47,8 +51,48 @@ impl Vector4i {
         Self::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
     }
 
+    /// Returns the component-wise sum of this vector and `with`, or `None` if any component overflows, by running `checked_add` on each component.
+    pub fn checked_add(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(with.x)?,
+            self.y.checked_add(with.y)?,
+            self.z.checked_add(with.z)?,
+            self.w.checked_add(with.w)?,
+        ))
+    }
+
+    /// Returns the component-wise quotient of this vector and `with`, or `None` if any component overflows or any component of `with` is `0`, by running `checked_div` on each component.
+    pub fn checked_div(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_div(with.x)?,
+            self.y.checked_div(with.y)?,
+            self.z.checked_div(with.z)?,
+            self.w.checked_div(with.w)?,
+        ))
+    }
+
+    /// Returns the component-wise product of this vector and `with`, or `None` if any component overflows, by running `checked_mul` on each component.
+    pub fn checked_mul(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(with.x)?,
+            self.y.checked_mul(with.y)?,
+            self.z.checked_mul(with.z)?,
+            self.w.checked_mul(with.w)?,
+        ))
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, or `None` if any component overflows, by running `checked_sub` on each component.
+    pub fn checked_sub(&self, with: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(with.x)?,
+            self.y.checked_sub(with.y)?,
+            self.z.checked_sub(with.z)?,
+            self.w.checked_sub(with.w)?,
+        ))
+    }
+
     /// Returns a new vector with all components clamped between the components of `min` and `max`, by running `clamp` on each component.
-    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+    pub fn clamp(self, min: &Self, max: &Self) -> Self {
         Self::new(
             self.x.clamp(min.x, max.x),
             self.y.clamp(min.y, max.y),
@@ -67,32 +111,52 @@ impl Vector4i {
         )
     }
 
-    /// Returns the squared distance between this vector and `to`.
+    /// Returns the squared distance between this vector and `to`, as a wider integer that can't overflow for any pair of vectors short of saturating at its maximum.
     ///
     /// This method runs faster than [`Vector4i::distance_to`], so prefer it if you need to compare vectors or need the squared distance for some formula.
-    pub fn distance_squared_to(&self, to: &Self) -> int!() {
-        (to - self).length_squared()
+    pub fn distance_squared_to(&self, to: &Self) -> wide_int!() {
+        let x = to.x as wide_int!() - self.x as wide_int!();
+        let y = to.y as wide_int!() - self.y as wide_int!();
+        let z = to.z as wide_int!() - self.z as wide_int!();
+        let w = to.w as wide_int!() - self.w as wide_int!();
+        x.saturating_mul(x).saturating_add(y.saturating_mul(y)).saturating_add(z.saturating_mul(z)).saturating_add(w.saturating_mul(w))
     }
 
     /// Returns the distance between this vector and `to`.
     pub fn distance_to(&self, to: &Self) -> float!() {
-        (to - self).length()
+        math::sqrt(self.distance_squared_to(to) as float!())
+    }
+
+    /// Returns the component-wise Euclidean quotient of this vector and `with`, rounding towards negative infinity for positive divisors, by running `div_euclid` on each component. This is the cell of a grid of size `with` containing this vector.
+    ///
+    /// **Note:** Panics if any component of `with` is `0`.
+    pub fn div_euclid(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.div_euclid(with.x),
+            self.y.div_euclid(with.y),
+            self.z.div_euclid(with.z),
+            self.w.div_euclid(with.w),
+        )
     }
 
     /// Returns the length (magnitude) of this vector.
     pub fn length(&self) -> float!() {
-        (self.length_squared() as float!()).sqrt()
+        math::sqrt(self.length_squared() as float!())
     }
 
-    /// Returns the squared length (squared magnitude) of this vector.
+    /// Returns the squared length (squared magnitude) of this vector, as a wider integer that can't overflow short of saturating at its maximum.
     ///
     /// This method runs faster than [`Vector4i::length`], so prefer it if you need to compare vectors or need the squared distance for some formula.
-    pub const fn length_squared(&self) -> int!() {
-        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    pub const fn length_squared(&self) -> wide_int!() {
+        let x = self.x as wide_int!();
+        let y = self.y as wide_int!();
+        let z = self.z as wide_int!();
+        let w = self.w as wide_int!();
+        (x * x).saturating_add(y * y).saturating_add(z * z).saturating_add(w * w)
     }
 
     /// Returns the component-wise maximum of this and `with`, equivalent to `Vector4i::new(x.max(with.x), y.max(with.y), z.max(with.z), w.max(with.w))`.
-    pub fn max(&self, with: &Self) -> Self {
+    pub fn max(self, with: &Self) -> Self {
         Self::new(
             self.x.max(with.x),
             self.y.max(with.y),
@@ -139,7 +203,7 @@ impl Vector4i {
     }
 
     /// Returns the component-wise minimum of this and `with`, equivalent to `Vector4i::new(x.min(with.x), y.min(with.y), z.min(with.z), w.min(with.w))`.
-    pub fn min(&self, with: &Self) -> Self {
+    pub fn min(self, with: &Self) -> Self {
         Self::new(
             self.x.min(with.x),
             self.y.min(with.y),
@@ -185,6 +249,48 @@ impl Vector4i {
         )
     }
 
+    /// Returns the component-wise Euclidean remainder of this vector and `with`, which is never negative, by running `rem_euclid` on each component. This is the position of this vector inside its cell of a grid of size `with`.
+    ///
+    /// **Note:** Panics if any component of `with` is `0`.
+    pub fn rem_euclid(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.rem_euclid(with.x),
+            self.y.rem_euclid(with.y),
+            self.z.rem_euclid(with.z),
+            self.w.rem_euclid(with.w),
+        )
+    }
+
+    /// Returns the component-wise sum of this vector and `with`, clamping overflowing components to [`Vector4i::MIN`] or [`Vector4i::MAX`], by running `saturating_add` on each component.
+    pub fn saturating_add(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.saturating_add(with.x),
+            self.y.saturating_add(with.y),
+            self.z.saturating_add(with.z),
+            self.w.saturating_add(with.w),
+        )
+    }
+
+    /// Returns the component-wise product of this vector and `with`, clamping overflowing components to [`Vector4i::MIN`] or [`Vector4i::MAX`], by running `saturating_mul` on each component.
+    pub fn saturating_mul(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.saturating_mul(with.x),
+            self.y.saturating_mul(with.y),
+            self.z.saturating_mul(with.z),
+            self.w.saturating_mul(with.w),
+        )
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, clamping overflowing components to [`Vector4i::MIN`] or [`Vector4i::MAX`], by running `saturating_sub` on each component.
+    pub fn saturating_sub(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.saturating_sub(with.x),
+            self.y.saturating_sub(with.y),
+            self.z.saturating_sub(with.z),
+            self.w.saturating_sub(with.w),
+        )
+    }
+
     /// Returns a new vector with each component set to `1` if it's positive, `-1` if it's negative, and `0` if it's zero. The result is identical to calling `sign` on each component.
     pub fn sign(&self) -> Self {
         Self::new(
@@ -215,6 +321,36 @@ impl Vector4i {
         )
     }
 
+    /// Returns the component-wise sum of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_add` on each component.
+    pub fn wrapping_add(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.wrapping_add(with.x),
+            self.y.wrapping_add(with.y),
+            self.z.wrapping_add(with.z),
+            self.w.wrapping_add(with.w),
+        )
+    }
+
+    /// Returns the component-wise product of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_mul` on each component.
+    pub fn wrapping_mul(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.wrapping_mul(with.x),
+            self.y.wrapping_mul(with.y),
+            self.z.wrapping_mul(with.z),
+            self.w.wrapping_mul(with.w),
+        )
+    }
+
+    /// Returns the component-wise difference of this vector and `with`, wrapping around at the boundary of the integer type, by running `wrapping_sub` on each component.
+    pub fn wrapping_sub(&self, with: &Self) -> Self {
+        Self::new(
+            self.x.wrapping_sub(with.x),
+            self.y.wrapping_sub(with.y),
+            self.z.wrapping_sub(with.z),
+            self.w.wrapping_sub(with.w),
+        )
+    }
+
     pub const fn get(&self, index: usize) -> int!() {
         match index {
             0 => self.x,
@@ -405,6 +541,116 @@ impl_op_ex!(/= |a: &mut Vector4i, b: &int!()| {
     a.w = a.w / b;
 });
 
+impl_op_ex!(& |a: &Vector4i, b: &Vector4i| -> Vector4i {
+    Vector4i::new(a.x & b.x, a.y & b.y, a.z & b.z, a.w & b.w)
+});
+
+impl_op_ex!(&= |a: &mut Vector4i, b: &Vector4i| {
+    a.x = a.x & b.x;
+    a.y = a.y & b.y;
+    a.z = a.z & b.z;
+    a.w = a.w & b.w;
+});
+
+impl_op_ex!(& |a: &Vector4i, b: &int!()| -> Vector4i {
+    Vector4i::new(a.x & b, a.y & b, a.z & b, a.w & b)
+});
+
+impl_op_ex!(&= |a: &mut Vector4i, b: &int!()| {
+    a.x = a.x & b;
+    a.y = a.y & b;
+    a.z = a.z & b;
+    a.w = a.w & b;
+});
+
+impl_op_ex!(| |a: &Vector4i, b: &Vector4i| -> Vector4i {
+    Vector4i::new(a.x | b.x, a.y | b.y, a.z | b.z, a.w | b.w)
+});
+
+impl_op_ex!(|= |a: &mut Vector4i, b: &Vector4i| {
+    a.x = a.x | b.x;
+    a.y = a.y | b.y;
+    a.z = a.z | b.z;
+    a.w = a.w | b.w;
+});
+
+impl_op_ex!(| |a: &Vector4i, b: &int!()| -> Vector4i {
+    Vector4i::new(a.x | b, a.y | b, a.z | b, a.w | b)
+});
+
+impl_op_ex!(|= |a: &mut Vector4i, b: &int!()| {
+    a.x = a.x | b;
+    a.y = a.y | b;
+    a.z = a.z | b;
+    a.w = a.w | b;
+});
+
+impl_op_ex!(^ |a: &Vector4i, b: &Vector4i| -> Vector4i {
+    Vector4i::new(a.x ^ b.x, a.y ^ b.y, a.z ^ b.z, a.w ^ b.w)
+});
+
+impl_op_ex!(^= |a: &mut Vector4i, b: &Vector4i| {
+    a.x = a.x ^ b.x;
+    a.y = a.y ^ b.y;
+    a.z = a.z ^ b.z;
+    a.w = a.w ^ b.w;
+});
+
+impl_op_ex!(^ |a: &Vector4i, b: &int!()| -> Vector4i {
+    Vector4i::new(a.x ^ b, a.y ^ b, a.z ^ b, a.w ^ b)
+});
+
+impl_op_ex!(^= |a: &mut Vector4i, b: &int!()| {
+    a.x = a.x ^ b;
+    a.y = a.y ^ b;
+    a.z = a.z ^ b;
+    a.w = a.w ^ b;
+});
+
+impl_op_ex!(<< |a: &Vector4i, b: &Vector4i| -> Vector4i {
+    Vector4i::new(a.x << b.x, a.y << b.y, a.z << b.z, a.w << b.w)
+});
+
+impl_op_ex!(<<= |a: &mut Vector4i, b: &Vector4i| {
+    a.x = a.x << b.x;
+    a.y = a.y << b.y;
+    a.z = a.z << b.z;
+    a.w = a.w << b.w;
+});
+
+impl_op_ex!(<< |a: &Vector4i, b: &int!()| -> Vector4i {
+    Vector4i::new(a.x << b, a.y << b, a.z << b, a.w << b)
+});
+
+impl_op_ex!(<<= |a: &mut Vector4i, b: &int!()| {
+    a.x = a.x << b;
+    a.y = a.y << b;
+    a.z = a.z << b;
+    a.w = a.w << b;
+});
+
+impl_op_ex!(>> |a: &Vector4i, b: &Vector4i| -> Vector4i {
+    Vector4i::new(a.x >> b.x, a.y >> b.y, a.z >> b.z, a.w >> b.w)
+});
+
+impl_op_ex!(>>= |a: &mut Vector4i, b: &Vector4i| {
+    a.x = a.x >> b.x;
+    a.y = a.y >> b.y;
+    a.z = a.z >> b.z;
+    a.w = a.w >> b.w;
+});
+
+impl_op_ex!(>> |a: &Vector4i, b: &int!()| -> Vector4i {
+    Vector4i::new(a.x >> b, a.y >> b, a.z >> b, a.w >> b)
+});
+
+impl_op_ex!(>>= |a: &mut Vector4i, b: &int!()| {
+    a.x = a.x >> b;
+    a.y = a.y >> b;
+    a.z = a.z >> b;
+    a.w = a.w >> b;
+});
+
 impl Not for Vector4i {
     type Output = bool;
 
@@ -415,13 +661,7 @@ impl Not for Vector4i {
 
 impl PartialOrd for Vector4i {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self < other {
-            Some(Ordering::Less)
-        } else if self > other {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Equal)
-        }
+        Some(self.cmp(other))
     }
     fn lt(&self, other: &Self) -> bool {
         if self.x < other.x {
@@ -475,7 +715,7 @@ impl Neg for Vector4i {
     }
 }
 impl Display for Vector4i {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
             "Vector4i({}, {}, {}, {})",
             self.x, self.y, self.z, self.w
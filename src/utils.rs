@@ -1,3 +1,7 @@
+use crate::math;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+
 #[cfg(feature = "double-precision-float")]
 #[macro_export]
 macro_rules! float {
@@ -29,8 +33,25 @@ macro_rules! int {
     };
 }
 
+#[cfg(feature = "double-precision-int")]
+#[macro_export]
+macro_rules! wide_int {
+    () => {
+        i128
+    };
+}
+
+#[cfg(not(feature = "double-precision-int"))]
+#[macro_export]
+macro_rules! wide_int {
+    () => {
+        i64
+    };
+}
+
 pub use float;
 pub use int;
+pub use wide_int;
 
 pub const CMP_EPSILON: float!() = 0.00001;
 pub const CMP_EPSILON2: float!() = CMP_EPSILON * CMP_EPSILON;
@@ -174,9 +195,9 @@ pub fn snapped_i(value: int!(), step: int!()) -> int!() {
 }
 
 #[cfg(not(feature = "double-precision-float"))]
-pub use std::f32::consts as float_consts;
+pub use core::f32::consts as float_consts;
 #[cfg(feature = "double-precision-float")]
-pub use std::f64::consts as float_consts;
+pub use core::f64::consts as float_consts;
 
 pub trait FloatExt {
     fn lerp(self, rhs: Self, t: Self) -> Self;
@@ -221,7 +242,7 @@ impl FloatExt for float!() {
         } else if self > 1.0 {
             0.0
         } else {
-            self.acos()
+            math::acos(self)
         }
     }
     fn safe_asin(self) -> Self {
@@ -230,7 +251,7 @@ impl FloatExt for float!() {
         } else if self > 1.0 {
             float_consts::PI / 2.0
         } else {
-            self.asin()
+            math::asin(self)
         }
     }
 }
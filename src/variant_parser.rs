@@ -0,0 +1,404 @@
+use crate::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use crate::types::{
+    Basis, Color, Plane, Quaternion, Rect2, Rect2i, Transform2D, Transform3D, AABB,
+};
+use crate::utils::{float, int};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Write};
+
+/// The reason why a text can't be parsed with [`VariantText::parse_var`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The text ends before the value does.
+    UnexpectedEnd,
+    /// The character isn't valid at this position. `expected` describes what was expected instead.
+    UnexpectedCharacter { expected: &'static str, found: char },
+    /// The constructor is `found` instead of the `expected` name of the parsed type.
+    UnexpectedType { expected: &'static str, found: String },
+    /// The number can't be parsed, or it's out of the range of its type.
+    InvalidNumber,
+    /// The constructor has `found` arguments instead of the `expected` count.
+    WrongArgumentCount { expected: usize, found: usize },
+    /// There are characters other than whitespace after the value.
+    TrailingCharacters,
+}
+
+/// An error of [`VariantText::parse_var`], with the byte offset in the text where it occurred.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset of the failing character, number or name.
+    pub position: usize,
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedEnd => write!(f, "Unexpected end of text"),
+            ParseErrorKind::UnexpectedCharacter { expected, found } => {
+                write!(f, "Expected {}, found '{}'", expected, found)
+            }
+            ParseErrorKind::UnexpectedType { expected, found } => {
+                write!(f, "Expected '{}', found '{}'", expected, found)
+            }
+            ParseErrorKind::InvalidNumber => write!(f, "Invalid number"),
+            ParseErrorKind::WrongArgumentCount { expected, found } => write!(
+                f,
+                "Expected {} arguments for constructor, found {}",
+                expected, found
+            ),
+            ParseErrorKind::TrailingCharacters => write!(f, "Unexpected characters after the value"),
+        }?;
+        write!(f, " at position {}.", self.position)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// A type that can be written to and parsed from Godot's text representation of values, which `var_to_str`, `str_to_var` and the `.tscn` and `.tres` files use, like `Vector3(1, 2, 3)`.
+///
+/// The components are in the same order as in Godot: [`Transform2D`] lists its columns, and [`Basis`] and [`Transform3D`] list the rows of the basis. Floats are written with the fewest digits that parse back to the same value, so writing and parsing is lossless. Infinities and NaN are written as `inf`, `-inf` and `nan`, like in Godot.
+pub trait VariantText: Sized {
+    /// The name of the constructor, like `Vector3`.
+    const NAME: &'static str;
+
+    /// Appends the text representation of this value to `out`.
+    fn write_var(&self, out: &mut String);
+
+    /// Parses a value from the start of `text`, and returns it with the number of bytes it took. Leading whitespace is skipped, and the remaining text is ignored.
+    fn parse_var(text: &str) -> Result<(Self, usize), ParseError>;
+}
+
+/// Returns the text representation of `value`, like Godot's `var_to_str`.
+pub fn var_to_str<T: VariantText>(value: &T) -> String {
+    let mut out = String::new();
+    value.write_var(&mut out);
+    out
+}
+
+/// Parses a value from `text` with [`VariantText::parse_var`], like Godot's `str_to_var`. Only whitespace may follow the value.
+pub fn str_to_var<T: VariantText>(text: &str) -> Result<T, ParseError> {
+    let (value, length) = T::parse_var(text)?;
+    let trailing = text[length..].trim_start();
+    if trailing.is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError {
+            position: text.len() - trailing.len(),
+            kind: ParseErrorKind::TrailingCharacters,
+        })
+    }
+}
+
+/// A component of a value, written and parsed as a number.
+trait Number: Copy + Default {
+    /// Returns whether `c` can be part of a number of this type, after its first character.
+    fn is_number_char(c: char) -> bool;
+
+    fn parse(token: &str) -> Option<Self>;
+
+    fn write(self, out: &mut String);
+}
+
+impl Number for float!() {
+    fn is_number_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')
+    }
+
+    fn parse(token: &str) -> Option<Self> {
+        token.parse().ok()
+    }
+
+    fn write(self, out: &mut String) {
+        if self.is_nan() {
+            out.push_str("nan");
+        } else if self.is_infinite() {
+            out.push_str(if self > 0.0 { "inf" } else { "-inf" });
+        } else {
+            // The debug format is the shortest that round-trips, and switches to an exponent for very large or small values.
+            let text = format!("{:?}", self);
+            out.push_str(text.strip_suffix(".0").unwrap_or(&text));
+        }
+    }
+}
+
+impl Number for int!() {
+    fn is_number_char(c: char) -> bool {
+        c.is_ascii_digit()
+    }
+
+    fn parse(token: &str) -> Option<Self> {
+        token.parse().ok()
+    }
+
+    fn write(self, out: &mut String) {
+        // Writing to a `String` can't fail.
+        let _ = write!(out, "{}", self);
+    }
+}
+
+fn write_constructor<T: Number>(out: &mut String, name: &str, values: &[T]) {
+    out.push_str(name);
+    out.push('(');
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            out.push_str(", ");
+        }
+        value.write(out);
+    }
+    out.push(')');
+}
+
+/// A cursor over the text being parsed.
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            position: self.position,
+            kind,
+        }
+    }
+
+    /// Returns an error for the character at the cursor, or for the end of the text.
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        match self.peek() {
+            Some(found) => self.error(ParseErrorKind::UnexpectedCharacter { expected, found }),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Consumes the longest run of characters matching `predicate`, after an optional first character matching `first`.
+    fn take_while(&mut self, first: impl Fn(char) -> bool, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position;
+        if let Some(c) = self.peek().filter(|&c| first(c)) {
+            self.position += c.len_utf8();
+        }
+        while let Some(c) = self.peek().filter(|&c| predicate(c)) {
+            self.position += c.len_utf8();
+        }
+        &self.text[start..self.position]
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn parse_number<T: Number>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let token = self.take_while(|c| matches!(c, '+' | '-'), T::is_number_char);
+        // Digits, or the `inf` and `nan` of floats, must follow the sign.
+        let sign = token.len() - token.trim_start_matches(['+', '-']).len();
+        if !token[sign..].starts_with(|c: char| c.is_ascii_digit() || matches!(c, '.' | 'i' | 'n')) {
+            self.position = start + sign;
+            return Err(self.unexpected("a number"));
+        }
+        T::parse(token).ok_or(ParseError {
+            position: start,
+            kind: ParseErrorKind::InvalidNumber,
+        })
+    }
+
+    /// Parses `name(a, b, ...)` with exactly `N` arguments.
+    fn parse_constructor<T: Number, const N: usize>(
+        &mut self,
+        name: &'static str,
+    ) -> Result<[T; N], ParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let found = self.take_while(|c| c.is_ascii_alphabetic(), |c| c.is_ascii_alphanumeric() || c == '_');
+        if found.is_empty() {
+            return Err(self.unexpected("a constructor name"));
+        }
+        if found != name {
+            return Err(ParseError {
+                position: start,
+                kind: ParseErrorKind::UnexpectedType {
+                    expected: name,
+                    found: found.to_string(),
+                },
+            });
+        }
+        self.expect('(', "'('")?;
+
+        let mut values = Vec::with_capacity(N);
+        // Extra arguments are still parsed, to report their count at the first one.
+        let mut first_extra = None;
+        self.skip_whitespace();
+        if self.peek() != Some(')') {
+            loop {
+                self.skip_whitespace();
+                if values.len() == N {
+                    first_extra.get_or_insert(self.position);
+                }
+                values.push(self.parse_number()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => self.position += 1,
+                    Some(')') => break,
+                    _ => return Err(self.unexpected("',' or ')'")),
+                }
+            }
+        }
+        if let Some(position) = first_extra {
+            return Err(ParseError {
+                position,
+                kind: ParseErrorKind::WrongArgumentCount {
+                    expected: N,
+                    found: values.len(),
+                },
+            });
+        }
+        if values.len() != N {
+            return Err(self.error(ParseErrorKind::WrongArgumentCount {
+                expected: N,
+                found: values.len(),
+            }));
+        }
+        self.position += 1;
+
+        let mut array = [T::default(); N];
+        array.copy_from_slice(&values);
+        Ok(array)
+    }
+}
+
+/// Parses the constructor of `T` with `N` arguments from the start of `text`.
+fn parse_values<T: VariantText, U: Number, const N: usize>(
+    text: &str,
+) -> Result<([U; N], usize), ParseError> {
+    let mut parser = Parser { text, position: 0 };
+    let values = parser.parse_constructor(T::NAME)?;
+    Ok((values, parser.position))
+}
+
+macro_rules! impl_variant_text {
+    ($type:ty, $number:ty, |$value:ident| $to:expr, |[$($component:ident),+]| $from:expr) => {
+        impl VariantText for $type {
+            const NAME: &'static str = stringify!($type);
+
+            fn write_var(&self, out: &mut String) {
+                let $value = self;
+                write_constructor::<$number>(out, Self::NAME, &$to);
+            }
+
+            fn parse_var(text: &str) -> Result<(Self, usize), ParseError> {
+                let ([$($component),+], length) = parse_values::<Self, $number, _>(text)?;
+                Ok(($from, length))
+            }
+        }
+    };
+}
+
+impl_variant_text!(Vector2, float!(), |v| [v.x, v.y], |[x, y]| Vector2::new(x, y));
+impl_variant_text!(Vector2i, int!(), |v| [v.x, v.y], |[x, y]| Vector2i::new(x, y));
+impl_variant_text!(Vector3, float!(), |v| [v.x, v.y, v.z], |[x, y, z]| Vector3::new(x, y, z));
+impl_variant_text!(Vector3i, int!(), |v| [v.x, v.y, v.z], |[x, y, z]| Vector3i::new(x, y, z));
+impl_variant_text!(Vector4, float!(), |v| [v.x, v.y, v.z, v.w], |[x, y, z, w]| {
+    Vector4::new(x, y, z, w)
+});
+impl_variant_text!(Vector4i, int!(), |v| [v.x, v.y, v.z, v.w], |[x, y, z, w]| {
+    Vector4i::new(x, y, z, w)
+});
+impl_variant_text!(
+    Rect2,
+    float!(),
+    |r| [r.position().x, r.position().y, r.size().x, r.size().y],
+    |[x, y, width, height]| Rect2::new(Vector2::new(x, y), Vector2::new(width, height))
+);
+impl_variant_text!(
+    Rect2i,
+    int!(),
+    |r| [r.position().x, r.position().y, r.size().x, r.size().y],
+    |[x, y, width, height]| Rect2i::new_from_dimension(x, y, width, height)
+);
+impl_variant_text!(
+    Transform2D,
+    float!(),
+    |t| [t.x.x, t.x.y, t.y.x, t.y.y, t.origin.x, t.origin.y],
+    |[xx, xy, yx, yy, ox, oy]| Transform2D::new_from_floats(xx, xy, yx, yy, ox, oy)
+);
+impl_variant_text!(
+    Plane,
+    float!(),
+    |p| [p.normal.x, p.normal.y, p.normal.z, p.d],
+    |[a, b, c, d]| Plane::new_from_floats(a, b, c, d)
+);
+impl_variant_text!(
+    Quaternion,
+    float!(),
+    |q| [q.x, q.y, q.z, q.w],
+    |[x, y, z, w]| Quaternion::new(x, y, z, w)
+);
+impl_variant_text!(
+    AABB,
+    float!(),
+    |a| [a.position().x, a.position().y, a.position().z, a.size().x, a.size().y, a.size().z],
+    |[x, y, z, width, height, depth]| {
+        AABB::new(Vector3::new(x, y, z), Vector3::new(width, height, depth))
+    }
+);
+impl_variant_text!(
+    Basis,
+    float!(),
+    |b| [b.x.x, b.x.y, b.x.z, b.y.x, b.y.y, b.y.z, b.z.x, b.z.y, b.z.z],
+    |[xx, xy, xz, yx, yy, yz, zx, zy, zz]| Basis::new_rows(
+        Vector3::new(xx, xy, xz),
+        Vector3::new(yx, yy, yz),
+        Vector3::new(zx, zy, zz),
+    )
+);
+impl_variant_text!(
+    Transform3D,
+    float!(),
+    |t| [
+        t.basis.x.x,
+        t.basis.x.y,
+        t.basis.x.z,
+        t.basis.y.x,
+        t.basis.y.y,
+        t.basis.y.z,
+        t.basis.z.x,
+        t.basis.z.y,
+        t.basis.z.z,
+        t.origin.x,
+        t.origin.y,
+        t.origin.z,
+    ],
+    |[xx, xy, xz, yx, yy, yz, zx, zy, zz, ox, oy, oz]| Transform3D::new(
+        Basis::new_rows(
+            Vector3::new(xx, xy, xz),
+            Vector3::new(yx, yy, yz),
+            Vector3::new(zx, zy, zz),
+        ),
+        Vector3::new(ox, oy, oz),
+    )
+);
+impl_variant_text!(
+    Color,
+    float!(),
+    |c| [c.r(), c.g(), c.b(), c.a()],
+    |[r, g, b, a]| Color::rgba(r, g, b, a)
+);
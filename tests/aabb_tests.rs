@@ -0,0 +1,178 @@
+use huginn::types::vectors::Vector3;
+use huginn::types::{Plane, AABB};
+
+#[test]
+fn constructor_methods() {
+    let aabb = AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(4.0, 5.0, 6.0));
+    let mut aabb_set = AABB::default();
+    aabb_set.set_position(Vector3::new(-1.5, 2.0, -2.5));
+    aabb_set.set_size(Vector3::new(4.0, 5.0, 6.0));
+
+    assert_eq!(
+        aabb, aabb_set,
+        "AABBs created with the same dimensions but by different methods should be equal."
+    );
+    assert!(!AABB::default(), "An empty AABB should evaluate to `false`.");
+}
+
+#[test]
+fn string_conversion() {
+    assert_eq!(
+        AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(4.0, 5.0, 6.0)).to_string(),
+        "[P: (-1.5, 2, -2.5), S: (4, 5, 6)]",
+        "The string representation should match the expected value."
+    );
+}
+
+#[test]
+fn basic_getters() {
+    let aabb = AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(4.0, 5.0, 6.0));
+
+    assert_eq!(aabb.end(), Vector3::new(2.5, 7.0, 3.5), "end() should return position + size.");
+    assert_eq!(
+        aabb.get_center(),
+        Vector3::new(0.5, 4.5, 0.5),
+        "get_center() should return the middle of the box."
+    );
+    assert_eq!(aabb.get_volume(), 120.0, "get_volume() should return the product of the size.");
+    assert_eq!(aabb.get_longest_axis(), Vector3::BACK, "The longest axis should be Z.");
+    assert_eq!(aabb.get_longest_axis_size(), 6.0, "The longest axis size should be 6.");
+    assert_eq!(aabb.get_shortest_axis(), Vector3::RIGHT, "The shortest axis should be X.");
+    assert_eq!(aabb.get_shortest_axis_size(), 4.0, "The shortest axis size should be 4.");
+    assert_eq!(aabb.get_endpoint(0), aabb.position(), "Endpoint 0 should be the position.");
+    assert_eq!(aabb.get_endpoint(7), aabb.end(), "Endpoint 7 should be the end.");
+    assert_eq!(
+        aabb.get_endpoint(5),
+        Vector3::new(2.5, 2.0, 3.5),
+        "Endpoint 5 should take X and Z from the end."
+    );
+    assert_eq!(
+        aabb.get_support(&Vector3::new(1.0, -1.0, 1.0)),
+        Vector3::new(2.5, 2.0, 3.5),
+        "get_support() should return the vertex furthest along the direction."
+    );
+}
+
+#[test]
+fn surface_and_volume() {
+    assert!(
+        AABB::new(Vector3::ZERO, Vector3::new(1.0, 1.0, 1.0)).has_volume(),
+        "A box with positive size should have a volume."
+    );
+    assert!(
+        !AABB::new(Vector3::ZERO, Vector3::new(1.0, 0.0, 1.0)).has_volume(),
+        "A flat box should not have a volume."
+    );
+    assert!(
+        AABB::new(Vector3::ZERO, Vector3::new(1.0, 0.0, 0.0)).has_surface(),
+        "A box with one positive dimension should have a surface."
+    );
+    assert!(
+        !AABB::new(Vector3::ZERO, Vector3::ZERO).has_surface(),
+        "A box without size should not have a surface."
+    );
+}
+
+#[test]
+fn absolute_coordinates() {
+    let aabb = AABB::new(Vector3::new(2.5, 2.0, 3.5), Vector3::new(-4.0, -5.0, -6.0));
+
+    assert_eq!(
+        aabb.abs(),
+        AABB::new(Vector3::new(-1.5, -3.0, -2.5), Vector3::new(4.0, 5.0, 6.0)),
+        "abs() should return an equivalent box with a non-negative size."
+    );
+}
+
+#[test]
+fn intersection_and_merging() {
+    let aabb_big = AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(4.0, 5.0, 6.0));
+    let aabb_small = AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(1.0, 1.0, 1.0));
+    let aabb_far = AABB::new(Vector3::new(10.0, 10.0, 10.0), Vector3::new(1.0, 1.0, 1.0));
+
+    assert!(aabb_big.intersects(&aabb_small), "Overlapping boxes should intersect.");
+    assert!(!aabb_big.intersects(&aabb_far), "Distant boxes should not intersect.");
+    assert_eq!(
+        aabb_big.intersection(&aabb_small),
+        aabb_small,
+        "The intersection with an enclosed box should be that box."
+    );
+    assert_eq!(
+        aabb_big.intersection(&aabb_far),
+        AABB::default(),
+        "The intersection with a distant box should be empty."
+    );
+    assert!(aabb_big.encloses(&aabb_small), "The big box should enclose the small box.");
+    assert!(!aabb_small.encloses(&aabb_big), "The small box should not enclose the big box.");
+    assert_eq!(
+        aabb_small.merge(&aabb_far),
+        AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(12.5, 9.0, 13.5)),
+        "merge() should enclose both boxes."
+    );
+    assert_eq!(
+        aabb_small.expand(&Vector3::new(5.0, 0.0, 0.0)),
+        AABB::new(Vector3::new(-1.5, 0.0, -2.5), Vector3::new(6.5, 3.0, 2.5)),
+        "expand() should grow the box to include the point."
+    );
+    assert_eq!(
+        aabb_small.grow(1.0),
+        AABB::new(Vector3::new(-2.5, 1.0, -3.5), Vector3::new(3.0, 3.0, 3.0)),
+        "grow() should extend every side."
+    );
+}
+
+#[test]
+fn has_point() {
+    let aabb = AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(4.0, 5.0, 6.0));
+
+    assert!(aabb.has_point(&Vector3::new(-1.0, 3.0, 0.0)), "has_point() should include inner points.");
+    assert!(aabb.has_point(&Vector3::new(-1.5, 2.0, -2.5)), "has_point() should include the position.");
+    assert!(!aabb.has_point(&Vector3::new(2.5, 7.0, 3.5)), "has_point() should exclude the end.");
+    assert!(!aabb.has_point(&Vector3::new(-20.0, 0.0, 0.0)), "has_point() should exclude outer points.");
+}
+
+#[test]
+fn intersect_plane_ray_segment() {
+    let aabb = AABB::new(Vector3::new(-1.5, 2.0, -2.5), Vector3::new(4.0, 5.0, 6.0));
+
+    assert!(
+        aabb.intersects_plane(&Plane::new(Vector3::new(0.0, 1.0, 0.0), 4.0)),
+        "A plane crossing the box should intersect it."
+    );
+    assert!(
+        !aabb.intersects_plane(&Plane::new(Vector3::new(0.0, 1.0, 0.0), 8.0)),
+        "A plane above the box should not intersect it."
+    );
+
+    assert_eq!(
+        aabb.intersects_ray(&Vector3::new(-100.0, 3.0, 0.0), &Vector3::new(1.0, 0.0, 0.0)),
+        Some(Vector3::new(-1.5, 3.0, 0.0)),
+        "intersects_ray() should return the entry point."
+    );
+    assert_eq!(
+        aabb.intersects_ray(&Vector3::new(0.0, 3.0, 0.0), &Vector3::new(1.0, 0.0, 0.0)),
+        Some(Vector3::new(0.0, 3.0, 0.0)),
+        "intersects_ray() should return the ray origin when starting inside."
+    );
+    assert_eq!(
+        aabb.intersects_ray(&Vector3::new(-100.0, 3.0, 0.0), &Vector3::new(-1.0, 0.0, 0.0)),
+        None,
+        "intersects_ray() should fail when pointing away from the box."
+    );
+
+    assert_eq!(
+        aabb.intersects_segment(&Vector3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 4.0, 0.0)),
+        Some(Vector3::new(0.0, 2.0, 0.0)),
+        "intersects_segment() should return the entry point."
+    );
+    assert_eq!(
+        aabb.intersects_segment(&Vector3::new(0.0, 10.0, 0.0), &Vector3::new(0.0, 4.0, 0.0)),
+        Some(Vector3::new(0.0, 7.0, 0.0)),
+        "intersects_segment() should work in the negative direction."
+    );
+    assert_eq!(
+        aabb.intersects_segment(&Vector3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0)),
+        None,
+        "intersects_segment() should fail when the segment ends before the box."
+    );
+}
@@ -0,0 +1,151 @@
+use huginn::approx::{AbsDiff, ApproxEq, Relative, Ulps};
+use huginn::types::vectors::{Vector2i, Vector3};
+use huginn::types::{Basis, Color, Rect2, Transform3D};
+use huginn::utils::CMP_EPSILON;
+use huginn::{assert_abs_diff_eq, assert_approx_eq, assert_relative_eq, assert_ulps_eq, float};
+
+#[test]
+fn scalar_comparisons() {
+    let one: float!() = 1.0;
+    let thousand: float!() = 1000.0;
+    let small: float!() = 0.0001;
+
+    assert!(
+        one.abs_diff_eq(&1.05, 0.1),
+        "abs_diff_eq should accept differences up to epsilon."
+    );
+    assert!(
+        !one.abs_diff_eq(&1.2, 0.1),
+        "abs_diff_eq should reject differences over epsilon."
+    );
+    assert!(
+        thousand.relative_eq(&1001.0, 0.0, 0.01),
+        "relative_eq should scale the tolerance with the values."
+    );
+    assert!(
+        !thousand.abs_diff_eq(&1001.0, 0.01),
+        "abs_diff_eq should not scale the tolerance with the values."
+    );
+    assert!(
+        one.ulps_eq(&(one + <float!()>::EPSILON), 0.0, 1),
+        "ulps_eq should accept neighboring values."
+    );
+    assert!(
+        !one.ulps_eq(&(one + 4.0 * <float!()>::EPSILON), 0.0, 1),
+        "ulps_eq should reject values more than max_ulps apart."
+    );
+    assert!(
+        !(-small).ulps_eq(&small, 0.0, u32::MAX),
+        "ulps_eq should reject values of opposite signs."
+    );
+    assert!(
+        <float!()>::INFINITY.relative_eq(&<float!()>::INFINITY, 0.0, 0.0),
+        "Infinities of the same sign should be equal."
+    );
+    assert!(
+        !<float!()>::NAN.relative_eq(&<float!()>::NAN, 1.0, 1.0),
+        "NaN should never be approximately equal."
+    );
+    assert!(
+        !<float!()>::NAN.ulps_eq(&<float!()>::NAN, 1.0, u32::MAX),
+        "NaN should never be approximately equal in ulps."
+    );
+}
+
+#[test]
+fn compound_comparisons() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(1.0, 2.0, 3.05);
+
+    assert!(
+        a.abs_diff_eq(&b, 0.1),
+        "Vectors should compare component-wise."
+    );
+    assert!(
+        !a.abs_diff_eq(&b, 0.01),
+        "One component out of tolerance should fail the comparison."
+    );
+    assert!(
+        Vector2i::new(10, 20).abs_diff_eq(&Vector2i::new(11, 20), 1.0),
+        "Integer vectors should compare with float tolerances."
+    );
+    assert!(
+        Rect2::new(a.xy(), b.xy()).relative_eq(&Rect2::new(a.xy(), b.xy() * 1.001), 0.0, 0.01),
+        "Rects should compare their position and size."
+    );
+    assert!(
+        !Color::rgba(1.0, 0.5, 0.0, 1.0).abs_diff_eq(&Color::rgba(1.0, 0.5, 0.0, 0.5), 0.1),
+        "Colors should compare their alpha."
+    );
+    assert!(
+        AbsDiff::default().eq(&a, &(a + Vector3::ONE * CMP_EPSILON * 0.5)),
+        "AbsDiff should default to CMP_EPSILON."
+    );
+    assert!(
+        Relative::default().max_relative(0.1).eq(&a, &(a * 1.05)),
+        "Relative should use the given max_relative."
+    );
+    assert!(
+        !Ulps::default().epsilon(0.0).max_ulps(0).eq(&a, &(a * 1.05)),
+        "Ulps should use the given epsilon and max_ulps."
+    );
+}
+
+#[test]
+fn assertion_macros() {
+    let basis = Basis::from_euler(&Vector3::new(0.3, 0.2, 0.1), None);
+    let transform = Transform3D::new(basis, Vector3::new(1.0, 2.0, 3.0));
+
+    assert_approx_eq!(
+        transform * transform.affine_inverse(),
+        Transform3D::IDENTITY,
+        "A transform times its inverse should be the identity."
+    );
+    assert_abs_diff_eq!(
+        transform.origin,
+        Vector3::new(1.0, 2.0, 3.04),
+        epsilon = 0.05
+    );
+    assert_relative_eq!(
+        transform.origin * 1000.0,
+        Vector3::new(1000.0, 2001.0, 3000.0),
+        max_relative = 0.001,
+        "The tolerance of {} should scale with the values.",
+        "relative_eq"
+    );
+    assert_ulps_eq!(basis, basis);
+}
+
+#[test]
+fn assertion_failure_diff() {
+    let result = std::panic::catch_unwind(|| {
+        assert_abs_diff_eq!(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(1.0, 2.5, 3.0),
+            epsilon = 0.1,
+            "Vectors {} differ",
+            "should"
+        );
+    });
+    let message = *result
+        .expect_err("The assertion should fail.")
+        .downcast::<String>()
+        .expect("The panic message should be formatted.");
+
+    assert!(
+        message.contains("Vectors should differ"),
+        "The failure should include the custom message."
+    );
+    assert!(
+        message.contains("AbsDiff { epsilon: 0.1 }"),
+        "The failure should include the comparison and its tolerance."
+    );
+    assert!(
+        message.contains("-     y: 2.0,\n+     y: 2.5,"),
+        "The failure should mark the differing components."
+    );
+    assert!(
+        message.contains("\n      x: 1.0,"),
+        "The failure should show the matching components unmarked."
+    );
+}
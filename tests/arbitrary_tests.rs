@@ -0,0 +1,71 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use huginn::types::vectors::Vector3;
+use huginn::types::{Basis, Color, Quaternion, Rect2i, Transform2D, Transform3D, AABB};
+
+fn data(seed: u32) -> Vec<u8> {
+    // A cheap xorshift, enough to feed varied bytes to `Unstructured`.
+    let mut state = seed.wrapping_mul(2_654_435_761) | 1;
+    (0..1024)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn invariants() {
+    for seed in 0..64 {
+        let data = data(seed);
+        let mut u = Unstructured::new(&data);
+
+        let v = Vector3::arbitrary(&mut u).unwrap();
+        assert!(v.is_finite(), "Arbitrary vectors should be finite.");
+        let q = Quaternion::arbitrary(&mut u).unwrap();
+        assert!(q.is_normalized(), "Arbitrary quaternions should be normalized.");
+        let basis = Basis::arbitrary(&mut u).unwrap();
+        assert!(basis.is_rotation(), "Arbitrary bases should be rotations.");
+        let t2 = Transform2D::arbitrary(&mut u).unwrap();
+        assert!(t2.determinant() != 0.0, "Arbitrary Transform2Ds should be invertible.");
+        let t3 = Transform3D::arbitrary(&mut u).unwrap();
+        assert!(
+            t3.basis.determinant() != 0.0,
+            "Arbitrary Transform3Ds should be invertible."
+        );
+        let rect = Rect2i::arbitrary(&mut u).unwrap();
+        assert!(
+            rect.size().x >= 0 && rect.size().y >= 0,
+            "Arbitrary Rect2is should have a non-negative size."
+        );
+        let aabb = AABB::arbitrary(&mut u).unwrap();
+        assert!(
+            aabb.size().x >= 0.0 && aabb.size().y >= 0.0 && aabb.size().z >= 0.0,
+            "Arbitrary AABBs should have a non-negative size."
+        );
+        let color = Color::arbitrary(&mut u).unwrap();
+        assert!(
+            [color.r(), color.g(), color.b(), color.a()]
+                .iter()
+                .all(|c| (0.0..=1.0).contains(c)),
+            "Arbitrary colors should have components in 0..=1."
+        );
+    }
+}
+
+#[test]
+fn empty_data() {
+    let mut u = Unstructured::new(&[]);
+
+    assert!(
+        Quaternion::arbitrary(&mut u).unwrap().is_normalized(),
+        "Exhausted data should still give a unit quaternion."
+    );
+    assert!(
+        Transform3D::arbitrary(&mut u).unwrap().basis.determinant() != 0.0,
+        "Exhausted data should still give an invertible transform."
+    );
+}
@@ -517,3 +517,91 @@ fn is_rotation_checks() {
         "with only rotation should be a rotation."
     );
 }
+
+#[test]
+fn symmetric_eigen() {
+    let tensor = Basis::new_from_floats(4.0, 1.0, -2.0, 1.0, 3.0, 0.5, -2.0, 0.5, 6.0);
+    let (values, vectors) = tensor.symmetric_eigen();
+
+    assert!(
+        values.x >= values.y && values.y >= values.z,
+        "Eigenvalues should be sorted from largest to smallest."
+    );
+    assert!(vectors.is_rotation(), "Eigenvectors should form a rotation.");
+    for i in 0..3 {
+        let vector = vectors.get_column(i);
+        assert!(
+            (tensor.xform(&vector) - vector * values.get(i)).length() < 1e-4,
+            "Each column should be an eigenvector of its eigenvalue."
+        );
+    }
+    assert_approx_eq_with_tolerance!(
+        values.x + values.y + values.z,
+        13.0,
+        1e-4,
+        "The eigenvalues should add up to the trace."
+    );
+
+    let (values, vectors) = Basis::from_scale(&Vector3::new(2.0, 2.0, 2.0)).symmetric_eigen();
+    assert!(
+        values.is_equal_approx(&Vector3::new(2.0, 2.0, 2.0)),
+        "Repeated eigenvalues should be found."
+    );
+    assert!(vectors.is_rotation(), "Eigenvectors of repeated eigenvalues should form a rotation.");
+
+    let (d, r) = tensor.diagonalize();
+    assert!(
+        (r * d * r.transposed()).is_equal_approx(&tensor),
+        "diagonalize() should recompose into the original basis."
+    );
+}
+
+#[test]
+fn svd() {
+    let rotation = Basis::from_euler(&Vector3::new(0.3, -1.2, 0.7), None);
+    let sheared = Basis::new_from_floats(1.0, 0.8, 0.0, 0.0, 2.0, -0.3, 0.4, 0.0, 0.5);
+    let reflected = rotation * Basis::from_scale(&Vector3::new(1.0, -3.0, 2.0));
+    let degenerate = Basis::new_from_floats(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 0.0);
+
+    for basis in [rotation, sheared, reflected, degenerate, Basis::default()] {
+        let (u, sigma, v) = basis.svd();
+        assert!(u.is_rotation(), "U should be a rotation.");
+        assert!(v.is_rotation(), "V should be a rotation.");
+        assert!(
+            sigma.x.abs() + CMP_EPSILON >= sigma.y.abs()
+                && sigma.y.abs() + CMP_EPSILON >= sigma.z.abs(),
+            "Singular values should be sorted by decreasing magnitude."
+        );
+        assert!(
+            (u * Basis::from_scale(&sigma) * v.transposed()).is_equal_approx(&basis),
+            "svd() should recompose into the original basis."
+        );
+    }
+    assert!(
+        reflected.svd().1.z < 0.0,
+        "A reflection should be stored as a negative singular value."
+    );
+    assert!(
+        reflected.svd().1.abs().is_equal_approx(&Vector3::new(3.0, 2.0, 1.0)),
+        "The singular values of a scaled rotation should be its scale."
+    );
+}
+
+#[test]
+fn polar_decompose() {
+    let rotation = Basis::from_euler(&Vector3::new(-0.4, 0.9, 2.0), None);
+    let stretch = Basis::new_from_floats(2.0, 0.3, -0.1, 0.3, 1.5, 0.2, -0.1, 0.2, 0.8);
+
+    let (r, s) = (rotation * stretch).polar_decompose();
+    assert!(r.is_equal_approx(&rotation), "The rotation should be recovered.");
+    assert!(s.is_equal_approx(&stretch), "The stretch should be recovered.");
+
+    let mirrored = rotation * Basis::FLIP_X;
+    let (r, s) = mirrored.polar_decompose();
+    assert!(r.is_rotation(), "The rotation part of a reflection should still be a rotation.");
+    assert!(s.determinant() < 0.0, "The reflection should be kept in the stretch.");
+    assert!(
+        (r * s).is_equal_approx(&mirrored),
+        "polar_decompose() should recompose into the original basis."
+    );
+}
@@ -0,0 +1,210 @@
+use huginn::float;
+use huginn::types::collision2d::{Capsule2D, Circle, ConvexPolygon2D, Segment2D, Shape2D};
+use huginn::types::vectors::Vector2;
+use huginn::types::{Rect2, Transform2D};
+use huginn::utils::float_consts::{FRAC_PI_4, SQRT_2};
+
+fn at(x: float!(), y: float!()) -> Transform2D {
+    Transform2D::IDENTITY.translated(&Vector2::new(x, y))
+}
+
+fn square(half: float!()) -> ConvexPolygon2D {
+    ConvexPolygon2D::new(vec![
+        Vector2::new(-half, -half),
+        Vector2::new(half, -half),
+        Vector2::new(half, half),
+        Vector2::new(-half, half),
+    ])
+}
+
+#[test]
+fn circle_circle() {
+    let circle = Circle::new(1.0);
+    let contact = circle.collide(&at(0.0, 0.0), &circle, &at(0.0, 1.0)).unwrap();
+    assert_eq!(contact.normal, Vector2::DOWN, "The normal should point toward the second shape.");
+    assert_eq!(contact.depth, 1.0, "The depth should be the overlap of the radii.");
+    assert_eq!(contact.points(), [Vector2::new(0.0, 0.5)], "The contact should be halfway through the overlap.");
+
+    assert!(circle.collide(&at(0.0, 0.0), &circle, &at(2.5, 0.0)).is_none(), "Distant circles shouldn't collide.");
+    let touching = circle.collide(&at(0.0, 0.0), &circle, &at(2.0, 0.0)).unwrap();
+    assert_eq!(touching.depth, 0.0, "Touching circles should collide with no depth.");
+}
+
+#[test]
+fn circle_rect() {
+    let rect = Rect2::new_from_dimension(0.0, 0.0, 10.0, 10.0);
+    let circle = Circle::new(1.0);
+
+    let side = rect.collide(&Transform2D::IDENTITY, &circle, &at(10.5, 5.0)).unwrap();
+    assert_eq!(side.normal, Vector2::RIGHT, "A circle beside the rect should be pushed out sideways.");
+    assert!((side.depth - 0.5).abs() < 1e-5, "Expected a depth of 0.5, got {}.", side.depth);
+    assert!(side.points()[0].is_equal_approx(&Vector2::new(9.75, 5.0)), "Got the contact {}.", side.points()[0]);
+
+    // Near a corner, the normal points from the corner toward the center of the circle.
+    let corner = rect.collide(&Transform2D::IDENTITY, &circle, &at(10.5, 10.5)).unwrap();
+    assert!(corner.normal.is_equal_approx(&Vector2::ONE.normalized()), "Got the normal {}.", corner.normal);
+    assert!((corner.depth - (1.0 - 0.5 * SQRT_2)).abs() < 1e-5, "Got the depth {}.", corner.depth);
+    assert!(
+        rect.collide(&Transform2D::IDENTITY, &circle, &at(11.0, 11.0)).is_none(),
+        "A circle diagonally past the corner shouldn't collide."
+    );
+
+    // A circle deep inside is pushed out through the closest side.
+    let inside = rect.collide(&Transform2D::IDENTITY, &circle, &at(8.0, 5.0)).unwrap();
+    assert_eq!(inside.normal, Vector2::RIGHT, "The closest side is on the right.");
+    assert!((inside.depth - 3.0).abs() < 1e-5, "Expected a depth of 3, got {}.", inside.depth);
+}
+
+#[test]
+fn box_on_box_has_two_contacts() {
+    let a = square(1.0);
+    let b = square(0.5);
+    let contact = a.collide(&at(0.0, 0.0), &b, &at(0.25, -1.4)).unwrap();
+    assert!(contact.normal.is_equal_approx(&Vector2::UP), "Got the normal {}.", contact.normal);
+    assert!((contact.depth - 0.1).abs() < 1e-5, "Expected a depth of 0.1, got {}.", contact.depth);
+    let mut points = contact.points().to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x));
+    assert_eq!(points.len(), 2, "Resting faces should give two contacts.");
+    assert!(points[0].is_equal_approx(&Vector2::new(-0.25, -0.95)), "Got the left contact {}.", points[0]);
+    assert!(points[1].is_equal_approx(&Vector2::new(0.75, -0.95)), "Got the right contact {}.", points[1]);
+}
+
+#[test]
+fn rotated_box_touches_with_a_corner() {
+    let a = square(1.0);
+    let b = square(1.0);
+    let rotated = Transform2D::IDENTITY
+        .rotated(FRAC_PI_4)
+        .translated(&Vector2::new(0.0, 2.3));
+    let contact = a.collide(&Transform2D::IDENTITY, &b, &rotated).unwrap();
+    assert!(contact.normal.is_equal_approx(&Vector2::DOWN), "Got the normal {}.", contact.normal);
+    let depth = 1.0 + SQRT_2 - 2.3;
+    assert!((contact.depth - depth).abs() < 1e-4, "Expected a depth of {depth}, got {}.", contact.depth);
+    assert_eq!(contact.points().len(), 1, "A corner should give a single contact.");
+    assert!((contact.points()[0].x).abs() < 1e-4, "The contact should be at the corner, got {}.", contact.points()[0]);
+}
+
+#[test]
+fn capsules() {
+    let capsule = Capsule2D::new(0.5, 3.0);
+    // Side by side, the straight parts overlap along their whole length.
+    let contact = capsule.collide(&at(0.0, 0.0), &capsule, &at(0.8, 0.0)).unwrap();
+    assert_eq!(contact.normal, Vector2::RIGHT, "Side by side capsules should be pushed apart sideways.");
+    assert!((contact.depth - 0.2).abs() < 1e-5, "Expected a depth of 0.2, got {}.", contact.depth);
+    assert_eq!(contact.points().len(), 2, "Parallel straight parts should give two contacts.");
+
+    // End to end, the caps touch at a single point.
+    let contact = capsule.collide(&at(0.0, 0.0), &capsule, &at(0.0, 2.5)).unwrap();
+    assert_eq!(contact.normal, Vector2::DOWN, "End to end capsules should be pushed apart along their axis.");
+    assert!((contact.depth - 0.5).abs() < 1e-5, "Expected a depth of 0.5, got {}.", contact.depth);
+    assert_eq!(contact.points(), [Vector2::new(0.0, 1.25)], "The caps should touch at a single point.");
+
+    // A capsule shorter than its diameter is a circle.
+    let round = Capsule2D::new(1.0, 1.0);
+    let contact = round.collide(&at(0.0, 0.0), &Circle::new(1.0), &at(1.0, 0.0)).unwrap();
+    assert_eq!(contact.depth, 1.0, "The round capsule should collide like a circle.");
+}
+
+#[test]
+fn segments() {
+    let segment = Segment2D::new(Vector2::new(-1.0, 0.0), Vector2::new(1.0, 0.0));
+    let crossing = Segment2D::new(Vector2::new(0.0, -1.0), Vector2::new(0.0, 1.0));
+    assert!(segment.intersects(&at(0.0, 0.0), &crossing, &at(0.0, 0.0)), "Crossing segments should intersect.");
+    assert!(!segment.intersects(&at(0.0, 0.0), &crossing, &at(1.5, 0.0)), "Separate segments shouldn't intersect.");
+
+    let contact = segment.collide(&at(0.0, 0.0), &Circle::new(1.0), &at(0.5, 0.5)).unwrap();
+    assert_eq!(contact.normal, Vector2::DOWN, "The circle below the segment should be pushed down.");
+    assert_eq!(contact.depth, 0.5, "The depth should be the radius minus the distance to the segment.");
+}
+
+#[test]
+fn polygon_from_point_cloud() {
+    let polygon = ConvexPolygon2D::from_point_cloud(&[
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(1.0, 2.0),
+        Vector2::new(1.0, 1.0),
+    ]);
+    assert_eq!(polygon.points().len(), 3, "Inner, collinear and repeated points should be dropped.");
+    for point in [Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(1.0, 2.0)] {
+        assert!(polygon.points().contains(&point), "The hull should keep the corner {point}.");
+    }
+
+    let triangle = polygon.collide(&at(0.0, 0.0), &Circle::new(0.5), &at(1.0, -0.25)).unwrap();
+    assert_eq!(triangle.normal, Vector2::UP, "The circle above the base should be pushed up.");
+    assert_eq!(triangle.depth, 0.25, "The depth should be the radius minus the distance to the base.");
+}
+
+#[test]
+fn collide_is_symmetric() {
+    let (square, capsule) = (square(1.0), Capsule2D::new(0.5, 2.0));
+    let xa = at(0.0, 0.0);
+    let xb = Transform2D::IDENTITY.rotated(0.3).translated(&Vector2::new(1.2, 0.4));
+    let forward = square.collide(&xa, &capsule, &xb).unwrap();
+    let backward = capsule.collide(&xb, &square, &xa).unwrap();
+    assert!(forward.normal.is_equal_approx(&-backward.normal), "Swapping the shapes should flip the normal.");
+    assert!((forward.depth - backward.depth).abs() < 1e-5, "Swapping the shapes should keep the depth.");
+}
+
+#[test]
+fn cast_against_rect() {
+    let wall = Rect2::new_from_dimension(10.0, -5.0, 2.0, 10.0);
+    let circle = Circle::new(1.0);
+
+    let hit = circle.cast(&at(0.0, 0.0), &Vector2::new(20.0, 0.0), &wall, &Transform2D::IDENTITY).unwrap();
+    assert!((hit.time - 0.45).abs() < 1e-5, "Expected a hit at 0.45, got {}.", hit.time);
+    assert_eq!(hit.normal, Vector2::LEFT, "The hit normal should face the circle.");
+    assert!(hit.point.is_equal_approx(&Vector2::new(10.0, 0.0)), "Got the hit point {}.", hit.point);
+
+    assert!(
+        circle.cast(&at(0.0, 0.0), &Vector2::new(5.0, 0.0), &wall, &Transform2D::IDENTITY).is_none(),
+        "A motion that stops short shouldn't hit."
+    );
+    assert!(
+        circle.cast(&at(0.0, 0.0), &Vector2::new(-20.0, 0.0), &wall, &Transform2D::IDENTITY).is_none(),
+        "A motion away from the shape shouldn't hit."
+    );
+
+    let overlapping = circle.cast(&at(10.5, 0.0), &Vector2::new(1.0, 0.0), &wall, &Transform2D::IDENTITY).unwrap();
+    assert_eq!(overlapping.time, 0.0, "Overlapping shapes should hit immediately.");
+}
+
+#[test]
+fn cast_round_shapes() {
+    let circle = Circle::new(1.0);
+    // The circle grazes the corner of the square, which only a rounded test can find.
+    let hit = circle.cast(&at(-5.0, 1.5), &Vector2::new(10.0, 0.0), &square(1.0), &at(0.0, 0.0)).unwrap();
+    let center = Vector2::new(-5.0 + 10.0 * hit.time, 1.5);
+    assert!((center.distance_to(&Vector2::new(-1.0, 1.0)) - 1.0).abs() < 1e-3, "The circle should stop touching the corner, at {center}.");
+    // The corner is at a height of 0.5 below the center, so sqrt(1 - 0.5²) to its left.
+    assert!((center.x - (-1.0 - 0.866_025)).abs() < 1e-3, "The circle should stop left of the corner, at {center}.");
+
+    let capsule = Capsule2D::new(0.5, 2.0);
+    let hit = capsule.cast(&at(0.0, -5.0), &Vector2::new(0.0, 10.0), &circle, &at(0.0, 0.0)).unwrap();
+    assert!((hit.time - 0.3).abs() < 1e-4, "Expected a hit at 0.3, got {}.", hit.time);
+    assert!(hit.normal.is_equal_approx(&Vector2::UP), "Got the normal {}.", hit.normal);
+}
+
+#[test]
+fn shapes_as_trait_objects() {
+    let shapes: Vec<Box<dyn Shape2D>> = vec![
+        Box::new(Circle::new(1.0)),
+        Box::new(Capsule2D::new(0.5, 2.0)),
+        Box::new(square(1.0)),
+        Box::new(Rect2::new_from_dimension(-1.0, -1.0, 2.0, 2.0)),
+    ];
+    for shape in &shapes {
+        for other in &shapes {
+            assert!(
+                shape.intersects(&at(0.0, 0.0), other.as_ref(), &at(0.5, 0.0)),
+                "Overlapping shapes should intersect through trait objects."
+            );
+            assert!(
+                shape.cast(&at(0.0, 0.0), &Vector2::new(10.0, 0.0), other.as_ref(), &at(5.0, 0.0)).is_some(),
+                "A shape moving into another should hit it through trait objects."
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use huginn::float;
+use huginn::types::collision3d::{BoxShape3D, Capsule3D, ConvexHull3D, ConvexShape3D, Sphere};
+use huginn::types::vectors::Vector3;
+use huginn::types::{Basis, Transform3D, AABB};
+use huginn::utils::float_consts::FRAC_PI_4;
+
+fn at(x: float!(), y: float!(), z: float!()) -> Transform3D {
+    Transform3D::IDENTITY.translated(&Vector3::new(x, y, z))
+}
+
+fn assert_near(actual: float!(), expected: float!()) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "Expected {expected}, got {actual}."
+    );
+}
+
+#[test]
+fn sphere_sphere() {
+    let sphere = Sphere::new(1.0);
+    let contact = sphere
+        .collide(&at(0.0, 0.0, 0.0), &sphere, &at(0.0, 0.0, 1.5))
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::BACK),
+        "Got the normal {}.",
+        contact.normal
+    );
+    assert_near(contact.depth, 0.5);
+    assert!(
+        contact.point.is_equal_approx(&Vector3::new(0.0, 0.0, 0.75)),
+        "The contact should be halfway through the overlap, got {}.",
+        contact.point
+    );
+
+    assert!(
+        !sphere.intersects(&at(0.0, 0.0, 0.0), &sphere, &at(3.0, 0.0, 0.0)),
+        "Distant spheres shouldn't intersect."
+    );
+    assert_near(
+        sphere.distance_to(&at(0.0, 0.0, 0.0), &sphere, &at(3.0, 0.0, 0.0)),
+        1.0,
+    );
+
+    // Concentric spheres still get a normal.
+    let concentric = sphere
+        .collide(&at(0.0, 0.0, 0.0), &sphere, &at(0.0, 0.0, 0.0))
+        .unwrap();
+    assert!(
+        concentric.normal.is_normalized(),
+        "Got the normal {}.",
+        concentric.normal
+    );
+    assert_near(concentric.depth, 2.0);
+}
+
+#[test]
+fn box_box() {
+    let cube = BoxShape3D::new(Vector3::new(2.0, 2.0, 2.0));
+    let contact = cube
+        .collide(&at(0.0, 0.0, 0.0), &cube, &at(1.8, 0.5, 0.3))
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::RIGHT),
+        "The smallest overlap is along X."
+    );
+    assert_near(contact.depth, 0.2);
+    assert!(
+        contact.point.x > 0.7 && contact.point.x < 1.1,
+        "The contact should be in the overlap, got {}.",
+        contact.point
+    );
+
+    assert!(
+        !cube.intersects(&at(0.0, 0.0, 0.0), &cube, &at(2.5, 0.0, 0.0)),
+        "Separate boxes shouldn't intersect."
+    );
+    let (a, b) = cube
+        .closest_points(&at(0.0, 0.0, 0.0), &cube, &at(2.5, 0.0, 0.0))
+        .unwrap();
+    assert_near(a.x, 1.0);
+    assert_near(b.x, 1.5);
+}
+
+#[test]
+fn rotated_box_corner() {
+    let cube = BoxShape3D::new(Vector3::new(2.0, 2.0, 2.0));
+    let rotated = Transform3D::new(
+        Basis::from_euler(&Vector3::new(0.0, 0.0, FRAC_PI_4), None),
+        Vector3::new(0.0, 2.3, 0.0),
+    );
+    let contact = cube
+        .collide(&Transform3D::IDENTITY, &cube, &rotated)
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::UP),
+        "Got the normal {}.",
+        contact.normal
+    );
+    assert_near(
+        contact.depth,
+        1.0 + huginn::utils::float_consts::SQRT_2 - 2.3,
+    );
+    assert_near(contact.point.x, 0.0);
+}
+
+#[test]
+fn sphere_inside_box() {
+    let cube = BoxShape3D::new(Vector3::new(4.0, 4.0, 4.0));
+    let contact = cube
+        .collide(&at(0.0, 0.0, 0.0), &Sphere::new(0.5), &at(0.0, 1.5, 0.2))
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::UP),
+        "The sphere is closest to the top face."
+    );
+    assert_near(contact.depth, 1.0);
+}
+
+#[test]
+fn capsules() {
+    let capsule = Capsule3D::new(0.5, 4.0);
+    // Crossing capsules have cores that intersect, with no volume between them.
+    let crossing = Transform3D::new(
+        Basis::from_euler(&Vector3::new(FRAC_PI_4 * 2.0, 0.0, 0.0), None),
+        Vector3::new(0.8, 0.0, 0.0),
+    );
+    let contact = capsule
+        .collide(&at(0.0, 0.0, 0.0), &capsule, &crossing)
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::RIGHT),
+        "Crossing capsules should be pushed apart sideways, got {}.",
+        contact.normal
+    );
+    assert_near(contact.depth, 0.2);
+
+    let stacked = capsule
+        .collide(&at(0.0, 0.0, 0.0), &capsule, &at(0.0, 3.5, 0.0))
+        .unwrap();
+    assert!(
+        stacked.normal.is_equal_approx(&Vector3::UP),
+        "Stacked capsules should be pushed apart vertically, got {}.",
+        stacked.normal
+    );
+    assert_near(stacked.depth, 0.5);
+    assert!(
+        stacked.point.is_equal_approx(&Vector3::new(0.0, 1.75, 0.0)),
+        "The caps should touch halfway through the overlap, got {}.",
+        stacked.point
+    );
+
+    assert_near(
+        capsule.distance_to(&at(0.0, 0.0, 0.0), &Sphere::new(1.0), &at(3.0, 1.0, 0.0)),
+        1.5,
+    );
+}
+
+#[test]
+fn convex_hull() {
+    let tetrahedron = ConvexHull3D::new(vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.1, 0.1, 0.1),
+    ]);
+    assert_eq!(
+        tetrahedron.get_support(&Vector3::ONE).length(),
+        1.0,
+        "The inner point shouldn't be a support point."
+    );
+    let sphere = Sphere::new(0.5);
+    assert_near(
+        tetrahedron.distance_to(&at(0.0, 0.0, 0.0), &sphere, &at(-2.0, 0.5, 0.5)),
+        1.5,
+    );
+
+    let contact = tetrahedron
+        .collide(&at(0.0, 0.0, 0.0), &sphere, &at(0.5, 0.5, -0.3))
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::FORWARD),
+        "Got the normal {}.",
+        contact.normal
+    );
+    assert_near(contact.depth, 0.2);
+}
+
+#[test]
+fn aabb_and_custom_shapes() {
+    /// A cylinder along Y, which the crate doesn't provide.
+    struct Cylinder {
+        radius: float!(),
+        height: float!(),
+    }
+
+    impl ConvexShape3D for Cylinder {
+        fn get_support(&self, direction: &Vector3) -> Vector3 {
+            let side = Vector3::new(direction.x, 0.0, direction.z).normalized() * self.radius;
+            let y = if direction.y < 0.0 {
+                -self.height * 0.5
+            } else {
+                self.height * 0.5
+            };
+            Vector3::new(side.x, y, side.z)
+        }
+    }
+
+    let cylinder = Cylinder {
+        radius: 1.0,
+        height: 2.0,
+    };
+    let floor = AABB::new(
+        Vector3::new(-10.0, -1.0, -10.0),
+        Vector3::new(20.0, 1.0, 20.0),
+    );
+    let contact = floor
+        .collide(&Transform3D::IDENTITY, &cylinder, &at(0.0, 0.9, 0.0))
+        .unwrap();
+    assert!(
+        contact.normal.is_equal_approx(&Vector3::UP),
+        "The cylinder should be pushed out of the floor, got {}.",
+        contact.normal
+    );
+    assert_near(contact.depth, 0.1);
+    assert_near(
+        floor.distance_to(&Transform3D::IDENTITY, &cylinder, &at(3.0, 1.5, 0.0)),
+        0.5,
+    );
+}
+
+#[test]
+fn collide_is_symmetric() {
+    let cube = BoxShape3D::new(Vector3::new(2.0, 1.0, 1.5));
+    let capsule = Capsule3D::new(0.4, 2.0);
+    let xa = Transform3D::IDENTITY;
+    let xb = Transform3D::new(
+        Basis::from_euler(&Vector3::new(0.3, 0.5, 0.2), None),
+        Vector3::new(1.1, 0.6, 0.2),
+    );
+    let forward = cube.collide(&xa, &capsule, &xb).unwrap();
+    let backward = capsule.collide(&xb, &cube, &xa).unwrap();
+    assert!(
+        forward.normal.is_equal_approx(&-backward.normal),
+        "Swapping the shapes should flip the normal."
+    );
+    assert_near(forward.depth, backward.depth);
+}
+
+#[test]
+fn shapes_as_trait_objects() {
+    let shapes: Vec<Box<dyn ConvexShape3D>> = vec![
+        Box::new(Sphere::new(1.0)),
+        Box::new(Capsule3D::new(0.5, 2.0)),
+        Box::new(BoxShape3D::new(Vector3::ONE * 2.0)),
+        Box::new(AABB::new(Vector3::ONE * -1.0, Vector3::ONE * 2.0)),
+    ];
+    for shape in &shapes {
+        for other in &shapes {
+            assert!(
+                shape.intersects(&at(0.0, 0.0, 0.0), other.as_ref(), &at(0.5, 0.0, 0.0)),
+                "Overlapping shapes should intersect through trait objects."
+            );
+            assert_near(
+                shape.distance_to(&at(0.0, 0.0, 0.0), other.as_ref(), &at(0.0, 0.0, 0.5)),
+                0.0,
+            );
+        }
+    }
+}
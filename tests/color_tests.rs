@@ -204,6 +204,15 @@ fn named_colors() {
     assert!(
         Color::named("doesn't exist", None).is_equal_approx(&Color::default()),
         "The invalid named color \"doesn't exist\" should result in a Color with the default values.");
+    assert!(
+        Color::named("light goldenrod yellow but much longer", Some(&Color::BLUE))
+            .is_equal_approx(&Color::BLUE),
+        "A name longer than any named color should result in the given default."
+    );
+    assert!(
+        Color::named("rëd", Some(&Color::BLUE)).is_equal_approx(&Color::BLUE),
+        "A non-ASCII name should result in the given default."
+    );
 }
 
 #[test]
@@ -0,0 +1,70 @@
+#![cfg(any(feature = "deterministic", not(feature = "std")))]
+
+use huginn::types::vectors::Vector3;
+use huginn::types::{Basis, Quaternion};
+
+// The expected bits were recorded once; they must be the same on every platform.
+
+#[cfg(not(feature = "double-precision-float"))]
+mod expected {
+    pub const QUATERNION_SLERP: [u32; 4] = [3204949955, 3189845019, 1060033277, 1056211780];
+    pub const BASIS_FROM_EULER: [u32; 9] = [
+        3202366496, 3210925276, 3189479670, 1060718850, 3195876138, 3206867827, 1057522344,
+        3200828341, 1061152139,
+    ];
+    pub const VECTOR3_SLERP: [u32; 3] = [3216642438, 1080720607, 1069047985];
+}
+
+#[cfg(feature = "double-precision-float")]
+mod expected {
+    pub const QUATERNION_SLERP: [u64; 4] = [
+        13826320203351897714,
+        13818210802459979016,
+        4604326297698039005,
+        4602274647022732016,
+    ];
+    pub const BASIS_FROM_EULER: [u64; 9] = [
+        13824933220105950032,
+        13829528179461792088,
+        13818014657550502546,
+        4604694362211577281,
+        13821448735634648842,
+        13827349853472430520,
+        4602978251391268010,
+        13824107429410740320,
+        4604926982465466295,
+    ];
+    pub const VECTOR3_SLERP: [u64; 3] = [13832597558636733826, 4615432724243462480, 4609166030953685855];
+}
+
+#[test]
+fn quaternion_slerp() {
+    let from = Quaternion::from_euler(&Vector3::new(0.3, -1.2, 2.5));
+    let to = Quaternion::from_euler(&Vector3::new(-0.8, 0.4, 0.1));
+    let q = from.slerp(&to, 0.3);
+    assert_eq!(
+        [q.x, q.y, q.z, q.w].map(|f| f.to_bits()),
+        expected::QUATERNION_SLERP,
+        "Quaternion::slerp should give the same bits on every platform."
+    );
+}
+
+#[test]
+fn basis_from_euler() {
+    let b = Basis::from_euler(&Vector3::new(0.7, -0.2, 1.9), None);
+    assert_eq!(
+        [b.x.x, b.x.y, b.x.z, b.y.x, b.y.y, b.y.z, b.z.x, b.z.y, b.z.z].map(|f| f.to_bits()),
+        expected::BASIS_FROM_EULER,
+        "Basis::from_euler should give the same bits on every platform."
+    );
+}
+
+#[test]
+fn vector3_slerp() {
+    let v = Vector3::new(1.0, 2.0, -3.0).slerp(&Vector3::new(-2.0, 0.5, 4.0), 0.6);
+    assert_eq!(
+        [v.x, v.y, v.z].map(|f| f.to_bits()),
+        expected::VECTOR3_SLERP,
+        "Vector3::slerp should give the same bits on every platform."
+    );
+}
@@ -0,0 +1,279 @@
+use huginn::types::fixed::{
+    Fixed, FixedBasis, FixedQuaternion, FixedTransform2D, FixedVector2, FixedVector3,
+};
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::{Basis, Quaternion, Transform2D};
+use huginn::utils::{float, float_consts};
+
+fn fixed(value: float!()) -> Fixed {
+    Fixed::from_float(value)
+}
+
+macro_rules! assert_fixed_approx {
+    ($x:expr, $y:expr, $tolerance:expr, $msg:expr) => {
+        assert!(
+            ($x.to_float() - $y).abs() < $tolerance,
+            "{} (got {}, expected {})",
+            $msg,
+            $x,
+            $y
+        );
+    };
+}
+
+#[test]
+fn scalar_arithmetic() {
+    let a = Fixed::from_int(3);
+    let b = Fixed::from_int(2);
+    assert_eq!(a + b, Fixed::from_int(5), "Addition should be exact.");
+    assert_eq!(a - b, Fixed::ONE, "Subtraction should be exact.");
+    assert_eq!(a * b, Fixed::from_int(6), "Multiplication should be exact.");
+    assert_eq!(a / b, fixed(1.5), "Division should be exact.");
+    assert_eq!(-a % b, -Fixed::ONE, "Remainder should keep the sign of the dividend.");
+    assert_eq!((-a).posmod(b), Fixed::ONE, "posmod should keep the sign of the module.");
+
+    assert_eq!(fixed(-1.5).floor(), Fixed::from_int(-2), "floor should round toward negative infinity.");
+    assert_eq!(fixed(-1.5).ceil(), Fixed::from_int(-1), "ceil should round toward positive infinity.");
+    assert_eq!(fixed(-1.5).round(), Fixed::from_int(-2), "round should round half-way away from zero.");
+    assert_eq!(fixed(-1.5).trunc(), Fixed::from_int(-1), "trunc should round toward zero.");
+    assert_eq!(fixed(-1.25).fract(), fixed(0.75), "fract should be in 0..1.");
+    assert_eq!(fixed(2.75).to_int(), 2, "to_int should floor.");
+
+    assert_eq!(Fixed::HALF + Fixed::HALF, Fixed::ONE, "Constants should be consistent.");
+    assert_eq!(Fixed::from_bits(Fixed::PI.to_bits()), Fixed::PI, "Bits should round-trip.");
+    assert!(Fixed::DELTA > Fixed::ZERO, "DELTA should be the smallest positive number.");
+}
+
+#[test]
+fn scalar_float_conversions() {
+    assert_eq!(fixed(0.25).to_float(), 0.25, "Exactly representable values should round-trip.");
+    assert!((Fixed::PI.to_float() - float_consts::PI).abs() < 1e-6, "PI should match the float constant.");
+    assert!((Fixed::TAU.to_float() - float_consts::TAU).abs() < 1e-6, "TAU should match the float constant.");
+    assert_eq!(Fixed::from(3), Fixed::from_int(3), "From an integer should be exact.");
+    let f: float!() = Fixed::from_int(-7).into();
+    assert_eq!(f, -7.0, "Into a float should be exact for integers.");
+}
+
+#[test]
+fn scalar_transcendental_functions() {
+    let tolerance = if cfg!(feature = "double-precision-float") { 1e-6 } else { 1e-5 };
+    for i in -40..=40 {
+        let x = i as float!() * 0.2;
+        let fx = fixed(x);
+        assert_fixed_approx!(fx.sin(), x.sin(), tolerance, "sin should match the float version.");
+        assert_fixed_approx!(fx.cos(), x.cos(), tolerance, "cos should match the float version.");
+        assert_fixed_approx!(fx.atan(), x.atan(), tolerance, "atan should match the float version.");
+        if x >= 0.0 {
+            assert_fixed_approx!(fx.sqrt(), x.sqrt(), tolerance, "sqrt should match the float version.");
+        }
+        if x.abs() <= 1.0 {
+            assert_fixed_approx!(fx.asin(), x.asin(), tolerance, "asin should match the float version.");
+            assert_fixed_approx!(fx.acos(), x.acos(), tolerance, "acos should match the float version.");
+        }
+        for j in [-3.0, -0.5, 0.0, 0.5, 3.0] {
+            assert_fixed_approx!(
+                fx.atan2(fixed(j)),
+                x.atan2(j),
+                tolerance,
+                "atan2 should match the float version."
+            );
+        }
+    }
+    assert_eq!(Fixed::from_int(-4).sqrt(), Fixed::ZERO, "sqrt of a negative number should be zero.");
+    assert_eq!(Fixed::from_int(16).sqrt(), Fixed::from_int(4), "sqrt of a perfect square should be exact.");
+}
+
+#[test]
+fn vector_operations() {
+    let a = FixedVector2::new(Fixed::from_int(3), Fixed::from_int(4));
+    assert_eq!(a.length(), Fixed::from_int(5), "length should be exact for a 3-4-5 triangle.");
+    assert!(a.normalized().is_normalized(), "normalized should return a unit vector.");
+    assert_eq!(a.dot(&FixedVector2::ONE), Fixed::from_int(7), "dot should be exact.");
+    assert_eq!(a.cross(&FixedVector2::RIGHT), Fixed::from_int(-4), "cross should be exact.");
+    assert!(
+        FixedVector2::RIGHT
+            .rotated(Fixed::FRAC_PI_2)
+            .is_equal_approx(&FixedVector2::DOWN),
+        "rotated should rotate toward +Y."
+    );
+
+    let b = FixedVector3::new(Fixed::ONE, Fixed::TWO, Fixed::from_int(3));
+    assert_eq!(
+        FixedVector3::RIGHT.cross(&FixedVector3::UP),
+        FixedVector3::BACK,
+        "cross should follow the right-hand rule."
+    );
+    assert_eq!(b.length_squared(), Fixed::from_int(14), "length_squared should be exact.");
+    assert_eq!(-b + b, FixedVector3::ZERO, "Negation should be exact.");
+
+    let float_vector = Vector3::new(0.5, -1.25, 8.0);
+    assert_eq!(
+        Vector3::from(FixedVector3::from(float_vector)),
+        float_vector,
+        "Exactly representable vectors should round-trip."
+    );
+    let float_vector = Vector2::new(0.5, -1.25);
+    assert_eq!(
+        Vector2::from(FixedVector2::from(float_vector)),
+        float_vector,
+        "Exactly representable vectors should round-trip."
+    );
+}
+
+#[test]
+fn rotations() {
+    let euler = FixedVector3::new(fixed(0.3), fixed(-0.7), fixed(1.1));
+    let quaternion = FixedQuaternion::from_euler(&euler);
+    assert!(quaternion.is_normalized(), "from_euler should return a unit quaternion.");
+    assert!(quaternion.get_euler(None).is_equal_approx(&euler), "Euler angles should round-trip through a quaternion.");
+
+    let basis = FixedBasis::from(&quaternion);
+    assert!(basis.is_rotation(), "A basis from a quaternion should be a rotation.");
+    assert!(basis.get_euler(None).is_equal_approx(&euler), "Euler angles should round-trip through a basis.");
+    assert!(
+        FixedQuaternion::from(&basis).is_equal_approx(&quaternion),
+        "A quaternion should round-trip through a basis."
+    );
+
+    let v = FixedVector3::new(Fixed::ONE, Fixed::TWO, Fixed::from_int(3));
+    assert!((quaternion * v).is_equal_approx(&(basis * v)), "The quaternion and the basis should rotate vectors the same way.");
+    assert!((basis.inverse() * (basis * v)).is_equal_approx(&v), "inverse should undo the rotation.");
+
+    let float_quaternion = Quaternion::from(quaternion);
+    let expected = Quaternion::from_euler(&Vector3::from(euler));
+    assert!(float_quaternion.is_equal_approx(&expected), "The fixed quaternion should match the float version.");
+
+    let half = FixedQuaternion::IDENTITY.slerp(&quaternion, Fixed::HALF);
+    assert!(
+        half.angle_to(&FixedQuaternion::IDENTITY)
+            .is_equal_approx(quaternion.angle_to(&FixedQuaternion::IDENTITY) * Fixed::HALF),
+        "slerp should interpolate the angle linearly."
+    );
+}
+
+#[test]
+fn transforms() {
+    let transform = FixedTransform2D::from((
+        fixed(0.5),
+        FixedVector2::new(Fixed::TWO, Fixed::from_int(3)),
+        Fixed::ZERO,
+        FixedVector2::new(Fixed::from_int(10), Fixed::from_int(-5)),
+    ));
+    let v = FixedVector2::new(fixed(1.5), fixed(-2.5));
+    assert!(
+        transform.affine_inverse().xform(&transform.xform(&v)).is_equal_approx(&v),
+        "affine_inverse should undo the transform."
+    );
+    assert!(transform.get_rotation().is_equal_approx(fixed(0.5)), "get_rotation should return the rotation.");
+    assert!(
+        transform
+            .get_scale()
+            .is_equal_approx(&FixedVector2::new(Fixed::TWO, Fixed::from_int(3))),
+        "get_scale should return the scale."
+    );
+
+    let rigid = FixedTransform2D::from((fixed(-1.2), FixedVector2::new(Fixed::ONE, Fixed::TWO)));
+    assert!((rigid.inverse() * rigid).is_equal_approx(&FixedTransform2D::IDENTITY), "inverse should undo a rigid transform.");
+    assert!(rigid.xform_inv(&rigid.xform(&v)).is_equal_approx(&v), "xform_inv should undo xform.");
+
+    let float_transform = Transform2D::from(transform);
+    let expected = Transform2D::from((0.5, Vector2::new(2.0, 3.0), 0.0, Vector2::new(10.0, -5.0)));
+    assert!(float_transform.is_equal_approx(&expected), "The fixed transform should match the float version.");
+}
+
+#[test]
+fn interpolation_matches_floats() {
+    let (a, b, pre, post) = (Vector3::new(1.0, 2.0, -1.0), Vector3::new(4.0, -2.0, 0.5), Vector3::ZERO, Vector3::ONE);
+    let [fa, fb, fpre, fpost] = [a, b, pre, post].map(FixedVector3::from);
+    let weight = fixed(0.3);
+    let cases = [
+        (fa.bezier_interpolate(&fb, &fpre, &fpost, weight), a.bezier_interpolate(&b, &pre, &post, 0.3), "bezier_interpolate"),
+        (fa.bezier_derivative(&fb, &fpre, &fpost, weight), a.bezier_derivative(&b, &pre, &post, 0.3), "bezier_derivative"),
+        (fa.cubic_interpolate(&fb, &fpre, &fpost, weight), a.cubic_interpolate(&b, &pre, &post, 0.3), "cubic_interpolate"),
+        (
+            fa.cubic_interpolate_in_time(&fb, &fpre, &fpost, weight, Fixed::ONE, -Fixed::HALF, Fixed::TWO),
+            a.cubic_interpolate_in_time(&b, &pre, &post, 0.3, 1.0, -0.5, 2.0),
+            "cubic_interpolate_in_time",
+        ),
+    ];
+    for (actual, expected, name) in cases {
+        assert!(Vector3::from(actual).is_equal_approx(&expected), "{name} should match the float version (got {actual}, expected {expected}).");
+    }
+
+    let v = FixedVector2::new(fixed(-2.5), fixed(7.25));
+    assert_eq!(v.clamp_f(-Fixed::ONE, Fixed::ONE), FixedVector2::new(-Fixed::ONE, Fixed::ONE), "clamp_f should clamp every component.");
+    assert_eq!(v.posmodv(&FixedVector2::new(Fixed::TWO, Fixed::from_int(3))), FixedVector2::new(fixed(1.5), fixed(1.25)), "posmodv should use each module.");
+    assert_eq!(v.snapped_f(Fixed::TWO), FixedVector2::new(Fixed::from_int(-2), Fixed::from_int(8)), "snapped_f should snap every component.");
+
+    let normal = FixedVector3::new(fixed(0.6), fixed(-0.8), Fixed::ZERO).normalized();
+    let decoded = FixedVector3::octahedron_decode(&normal.octahedron_encode());
+    assert!(decoded.is_equal_approx(&normal), "octahedron_decode should undo octahedron_encode (got {decoded}).");
+}
+
+#[test]
+fn quaternion_logarithms() {
+    let axis = FixedVector3::new(Fixed::ONE, Fixed::TWO, fixed(-0.5)).normalized();
+    let quaternion = FixedQuaternion::from((&axis, fixed(0.8)));
+    let log = quaternion.log();
+    assert_eq!(log.w, Fixed::ZERO, "log should have no real part.");
+    assert!(FixedVector3::new(log.x, log.y, log.z).is_equal_approx(&(axis * fixed(0.8))), "log should be the axis scaled by the angle.");
+    assert!(log.exp().is_equal_approx(&quaternion), "exp should undo log.");
+
+    let [from, to, pre, post] = [0.2, 1.0, -0.5, 1.5].map(|angle| FixedQuaternion::from((&axis, fixed(angle))));
+    let result = from.spherical_cubic_interpolate(&to, &pre, &post, Fixed::HALF);
+    let expected = Quaternion::from(from).spherical_cubic_interpolate(&to.into(), &pre.into(), &post.into(), 0.5);
+    assert!(Quaternion::from(result).is_equal_approx(&expected), "spherical_cubic_interpolate should match the float version.");
+    let result = from.spherical_cubic_interpolate_in_time(&to, &pre, &post, Fixed::HALF, Fixed::ONE, -Fixed::ONE, Fixed::TWO);
+    let expected = Quaternion::from(from).spherical_cubic_interpolate_in_time(&to.into(), &pre.into(), &post.into(), 0.5, 1.0, -1.0, 2.0);
+    assert!(Quaternion::from(result).is_equal_approx(&expected), "spherical_cubic_interpolate_in_time should match the float version.");
+}
+
+#[test]
+fn basis_decompositions() {
+    let axis = FixedVector3::new(Fixed::ONE, Fixed::ONE, Fixed::ZERO).normalized();
+    let rotation = FixedBasis::from((&axis, fixed(0.5)));
+    assert!(rotation.is_orthonormal(), "A rotation should be orthonormal.");
+    let (mut found_axis, mut angle) = (FixedVector3::ZERO, Fixed::ZERO);
+    rotation.get_axis_angle(&mut found_axis, &mut angle);
+    assert!(found_axis.is_equal_approx(&axis), "get_axis_angle should find the axis (got {found_axis}).");
+    assert!(angle.is_equal_approx(fixed(0.5)), "get_axis_angle should find the angle (got {angle}).");
+
+    let mut scaled = FixedBasis::IDENTITY;
+    let scale = FixedVector3::new(Fixed::TWO, Fixed::ONE, fixed(0.5));
+    scaled.set_quaternion_scale(&FixedQuaternion::from(&rotation), &scale);
+    let mut expected = Basis::IDENTITY;
+    expected.set_quaternion_scale(&Quaternion::from(FixedQuaternion::from(&rotation)), &Vector3::from(scale));
+    assert!(Basis::from(scaled).is_equal_approx(&expected), "set_quaternion_scale should match the float version.");
+    assert!(!scaled.is_orthonormal(), "A scaled basis shouldn't be orthonormal.");
+
+    let shear = FixedBasis::new_from_floats(
+        Fixed::TWO, Fixed::HALF, Fixed::ZERO,
+        Fixed::HALF, Fixed::ONE, Fixed::ZERO,
+        Fixed::ZERO, Fixed::ZERO, Fixed::from_int(3),
+    );
+    let (values, vectors) = shear.symmetric_eigen();
+    let (float_values, _) = Basis::from(shear).symmetric_eigen();
+    assert!(Vector3::from(values).is_equal_approx(&float_values), "symmetric_eigen should match the float version (got {values}).");
+    assert!(vectors.is_rotation(), "The eigenvectors should form a rotation.");
+
+    let (r, s) = (rotation * shear).polar_decompose();
+    assert!(r.is_equal_approx(&rotation), "polar_decompose should recover the rotation.");
+    assert!(s.is_equal_approx(&shear), "polar_decompose should recover the stretch.");
+    let (u, sigma, v) = shear.svd();
+    assert!((u * FixedBasis::from_scale(&sigma) * v.transposed()).is_equal_approx(&shear), "svd should recompose the basis.");
+}
+
+#[test]
+fn transform_skew() {
+    let mut transform = FixedTransform2D::new_from_floats(Fixed::TWO, Fixed::ZERO, Fixed::ZERO, Fixed::from_int(3), Fixed::ONE, Fixed::ONE);
+    transform.set_skew(fixed(0.25));
+    assert!(transform.get_skew().is_equal_approx(fixed(0.25)), "get_skew should return the skew that was set.");
+    assert!(transform.get_scale().is_equal_approx(&FixedVector2::new(Fixed::TWO, Fixed::from_int(3))), "set_skew should keep the scale.");
+}
+
+#[test]
+fn determinism() {
+    // The exact bits must not depend on the platform, so they are pinned here.
+    assert_eq!(Fixed::ONE.sin().to_bits(), 3614090361, "sin(1) should be within one unit of the exact value.");
+    assert_eq!(Fixed::TWO.sqrt().to_bits(), 6074000999, "sqrt(2) should be the exact value rounded down.");
+}
@@ -0,0 +1,143 @@
+use huginn::float;
+use huginn::types::linalg::MatrixN;
+use huginn::types::vectors::Vector3;
+use huginn::types::{Basis, Containment, Frustum, FrustumPlane, Plane, Transform3D, AABB};
+use huginn::utils::float_consts::FRAC_PI_2;
+
+fn cube(center: Vector3, half: float!()) -> AABB {
+    AABB::new(center - Vector3::ONE * half, Vector3::ONE * half * 2.0)
+}
+
+/// A camera at `(0, 0, 10)` looking toward the origin, with a 90° field of view.
+fn camera_frustum() -> Frustum {
+    let camera = Transform3D::IDENTITY.translated(&Vector3::new(0.0, 0.0, 10.0));
+    Frustum::perspective(&camera, FRAC_PI_2, 2.0, 1.0, 100.0)
+}
+
+#[test]
+fn perspective_planes() {
+    let frustum = camera_frustum();
+    let near = frustum.get_plane(FrustumPlane::Near);
+    assert!(near.normal.is_equal_approx(&Vector3::BACK), "The normals point outward.");
+    assert!(near.has_point(&Vector3::new(0.0, 0.0, 9.0), 1e-4));
+    assert!(frustum.get_plane(FrustumPlane::Far).has_point(&Vector3::new(0.0, 0.0, -90.0), 1e-4));
+
+    assert!(frustum.has_point(&Vector3::ZERO));
+    assert!(frustum.has_point(&Vector3::new(19.0, 0.0, -10.0)), "The view is twice as wide as it is high.");
+    assert!(!frustum.has_point(&Vector3::new(0.0, 21.0, -10.0)));
+    assert!(!frustum.has_point(&Vector3::new(0.0, 0.0, 9.5)), "Points before the near plane are outside.");
+    assert!(!frustum.has_point(&Vector3::new(0.0, 0.0, -95.0)));
+}
+
+#[test]
+fn spheres() {
+    let frustum = camera_frustum();
+    assert_eq!(frustum.test_sphere(&Vector3::ZERO, 1.0), Containment::Inside);
+    assert_eq!(frustum.test_sphere(&Vector3::new(0.0, 10.5, 0.0), 1.0), Containment::Intersecting);
+    assert_eq!(frustum.test_sphere(&Vector3::new(0.0, 0.0, 12.0), 1.0), Containment::Outside);
+    assert_eq!(frustum.test_sphere(&Vector3::ZERO, 500.0), Containment::Intersecting);
+}
+
+#[test]
+fn boxes() {
+    let frustum = camera_frustum();
+    assert_eq!(frustum.test_aabb(&cube(Vector3::ZERO, 1.0)), Containment::Inside);
+    assert_eq!(frustum.test_aabb(&cube(Vector3::new(0.0, 0.0, -90.0), 1.0)), Containment::Intersecting);
+    assert_eq!(frustum.test_aabb(&cube(Vector3::new(30.0, 0.0, 0.0), 1.0)), Containment::Outside);
+    assert_eq!(
+        frustum.test_aabb(&cube(Vector3::ZERO, 1000.0)),
+        Containment::Intersecting,
+        "A box around the whole frustum crosses every plane."
+    );
+}
+
+#[test]
+fn batches() {
+    let frustum = camera_frustum();
+    let points = [Vector3::ZERO, Vector3::new(0.0, 0.0, 20.0)];
+    let mut inside = [false; 2];
+    frustum.test_points(&points, &mut inside);
+    assert_eq!(inside, [true, false]);
+
+    let spheres = [(Vector3::ZERO, 1.0), (Vector3::new(0.0, 0.0, -90.0), 2.0), (Vector3::new(0.0, 0.0, 20.0), 2.0)];
+    let mut results = [Containment::Outside; 3];
+    frustum.test_spheres(&spheres, &mut results);
+    assert_eq!(results, [Containment::Inside, Containment::Intersecting, Containment::Outside]);
+
+    let boxes = spheres.map(|(center, radius)| cube(center, radius));
+    let mut results = [Containment::Inside; 3];
+    frustum.test_aabbs(&boxes, &mut results);
+    assert_eq!(results, [Containment::Inside, Containment::Intersecting, Containment::Outside]);
+}
+
+#[test]
+#[should_panic]
+fn batch_length_mismatch() {
+    camera_frustum().test_points(&[Vector3::ZERO], &mut []);
+}
+
+#[test]
+fn corners() {
+    let corners = camera_frustum().get_corners().unwrap();
+    let expected = [
+        Vector3::new(-2.0, 1.0, 9.0),
+        Vector3::new(2.0, 1.0, 9.0),
+        Vector3::new(2.0, -1.0, 9.0),
+        Vector3::new(-2.0, -1.0, 9.0),
+        Vector3::new(-200.0, 100.0, -90.0),
+        Vector3::new(200.0, 100.0, -90.0),
+        Vector3::new(200.0, -100.0, -90.0),
+        Vector3::new(-200.0, -100.0, -90.0),
+    ];
+    for (corner, expected) in corners.iter().zip(expected) {
+        assert!((*corner - expected).length() < 1e-3, "Expected {expected}, got {corner}.");
+    }
+
+    let parallel = Frustum::new([Plane::PLANE_XY; 6]);
+    assert!(parallel.get_corners().is_none());
+}
+
+#[test]
+fn orthogonal() {
+    let camera = Transform3D::new(
+        Basis::from_euler(&Vector3::new(0.0, FRAC_PI_2, 0.0), None),
+        Vector3::ZERO,
+    );
+    // Turned to the left, the camera looks down -X.
+    let frustum = Frustum::orthogonal(&camera, 4.0, 1.0, 1.0, 10.0);
+    assert!(frustum.has_point(&Vector3::new(-5.0, 1.9, 1.9)));
+    assert!(!frustum.has_point(&Vector3::new(-5.0, 2.1, 0.0)));
+    assert!(!frustum.has_point(&Vector3::new(5.0, 0.0, 0.0)));
+    assert_eq!(frustum.test_aabb(&cube(Vector3::new(-9.5, 0.0, 0.0), 1.0)), Containment::Intersecting);
+}
+
+#[test]
+fn from_view_projection() {
+    let camera = Transform3D::new(
+        Basis::from_euler(&Vector3::new(0.2, 0.5, 0.1), None),
+        Vector3::new(3.0, -1.0, 4.0),
+    );
+    let (fov_y, aspect, near, far): (float!(), float!(), float!(), float!()) = (1.1, 1.5, 0.5, 50.0);
+
+    let f = 1.0 / (fov_y * 0.5).tan();
+    let projection = MatrixN::<4>::new([
+        [f / aspect, 0.0, 0.0, 0.0],
+        [0.0, f, 0.0, 0.0],
+        [0.0, 0.0, (far + near) / (near - far), 2.0 * far * near / (near - far)],
+        [0.0, 0.0, -1.0, 0.0],
+    ]);
+    let view_xform = camera.affine_inverse();
+    let view = MatrixN::<4>::from_fn(|r, c| match (r, c) {
+        (3, 3) => 1.0,
+        (3, _) => 0.0,
+        (_, 3) => view_xform.origin.get(r),
+        _ => view_xform.basis.get_row(r).get(c),
+    });
+
+    let extracted = Frustum::from_view_projection(&(projection * view));
+    let built = Frustum::perspective(&camera, fov_y, aspect, near, far);
+    for (a, b) in extracted.planes().iter().zip(built.planes()) {
+        assert!((a.normal - b.normal).length() < 1e-4, "Expected {b}, got {a}.");
+        assert!((a.d - b.d).abs() < 1e-3, "Expected {b}, got {a}.");
+    }
+}
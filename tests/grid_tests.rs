@@ -0,0 +1,370 @@
+use huginn::types::grid::{Connectivity2D, Connectivity3D};
+use huginn::types::vectors::{Vector2i, Vector3i};
+use huginn::types::Rect2i;
+use std::collections::HashSet;
+
+#[test]
+fn rect_cells() {
+    let rect = Rect2i::new_from_dimension(-1, 2, 3, 2);
+    let cells: Vec<Vector2i> = rect.cells().collect();
+
+    assert_eq!(
+        cells,
+        vec![
+            Vector2i::new(-1, 2),
+            Vector2i::new(0, 2),
+            Vector2i::new(1, 2),
+            Vector2i::new(-1, 3),
+            Vector2i::new(0, 3),
+            Vector2i::new(1, 3),
+        ],
+        "cells() should walk the rectangle in row-major order."
+    );
+    assert_eq!(
+        rect.cells().len(),
+        rect.get_area() as usize,
+        "cells() should report the rectangle's area as its length."
+    );
+    assert!(
+        cells.iter().all(|c| rect.has_point(c)),
+        "Every cell should be inside the rectangle."
+    );
+    assert_eq!(
+        Rect2i::new_from_dimension(0, 0, 0, 5).cells().count(),
+        0,
+        "cells() should be empty for a rectangle without area."
+    );
+    assert_eq!(
+        Rect2i::new_from_dimension(0, 0, 5, -2).cells().count(),
+        0,
+        "cells() should be empty for a rectangle with negative size."
+    );
+}
+
+#[test]
+fn rect_border_cells() {
+    let rect = Rect2i::new_from_dimension(0, 0, 4, 3);
+    let border: Vec<Vector2i> = rect.border_cells().collect();
+
+    assert_eq!(
+        border,
+        vec![
+            Vector2i::new(0, 0),
+            Vector2i::new(1, 0),
+            Vector2i::new(2, 0),
+            Vector2i::new(3, 0),
+            Vector2i::new(3, 1),
+            Vector2i::new(3, 2),
+            Vector2i::new(2, 2),
+            Vector2i::new(1, 2),
+            Vector2i::new(0, 2),
+            Vector2i::new(0, 1),
+        ],
+        "border_cells() should walk the edge clockwise."
+    );
+    assert_eq!(
+        Rect2i::new_from_dimension(5, 5, 1, 3).border_cells().collect::<Vec<_>>(),
+        vec![Vector2i::new(5, 5), Vector2i::new(5, 6), Vector2i::new(5, 7)],
+        "border_cells() should not repeat cells on a single column."
+    );
+    assert_eq!(
+        Rect2i::new_from_dimension(5, 5, 3, 1).border_cells().len(),
+        3,
+        "border_cells() should not repeat cells on a single row."
+    );
+    assert_eq!(
+        Rect2i::new_from_dimension(5, 5, 1, 1).border_cells().collect::<Vec<_>>(),
+        vec![Vector2i::new(5, 5)],
+        "border_cells() of a single cell should be that cell."
+    );
+    assert_eq!(
+        Rect2i::new_from_dimension(5, 5, 0, 0).border_cells().count(),
+        0,
+        "border_cells() should be empty for a rectangle without area."
+    );
+}
+
+#[test]
+fn bresenham_line() {
+    let from = Vector2i::new(0, 0);
+
+    assert_eq!(
+        from.line_to(&Vector2i::new(4, 2)).collect::<Vec<_>>(),
+        vec![
+            Vector2i::new(0, 0),
+            Vector2i::new(1, 0),
+            Vector2i::new(2, 1),
+            Vector2i::new(3, 1),
+            Vector2i::new(4, 2),
+        ],
+        "line_to() should match the Bresenham algorithm."
+    );
+    assert_eq!(
+        from.line_to(&from).collect::<Vec<_>>(),
+        vec![from],
+        "A line to itself should only contain the starting cell."
+    );
+
+    for to in [
+        Vector2i::new(7, -3),
+        Vector2i::new(-5, -9),
+        Vector2i::new(-6, 2),
+        Vector2i::new(0, -4),
+    ] {
+        let line: Vec<Vector2i> = from.line_to(&to).collect();
+        assert_eq!(line.first(), Some(&from), "A line should start at `from`.");
+        assert_eq!(line.last(), Some(&to), "A line should end at `to`.");
+        assert_eq!(
+            line.len(),
+            (to - from).abs().x.max((to - from).abs().y) as usize + 1,
+            "A line should contain one cell per step along its major axis."
+        );
+        assert!(
+            line.windows(2).all(|w| {
+                let d = (w[1] - w[0]).abs();
+                d.x <= 1 && d.y <= 1
+            }),
+            "Consecutive line cells should be 8-connected."
+        );
+    }
+}
+
+#[test]
+fn supercover_line() {
+    let from = Vector2i::new(0, 0);
+
+    assert_eq!(
+        from.supercover_line_to(&Vector2i::new(2, 2)).collect::<Vec<_>>(),
+        vec![
+            Vector2i::new(0, 0),
+            Vector2i::new(1, 0),
+            Vector2i::new(0, 1),
+            Vector2i::new(1, 1),
+            Vector2i::new(2, 1),
+            Vector2i::new(1, 2),
+            Vector2i::new(2, 2),
+        ],
+        "supercover_line_to() should cover both cells around a crossed corner."
+    );
+
+    let to = Vector2i::new(-5, 2);
+    let line: Vec<Vector2i> = from.supercover_line_to(&to).collect();
+    assert_eq!(line.first(), Some(&from), "A supercover line should start at `from`.");
+    assert_eq!(line.last(), Some(&to), "A supercover line should end at `to`.");
+    assert_eq!(
+        line.len(),
+        8,
+        "A supercover line without corner crossings should take one step per unit on each axis."
+    );
+    assert!(
+        line.windows(2).all(|w| (w[1] - w[0]).abs().length_squared() == 1),
+        "Consecutive supercover cells should share an edge."
+    );
+}
+
+#[test]
+fn supercover_line_through_a_corner() {
+    // The segment goes exactly through the corner between (1, 0), (2, 0), (1, 1) and (2, 1).
+    let line: Vec<Vector2i> = Vector2i::new(0, 0).supercover_line_to(&Vector2i::new(3, 1)).collect();
+    assert_eq!(
+        line,
+        vec![
+            Vector2i::new(0, 0),
+            Vector2i::new(1, 0),
+            Vector2i::new(2, 0),
+            Vector2i::new(1, 1),
+            Vector2i::new(2, 1),
+            Vector2i::new(3, 1),
+        ],
+        "supercover_line_to() should yield both cells around the corner before the diagonal one."
+    );
+    assert_eq!(
+        line.windows(2).filter(|w| (w[1] - w[0]).abs() == Vector2i::ONE).count(),
+        1,
+        "Only the two cells around the corner should touch diagonally."
+    );
+    assert!(
+        line.iter()
+            .enumerate()
+            .skip(1)
+            .all(|(i, cell)| line[..i].iter().any(|other| (*cell - *other).abs().length_squared() == 1)),
+        "Every supercover cell should share an edge with an earlier one."
+    );
+}
+
+#[test]
+fn circle_cells() {
+    let center = Vector2i::new(3, -2);
+
+    assert_eq!(
+        center.circle_cells(0).collect::<Vec<_>>(),
+        vec![center],
+        "A circle of radius 0 should only contain its center."
+    );
+    assert_eq!(
+        center.circle_cells(-1).count(),
+        0,
+        "A circle of negative radius should be empty."
+    );
+
+    for radius in 1..12 {
+        let cells: Vec<Vector2i> = center.circle_cells(radius).collect();
+        let unique: HashSet<(i64, i64)> = cells.iter().map(|c| (c.x as i64, c.y as i64)).collect();
+        assert_eq!(
+            cells.len(),
+            unique.len(),
+            "circle_cells() should not yield the same cell twice."
+        );
+        for cell in &cells {
+            let d = *cell - center;
+            let distance = (d.length_squared() as f64).sqrt();
+            assert!(
+                (distance - radius as f64).abs() < 1.0,
+                "Every outline cell should lie within one cell of the radius."
+            );
+            assert!(
+                unique.contains(&((center.x - d.x) as i64, (center.y - d.y) as i64)),
+                "The outline should be point-symmetric around its center."
+            );
+        }
+    }
+}
+
+#[test]
+fn disk_cells() {
+    let center = Vector2i::new(-4, 7);
+
+    assert_eq!(
+        center.disk_cells(0).collect::<Vec<_>>(),
+        vec![center],
+        "A disk of radius 0 should only contain its center."
+    );
+    assert_eq!(
+        center.disk_cells(-3).count(),
+        0,
+        "A disk of negative radius should be empty."
+    );
+    assert_eq!(
+        center.disk_cells(1).count(),
+        9,
+        "A disk of radius 1 should cover a 3x3 square."
+    );
+
+    for radius in 1..12 {
+        let disk: HashSet<(i64, i64)> = center
+            .disk_cells(radius)
+            .map(|c| (c.x as i64, c.y as i64))
+            .collect();
+        assert_eq!(
+            disk.len(),
+            center.disk_cells(radius).count(),
+            "disk_cells() should not yield the same cell twice."
+        );
+        assert!(
+            center
+                .circle_cells(radius)
+                .all(|c| disk.contains(&(c.x as i64, c.y as i64))),
+            "The filled disk should contain its outline."
+        );
+    }
+}
+
+#[test]
+fn box_cells() {
+    let position = Vector3i::new(1, -1, 0);
+    let cells: Vec<Vector3i> = position.box_cells(&Vector3i::new(2, 2, 2)).collect();
+
+    assert_eq!(
+        cells,
+        vec![
+            Vector3i::new(1, -1, 0),
+            Vector3i::new(2, -1, 0),
+            Vector3i::new(1, 0, 0),
+            Vector3i::new(2, 0, 0),
+            Vector3i::new(1, -1, 1),
+            Vector3i::new(2, -1, 1),
+            Vector3i::new(1, 0, 1),
+            Vector3i::new(2, 0, 1),
+        ],
+        "box_cells() should vary X fastest, then Y, then Z."
+    );
+    assert_eq!(
+        position.box_cells(&Vector3i::new(3, 4, 5)).len(),
+        60,
+        "box_cells() should report the box's volume as its length."
+    );
+    assert_eq!(
+        position.box_cells(&Vector3i::new(3, 0, 5)).count(),
+        0,
+        "box_cells() should be empty for a box without volume."
+    );
+}
+
+#[test]
+fn neighbors_2d() {
+    let cell = Vector2i::new(3, 4);
+    let four: Vec<Vector2i> = cell.neighbors(Connectivity2D::Four).collect();
+    assert_eq!(
+        four,
+        vec![
+            Vector2i::new(4, 4),
+            Vector2i::new(3, 5),
+            Vector2i::new(2, 4),
+            Vector2i::new(3, 3),
+        ],
+        "The 4 neighbors should be the sides, clockwise from the right."
+    );
+    let eight: Vec<Vector2i> = cell.neighbors(Connectivity2D::Eight).collect();
+    assert_eq!(eight.len(), 8, "There should be 8 neighbors.");
+    assert_eq!(eight[..4], four[..], "The 8 neighbors should start with the 4 sides.");
+    assert!(
+        eight.iter().all(|n| n.chebyshev_distance_to(&cell) == 1),
+        "Every neighbor should be one step away."
+    );
+    assert_eq!(
+        eight.iter().collect::<HashSet<_>>().len(),
+        8,
+        "Every neighbor should be yielded once."
+    );
+
+    let bounds = Rect2i::new_from_dimension(0, 0, 4, 4);
+    let corner: Vec<Vector2i> = Vector2i::new(0, 0).neighbors_in(Connectivity2D::Eight, &bounds).collect();
+    assert_eq!(
+        corner,
+        vec![Vector2i::new(1, 0), Vector2i::new(0, 1), Vector2i::new(1, 1)],
+        "neighbors_in should skip the cells outside of the bounds."
+    );
+    assert_eq!(
+        Vector2i::new(3, 4).neighbors_in(Connectivity2D::Four, &bounds).collect::<Vec<_>>(),
+        vec![Vector2i::new(3, 3)],
+        "neighbors_in should work for a cell outside of the bounds."
+    );
+}
+
+#[test]
+fn neighbors_3d() {
+    let cell = Vector3i::new(-1, 2, 5);
+    for (connectivity, count, distance) in [
+        (Connectivity3D::Six, 6, 1),
+        (Connectivity3D::Eighteen, 18, 2),
+        (Connectivity3D::TwentySix, 26, 3),
+    ] {
+        let neighbors: HashSet<Vector3i> = cell.neighbors(connectivity).collect();
+        assert_eq!(cell.neighbors(connectivity).len(), count, "The neighbor count should match the connectivity.");
+        assert_eq!(neighbors.len(), count, "Every neighbor should be yielded once.");
+        assert!(
+            neighbors.iter().all(|n| n.chebyshev_distance_to(&cell) == 1),
+            "Every neighbor should touch the cell."
+        );
+        assert!(
+            neighbors.iter().all(|n| n.manhattan_distance_to(&cell) <= distance),
+            "The neighbors should share a face, an edge or a corner depending on the connectivity."
+        );
+    }
+    assert_eq!(
+        Connectivity3D::Eighteen.offsets()[..6],
+        Connectivity3D::Six.offsets()[..],
+        "The offsets should start with the faces."
+    );
+}
+
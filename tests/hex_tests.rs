@@ -0,0 +1,302 @@
+use huginn::types::hex::{Hex, HexLayout, HexOffset, HexOrientation};
+use huginn::types::vectors::{Vector2, Vector2i, Vector3, Vector3i};
+use std::collections::HashSet;
+
+#[test]
+fn coordinates() {
+    let hex = Hex::new(3, -5);
+
+    assert_eq!(hex.s(), 2, "s should be -q - r.");
+    assert_eq!(
+        hex.axial(),
+        Vector2i::new(3, -5),
+        "axial() should return (q, r)."
+    );
+    assert_eq!(
+        hex.cube(),
+        Vector3i::new(3, -5, 2),
+        "cube() should return (q, r, s)."
+    );
+    assert_eq!(
+        Hex::from_cube(&Vector3i::new(3, -5, 2)),
+        Some(hex),
+        "from_cube() should accept coordinates adding up to 0."
+    );
+    assert_eq!(
+        Hex::from_cube(&Vector3i::new(3, -5, 1)),
+        None,
+        "from_cube() should reject coordinates not adding up to 0."
+    );
+    assert_eq!(
+        Hex::from(Vector2i::new(3, -5)),
+        hex,
+        "Hex should convert from axial Vector2i."
+    );
+    assert_eq!(
+        hex + Hex::new(1, 1),
+        Hex::new(4, -4),
+        "Hexes should add component-wise."
+    );
+    assert_eq!(
+        hex * 2,
+        Hex::new(6, -10),
+        "Hexes should scale component-wise."
+    );
+    assert_eq!(
+        format!("{}", hex),
+        "Hex(3, -5)",
+        "Hex should display its axial coordinates."
+    );
+}
+
+#[test]
+fn neighbors_and_distance() {
+    let center = Hex::new(2, -1);
+
+    for (i, neighbor) in center.neighbors().iter().enumerate() {
+        assert_eq!(
+            center.distance_to(neighbor),
+            1,
+            "Every neighbor should be one step away."
+        );
+        assert_eq!(
+            center.neighbor(i as _),
+            *neighbor,
+            "neighbor() should match neighbors()."
+        );
+    }
+    for diagonal in center.diagonals() {
+        assert_eq!(
+            center.distance_to(&diagonal),
+            2,
+            "Every diagonal should be two steps away."
+        );
+    }
+    assert_eq!(
+        Hex::direction(-1),
+        Hex::direction(5),
+        "Directions should wrap around."
+    );
+    assert_eq!(
+        Hex::new(0, 0).distance_to(&Hex::new(3, -7)),
+        7,
+        "distance_to() should be the largest absolute cube difference."
+    );
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(
+        Hex::round(&Vector3::new(0.9, -0.1, -0.8)),
+        Hex::new(1, 0),
+        "round() should find the closest hex."
+    );
+    assert_eq!(
+        Hex::round(&Vector3::new(0.4, 0.4, -0.8)),
+        Hex::new(0, 1),
+        "round() should fix the component with the largest error."
+    );
+}
+
+#[test]
+fn offsets() {
+    let layouts = [
+        HexOffset::OddR,
+        HexOffset::EvenR,
+        HexOffset::OddQ,
+        HexOffset::EvenQ,
+    ];
+    for hex in Hex::ZERO.range(4) {
+        for layout in layouts {
+            assert_eq!(
+                Hex::from_offset(&hex.to_offset(layout), layout),
+                hex,
+                "Offset coordinates should round-trip."
+            );
+        }
+    }
+    assert_eq!(
+        Hex::new(-1, 1).to_offset(HexOffset::OddR),
+        Vector2i::new(-1, 1),
+        "odd-r should shift odd rows."
+    );
+    assert_eq!(
+        Hex::new(-1, 1).to_offset(HexOffset::EvenR),
+        Vector2i::new(0, 1),
+        "even-r should shift even rows."
+    );
+    assert_eq!(
+        Hex::new(1, -1).to_offset(HexOffset::OddQ),
+        Vector2i::new(1, -1),
+        "odd-q should shift odd columns."
+    );
+    assert_eq!(
+        Hex::new(1, -1).to_offset(HexOffset::EvenQ),
+        Vector2i::new(1, 0),
+        "even-q should shift even columns."
+    );
+}
+
+#[test]
+fn layout() {
+    let pointy = HexLayout::new(
+        HexOrientation::PointyTop,
+        Vector2::new(10.0, 10.0),
+        Vector2::new(100.0, 50.0),
+    );
+    let flat = HexLayout::new(
+        HexOrientation::FlatTop,
+        Vector2::new(8.0, 12.0),
+        Vector2::ZERO,
+    );
+
+    assert_eq!(
+        pointy.hex_to_world(&Hex::ZERO),
+        Vector2::new(100.0, 50.0),
+        "The origin hex should be centered on the layout's origin."
+    );
+    assert!(
+        pointy
+            .hex_to_world(&Hex::new(1, 0))
+            .is_equal_approx(&Vector2::new(117.320_51, 50.0)),
+        "Pointy-top +q should be to the right."
+    );
+    assert!(
+        flat.hex_to_world(&Hex::new(1, 0))
+            .is_equal_approx(&Vector2::new(12.0, 10.392_305)),
+        "Flat-top +q should be down-right."
+    );
+    for layout in [pointy, flat] {
+        for hex in Hex::new(-3, 2).spiral(3) {
+            let world = layout.hex_to_world(&hex);
+            assert_eq!(
+                layout.world_to_hex(&world),
+                hex,
+                "World positions should round-trip."
+            );
+            for corner in layout.corners(&hex) {
+                let inside = world + (corner - world) * 0.9;
+                assert_eq!(
+                    layout.world_to_hex(&inside),
+                    hex,
+                    "Points near the corners should be inside the hex."
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn lines() {
+    let from = Hex::new(-2, 1);
+    let to = Hex::new(3, -4);
+    let line: Vec<Hex> = from.line_to(&to).collect();
+
+    assert_eq!(line.len(), 6, "A line should contain distance + 1 hexes.");
+    assert_eq!(line.first(), Some(&from), "A line should start at `from`.");
+    assert_eq!(line.last(), Some(&to), "A line should end at `to`.");
+    assert!(
+        line.windows(2).all(|w| w[0].distance_to(&w[1]) == 1),
+        "Consecutive line hexes should be neighbors."
+    );
+    assert_eq!(
+        from.line_to(&from).collect::<Vec<_>>(),
+        vec![from],
+        "A line to itself should only contain the starting hex."
+    );
+}
+
+#[test]
+fn rings_spirals_ranges() {
+    let center = Hex::new(1, 2);
+
+    assert_eq!(
+        center.ring(0).collect::<Vec<_>>(),
+        vec![center],
+        "A ring of radius 0 is its center."
+    );
+    assert_eq!(
+        center.ring(-1).count(),
+        0,
+        "A ring of negative radius should be empty."
+    );
+    for radius in 1..5 {
+        let ring: Vec<Hex> = center.ring(radius).collect();
+        assert_eq!(
+            ring.len(),
+            6 * radius as usize,
+            "A ring should contain 6 * radius hexes."
+        );
+        assert!(
+            ring.iter().all(|h| center.distance_to(h) == radius),
+            "Every ring hex should be at the ring's radius."
+        );
+        assert!(
+            ring.windows(2).all(|w| w[0].distance_to(&w[1]) == 1),
+            "Consecutive ring hexes should be neighbors."
+        );
+    }
+
+    for radius in 0..5 {
+        let spiral: Vec<Hex> = center.spiral(radius).collect();
+        let range: HashSet<Hex> = center.range(radius).collect();
+        let expected = (3 * radius * (radius + 1) + 1) as usize;
+        assert_eq!(
+            spiral.len(),
+            expected,
+            "A spiral should contain every hex within its radius."
+        );
+        assert_eq!(
+            center.spiral(radius).len(),
+            expected,
+            "spiral() should report its length."
+        );
+        assert_eq!(
+            range.len(),
+            expected,
+            "A range should contain every hex within its radius."
+        );
+        assert_eq!(spiral[0], center, "A spiral should start at its center.");
+        assert!(
+            spiral.iter().all(|h| range.contains(h)),
+            "spiral() and range() should yield the same hexes."
+        );
+    }
+    assert_eq!(
+        center.spiral(-1).count(),
+        0,
+        "A spiral of negative radius should be empty."
+    );
+    assert_eq!(
+        center.range(-1).count(),
+        0,
+        "A range of negative radius should be empty."
+    );
+}
+
+#[test]
+fn rotation() {
+    let center = Hex::new(1, 1);
+    let hex = center + Hex::direction(0) * 2;
+
+    assert_eq!(
+        hex.rotated(&center, 1),
+        center + Hex::direction(5) * 2,
+        "A positive turn should rotate clockwise."
+    );
+    assert_eq!(
+        hex.rotated(&center, -1),
+        center + Hex::direction(1) * 2,
+        "A negative turn should rotate counter-clockwise."
+    );
+    assert_eq!(
+        hex.rotated(&center, 6),
+        hex,
+        "Six turns should be a full rotation."
+    );
+    assert_eq!(
+        hex.rotated(&center, 3),
+        center - Hex::direction(0) * 2,
+        "Three turns should mirror through the center."
+    );
+}
@@ -0,0 +1,178 @@
+#![cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::{Basis, Quaternion, Transform2D, Transform3D};
+
+fn transform2d() -> Transform2D {
+    Transform2D::new(
+        Vector2::new(1.0, 2.0),
+        Vector2::new(-3.0, 0.5),
+        Vector2::new(4.0, -5.0),
+    )
+}
+
+fn transform3d() -> Transform3D {
+    Transform3D::new(
+        Basis::new(
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(0.0, 1.0, -1.0),
+            Vector3::new(2.0, 0.0, 3.0),
+        ),
+        Vector3::new(4.0, -5.0, 6.0),
+    )
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam() {
+    use huginn::types::vectors::{Vector3i, Vector4};
+    #[cfg(not(feature = "double-precision-float"))]
+    use glam::{Affine2, Affine3A as Affine3, Mat3, Quat, Vec4};
+    #[cfg(feature = "double-precision-float")]
+    use glam::{
+        DAffine2 as Affine2, DAffine3 as Affine3, DMat3 as Mat3, DQuat as Quat, DVec4 as Vec4,
+    };
+    #[cfg(not(feature = "double-precision-int"))]
+    use glam::IVec3;
+    #[cfg(feature = "double-precision-int")]
+    use glam::I64Vec3 as IVec3;
+
+    let point = Vector3::new(1.0, -2.0, 3.0);
+    let affine = Affine3::from(transform3d());
+    assert_eq!(
+        Vector3::from(affine.transform_point3(point.into())),
+        transform3d() * point,
+        "A glam affine transform should transform points like Transform3D."
+    );
+    assert_eq!(
+        Transform3D::from(affine),
+        transform3d(),
+        "Transform3D should round-trip through glam."
+    );
+
+    let affine = Affine2::from(transform2d());
+    assert_eq!(
+        Vector2::from(affine.transform_point2(Vector2::new(2.0, 3.0).into())),
+        transform2d() * Vector2::new(2.0, 3.0),
+        "A glam affine transform should transform points like Transform2D."
+    );
+    assert_eq!(
+        Transform2D::from(affine),
+        transform2d(),
+        "Transform2D should round-trip through glam."
+    );
+
+    let basis = transform3d().basis;
+    assert_eq!(
+        Vector3::from(Mat3::from(basis).mul_vec3(point.into())),
+        basis.xform(&point),
+        "A glam matrix should transform vectors like Basis."
+    );
+
+    let quaternion = Quaternion::new(0.0, 0.6, 0.0, 0.8);
+    assert!(
+        Vector3::from(Quat::from(quaternion).mul_vec3(point.into()))
+            .is_equal_approx(&quaternion.xform(&point)),
+        "A glam quaternion should rotate vectors like Quaternion."
+    );
+    assert_eq!(
+        Vec4::from(Vector4::new(1.0, 2.0, 3.0, 4.0)).w,
+        4.0,
+        "Vector4 components should map by name."
+    );
+    assert_eq!(
+        Vector3i::from(IVec3::new(1, 2, 3)),
+        Vector3i::new(1, 2, 3),
+        "Vector3i should convert from glam."
+    );
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra() {
+    use huginn::float;
+
+    let point = Vector3::new(1.0, -2.0, 3.0);
+    let matrix = nalgebra::Matrix4::<float!()>::from(transform3d());
+    assert_eq!(
+        Vector3::from(matrix.transform_point(&point.into())),
+        transform3d() * point,
+        "A nalgebra homogeneous matrix should transform points like Transform3D."
+    );
+    assert_eq!(
+        Transform3D::from(nalgebra::Affine3::<float!()>::from(transform3d())),
+        transform3d(),
+        "Transform3D should round-trip through nalgebra."
+    );
+
+    let matrix = nalgebra::Matrix3::<float!()>::from(transform2d());
+    assert_eq!(
+        Vector2::from(matrix.transform_point(&Vector2::new(2.0, 3.0).into())),
+        transform2d() * Vector2::new(2.0, 3.0),
+        "A nalgebra homogeneous matrix should transform points like Transform2D."
+    );
+    assert_eq!(
+        Transform2D::from(matrix),
+        transform2d(),
+        "Transform2D should round-trip through nalgebra."
+    );
+
+    let basis = transform3d().basis;
+    assert_eq!(
+        Vector3::from(
+            nalgebra::Matrix3::<float!()>::from(basis) * nalgebra::Vector3::from(point)
+        ),
+        basis.xform(&point),
+        "A nalgebra matrix should transform vectors like Basis."
+    );
+
+    let quaternion = Quaternion::new(0.0, 0.6, 0.0, 0.8);
+    let unit = nalgebra::UnitQuaternion::<float!()>::from(quaternion);
+    assert!(
+        Vector3::from(unit * nalgebra::Vector3::from(point))
+            .is_equal_approx(&quaternion.xform(&point)),
+        "A nalgebra quaternion should rotate vectors like Quaternion."
+    );
+    assert_eq!(
+        Quaternion::from(unit),
+        quaternion,
+        "Quaternion should round-trip through nalgebra."
+    );
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint() {
+    use huginn::types::vectors::Vector3i;
+    use huginn::{float, int};
+
+    let columns = mint::ColumnMatrix3x4::<float!()>::from(transform3d());
+    assert_eq!(
+        Vector3::from(columns.w),
+        transform3d().origin,
+        "The last mint column should be the origin."
+    );
+    assert_eq!(
+        Vector3::from(columns.x),
+        transform3d().basis.x(),
+        "The mint columns should be the basis columns."
+    );
+    assert_eq!(
+        Transform3D::from(columns),
+        transform3d(),
+        "Transform3D should round-trip through mint."
+    );
+    assert_eq!(
+        Transform2D::from(mint::ColumnMatrix2x3::<float!()>::from(transform2d())),
+        transform2d(),
+        "Transform2D should round-trip through mint."
+    );
+
+    let quaternion = mint::Quaternion::<float!()>::from(Quaternion::new(0.0, 0.6, 0.0, 0.8));
+    assert_eq!(quaternion.s, 0.8, "The mint scalar part should be w.");
+    assert_eq!(
+        Vector3i::from(mint::Point3::<int!()> { x: 1, y: 2, z: 3 }),
+        Vector3i::new(1, 2, 3),
+        "Vector3i should convert from a mint point."
+    );
+}
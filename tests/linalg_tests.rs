@@ -0,0 +1,236 @@
+use huginn::types::linalg::{fit_circle, fit_line, fit_plane, LeastSquares, Matrix, MatrixN};
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::Basis;
+
+fn assert_slice_approx(
+    a: &[huginn::float!()],
+    b: &[huginn::float!()],
+    tolerance: huginn::float!(),
+    msg: &str,
+) {
+    assert_eq!(a.len(), b.len(), "{}", msg);
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).abs() < tolerance, "{} ({:?} != {:?})", msg, a, b);
+    }
+}
+
+fn system() -> MatrixN<4> {
+    Matrix::new([
+        [4.0, -2.0, 1.0, 0.5],
+        [3.0, 6.0, -4.0, 2.0],
+        [2.0, 1.0, 8.0, -1.0],
+        [-1.0, 0.5, 2.0, 5.0],
+    ])
+}
+
+#[test]
+fn matrix_basics() {
+    let a = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let b = Matrix::new([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+    assert_eq!(
+        a * b,
+        Matrix::new([[4.0, 5.0], [10.0, 11.0]]),
+        "Matrix multiplication should combine rows and columns."
+    );
+    assert_eq!(
+        a.transposed(),
+        Matrix::new([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]),
+        "transposed() should swap rows and columns."
+    );
+    assert_eq!(a.mul_vector(&[1.0, 1.0, 1.0]), [6.0, 15.0], "mul_vector() should sum each row.");
+    assert_eq!(a[(1, 2)], 6.0, "Indexing should use (row, column).");
+    assert_eq!(a.column(1), [2.0, 5.0], "column() should return a column.");
+    assert_eq!(
+        MatrixN::<3>::identity() * b,
+        b,
+        "Multiplying by the identity should not change a matrix."
+    );
+    assert_eq!(
+        a.to_string(),
+        "[(1, 2, 3), (4, 5, 6)]",
+        "The string representation should match the expected value."
+    );
+
+    let basis = Basis::from_euler(&Vector3::new(0.1, 0.2, 0.3), None);
+    assert_eq!(
+        Basis::from(Matrix::from(basis)),
+        basis,
+        "Converting a Basis to a Matrix and back should be lossless."
+    );
+    assert!(
+        (Matrix::from(basis).determinant() - basis.determinant()).abs() < 1e-5,
+        "determinant() should match the Basis determinant."
+    );
+}
+
+#[test]
+fn lu() {
+    let a = system();
+    let x = [1.0, -2.0, 0.5, 3.0];
+    let b = a.mul_vector(&x);
+
+    let solved = a.solve(&b).expect("The system should be solvable.");
+    assert_slice_approx(&solved, &x, 1e-4, "solve() should recover x.");
+    assert!(
+        (a * a.inverse().unwrap()).is_equal_approx(&MatrixN::<4>::identity()),
+        "A matrix times its inverse should be the identity."
+    );
+
+    let singular = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    assert!(singular.lu().is_none(), "A singular matrix should have no LU decomposition.");
+    assert_eq!(singular.determinant(), 0.0, "A singular matrix should have a zero determinant.");
+
+    let permuted = Matrix::new([[0.0, 1.0], [1.0, 0.0]]);
+    assert_eq!(
+        permuted.determinant(),
+        -1.0,
+        "Pivoting should be reflected in the determinant's sign."
+    );
+}
+
+#[test]
+fn cholesky() {
+    let a = system();
+    let spd = a.transposed() * a;
+    let x = [0.25, 1.0, -1.5, 2.0];
+    let b = spd.mul_vector(&x);
+
+    let cholesky = spd.cholesky().expect("AᵀA should be positive-definite.");
+    assert_slice_approx(
+        &cholesky.solve(&b),
+        &x,
+        1e-3,
+        "Cholesky solve() should recover x.",
+    );
+    assert!(
+        (cholesky.l() * cholesky.l().transposed()).is_equal_approx(&spd),
+        "L * Lᵀ should recompose into the original matrix."
+    );
+    assert!(
+        Matrix::new([[1.0, 2.0], [2.0, 1.0]]).cholesky().is_none(),
+        "An indefinite matrix should have no Cholesky decomposition."
+    );
+}
+
+#[test]
+fn qr() {
+    let a = Matrix::new([
+        [1.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0],
+        [2.0, -1.0, 0.5],
+    ]);
+    let qr = a.qr();
+
+    assert!(
+        (qr.q() * qr.r()).is_equal_approx(&a),
+        "Q * R should recompose into the original matrix."
+    );
+    assert!(
+        (qr.q().transposed() * qr.q()).is_equal_approx(&MatrixN::<3>::identity()),
+        "The columns of Q should be orthonormal."
+    );
+
+    let x = [2.0, -1.0, 0.5];
+    let b = a.mul_vector(&x);
+    assert_slice_approx(
+        &a.solve_least_squares(&b).unwrap(),
+        &x,
+        1e-4,
+        "A consistent overdetermined system should be solved exactly.",
+    );
+
+    // The least-squares residual should be orthogonal to the columns of A.
+    let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let x = a.solve_least_squares(&b).unwrap();
+    let ax = a.mul_vector(&x);
+    let residual: [huginn::float!(); 5] = std::array::from_fn(|i| ax[i] - b[i]);
+    let normal = a.transposed().mul_vector(&residual);
+    assert_slice_approx(&normal, &[0.0; 3], 1e-4, "The residual should be orthogonal to A.");
+
+    let dependent = Matrix::new([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    assert!(
+        dependent.solve_least_squares(&[1.0, 2.0, 3.0]).is_none(),
+        "Linearly dependent columns should have no unique solution."
+    );
+}
+
+#[test]
+fn least_squares() {
+    let mut fit = LeastSquares::<2>::new();
+    assert!(fit.is_empty(), "A new problem should be empty.");
+    assert!(fit.solve().is_none(), "An empty problem should have no solution.");
+
+    // y = 3x - 1, with symmetric noise.
+    for (i, noise) in [0.1, -0.1, 0.1, -0.1, 0.1, -0.1].iter().enumerate() {
+        let x = i as huginn::float!();
+        fit.add_row(&[x, 1.0], 3.0 * x - 1.0 + noise);
+    }
+    assert_eq!(fit.len(), 6, "len() should count the added equations.");
+    let [slope, intercept] = fit.solve().unwrap();
+    assert!((slope - 3.0).abs() < 0.05, "The slope should be recovered.");
+    assert!((intercept + 1.0).abs() < 0.15, "The intercept should be recovered.");
+
+    let mut weighted = LeastSquares::<1>::new();
+    weighted.add_weighted_row(&[1.0], 0.0, 1.0);
+    weighted.add_weighted_row(&[1.0], 4.0, 3.0);
+    assert!(
+        (weighted.solve().unwrap()[0] - 3.0).abs() < 1e-5,
+        "Weights should pull the solution towards heavier equations."
+    );
+}
+
+#[test]
+fn fitting() {
+    let plane_points = [
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(2.0, 1.0, 0.0),
+        Vector3::new(0.0, 1.0, 2.0),
+        Vector3::new(2.0, 1.0, 2.0),
+        Vector3::new(1.0, 1.0, 5.0),
+    ];
+    let plane = fit_plane(&plane_points).expect("Coplanar points should fit a plane.");
+    assert!(
+        plane.is_equal_approx_any_side(&huginn::types::Plane::new(Vector3::UP, 1.0)),
+        "fit_plane() should find the plane containing the points."
+    );
+    assert!(
+        fit_plane(&[Vector3::ZERO, Vector3::ONE, Vector3::ONE * 2.0]).is_none(),
+        "Collinear points should not fit a plane."
+    );
+
+    let direction = Vector3::new(1.0, 2.0, -2.0).normalized();
+    let line_points: Vec<Vector3> = (0..5)
+        .map(|i| Vector3::new(1.0, 0.0, 3.0) + direction * i as huginn::float!())
+        .collect();
+    let (point, fitted) = fit_line(&line_points).expect("Distinct points should fit a line.");
+    assert!(
+        fitted.cross(&direction).is_zero_approx(),
+        "fit_line() should find the direction of the points."
+    );
+    assert!(
+        point.is_equal_approx(&(Vector3::new(1.0, 0.0, 3.0) + direction * 2.0)),
+        "fit_line() should return the centroid."
+    );
+    assert!(
+        fit_line(&[Vector3::ONE, Vector3::ONE]).is_none(),
+        "Coincident points should not fit a line."
+    );
+
+    let center = Vector2::new(100.0, -50.0);
+    let circle_points: Vec<Vector2> = (0..7)
+        .map(|i| center + Vector2::from_angle(i as huginn::float!() * 0.9) * 4.0)
+        .collect();
+    let (fitted_center, radius) = fit_circle(&circle_points).expect("Points on a circle should fit it.");
+    assert!(
+        (fitted_center - center).length() < 1e-3,
+        "fit_circle() should find the center."
+    );
+    assert!((radius - 4.0).abs() < 1e-3, "fit_circle() should find the radius.");
+    assert!(
+        fit_circle(&[Vector2::ZERO, Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0)]).is_none(),
+        "Collinear points should not fit a circle."
+    );
+}
@@ -0,0 +1,133 @@
+use huginn::marshalls::{bytes_to_var, var_to_bytes, DecodeError, Variant, ENCODE_FLAG_64};
+use huginn::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use huginn::types::{Basis, Color, Quaternion, Rect2, Rect2i, Transform2D, Transform3D};
+
+fn round_trip<T: Variant + PartialEq + core::fmt::Debug>(value: T) {
+    let bytes = var_to_bytes(&value);
+    let (decoded, length) = T::decode_var(&bytes).unwrap();
+    assert_eq!(decoded, value, "Decoding should return the encoded value.");
+    assert_eq!(length, bytes.len(), "Decoding should consume the whole encoding.");
+    assert_eq!(bytes[0] as u32, T::TYPE, "The header should hold the Variant type.");
+}
+
+#[test]
+fn known_encodings() {
+    #[cfg(not(feature = "double-precision-float"))]
+    assert_eq!(
+        var_to_bytes(&Vector2::new(1.0, 2.0)),
+        [5, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64],
+        "Vector2 should match Godot's encoding."
+    );
+    #[cfg(feature = "double-precision-float")]
+    assert_eq!(
+        var_to_bytes(&Vector2::new(1.0, 2.0)),
+        [5, 0, 1, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64],
+        "Vector2 should match Godot's double-precision encoding."
+    );
+    assert_eq!(
+        var_to_bytes(&Vector2i::new(1, -1)),
+        [6, 0, 0, 0, 1, 0, 0, 0, 255, 255, 255, 255],
+        "Vector2i should match Godot's encoding."
+    );
+    assert_eq!(
+        var_to_bytes(&Color::rgba(1.0, 0.0, 0.0, 1.0)),
+        [20, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 63],
+        "Color should always use 32-bit floats."
+    );
+}
+
+#[test]
+fn round_trips() {
+    round_trip(Vector2::new(1.5, -2.25));
+    round_trip(Vector2i::new(3, -4));
+    round_trip(Vector3::new(1.5, -2.25, 8.0));
+    round_trip(Vector3i::new(3, -4, 5));
+    round_trip(Vector4::new(1.5, -2.25, 8.0, 0.125));
+    round_trip(Vector4i::new(3, -4, 5, -6));
+    round_trip(Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)));
+    round_trip(Rect2i::new_from_dimension(1, 2, 3, 4));
+    round_trip(Transform2D::new_from_floats(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+    round_trip(Basis::new_from_floats(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    round_trip(Transform3D::new(
+        Basis::new_from_floats(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0),
+        Vector3::new(10.0, 11.0, 12.0),
+    ));
+    round_trip(Quaternion::new(0.5, -0.5, 0.5, -0.5));
+    round_trip(Color::rgba(0.25, 0.5, 0.75, 1.0));
+}
+
+/// Decodes the components `start..start + 3` of an encoded value as a [`Vector3`], keeping its header flags.
+fn components(bytes: &[u8], start: usize) -> Vector3 {
+    let size = if bytes[2] & 1 == 1 { 8 } else { 4 };
+    let mut header = bytes[..4].to_vec();
+    header[0] = Vector3::TYPE as u8;
+    bytes_to_var(&[&header[..], &bytes[4 + start * size..4 + (start + 3) * size]].concat()).unwrap()
+}
+
+#[test]
+fn component_order() {
+    let basis = Basis::new_from_floats(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let transform = Transform3D::new(basis, Vector3::new(10.0, 11.0, 12.0));
+    let bytes = var_to_bytes(&transform);
+    assert_eq!(components(&bytes, 0), basis.x, "The basis should be encoded row by row.");
+    assert_eq!(components(&bytes, 3), basis.y, "The basis should be encoded row by row.");
+    assert_eq!(components(&bytes, 6), basis.z, "The basis should be encoded row by row.");
+    assert_eq!(components(&bytes, 9), transform.origin, "The origin should be encoded last.");
+
+    let transform = Transform2D::new_from_floats(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(
+        components(&var_to_bytes(&transform), 2),
+        Vector3::new(3.0, 4.0, 5.0),
+        "Transform2D should be encoded column by column."
+    );
+}
+
+#[test]
+fn decode_both_widths() {
+    let mut bytes_32 = 9u32.to_le_bytes().to_vec();
+    let mut bytes_64 = (9u32 | ENCODE_FLAG_64).to_le_bytes().to_vec();
+    for value in [1.5f64, -2.0, 0.25] {
+        bytes_32.extend_from_slice(&(value as f32).to_le_bytes());
+        bytes_64.extend_from_slice(&value.to_le_bytes());
+    }
+    let expected = Vector3::new(1.5, -2.0, 0.25);
+    assert_eq!(Vector3::decode_var(&bytes_32), Ok((expected, 16)), "32-bit floats should be decoded.");
+    assert_eq!(Vector3::decode_var(&bytes_64), Ok((expected, 28)), "64-bit floats should be decoded.");
+}
+
+#[test]
+fn decode_errors() {
+    let bytes = var_to_bytes(&Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        Vector3::decode_var(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::Truncated {
+            needed: bytes.len(),
+            available: bytes.len() - 1,
+        }),
+        "A truncated buffer should report the needed length."
+    );
+    assert_eq!(
+        Vector3::decode_var(&bytes[..2]),
+        Err(DecodeError::Truncated {
+            needed: 4,
+            available: 2,
+        }),
+        "A truncated header should report the header length."
+    );
+    assert_eq!(
+        Vector3i::decode_var(&bytes),
+        Err(DecodeError::UnexpectedType {
+            expected: 10,
+            found: 9,
+        }),
+        "A different Variant type should be an error."
+    );
+
+    let mut longer = bytes.clone();
+    longer.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(
+        Vector3::decode_var(&longer),
+        Ok((Vector3::new(1.0, 2.0, 3.0), bytes.len())),
+        "Trailing bytes should be left alone."
+    );
+}
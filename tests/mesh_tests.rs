@@ -0,0 +1,143 @@
+use huginn::types::mesh::TriangleMesh;
+use huginn::types::vectors::{Vector2, Vector3, Vector4};
+use huginn::types::{Transform3D, AABB};
+use huginn::utils::float_consts::FRAC_PI_2;
+
+/// A unit quad on the floor, facing up, from the origin toward +X and -Z.
+fn floor() -> TriangleMesh {
+    TriangleMesh::new(
+        vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(1.0, 0.0, -1.0),
+            Vector3::new(1.0, 0.0, 0.0),
+        ],
+        vec![0, 1, 2, 0, 2, 3],
+    )
+}
+
+/// The floor quad, with a wall facing +X rising from its right edge.
+fn corner() -> TriangleMesh {
+    let mut mesh = floor();
+    mesh.positions.extend([Vector3::new(1.0, 1.0, -1.0), Vector3::new(1.0, 1.0, 0.0)]);
+    mesh.indices.extend([3, 4, 2, 3, 5, 4]);
+    mesh
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(corner().get_aabb(), AABB::new(Vector3::new(0.0, 0.0, -1.0), Vector3::ONE));
+    assert_eq!(TriangleMesh::default().get_aabb(), AABB::default());
+}
+
+#[test]
+fn flat_normals() {
+    let mut mesh = floor();
+    mesh.generate_flat_normals();
+    assert_eq!(mesh.vertex_count(), 4, "Coplanar triangles keep sharing vertices.");
+    assert_eq!(mesh.normals, vec![Vector3::UP; 4]);
+
+    let mut mesh = corner();
+    mesh.generate_flat_normals();
+    assert_eq!(mesh.vertex_count(), 8, "The vertices along the crease are duplicated.");
+    assert_eq!(mesh.triangle_count(), 4);
+    for [a, b, c] in mesh.triangles().skip(2) {
+        assert_eq!([mesh.normals[a], mesh.normals[b], mesh.normals[c]], [Vector3::RIGHT; 3]);
+    }
+}
+
+#[test]
+fn smooth_normals() {
+    let mut mesh = corner();
+    mesh.generate_smooth_normals(FRAC_PI_2 + 0.1);
+    assert_eq!(mesh.vertex_count(), 6);
+    let crease = Vector3::new(1.0, 1.0, 0.0).normalized();
+    assert!(mesh.normals[2].is_equal_approx(&crease));
+    assert!(mesh.normals[3].is_equal_approx(&crease));
+    assert_eq!(mesh.normals[0], Vector3::UP);
+
+    let mut mesh = corner();
+    mesh.generate_smooth_normals(FRAC_PI_2 - 0.1);
+    assert_eq!(mesh.vertex_count(), 8, "Edges sharper than the threshold stay sharp.");
+    assert!(mesh.normals.iter().all(|normal| *normal == Vector3::UP || *normal == Vector3::RIGHT));
+}
+
+#[test]
+fn smooth_normals_across_seams() {
+    // The wall doesn't share its vertices with the floor, as if they had different UVs.
+    let mut mesh = corner();
+    mesh.positions.extend([Vector3::new(1.0, 0.0, -1.0), Vector3::new(1.0, 0.0, 0.0)]);
+    mesh.indices = vec![0, 1, 2, 0, 2, 3, 7, 4, 6, 7, 5, 4];
+    mesh.generate_smooth_normals(FRAC_PI_2 + 0.1);
+    let crease = Vector3::new(1.0, 1.0, 0.0).normalized();
+    assert!(mesh.normals[3].is_equal_approx(&crease));
+    assert!(mesh.normals[7].is_equal_approx(&crease));
+}
+
+#[test]
+fn tangents() {
+    let mut mesh = floor();
+    assert!(!mesh.generate_tangents(), "Tangents need normals and UVs.");
+
+    mesh.generate_flat_normals();
+    mesh.uvs = vec![Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(1.0, 0.0)];
+    assert!(mesh.generate_tangents());
+    assert_eq!(mesh.tangents, vec![Vector4::new(1.0, 0.0, 0.0, 1.0); 4]);
+
+    // Mirroring the texture horizontally flips the tangent and the sign of the bitangent.
+    mesh.uvs.iter_mut().for_each(|uv| uv.x = 1.0 - uv.x);
+    assert!(mesh.generate_tangents());
+    assert_eq!(mesh.tangents, vec![Vector4::new(-1.0, 0.0, 0.0, -1.0); 4]);
+    let tangent = mesh.tangents[0];
+    let bitangent = mesh.normals[0].cross(&Vector3::new(tangent.x, tangent.y, tangent.z)) * tangent.w;
+    assert_eq!(bitangent, Vector3::FORWARD, "The bitangent still follows +V.");
+}
+
+#[test]
+fn weld() {
+    let quad = floor();
+    let mut mesh = TriangleMesh::new(quad.indices.iter().map(|&i| quad.positions[i as usize]).collect(), (0..6).collect());
+    mesh.positions[3].x += 0.0005;
+    assert_eq!(mesh.weld(0.001), 2);
+    assert_eq!(mesh.vertex_count(), 4);
+    assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+
+    let mut mesh = TriangleMesh::new(quad.indices.iter().map(|&i| quad.positions[i as usize]).collect(), (0..6).collect());
+    mesh.uvs = vec![Vector2::ZERO; 6];
+    mesh.uvs[4] = Vector2::ONE;
+    assert_eq!(mesh.weld(0.001), 1, "Vertices with different attributes aren't merged.");
+
+    assert_eq!(mesh.weld(2.0), 3);
+    assert_eq!(mesh.triangle_count(), 0, "Collapsed triangles are removed.");
+}
+
+#[test]
+fn transform() {
+    let mut mesh = corner();
+    mesh.positions[4].x += 0.5;
+    mesh.positions[5].x += 0.5;
+    mesh.generate_flat_normals();
+    mesh.uvs = vec![Vector2::ZERO; mesh.vertex_count()];
+
+    // Non-uniform scales need the inverse transpose to keep the normals perpendicular.
+    let xform = Transform3D::IDENTITY.scaled(&Vector3::new(3.0, 1.0, 1.0)).translated(&Vector3::new(0.0, 2.0, 0.0));
+    let mut transformed = mesh.clone();
+    transformed.transform(&xform);
+    assert_eq!(transformed.positions[1], Vector3::new(0.0, 2.0, -1.0));
+    let mut expected = transformed.clone();
+    expected.generate_flat_normals();
+    for (normal, expected) in transformed.normals.iter().zip(&expected.normals) {
+        assert!(normal.is_equal_approx(expected), "Expected {expected}, got {normal}.");
+    }
+
+    let mut mirrored = floor();
+    mirrored.generate_flat_normals();
+    mirrored.tangents = vec![Vector4::new(1.0, 0.0, 0.0, 1.0); 4];
+    mirrored.transform(&Transform3D::IDENTITY.scaled(&Vector3::new(-1.0, 1.0, 1.0)));
+    assert_eq!(mirrored.indices, vec![0, 2, 1, 0, 3, 2], "The winding is flipped.");
+    assert_eq!(mirrored.normals, vec![Vector3::UP; 4]);
+    assert_eq!(mirrored.tangents, vec![Vector4::new(-1.0, 0.0, 0.0, -1.0); 4]);
+    let mut expected = mirrored.clone();
+    expected.generate_flat_normals();
+    assert_eq!(expected.normals, mirrored.normals);
+}
@@ -0,0 +1,168 @@
+use huginn::types::packing::{
+    pack_atlas, AtlasSize, MaxRectsHeuristic, MaxRectsPacker, Packer, Packing, SkylinePacker,
+};
+use huginn::types::vectors::Vector2i;
+use huginn::types::Rect2i;
+
+const HEURISTICS: [MaxRectsHeuristic; 4] = [
+    MaxRectsHeuristic::BestShortSideFit,
+    MaxRectsHeuristic::BestLongSideFit,
+    MaxRectsHeuristic::BestAreaFit,
+    MaxRectsHeuristic::BottomLeft,
+];
+
+/// Returns pseudo-random sizes between 1 and 40, the same on every run.
+fn sizes(count: usize) -> Vec<Vector2i> {
+    let mut state = 12345u32;
+    let mut next = move || {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        ((state >> 16) % 40 + 1) as _
+    };
+    (0..count).map(|_| Vector2i::new(next(), next())).collect()
+}
+
+/// Checks that every placement is inside the bin, has the requested size and keeps its distance to the others.
+fn assert_valid(packing: &Packing, sizes: &[Vector2i], padding: i32) {
+    let bin = Rect2i::new(Vector2i::ZERO, packing.size);
+    let padding = padding as _;
+    let placed: Vec<_> = packing.placements.iter().flatten().collect();
+    for (placement, size) in packing.placements.iter().zip(sizes) {
+        let Some(placement) = placement else { continue };
+        let expected = if placement.rotated { Vector2i::new(size.y, size.x) } else { *size };
+        assert_eq!(placement.rect.size(), expected, "The placed size should match the requested size.");
+        assert!(bin.encloses(&placement.rect), "Every rectangle should be inside the bin.");
+    }
+    for (i, a) in placed.iter().enumerate() {
+        let padded = Rect2i::new(a.rect.position(), a.rect.size() + Vector2i::new(padding, padding));
+        for b in &placed[i + 1..] {
+            let other = Rect2i::new(b.rect.position(), b.rect.size() + Vector2i::new(padding, padding));
+            assert!(!padded.intersects(&other), "Rectangles should be separated by the padding.");
+        }
+    }
+}
+
+#[test]
+fn max_rects() {
+    let sizes = sizes(60);
+    for heuristic in HEURISTICS {
+        let mut packer = MaxRectsPacker::new(Vector2i::new(200, 200), heuristic, 1, true);
+        let packing = packer.insert_all(&sizes);
+        assert_valid(&packing, &sizes, 1);
+        assert!(packing.is_complete(), "Every rectangle should fit.");
+        assert!(packer.occupancy() > 0.7, "MaxRects should pack tightly.");
+    }
+}
+
+#[test]
+fn skyline() {
+    let sizes = sizes(60);
+    let mut packer = SkylinePacker::new(Vector2i::new(200, 200), 1, true);
+    let packing = packer.insert_all(&sizes);
+    assert_valid(&packing, &sizes, 1);
+    assert!(packing.is_complete(), "Every rectangle should fit.");
+    assert!(packer.occupancy() > 0.7, "Skyline should pack tightly.");
+}
+
+#[test]
+fn exact_fit() {
+    let sizes = [Vector2i::new(64, 64); 4];
+    let mut max_rects = MaxRectsPacker::new(Vector2i::new(128, 128), MaxRectsHeuristic::BestAreaFit, 0, false);
+    let mut skyline = SkylinePacker::new(Vector2i::new(128, 128), 0, false);
+    for packer in [&mut max_rects as &mut dyn Packer, &mut skyline] {
+        let packing = packer.insert_all(&sizes);
+        assert!(packing.is_complete(), "Four quarters should fill the bin.");
+        assert_eq!(packer.occupancy(), 1.0, "The bin should be full.");
+        assert_eq!(packer.insert(Vector2i::ONE), None, "Nothing should fit in a full bin.");
+    }
+}
+
+#[test]
+fn rejections() {
+    let sizes = [
+        Vector2i::new(10, 10),
+        Vector2i::new(200, 10),
+        Vector2i::new(0, 10),
+        Vector2i::new(10, 10),
+    ];
+    let mut max_rects = MaxRectsPacker::new(Vector2i::new(100, 100), MaxRectsHeuristic::BestShortSideFit, 0, true);
+    let mut skyline = SkylinePacker::new(Vector2i::new(100, 100), 0, true);
+    for packer in [&mut max_rects as &mut dyn Packer, &mut skyline] {
+        let packing = packer.insert_all(&sizes);
+        assert_eq!(
+            packing.rejected().collect::<Vec<_>>(),
+            [1, 2],
+            "Rectangles too large or without area should be rejected."
+        );
+        assert!(!packing.is_complete(), "The packing should be incomplete.");
+    }
+}
+
+#[test]
+fn rotation_and_padding() {
+    let size = Vector2i::new(100, 10);
+    for allow_rotation in [false, true] {
+        let mut max_rects = MaxRectsPacker::new(Vector2i::new(10, 100), MaxRectsHeuristic::BottomLeft, 0, allow_rotation);
+        let mut skyline = SkylinePacker::new(Vector2i::new(10, 100), 0, allow_rotation);
+        for packer in [&mut max_rects as &mut dyn Packer, &mut skyline] {
+            let placement = packer.insert(size);
+            assert_eq!(placement.is_some(), allow_rotation, "The rectangle should only fit when rotated.");
+            if let Some(placement) = placement {
+                assert!(placement.rotated, "The placement should report the rotation.");
+                assert_eq!(placement.rect.size(), Vector2i::new(10, 100), "The rotated size should be swapped.");
+            }
+        }
+    }
+
+    for (padding, fits) in [(1, true), (2, false)] {
+        let mut max_rects = MaxRectsPacker::new(Vector2i::new(21, 10), MaxRectsHeuristic::BestShortSideFit, padding, false);
+        let mut skyline = SkylinePacker::new(Vector2i::new(21, 10), padding, false);
+        for packer in [&mut max_rects as &mut dyn Packer, &mut skyline] {
+            assert!(packer.insert(Vector2i::new(10, 10)).is_some(), "The first rectangle should fit.");
+            assert_eq!(
+                packer.insert(Vector2i::new(10, 10)).is_some(),
+                fits,
+                "The padding should only apply between rectangles."
+            );
+        }
+    }
+}
+
+#[test]
+fn incremental_insertion() {
+    let mut packer = MaxRectsPacker::new(Vector2i::new(64, 64), MaxRectsHeuristic::BestShortSideFit, 0, false);
+    packer.occupy(&Rect2i::new_from_dimension(0, 0, 64, 32));
+    let placement = packer.insert(Vector2i::new(32, 32)).unwrap();
+    assert_eq!(placement.rect.position().y, 32, "Occupied space should be skipped.");
+    let packing = packer.insert_all(&[Vector2i::new(32, 32)]);
+    assert!(packing.is_complete(), "Inserting into an existing bin should use the remaining space.");
+    assert_eq!(packer.insert(Vector2i::ONE), None, "The bin should be full.");
+}
+
+#[test]
+fn atlas_sizes() {
+    let sizes = sizes(30);
+    let new_packer = |size| MaxRectsPacker::new(size, MaxRectsHeuristic::BestShortSideFit, 2, true);
+    for constraint in [AtlasSize::Any, AtlasSize::PowerOfTwo, AtlasSize::Square, AtlasSize::SquarePowerOfTwo] {
+        let atlas = pack_atlas(&sizes, Vector2i::new(1024, 1024), constraint, new_packer);
+        assert!(atlas.is_complete(), "Every rectangle should fit in the atlas.");
+        assert_valid(&atlas, &sizes, 2);
+        let size = atlas.size;
+        match constraint {
+            AtlasSize::Any => assert_eq!(size, atlas.extent(), "The atlas should be cropped."),
+            AtlasSize::PowerOfTwo => assert!(
+                (size.x as u32).is_power_of_two() && (size.y as u32).is_power_of_two(),
+                "The atlas sides should be powers of two."
+            ),
+            AtlasSize::Square => assert_eq!(size.x, size.y, "The atlas should be square."),
+            AtlasSize::SquarePowerOfTwo => assert!(
+                size.x == size.y && (size.x as u32).is_power_of_two(),
+                "The atlas should be a square with a power of two side."
+            ),
+        }
+        assert!(size.x <= 256 && size.y <= 256, "The atlas should be small.");
+    }
+
+    let atlas = pack_atlas(&sizes, Vector2i::new(64, 64), AtlasSize::Any, new_packer);
+    assert!(atlas.rejected().count() > 0, "Rectangles that don't fit in the maximum size should be reported.");
+    assert_valid(&atlas, &sizes, 2);
+}
@@ -0,0 +1,161 @@
+use huginn::types::vectors::Vector3;
+use huginn::types::Plane;
+use huginn::utils::is_equal_approx;
+
+#[test]
+fn constructor_methods() {
+    let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    let plane_floats = Plane::new_from_floats(0.0, 1.0, 0.0, 2.0);
+    let plane_point = Plane::new_from_normal_point(
+        &Vector3::new(0.0, 1.0, 0.0),
+        &Vector3::new(5.0, 2.0, -3.0),
+    );
+    let plane_points = Plane::new_from_points(
+        &Vector3::new(0.0, 2.0, 0.0),
+        &Vector3::new(1.0, 2.0, 0.0),
+        &Vector3::new(0.0, 2.0, 1.0),
+    );
+
+    assert_eq!(
+        plane, plane_floats,
+        "Planes created with the same values but by different methods should be equal."
+    );
+    assert_eq!(
+        plane, plane_point,
+        "Planes created with the same values but by different methods should be equal."
+    );
+    assert!(
+        plane.is_equal_approx(&plane_points),
+        "Planes created with the same values but by different methods should be equal."
+    );
+}
+
+#[test]
+fn string_conversion() {
+    assert_eq!(
+        Plane::new_from_floats(1.0, 2.0, 3.0, 4.0).to_string(),
+        "[N: (1, 2, 3), D: 4]",
+        "The string representation should match the expected value."
+    );
+}
+
+#[test]
+fn basic_getters() {
+    let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    let plane_normalized = Plane::new(Vector3::new(0.0, 2.0, 0.0), 4.0).normalized();
+
+    assert!(
+        plane.get_center().is_equal_approx(&Vector3::new(0.0, 2.0, 0.0)),
+        "get_center() should return the point on the plane closest to the origin."
+    );
+    assert!(
+        plane.is_equal_approx(&plane_normalized),
+        "normalized() should scale the normal and distance together."
+    );
+    assert_eq!(
+        Plane::new(Vector3::ZERO, 3.0).normalized(),
+        Plane::default(),
+        "normalized() should return an empty plane when the normal has zero length."
+    );
+    assert!(
+        plane.is_equal_approx_any_side(&-plane),
+        "A plane should equal its negation when ignoring the side."
+    );
+    assert!(
+        !plane.is_equal_approx(&-plane),
+        "A plane should not equal its negation."
+    );
+}
+
+#[test]
+fn plane_point_operations() {
+    let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+    let y_facing_plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 4.0);
+
+    assert!(
+        is_equal_approx(plane.distance_to(&Vector3::new(0.0, 3.0, 0.0)), 2.0),
+        "distance_to() should return the signed distance to the point."
+    );
+    assert!(
+        is_equal_approx(plane.distance_to(&Vector3::new(0.0, -3.0, 0.0)), -4.0),
+        "distance_to() should be negative below the plane."
+    );
+    assert!(
+        plane.has_point(&Vector3::new(5.0, 1.0, -5.0), 1e-5),
+        "has_point() should be true for points on the plane."
+    );
+    assert!(
+        !plane.has_point(&Vector3::new(0.0, 1.5, 0.0), 0.1),
+        "has_point() should be false for points outside the tolerance."
+    );
+    assert!(
+        y_facing_plane.is_point_over(&Vector3::new(0.0, 5.0, 0.0)),
+        "is_point_over() should be true for points in front of the plane."
+    );
+    assert!(
+        !y_facing_plane.is_point_over(&Vector3::new(0.0, 3.0, 0.0)),
+        "is_point_over() should be false for points behind the plane."
+    );
+    assert!(
+        y_facing_plane
+            .project(&Vector3::new(1.0, 7.0, 2.0))
+            .is_equal_approx(&Vector3::new(1.0, 4.0, 2.0)),
+        "project() should move the point onto the plane along its normal."
+    );
+}
+
+#[test]
+fn intersection() {
+    let x_facing_plane = Plane::new(Vector3::new(1.0, 0.0, 0.0), 0.0);
+    let y_facing_plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 0.0);
+    let z_facing_plane = Plane::new(Vector3::new(0.0, 0.0, 1.0), 0.0);
+
+    assert_eq!(
+        x_facing_plane.intersect_3(&y_facing_plane, &z_facing_plane),
+        Some(Vector3::ZERO),
+        "intersect_3() should find the point shared by the three planes."
+    );
+    assert_eq!(
+        x_facing_plane.intersect_3(&x_facing_plane, &z_facing_plane),
+        None,
+        "intersect_3() should fail when two planes are parallel."
+    );
+
+    assert_eq!(
+        y_facing_plane.intersects_ray(&Vector3::new(0.0, 1.0, 1.0), &Vector3::new(0.0, -2.0, 0.0)),
+        Some(Vector3::new(0.0, 0.0, 1.0)),
+        "intersects_ray() should find the point where the ray crosses the plane."
+    );
+    assert_eq!(
+        y_facing_plane.intersects_ray(&Vector3::new(0.0, 1.0, 1.0), &Vector3::new(0.0, 2.0, 0.0)),
+        None,
+        "intersects_ray() should fail when the ray points away from the plane."
+    );
+
+    assert_eq!(
+        y_facing_plane.intersects_segment(&Vector3::new(0.0, 1.0, 1.0), &Vector3::new(0.0, -1.0, 1.0)),
+        Some(Vector3::new(0.0, 0.0, 1.0)),
+        "intersects_segment() should find the point where the segment crosses the plane."
+    );
+    assert_eq!(
+        y_facing_plane.intersects_segment(&Vector3::new(0.0, 1.0, 1.0), &Vector3::new(0.0, 0.5, 1.0)),
+        None,
+        "intersects_segment() should fail when the segment ends before the plane."
+    );
+}
+
+#[test]
+fn finite_number_checks() {
+    let y = Vector3::new(0.0, 1.0, 2.0);
+    let infinite = Vector3::new(f32::NAN as _, f32::NAN as _, f32::NAN as _);
+
+    assert!(Plane::new(y, 1.0).is_finite(), "Plane with all components finite should be finite.");
+    assert!(
+        !Plane::new(infinite, 1.0).is_finite(),
+        "Plane with one component infinite should not be finite."
+    );
+    assert!(
+        !Plane::new(y, f32::INFINITY as _).is_finite(),
+        "Plane with one component infinite should not be finite."
+    );
+}
@@ -0,0 +1,195 @@
+use huginn::float;
+use huginn::types::mesh::TriangleMesh;
+use huginn::types::primitives::{
+    BoxMesh, CapsuleMesh, CylinderMesh, IcosphereMesh, PlaneMesh, PrimitiveMesh, QuadMesh, SphereMesh, TorusMesh,
+};
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::AABB;
+
+fn assert_near(actual: float!(), expected: float!()) {
+    assert!((actual - expected).abs() < 1e-4, "Expected {expected}, got {actual}.");
+}
+
+fn assert_aabb(mesh: &TriangleMesh, position: Vector3, size: Vector3) {
+    let aabb = mesh.get_aabb();
+    assert!(aabb.is_equal_approx(&AABB::new(position, size)), "Expected {position} {size}, got {aabb}.");
+}
+
+/// Checks that every vertex is used, with a unit normal, and that every triangle faces the same way as its normals.
+fn assert_valid(mesh: &TriangleMesh) {
+    let count = mesh.vertex_count();
+    assert_eq!(mesh.normals.len(), count);
+    assert_eq!(mesh.uvs.len(), count);
+    assert!(mesh.normals.iter().all(Vector3::is_normalized));
+
+    let mut used = vec![false; count];
+    for [a, b, c] in mesh.triangles() {
+        for vertex in [a, b, c] {
+            used[vertex] = true;
+        }
+        let (pa, pb, pc) = (mesh.positions[a], mesh.positions[b], mesh.positions[c]);
+        let face = (pa - pc).cross(&(pa - pb));
+        if face.length() > 1e-6 {
+            let normal = mesh.normals[a] + mesh.normals[b] + mesh.normals[c];
+            assert!(face.dot(&normal) > 0.0, "The triangle at {pa} should be clockwise as seen from the front.");
+        }
+    }
+    assert!(used.iter().all(|used| *used), "Every vertex should be used.");
+}
+
+#[test]
+fn boxes() {
+    let mesh = BoxMesh::default().generate();
+    assert_valid(&mesh);
+    assert_eq!((mesh.vertex_count(), mesh.triangle_count()), (24, 12));
+    assert_aabb(&mesh, Vector3::ONE * -0.5, Vector3::ONE);
+    // The front face is the top-left cell of the texture.
+    assert_eq!(mesh.positions[0], Vector3::new(-0.5, 0.5, 0.5));
+    assert_eq!(mesh.uvs[0], Vector2::ZERO);
+    assert_eq!(mesh.uvs[3], Vector2::new(1.0 / 3.0, 0.5));
+
+    let mesh = BoxMesh {
+        size: Vector3::new(2.0, 1.0, 4.0),
+        subdivide_width: 1,
+        ..BoxMesh::default()
+    }
+    .generate();
+    assert_valid(&mesh);
+    assert_eq!((mesh.vertex_count(), mesh.triangle_count()), (32, 20));
+    assert_aabb(&mesh, Vector3::new(-1.0, -0.5, -2.0), Vector3::new(2.0, 1.0, 4.0));
+}
+
+#[test]
+fn planes_and_quads() {
+    let mesh = PlaneMesh {
+        subdivide_width: 2,
+        subdivide_depth: 1,
+        ..PlaneMesh::default()
+    }
+    .generate();
+    assert_valid(&mesh);
+    assert_eq!((mesh.vertex_count(), mesh.triangle_count()), (12, 12));
+    assert!(mesh.normals.iter().all(|normal| *normal == Vector3::UP));
+    assert_aabb(&mesh, Vector3::new(-1.0, 0.0, -1.0), Vector3::new(2.0, 0.0, 2.0));
+
+    let mesh = QuadMesh::default().generate();
+    assert_valid(&mesh);
+    assert_eq!(mesh.normals[0], Vector3::BACK, "Quads face +Z.");
+    assert_eq!(mesh.positions[0], Vector3::new(-0.5, 0.5, 0.0));
+    assert_eq!(mesh.uvs[0], Vector2::ZERO);
+}
+
+#[test]
+fn uv_sphere() {
+    let sphere = SphereMesh {
+        radius: 2.0,
+        radial_segments: 12,
+        rings: 6,
+    };
+    let mesh = sphere.generate();
+    assert_valid(&mesh);
+    assert_eq!(mesh.vertex_count(), 13 * 7);
+    for (position, normal) in mesh.positions.iter().zip(&mesh.normals) {
+        assert_near(position.length(), 2.0);
+        assert!((*position / 2.0).is_equal_approx(normal));
+    }
+    assert_aabb(&mesh, Vector3::ONE * -2.0, Vector3::ONE * 4.0);
+}
+
+#[test]
+fn icosphere() {
+    let mesh = IcosphereMesh {
+        radius: 1.0,
+        subdivisions: 0,
+    }
+    .generate();
+    assert_valid(&mesh);
+    assert_eq!(mesh.triangle_count(), 20);
+
+    let mesh = IcosphereMesh::default().generate();
+    assert_valid(&mesh);
+    assert_eq!(mesh.triangle_count(), 20 * 64);
+    assert!(mesh.positions.iter().all(|position| (position.length() - 0.5).abs() < 1e-5));
+    for [a, b, c] in mesh.triangles() {
+        let us = [a, b, c].map(|vertex| mesh.uvs[vertex].x);
+        let span = us.iter().copied().fold(0.0, <float!()>::max) - us.iter().copied().fold(2.0, <float!()>::min);
+        assert!(span < 0.5, "No triangle should stretch across the UV seam.");
+    }
+}
+
+#[test]
+fn capsule() {
+    let mesh = CapsuleMesh {
+        radius: 0.5,
+        height: 3.0,
+        radial_segments: 16,
+        rings: 4,
+    }
+    .generate();
+    assert_valid(&mesh);
+    assert_aabb(&mesh, Vector3::new(-0.5, -1.5, -0.5), Vector3::new(1.0, 3.0, 1.0));
+    for position in &mesh.positions {
+        let axis = Vector3::new(0.0, position.y.clamp(-1.0, 1.0), 0.0);
+        assert_near(position.distance_to(&axis), 0.5);
+    }
+    assert_eq!(mesh.uvs.first().unwrap().y, 0.0);
+    assert_near(mesh.uvs.last().unwrap().y, 1.0);
+}
+
+#[test]
+fn cylinders_and_cones() {
+    let mesh = CylinderMesh::default().generate();
+    assert_valid(&mesh);
+    assert_aabb(&mesh, Vector3::new(-0.5, -1.0, -0.5), Vector3::new(1.0, 2.0, 1.0));
+
+    let cone = CylinderMesh {
+        top_radius: 0.0,
+        bottom_radius: 1.0,
+        height: 1.0,
+        radial_segments: 8,
+        rings: 1,
+        ..CylinderMesh::default()
+    }
+    .generate();
+    assert_valid(&cone);
+    assert_eq!(cone.vertex_count(), 9 * 2 + 10, "The tip has no cap.");
+    let slope = Vector3::new(0.0, 1.0, 1.0).normalized();
+    assert!(cone.normals[0].is_equal_approx(&slope), "The side of a cone leans inward.");
+
+    let open = CylinderMesh {
+        cap_top: false,
+        cap_bottom: false,
+        radial_segments: 8,
+        rings: 1,
+        ..CylinderMesh::default()
+    }
+    .generate();
+    assert_eq!(open.triangle_count(), 16);
+}
+
+#[test]
+fn torus() {
+    let mesh = TorusMesh {
+        inner_radius: 1.0,
+        outer_radius: 2.0,
+        rings: 16,
+        ring_segments: 8,
+    }
+    .generate();
+    assert_valid(&mesh);
+    assert_aabb(&mesh, Vector3::new(-2.0, -0.5, -2.0), Vector3::new(4.0, 1.0, 4.0));
+    for position in &mesh.positions {
+        let center = Vector3::new(position.x, 0.0, position.z).normalized() * 1.5;
+        assert_near(position.distance_to(&center), 0.5);
+    }
+}
+
+#[test]
+fn tangents_can_be_generated() {
+    let mut mesh = SphereMesh::default().generate();
+    assert!(mesh.generate_tangents());
+    // At U = 0.25 the sphere faces +X, and U goes toward -Z.
+    let equator = mesh.positions.iter().position(|position| position.is_equal_approx(&Vector3::new(0.5, 0.0, 0.0))).unwrap();
+    let tangent = mesh.tangents[equator];
+    assert!(Vector3::new(tangent.x, tangent.y, tangent.z).is_equal_approx(&Vector3::FORWARD));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "proptest")]
+
+use huginn::proptest::{
+    aabb, color, invertible_transform2d, invertible_transform3d, normalized_vector2,
+    normalized_vector3, orthonormal_basis, plane, rect2, rect2i, unit_quaternion,
+};
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::Transform3D;
+use huginn::{assert_relative_eq, float};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn normalized_vectors(v2 in normalized_vector2(), v3 in normalized_vector3()) {
+        prop_assert!(v2.is_normalized(), "{} should be normalized.", v2);
+        prop_assert!(v3.is_normalized(), "{} should be normalized.", v3);
+    }
+
+    #[test]
+    fn rotations(q in unit_quaternion(), basis in orthonormal_basis()) {
+        prop_assert!(q.is_normalized(), "{:?} should be normalized.", q);
+        prop_assert!(basis.is_rotation(), "{:?} should be a rotation.", basis);
+    }
+
+    #[test]
+    fn invertible_transforms(
+        t2 in invertible_transform2d(),
+        t3 in invertible_transform3d(),
+        point in any::<Vector3>(),
+    ) {
+        prop_assert!(t2.determinant() != 0.0, "{:?} should be invertible.", t2);
+        prop_assert!(t3.basis.determinant() != 0.0, "{} should be invertible.", t3);
+        let p2 = point.xy();
+        assert_relative_eq!(
+            t2.affine_inverse() * (t2 * p2),
+            p2,
+            epsilon = 0.1,
+            max_relative = 0.001,
+            "The Transform2D inverse should undo it."
+        );
+        assert_relative_eq!(
+            t3.affine_inverse() * (t3 * point),
+            point,
+            epsilon = 0.1,
+            max_relative = 0.001,
+            "The Transform3D inverse should undo it."
+        );
+    }
+
+    #[test]
+    fn sizes_and_ranges(
+        r2 in rect2(),
+        r2i in rect2i(),
+        bounds in aabb(),
+        p in plane(),
+        c in color(),
+    ) {
+        prop_assert!(
+            r2.size().x >= 0.0 && r2.size().y >= 0.0,
+            "{} should have a non-negative size.",
+            r2
+        );
+        prop_assert!(
+            r2i.size().x >= 0 && r2i.size().y >= 0,
+            "{} should have a non-negative size.",
+            r2i
+        );
+        prop_assert!(
+            bounds.size().x >= 0.0 && bounds.size().y >= 0.0 && bounds.size().z >= 0.0,
+            "{} should have a non-negative size.",
+            bounds
+        );
+        prop_assert!(p.normal.is_normalized(), "{} should have a normalized normal.", p);
+        let unit: std::ops::RangeInclusive<float!()> = 0.0..=1.0;
+        prop_assert!(
+            [c.r(), c.g(), c.b(), c.a()].iter().all(|v| unit.contains(v)),
+            "{} should have components in 0..=1.",
+            c
+        );
+    }
+
+    #[test]
+    fn arbitrary_impls(v in any::<Vector2>(), t in any::<Transform3D>()) {
+        prop_assert!(v.is_finite(), "{} should be finite.", v);
+        prop_assert!(t.basis.determinant() != 0.0, "{} should be invertible.", t);
+    }
+}
@@ -0,0 +1,291 @@
+use huginn::float;
+use huginn::types::collision2d::{Circle, Segment2D};
+use huginn::types::collision3d::{BoxShape3D, Sphere, Triangle3D};
+use huginn::types::ray::{Ray2D, Ray3D, Raycast};
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::{Plane, Rect2, Transform2D, Transform3D, AABB};
+use huginn::utils::float_consts::FRAC_PI_2;
+
+const NO_LIMIT: float!() = <float!()>::INFINITY;
+
+#[test]
+fn constructor_normalizes() {
+    let ray = Ray2D::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    assert_eq!(ray.direction(), Vector2::new(0.6, 0.8));
+    assert!(ray.at(5.0).is_equal_approx(&Vector2::new(4.0, 6.0)));
+
+    let ray = Ray3D::new(Vector3::ONE, Vector3::new(0.0, 0.0, -2.0));
+    assert_eq!(ray.direction(), Vector3::FORWARD);
+    assert_eq!(ray.at(3.0), Vector3::new(1.0, 1.0, -2.0));
+}
+
+#[test]
+fn rect2() {
+    let rect = Rect2::new_from_dimension(0.0, 0.0, 10.0, 10.0);
+    let hit = rect
+        .raycast(
+            &Ray2D::new(Vector2::new(-5.0, 5.0), Vector2::RIGHT),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(hit.t, 5.0);
+    assert_eq!(hit.point, Vector2::new(0.0, 5.0));
+    assert_eq!(hit.normal, Vector2::LEFT);
+
+    assert!(
+        rect.raycast(&Ray2D::new(Vector2::new(-5.0, 5.0), Vector2::RIGHT), 4.0)
+            .is_none(),
+        "The hit is beyond the maximum distance."
+    );
+    assert!(rect
+        .raycast(
+            &Ray2D::new(Vector2::new(-5.0, 5.0), Vector2::LEFT),
+            NO_LIMIT
+        )
+        .is_none());
+
+    let inside = rect
+        .raycast(&Ray2D::new(Vector2::new(5.0, 5.0), Vector2::UP), NO_LIMIT)
+        .unwrap();
+    assert_eq!(inside.t, 0.0, "A ray starting inside hits immediately.");
+    assert_eq!(inside.normal, Vector2::ZERO);
+}
+
+#[test]
+fn circle() {
+    let circle = Circle::new(2.0);
+    let hit = circle
+        .raycast(
+            &Ray2D::new(Vector2::new(0.0, -10.0), Vector2::DOWN),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(hit.t, 8.0);
+    assert_eq!(hit.point, Vector2::new(0.0, -2.0));
+    assert_eq!(hit.normal, Vector2::UP);
+
+    assert!(circle
+        .raycast(
+            &Ray2D::new(Vector2::new(3.0, -10.0), Vector2::DOWN),
+            NO_LIMIT
+        )
+        .is_none());
+    assert!(circle
+        .raycast(&Ray2D::new(Vector2::new(0.0, -10.0), Vector2::UP), NO_LIMIT)
+        .is_none());
+    assert_eq!(
+        circle
+            .raycast(&Ray2D::new(Vector2::ZERO, Vector2::UP), NO_LIMIT)
+            .unwrap()
+            .t,
+        0.0
+    );
+}
+
+#[test]
+fn segment() {
+    let segment = Segment2D::new(Vector2::new(0.0, -1.0), Vector2::new(0.0, 1.0));
+    let hit = segment
+        .raycast(&Ray2D::new(Vector2::new(3.0, 0.5), Vector2::LEFT), NO_LIMIT)
+        .unwrap();
+    assert_eq!(hit.t, 3.0);
+    assert_eq!(hit.point, Vector2::new(0.0, 0.5));
+    assert_eq!(hit.normal, Vector2::RIGHT, "The normal faces the ray.");
+
+    let back = segment
+        .raycast(
+            &Ray2D::new(Vector2::new(-3.0, 0.5), Vector2::RIGHT),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(
+        back.normal,
+        Vector2::LEFT,
+        "Segments are hit from both sides."
+    );
+    assert!(segment
+        .raycast(&Ray2D::new(Vector2::new(3.0, 1.5), Vector2::LEFT), NO_LIMIT)
+        .is_none());
+    assert!(
+        segment
+            .raycast(
+                &Ray2D::new(Vector2::new(0.0, -5.0), Vector2::DOWN),
+                NO_LIMIT
+            )
+            .is_none(),
+        "Parallel rays don't hit."
+    );
+}
+
+#[test]
+fn boxes() {
+    let aabb = AABB::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(2.0, 2.0, 2.0));
+    let hit = aabb
+        .raycast(
+            &Ray3D::new(Vector3::new(0.5, 5.0, 0.0), Vector3::DOWN),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(hit.t, 4.0);
+    assert_eq!(hit.point, Vector3::new(0.5, 1.0, 0.0));
+    assert_eq!(hit.normal, Vector3::UP);
+    assert!(aabb
+        .raycast(&Ray3D::new(Vector3::new(0.5, 5.0, 0.0), Vector3::DOWN), 3.9)
+        .is_none());
+    assert!(aabb
+        .raycast(
+            &Ray3D::new(Vector3::new(2.0, 5.0, 0.0), Vector3::DOWN),
+            NO_LIMIT
+        )
+        .is_none());
+
+    let cube = BoxShape3D::new(Vector3::new(2.0, 2.0, 2.0));
+    let diagonal = Ray3D::new(Vector3::new(-3.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.2));
+    assert_eq!(
+        cube.raycast(&diagonal, NO_LIMIT),
+        aabb.raycast(&diagonal, NO_LIMIT)
+    );
+}
+
+#[test]
+fn sphere() {
+    let sphere = Sphere::new(1.0);
+    let hit = sphere
+        .raycast(
+            &Ray3D::new(Vector3::new(0.0, 0.0, 5.0), Vector3::FORWARD),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(hit.t, 4.0);
+    assert_eq!(hit.normal, Vector3::BACK);
+    assert!(sphere
+        .raycast(
+            &Ray3D::new(Vector3::new(0.0, 1.5, 5.0), Vector3::FORWARD),
+            NO_LIMIT
+        )
+        .is_none());
+}
+
+#[test]
+fn plane() {
+    let plane = Plane::new(Vector3::UP, 2.0);
+    let hit = plane
+        .raycast(
+            &Ray3D::new(Vector3::new(1.0, 5.0, 0.0), Vector3::DOWN),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(hit.t, 3.0);
+    assert_eq!(hit.point, Vector3::new(1.0, 2.0, 0.0));
+    assert_eq!(hit.normal, Vector3::UP);
+
+    let below = plane
+        .raycast(&Ray3D::new(Vector3::ZERO, Vector3::UP), NO_LIMIT)
+        .unwrap();
+    assert_eq!(below.normal, Vector3::DOWN, "The normal faces the ray.");
+    assert!(plane
+        .raycast(&Ray3D::new(Vector3::ZERO, Vector3::RIGHT), NO_LIMIT)
+        .is_none());
+    assert!(plane
+        .raycast(&Ray3D::new(Vector3::ZERO, Vector3::DOWN), NO_LIMIT)
+        .is_none());
+}
+
+#[test]
+fn triangle() {
+    let triangle = Triangle3D::new(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 2.0),
+    );
+    let hit = triangle
+        .raycast(
+            &Ray3D::new(Vector3::new(0.5, 3.0, 0.5), Vector3::DOWN),
+            NO_LIMIT,
+        )
+        .unwrap();
+    assert_eq!(hit.t, 3.0);
+    assert_eq!(hit.point, Vector3::new(0.5, 0.0, 0.5));
+    assert_eq!(hit.normal, Vector3::UP);
+
+    assert!(triangle
+        .raycast(
+            &Ray3D::new(Vector3::new(1.5, 3.0, 1.5), Vector3::DOWN),
+            NO_LIMIT
+        )
+        .is_none());
+    assert!(triangle
+        .raycast(
+            &Ray3D::new(Vector3::new(0.5, 3.0, 0.5), Vector3::UP),
+            NO_LIMIT
+        )
+        .is_none());
+}
+
+#[test]
+fn transformed_rays() {
+    let xform = Transform2D::IDENTITY
+        .rotated(FRAC_PI_2)
+        .translated(&Vector2::new(10.0, 0.0));
+    let world = Ray2D::new(Vector2::ZERO, Vector2::RIGHT);
+    let local = world * xform;
+    let hit = Circle::new(1.0).raycast(&local, NO_LIMIT).unwrap();
+    assert!(
+        (hit.t - 9.0).abs() < 1e-5,
+        "Rigid transforms keep distances."
+    );
+    assert!(
+        (xform * local).origin().is_equal_approx(&world.origin()),
+        "Transforming the local ray back should give the world ray."
+    );
+
+    let xform = Transform3D::IDENTITY.translated(&Vector3::new(0.0, 0.0, -10.0));
+    let local = Ray3D::new(Vector3::ZERO, Vector3::FORWARD) * xform;
+    assert_eq!(Sphere::new(2.0).raycast(&local, NO_LIMIT).unwrap().t, 8.0);
+
+    // A scaled shape is hit where it is in the world, with `t` in local units.
+    let xform = Transform3D::IDENTITY
+        .scaled(&Vector3::new(1.0, 1.0, 4.0))
+        .translated(&Vector3::new(0.0, 0.0, -20.0));
+    let world = Ray3D::new(Vector3::new(0.5, 0.0, 0.0), Vector3::FORWARD);
+    let hit = Sphere::new(1.0).raycast(&(world * xform), NO_LIMIT).unwrap();
+    let point = xform * hit.point;
+    // The ray meets the unit sphere where x = 0.5, at z = sqrt(1 - 0.5²).
+    let surface: float!() = 0.75;
+    let surface = surface.sqrt();
+    assert!(
+        (point.z - (-20.0 + 4.0 * surface)).abs() < 1e-4,
+        "The hit should be on the stretched sphere, got {point}."
+    );
+    assert!(
+        (hit.t - (5.0 - surface)).abs() < 1e-4,
+        "t should be in the units of the sphere, got {}.",
+        hit.t
+    );
+}
+
+#[test]
+fn cast_first() {
+    let shapes: Vec<Box<dyn Raycast<Ray3D>>> = vec![
+        Box::new(Plane::new(Vector3::BACK, -20.0)),
+        Box::new(AABB::new(
+            Vector3::new(-1.0, -1.0, -8.0),
+            Vector3::ONE * 2.0,
+        )),
+        Box::new(Sphere::new(0.5)),
+    ];
+    let ray = Ray3D::new(Vector3::new(0.0, 0.0, 5.0), Vector3::FORWARD);
+    let (index, hit) = ray
+        .cast_first(shapes.iter().map(|shape| shape.as_ref()), NO_LIMIT)
+        .unwrap();
+    assert_eq!(index, 2);
+    assert_eq!(hit.t, 4.5);
+
+    let (index, _) = ray
+        .cast_first(shapes[..2].iter().map(|shape| shape.as_ref()), NO_LIMIT)
+        .unwrap();
+    assert_eq!(index, 1);
+    assert!(ray
+        .cast_first(shapes[..2].iter().map(|shape| shape.as_ref()), 10.0)
+        .is_none());
+}
@@ -475,3 +475,84 @@ fn finite_number_checks() {
         "Rect2 with all components finite should be finite"
     );
 }
+
+#[test]
+fn ray_and_segment_queries() {
+    let rect = Rect2::new_from_dimension(0.0, 0.0, 10.0, 20.0);
+
+    let hit = rect
+        .intersects_ray(&Vector2::new(-5.0, -5.0), &Vector2::new(1.0, 1.0))
+        .unwrap();
+    assert_eq!(hit.entry, Vector2::new(0.0, 0.0), "intersects_ray() should return the entry point.");
+    assert_eq!(hit.exit, Vector2::new(10.0, 10.0), "intersects_ray() should return the exit point.");
+    assert_eq!(hit.exit_normal, Vector2::RIGHT, "intersects_ray() should return the exit normal.");
+
+    let hit = rect
+        .intersects_ray(&Vector2::new(5.0, 30.0), &Vector2::new(0.0, -2.0))
+        .unwrap();
+    assert_eq!(hit.entry, Vector2::new(5.0, 20.0), "intersects_ray() should work along an axis.");
+    assert_eq!(hit.entry_normal, Vector2::DOWN, "intersects_ray() should return the entry normal.");
+    assert_eq!(hit.exit_normal, Vector2::UP, "intersects_ray() should return the exit normal.");
+
+    let hit = rect
+        .intersects_ray(&Vector2::new(5.0, 5.0), &Vector2::LEFT)
+        .unwrap();
+    assert_eq!(hit.entry, Vector2::new(5.0, 5.0), "A ray starting inside should enter at its origin.");
+    assert_eq!(hit.entry_normal, Vector2::ZERO, "A ray starting inside should have no entry normal.");
+    assert_eq!(hit.exit, Vector2::new(0.0, 5.0), "A ray starting inside should still leave the rectangle.");
+
+    assert!(
+        rect.intersects_ray(&Vector2::new(-5.0, 5.0), &Vector2::LEFT).is_none(),
+        "intersects_ray() should ignore rectangles behind the ray."
+    );
+    assert!(
+        rect.intersects_ray(&Vector2::new(-5.0, 25.0), &Vector2::RIGHT).is_none(),
+        "intersects_ray() should miss rectangles beside the ray."
+    );
+    assert!(
+        rect.intersects_ray(&Vector2::new(5.0, 5.0), &Vector2::ZERO).is_none(),
+        "intersects_ray() should reject a zero direction."
+    );
+
+    let hit = rect
+        .intersects_segment(&Vector2::new(-10.0, 5.0), &Vector2::new(5.0, 5.0))
+        .unwrap();
+    assert_eq!(hit.entry, Vector2::new(0.0, 5.0), "intersects_segment() should return the entry point.");
+    assert_eq!(hit.entry_normal, Vector2::LEFT, "intersects_segment() should return the entry normal.");
+    assert_eq!(hit.exit, Vector2::new(5.0, 5.0), "A segment ending inside should exit at its end.");
+    assert_eq!(hit.exit_normal, Vector2::ZERO, "A segment ending inside should have no exit normal.");
+    assert!(
+        rect.intersects_segment(&Vector2::new(-10.0, 5.0), &Vector2::new(-1.0, 5.0)).is_none(),
+        "intersects_segment() should miss when the segment stops short."
+    );
+}
+
+#[test]
+fn closest_point_and_distance() {
+    let rect = Rect2::new_from_dimension(0.0, 0.0, 10.0, 20.0);
+    assert_eq!(
+        rect.closest_point(&Vector2::new(5.0, 5.0)),
+        Vector2::new(5.0, 5.0),
+        "closest_point() should return points inside unchanged."
+    );
+    assert_eq!(
+        rect.closest_point(&Vector2::new(-5.0, 25.0)),
+        Vector2::new(0.0, 20.0),
+        "closest_point() should return the nearest corner."
+    );
+    assert_approx_eq!(
+        rect.distance_to_point(&Vector2::new(13.0, 24.0)),
+        5.0,
+        "distance_to_point() should measure to the nearest corner."
+    );
+    assert_approx_eq!(
+        rect.distance_to_point(&Vector2::new(5.0, -3.0)),
+        3.0,
+        "distance_to_point() should measure to the nearest side."
+    );
+    assert_approx_eq!(
+        rect.distance_to_point(&Vector2::new(5.0, 5.0)),
+        0.0,
+        "distance_to_point() should be zero inside."
+    );
+}
@@ -377,3 +377,46 @@ fn merging() {
         Rect2i::new_from_dimension(0, 100, 1280, 720).merge(&Rect2i::new_from_dimension(-4000, -4000, 100, 100)), Rect2i::new_from_dimension(-4000, -4000, 5280, 4820),
         "merge() with non-enclosed Rect2i should return the expected result.");
 }
+
+#[test]
+fn subtracting() {
+    let rect = Rect2i::new_from_dimension(0, 0, 10, 10);
+    let parts = rect.subtract(&Rect2i::new_from_dimension(3, 4, 2, 2));
+    assert_eq!(
+        parts,
+        [
+            Rect2i::new_from_dimension(0, 0, 10, 4),
+            Rect2i::new_from_dimension(0, 6, 10, 4),
+            Rect2i::new_from_dimension(0, 4, 3, 2),
+            Rect2i::new_from_dimension(5, 4, 5, 2),
+        ],
+        "subtract() with a hole should return four rectangles."
+    );
+    assert_eq!(
+        parts.iter().fold(0, |area, part| area + part.get_area()),
+        rect.get_area() - 4,
+        "subtract() should cover the remaining area."
+    );
+    for (i, a) in parts.iter().enumerate() {
+        assert!(rect.encloses(a), "subtract() should return parts of the rectangle.");
+        assert!(
+            parts[i + 1..].iter().all(|b| !a.intersects(b)),
+            "subtract() should return non-overlapping rectangles."
+        );
+    }
+
+    assert_eq!(
+        rect.subtract(&Rect2i::new_from_dimension(20, 20, 5, 5)),
+        [rect],
+        "subtract() without overlap should return the rectangle."
+    );
+    assert!(
+        rect.subtract(&Rect2i::new_from_dimension(-1, -1, 12, 12)).is_empty(),
+        "subtract() with an enclosing rectangle should return nothing."
+    );
+    assert_eq!(
+        rect.subtract(&Rect2i::new_from_dimension(-5, -5, 10, 20)),
+        [Rect2i::new_from_dimension(5, 0, 5, 10)],
+        "subtract() over a side should return the rest."
+    );
+}
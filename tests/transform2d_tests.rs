@@ -1,5 +1,5 @@
 use huginn::float;
-use huginn::types::{vectors::Vector2, Transform2D};
+use huginn::types::{vectors::Vector2, Rect2, Transform2D};
 use huginn::utils::{float_consts, is_equal_approx, CMP_EPSILON};
 
 macro_rules! assert_approx_eq {
@@ -347,3 +347,57 @@ fn is_conformal_checks() {
         "Transform2D with a flip, rotation, and uniform scale should be conformal."
     );
 }
+
+#[test]
+fn rect_transforms() {
+    let transform = Transform2D::from((float_consts::FRAC_PI_2, Vector2::new(10.0, 0.0)));
+    let rect = Rect2::new_from_dimension(1.0, 2.0, 3.0, 4.0);
+
+    let transformed = transform * rect;
+    assert!(
+        transformed.is_equal_approx(&Rect2::new_from_dimension(4.0, 1.0, 4.0, 3.0)),
+        "Transform2D * Rect2 should transform the rectangle."
+    );
+    assert!(
+        (transformed * transform).is_equal_approx(&rect),
+        "Rect2 * Transform2D should apply the inverse transform."
+    );
+    let mut inverse = transformed;
+    inverse *= transform;
+    assert!(inverse.is_equal_approx(&rect), "Rect2 *= Transform2D should apply the inverse transform.");
+    assert!(
+        transform.xform_inv_rect(&transformed).is_equal_approx(&rect),
+        "xform_inv_rect() should undo xform_rect()."
+    );
+    assert!(
+        transform.xform_inv(&transform.xform(&Vector2::new(3.0, -7.0))).is_equal_approx(&Vector2::new(3.0, -7.0)),
+        "xform_inv() should undo xform()."
+    );
+}
+
+#[test]
+fn vector_transforms() {
+    let transform = Transform2D::from((float_consts::FRAC_PI_2, Vector2::new(10.0, 0.0)));
+    let v = Vector2::new(1.0, 0.0);
+
+    assert!(
+        (transform * v).is_equal_approx(&Vector2::new(10.0, 1.0)),
+        "Transform2D * Vector2 should rotate then translate the vector."
+    );
+    assert!(
+        (transform * v * transform).is_equal_approx(&v),
+        "Vector2 * Transform2D should apply the inverse transform."
+    );
+
+    let points = vec![v, Vector2::new(0.0, 5.0)];
+    let transformed = transform * &points;
+    assert!(
+        transformed[1].is_equal_approx(&(transform * points[1])),
+        "Transform2D * Vec<Vector2> should transform every vector."
+    );
+    let restored = transformed * transform;
+    assert!(
+        restored[0].is_equal_approx(&points[0]) && restored[1].is_equal_approx(&points[1]),
+        "Vec<Vector2> * Transform2D should apply the inverse transform to every vector."
+    );
+}
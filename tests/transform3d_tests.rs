@@ -1,6 +1,6 @@
 use huginn::float;
-use huginn::types::vectors::Vector3;
-use huginn::types::{Basis, Transform3D};
+use huginn::types::vectors::{Vector2, Vector3};
+use huginn::types::{Basis, Plane, Transform2D, Transform3D, AABB};
 use huginn::utils::float_consts;
 
 macro_rules! assert_approx_eq {
@@ -154,3 +154,140 @@ fn rotate_in_place_local_rotation() {
 
     assert!(rotated_transform.is_equal_approx(&expected), "The rotated transform should have a new orientation but still be based on the same origin.");
 }
+
+#[test]
+fn xform() {
+    let t = identity()
+        .rotated(&Vector3::new(0.0, 1.0, 0.0), float_consts::FRAC_PI_2)
+        .translated(&Vector3::new(1.0, 2.0, 3.0));
+    let v = Vector3::new(1.0, 0.0, 0.0);
+
+    assert!(
+        (t * v).is_equal_approx(&Vector3::new(1.0, 2.0, 2.0)),
+        "Transform3D * Vector3 should rotate then translate the vector."
+    );
+    assert!(
+        (v * t).is_equal_approx(&t.inverse().xform(&v)),
+        "Vector3 * Transform3D should apply the inverse transform."
+    );
+    assert!(
+        t.xform_inv(&t.xform(&v)).is_equal_approx(&v),
+        "xform_inv() should undo xform()."
+    );
+    assert!(
+        t.basis_xform(&v).is_equal_approx(&Vector3::new(0.0, 0.0, -1.0)),
+        "basis_xform() should ignore the origin."
+    );
+    assert!(
+        t.basis_xform_inv(&t.basis_xform(&v)).is_equal_approx(&v),
+        "basis_xform_inv() should undo basis_xform()."
+    );
+
+    let points = vec![v, Vector3::new(0.0, 5.0, -1.0)];
+    let transformed = t * &points;
+    assert!(
+        transformed[1].is_equal_approx(&(t * points[1])),
+        "Transform3D * Vec<Vector3> should transform every vector."
+    );
+    let restored = transformed * t;
+    assert!(
+        restored[0].is_equal_approx(&points[0]) && restored[1].is_equal_approx(&points[1]),
+        "Vec<Vector3> * Transform3D should apply the inverse transform to every vector."
+    );
+}
+
+#[test]
+fn looking_at() {
+    let t = Transform3D::new(Basis::IDENTITY, Vector3::new(1.0, 0.0, 0.0));
+    let looking = t.looking_at(&Vector3::new(1.0, 0.0, -5.0), None, false);
+
+    assert!(
+        looking.basis.is_equal_approx(&Basis::IDENTITY),
+        "Looking down -Z should keep the identity basis."
+    );
+    assert_eq!(looking.origin, t.origin, "looking_at() should keep the origin.");
+
+    let looking = t.looking_at(&Vector3::new(4.0, 0.0, 0.0), None, false);
+    assert!(
+        looking
+            .basis_xform(&Vector3::FORWARD)
+            .is_equal_approx(&Vector3::new(1.0, 0.0, 0.0)),
+        "The forward axis should point towards the target."
+    );
+    let looking = t.looking_at(&Vector3::new(4.0, 0.0, 0.0), None, true);
+    assert!(
+        looking
+            .basis_xform(&Vector3::MODEL_FRONT)
+            .is_equal_approx(&Vector3::new(1.0, 0.0, 0.0)),
+        "The model front axis should point towards the target."
+    );
+}
+
+#[test]
+fn plane_and_aabb_xform() {
+    let t = identity()
+        .rotated(&Vector3::new(0.0, 0.0, 1.0), float_consts::FRAC_PI_2)
+        .translated(&Vector3::new(0.0, 0.0, 2.0));
+    let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 3.0);
+
+    let transformed = t * plane;
+    assert!(
+        transformed.is_equal_approx(&Plane::new(Vector3::new(-1.0, 0.0, 0.0), 3.0)),
+        "Transform3D * Plane should transform the plane."
+    );
+    assert!(
+        (transformed * t).is_equal_approx(&plane),
+        "Plane * Transform3D should apply the inverse transform."
+    );
+
+    let scaled = identity().scaled(&Vector3::new(2.0, 1.0, 1.0));
+    let diagonal = Plane::new(Vector3::new(1.0, 1.0, 0.0).normalized(), 0.0);
+    let scaled_plane = scaled * diagonal;
+    assert!(
+        scaled_plane.has_point(&(scaled * Vector3::new(1.0, -1.0, 0.0)), 1e-4),
+        "Transformed planes should contain the transformed points under non-uniform scale."
+    );
+
+    let aabb = AABB::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+    let moved = aabb * identity().translated(&Vector3::new(1.0, 1.0, 1.0));
+    assert!(
+        moved.is_equal_approx(&AABB::new(Vector3::new(0.0, 1.0, 2.0), aabb.size())),
+        "AABB * Transform3D should apply the inverse transform."
+    );
+    let rotated = t * aabb;
+    assert!(
+        rotated.is_equal_approx(&AABB::new(
+            Vector3::new(-7.0, 1.0, 5.0),
+            Vector3::new(5.0, 4.0, 6.0)
+        )),
+        "Transform3D * AABB should enclose the transformed box."
+    );
+}
+
+#[test]
+fn transform2d_conversion() {
+    let t2 = Transform2D::from((0.5, Vector2::new(3.0, -4.0)));
+    let t3 = Transform3D::from(t2);
+
+    assert!(
+        t3.xform(&Vector3::new(1.0, 2.0, 7.0))
+            .is_equal_approx(&{
+                let v = t2.xform(&Vector2::new(1.0, 2.0));
+                Vector3::new(v.x, v.y, 7.0)
+            }),
+        "A Transform3D built from a Transform2D should act on the XY plane and keep Z."
+    );
+    assert!(
+        Transform2D::from(t3).is_equal_approx(&t2),
+        "Converting to Transform3D and back should be lossless."
+    );
+}
+
+#[test]
+fn string_conversion() {
+    assert_eq!(
+        Transform3D::new(Basis::IDENTITY, Vector3::new(1.0, 2.0, 3.0)).to_string(),
+        "[X: (1, 0, 0), Y: (0, 1, 0), Z: (0, 0, 1), O: (1, 2, 3)]",
+        "The string representation should match the expected value."
+    );
+}
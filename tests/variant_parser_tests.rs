@@ -0,0 +1,167 @@
+use huginn::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+use huginn::types::{
+    Basis, Color, Plane, Quaternion, Rect2, Rect2i, Transform2D, Transform3D, AABB,
+};
+use huginn::variant_parser::{str_to_var, var_to_str, ParseError, ParseErrorKind, VariantText};
+
+fn round_trip<T: VariantText + PartialEq + core::fmt::Debug>(value: T) {
+    let text = var_to_str(&value);
+    assert_eq!(str_to_var::<T>(&text), Ok(value), "Parsing should return the written value.");
+    assert!(text.starts_with(T::NAME), "The text should start with the constructor name.");
+}
+
+fn error<T: VariantText + core::fmt::Debug>(text: &str) -> ParseError {
+    str_to_var::<T>(text).unwrap_err()
+}
+
+#[test]
+fn godot_formatting() {
+    assert_eq!(var_to_str(&Vector3::new(1.0, 2.5, -3.0)), "Vector3(1, 2.5, -3)", "Integral floats should be written without a fraction.");
+    assert_eq!(var_to_str(&Vector2i::new(4, -5)), "Vector2i(4, -5)", "Integer vectors should be written as integers.");
+    assert_eq!(
+        var_to_str(&Transform3D::IDENTITY),
+        "Transform3D(1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0)",
+        "Transform3D should match Godot's text."
+    );
+    assert_eq!(
+        var_to_str(&Transform2D::new_from_floats(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)),
+        "Transform2D(1, 2, 3, 4, 5, 6)",
+        "Transform2D should list its columns."
+    );
+    assert_eq!(var_to_str(&Color::rgba(1.0, 0.0, 0.0, 1.0)), "Color(1, 0, 0, 1)", "Color should match Godot's text.");
+    assert_eq!(
+        var_to_str(&Vector2::new(f32::INFINITY as _, f32::NEG_INFINITY as _)),
+        "Vector2(inf, -inf)",
+        "Infinities should be written like in Godot."
+    );
+    assert_eq!(var_to_str(&Vector2::new(f32::NAN as _, 0.0)), "Vector2(nan, 0)", "NaN should be written like in Godot.");
+}
+
+#[test]
+fn godot_parsing() {
+    assert_eq!(
+        str_to_var::<Vector3>("Vector3(1e+20, -4.37114e-08, 0)"),
+        Ok(Vector3::new(1e20, -4.37114e-08, 0.0)),
+        "Godot's exponent notation should be parsed."
+    );
+    assert_eq!(
+        str_to_var::<Vector2>("  Vector2 ( 1 ,2\t)\n"),
+        Ok(Vector2::new(1.0, 2.0)),
+        "Whitespace should be skipped."
+    );
+    assert_eq!(
+        Vector2::parse_var("Vector2(1, 2), Vector2(3, 4)"),
+        Ok((Vector2::new(1.0, 2.0), 13)),
+        "parse_var should stop after the value."
+    );
+    let basis = str_to_var::<Basis>("Basis(1, 2, 3, 4, 5, 6, 7, 8, 9)").unwrap();
+    assert_eq!(basis.x, Vector3::new(1.0, 2.0, 3.0), "Basis should list its rows.");
+    let transform = str_to_var::<Transform3D>("Transform3D(1, 0, 0, 0, 1, 0, 0, 0, 1, 4, 5, 6)").unwrap();
+    assert_eq!(transform.origin, Vector3::new(4.0, 5.0, 6.0), "Transform3D should end with its origin.");
+}
+
+#[test]
+fn round_trips() {
+    round_trip(Vector2::new(0.1, -1e-7));
+    round_trip(Vector2i::new(3, -4));
+    round_trip(Vector3::new(1e20, 1.0 / 3.0, -0.0));
+    round_trip(Vector3i::new(3, -4, 5));
+    round_trip(Vector4::new(0.1, 0.2, 0.3, 0.4));
+    round_trip(Vector4i::new(3, -4, 5, -6));
+    round_trip(Rect2::new(Vector2::new(0.5, 1.5), Vector2::new(3.25, 4.0)));
+    round_trip(Rect2i::new_from_dimension(1, 2, 3, 4));
+    round_trip(Transform2D::from((0.7, Vector2::new(1.0, 2.0))));
+    round_trip(Plane::new_from_floats(0.0, 1.0, 0.0, 2.5));
+    round_trip(Quaternion::from_euler(&Vector3::new(0.3, -0.2, 0.1)));
+    round_trip(AABB::new(Vector3::new(-1.0, 0.0, 1.0), Vector3::new(2.0, 3.0, 4.0)));
+    round_trip(Basis::from_euler(&Vector3::new(0.3, -0.2, 0.1), None));
+    round_trip(Transform3D::new(
+        Basis::from_euler(&Vector3::new(0.3, -0.2, 0.1), None),
+        Vector3::new(0.1, 0.2, 0.3),
+    ));
+    round_trip(Color::rgba(0.1, 0.2, 0.3, 0.4));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        error::<Vector3>("Vector3(1, 2)"),
+        ParseError {
+            position: 12,
+            kind: ParseErrorKind::WrongArgumentCount { expected: 3, found: 2 },
+        },
+        "Missing arguments should be reported at the closing parenthesis."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1, 2, 3)"),
+        ParseError {
+            position: 14,
+            kind: ParseErrorKind::WrongArgumentCount { expected: 2, found: 3 },
+        },
+        "Extra arguments should be reported at the first extra argument."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1, 2, 3, 4, 5)"),
+        ParseError {
+            position: 14,
+            kind: ParseErrorKind::WrongArgumentCount { expected: 2, found: 5 },
+        },
+        "The count should include every argument up to the closing parenthesis."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1, x)"),
+        ParseError {
+            position: 11,
+            kind: ParseErrorKind::UnexpectedCharacter { expected: "a number", found: 'x' },
+        },
+        "A missing number should be reported at the unexpected character."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1, 2"),
+        ParseError {
+            position: 12,
+            kind: ParseErrorKind::UnexpectedEnd,
+        },
+        "A truncated text should be reported at its end."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector3(1, 2, 3)"),
+        ParseError {
+            position: 0,
+            kind: ParseErrorKind::UnexpectedType {
+                expected: "Vector2",
+                found: "Vector3".into(),
+            },
+        },
+        "A different constructor should be reported at its name."
+    );
+    assert_eq!(
+        error::<Vector2i>("Vector2i(1.5, 2)"),
+        ParseError {
+            position: 10,
+            kind: ParseErrorKind::UnexpectedCharacter { expected: "',' or ')'", found: '.' },
+        },
+        "Integer vectors should reject fractions."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1..2, 3)"),
+        ParseError {
+            position: 8,
+            kind: ParseErrorKind::InvalidNumber,
+        },
+        "An invalid number should be reported at its start."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1, 2) x"),
+        ParseError {
+            position: 14,
+            kind: ParseErrorKind::TrailingCharacters,
+        },
+        "Trailing characters should be reported where they start."
+    );
+    assert_eq!(
+        error::<Vector2>("Vector2(1, x)").to_string(),
+        "Expected a number, found 'x' at position 11.",
+        "The error message should include the position."
+    );
+}
@@ -1,4 +1,4 @@
-use huginn::types::vectors::{Vector2, Vector2i, AXIS};
+use huginn::types::vectors::{Vector2, Vector2i, Vector3, Vector3i, Vector4, AXIS};
 use huginn::utils::{
     float_consts::{FRAC_1_SQRT_2, PI, SQRT_2, TAU},
     CMP_EPSILON,
@@ -687,3 +687,36 @@ fn linear_algebra_methods() {
         "dot should return expected value."
     );
 }
+
+#[test]
+fn swizzle() {
+    let mut vector = Vector2::new(1.0, 2.0);
+
+    assert_eq!(
+        vector.yx(),
+        Vector2::new(2.0, 1.0),
+        "Vector2 yx() should swap the components."
+    );
+    assert_eq!(
+        vector.xyx(),
+        Vector3::new(1.0, 2.0, 1.0),
+        "Vector2 xyx() should widen to a Vector3."
+    );
+    assert_eq!(
+        vector.yyxx(),
+        Vector4::new(2.0, 2.0, 1.0, 1.0),
+        "Vector2 yyxx() should widen to a Vector4."
+    );
+    assert_eq!(
+        Vector2i::new(3, 4).yxy(),
+        Vector3i::new(4, 3, 4),
+        "Vector2i swizzles should return integer vectors."
+    );
+
+    vector.set_yx(&Vector2::new(5.0, 6.0));
+    assert_eq!(
+        vector,
+        Vector2::new(6.0, 5.0),
+        "Vector2 set_yx() should write the components in order."
+    );
+}
@@ -208,3 +208,86 @@ fn abs_and_sign_methods() {
         "sign should work as expected."
     );
 }
+
+#[test]
+fn overflow_methods() {
+    let big = Vector2i::MAX;
+    let one = Vector2i::ONE;
+    assert_eq!(big.checked_add(&one), None, "checked_add should detect overflow.");
+    assert_eq!(one.checked_add(&one), Some(Vector2i::new(2, 2)), "checked_add should add without overflow.");
+    assert_eq!(big.checked_mul(&Vector2i::new(2, 2)), None, "checked_mul should detect overflow.");
+    assert_eq!(Vector2i::MIN.checked_sub(&one), None, "checked_sub should detect overflow.");
+    assert_eq!(one.checked_div(&Vector2i::ZERO), None, "checked_div should detect a division by zero.");
+    assert_eq!(big.saturating_add(&one), big, "saturating_add should clamp to MAX.");
+    assert_eq!(Vector2i::MIN.saturating_sub(&one), Vector2i::MIN, "saturating_sub should clamp to MIN.");
+    assert_eq!(big.saturating_mul(&-one), -big, "saturating_mul should not clamp without overflow.");
+    assert_eq!(big.wrapping_add(&one), Vector2i::MIN, "wrapping_add should wrap around.");
+    assert_eq!(Vector2i::MIN.wrapping_sub(&one), big, "wrapping_sub should wrap around.");
+    assert_eq!(big.wrapping_mul(&Vector2i::new(2, 2)), Vector2i::new(-2, -2), "wrapping_mul should wrap around.");
+
+    assert_eq!(
+        Vector2i::new(-1, 7).div_euclid(&Vector2i::new(4, 4)),
+        Vector2i::new(-1, 1),
+        "div_euclid should round towards negative infinity."
+    );
+    assert_eq!(
+        Vector2i::new(-1, 7).rem_euclid(&Vector2i::new(4, 4)),
+        Vector2i::new(3, 3),
+        "rem_euclid should never be negative."
+    );
+
+    assert_eq!(
+        Vector2i::new(1_500_000_000, 1_500_000_000).length_squared(),
+        2 * 2_250_000_000_000_000_000,
+        "length_squared should not overflow."
+    );
+    assert_eq!(
+        Vector2i::MIN.distance_squared_to(&Vector2i::ZERO),
+        Vector2i::ZERO.distance_squared_to(&Vector2i::MIN),
+        "distance_squared_to should not overflow."
+    );
+    assert_eq!(
+        Vector2i::new(1_000_000_000, 0).distance_squared_to(&Vector2i::new(-1_000_000_000, 0)),
+        4_000_000_000_000_000_000,
+        "distance_squared_to should not overflow when the difference does."
+    );
+}
+
+#[test]
+fn bitwise_operators() {
+    let vector = Vector2i::new(12, 10);
+    assert_eq!(vector & 0b0101, Vector2i::new(4, 0), "& should work on each component.");
+    assert_eq!(vector | 0b0101, Vector2i::new(13, 15), "| should work on each component.");
+    assert_eq!(vector ^ vector, Vector2i::ZERO, "^ should work on each component.");
+    assert_eq!(vector << 1, vector * 2, "<< should work on each component.");
+    assert_eq!(vector >> Vector2i::new(1, 1), vector / 2, ">> should work on each component.");
+    assert_eq!(Vector2i::new(-8, -8) >> 2, Vector2i::new(-2, -2), ">> should be an arithmetic shift.");
+
+    let mut vector = vector;
+    vector &= Vector2i::ZERO;
+    vector |= 3;
+    vector ^= 1;
+    vector <<= 2;
+    vector >>= 1;
+    assert_eq!(vector, Vector2i::new(4, 4), "Assignment operators should work on each component.");
+}
+
+#[test]
+fn grid_distances() {
+    let from = Vector2i::new(1, -2);
+    let to = Vector2i::new(-3, 5);
+    assert_eq!(from.manhattan_distance_to(&to), 11, "manhattan_distance_to should add the steps on each axis.");
+    assert_eq!(from.chebyshev_distance_to(&to), 7, "chebyshev_distance_to should take the longest axis.");
+    assert_approx_eq!(
+        from.octile_distance_to(&to),
+        3.0 + 4.0 * float_consts::SQRT_2,
+        "octile_distance_to should take diagonal steps first."
+    );
+    assert_eq!(
+        i128::from(Vector2i::MIN.manhattan_distance_to(&Vector2i::MAX)),
+        2 * (i128::from(Vector2i::MAX.x) - i128::from(Vector2i::MIN.x)),
+        "manhattan_distance_to should not overflow."
+    );
+    assert_eq!(from.octile_distance_to(&from), 0.0, "The distance to itself should be zero.");
+}
+
@@ -1,5 +1,5 @@
 use huginn::float;
-use huginn::types::vectors::{Vector3, Vector3i, AXIS};
+use huginn::types::vectors::{Vector2, Vector3, Vector3i, Vector4, AXIS};
 use huginn::utils::float_consts::TAU;
 use huginn::utils::{float_consts, CMP_EPSILON};
 
@@ -839,3 +839,101 @@ fn finite_number_checks() {
         }
     }
 }
+
+#[test]
+fn swizzle() {
+    let mut vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(
+        vector.xz(),
+        Vector2::new(1.0, 3.0),
+        "Vector3 xz() should return a Vector2 of the x and z components."
+    );
+    assert_eq!(
+        vector.zyx(),
+        Vector3::new(3.0, 2.0, 1.0),
+        "Vector3 zyx() should reverse the components."
+    );
+    assert_eq!(
+        vector.xxyz(),
+        Vector4::new(1.0, 1.0, 2.0, 3.0),
+        "Vector3 xxyz() should allow repeated components and widen to a Vector4."
+    );
+
+    vector.set_zx(&Vector2::new(5.0, 6.0));
+    assert_eq!(
+        vector,
+        Vector3::new(6.0, 2.0, 5.0),
+        "Vector3 set_zx() should write the components in order."
+    );
+    vector.set_yzx(&Vector3::new(7.0, 8.0, 9.0));
+    assert_eq!(
+        vector,
+        Vector3::new(9.0, 7.0, 8.0),
+        "Vector3 set_yzx() should write every component."
+    );
+}
+
+#[test]
+fn standard_traits() {
+    let mut vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(vector[2], 3.0, "Vector3 indexing should match get().");
+    vector[0] = 4.0;
+    assert_eq!(vector.x, 4.0, "Vector3 IndexMut should write the component.");
+    assert_eq!(
+        Vector3::from([1.0, 2.0, 3.0]),
+        Vector3::new(1.0, 2.0, 3.0),
+        "Vector3 should convert from an array."
+    );
+    assert_eq!(
+        <[float!(); 3]>::from(vector),
+        [4.0, 2.0, 3.0],
+        "Vector3 should convert into an array."
+    );
+    assert_eq!(
+        Vector3::from((1.0, 2.0, 3.0)),
+        Vector3::new(1.0, 2.0, 3.0),
+        "Vector3 should convert from a tuple."
+    );
+    assert_eq!(
+        vector.as_ref(),
+        &[4.0, 2.0, 3.0],
+        "Vector3 should be viewable as an array."
+    );
+    vector.as_mut()[1] = 5.0;
+    assert_eq!(vector.y, 5.0, "Vector3 should be mutable as an array.");
+    assert_eq!(
+        vector.into_iter().collect::<Vec<_>>(),
+        vec![4.0, 5.0, 3.0],
+        "Vector3 should iterate over its components in order."
+    );
+    assert_eq!(
+        vector.into_iter().map(|c| c * 2.0).collect::<Vector3>(),
+        Vector3::new(8.0, 10.0, 6.0),
+        "Vector3 should be collectable from its components."
+    );
+
+    let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 2.0, 2.0)];
+    assert_eq!(
+        vectors.iter().sum::<Vector3>(),
+        Vector3::new(3.0, 4.0, 5.0),
+        "Vector3 should sum component-wise."
+    );
+    assert_eq!(
+        vectors.into_iter().product::<Vector3>(),
+        Vector3::new(2.0, 4.0, 6.0),
+        "Vector3 should multiply component-wise."
+    );
+    assert_eq!(
+        std::iter::empty::<Vector3>().sum::<Vector3>(),
+        Vector3::ZERO,
+        "An empty Vector3 sum should be zero."
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_iterator_too_short() {
+    let _ = [1.0, 2.0].into_iter().collect::<Vector3>();
+}
@@ -207,3 +207,85 @@ fn abs_and_sign_methods() {
         "sign should work as expected."
     );
 }
+
+#[test]
+fn overflow_methods() {
+    let big = Vector3i::MAX;
+    let one = Vector3i::ONE;
+    assert_eq!(big.checked_add(&one), None, "checked_add should detect overflow.");
+    assert_eq!(one.checked_add(&one), Some(Vector3i::new(2, 2, 2)), "checked_add should add without overflow.");
+    assert_eq!(big.checked_mul(&Vector3i::new(2, 2, 2)), None, "checked_mul should detect overflow.");
+    assert_eq!(Vector3i::MIN.checked_sub(&one), None, "checked_sub should detect overflow.");
+    assert_eq!(one.checked_div(&Vector3i::ZERO), None, "checked_div should detect a division by zero.");
+    assert_eq!(big.saturating_add(&one), big, "saturating_add should clamp to MAX.");
+    assert_eq!(Vector3i::MIN.saturating_sub(&one), Vector3i::MIN, "saturating_sub should clamp to MIN.");
+    assert_eq!(big.saturating_mul(&-one), -big, "saturating_mul should not clamp without overflow.");
+    assert_eq!(big.wrapping_add(&one), Vector3i::MIN, "wrapping_add should wrap around.");
+    assert_eq!(Vector3i::MIN.wrapping_sub(&one), big, "wrapping_sub should wrap around.");
+    assert_eq!(big.wrapping_mul(&Vector3i::new(2, 2, 2)), Vector3i::new(-2, -2, -2), "wrapping_mul should wrap around.");
+
+    assert_eq!(
+        Vector3i::new(-1, 7, -8).div_euclid(&Vector3i::new(4, 4, 4)),
+        Vector3i::new(-1, 1, -2),
+        "div_euclid should round towards negative infinity."
+    );
+    assert_eq!(
+        Vector3i::new(-1, 7, -8).rem_euclid(&Vector3i::new(4, 4, 4)),
+        Vector3i::new(3, 3, 0),
+        "rem_euclid should never be negative."
+    );
+
+    assert_eq!(
+        Vector3i::new(1_500_000_000, 1_500_000_000, 1_500_000_000).length_squared(),
+        3 * 2_250_000_000_000_000_000,
+        "length_squared should not overflow."
+    );
+    assert_eq!(
+        Vector3i::MIN.distance_squared_to(&Vector3i::ZERO),
+        Vector3i::ZERO.distance_squared_to(&Vector3i::MIN),
+        "distance_squared_to should not overflow."
+    );
+    assert_eq!(
+        Vector3i::new(1_000_000_000, 0, 0).distance_squared_to(&Vector3i::new(-1_000_000_000, 0, 0)),
+        4_000_000_000_000_000_000,
+        "distance_squared_to should not overflow when the difference does."
+    );
+}
+
+#[test]
+fn bitwise_operators() {
+    let vector = Vector3i::new(12, 10, 6);
+    assert_eq!(vector & 0b0101, Vector3i::new(4, 0, 4), "& should work on each component.");
+    assert_eq!(vector | 0b0101, Vector3i::new(13, 15, 7), "| should work on each component.");
+    assert_eq!(vector ^ vector, Vector3i::ZERO, "^ should work on each component.");
+    assert_eq!(vector << 1, vector * 2, "<< should work on each component.");
+    assert_eq!(vector >> Vector3i::new(1, 1, 1), vector / 2, ">> should work on each component.");
+    assert_eq!(Vector3i::new(-8, -8, -8) >> 2, Vector3i::new(-2, -2, -2), ">> should be an arithmetic shift.");
+
+    let mut vector = vector;
+    vector &= Vector3i::ZERO;
+    vector |= 3;
+    vector ^= 1;
+    vector <<= 2;
+    vector >>= 1;
+    assert_eq!(vector, Vector3i::new(4, 4, 4), "Assignment operators should work on each component.");
+}
+
+#[test]
+fn grid_distances() {
+    let from = Vector3i::new(1, -2, 0);
+    let to = Vector3i::new(-3, 5, 2);
+    assert_eq!(from.manhattan_distance_to(&to), 13, "manhattan_distance_to should add the steps on each axis.");
+    assert_eq!(from.chebyshev_distance_to(&to), 7, "chebyshev_distance_to should take the longest axis.");
+    assert_approx_eq!(
+        from.octile_distance_to(&to),
+        2.0 * SQRT_3 + 2.0 * float_consts::SQRT_2 + 3.0,
+        "octile_distance_to should take corner steps, then edge steps, then face steps."
+    );
+    assert_eq!(
+        from.octile_distance_to(&to),
+        to.octile_distance_to(&from),
+        "octile_distance_to should be symmetric."
+    );
+}
+
@@ -1,5 +1,5 @@
 use huginn::int;
-use huginn::types::vectors::{Vector4, Vector4i, AXIS};
+use huginn::types::vectors::{Vector2i, Vector3i, Vector4, Vector4i, AXIS};
 use huginn::utils::CMP_EPSILON;
 
 macro_rules! assert_approx_eq {
@@ -189,3 +189,170 @@ fn abs_and_sign_methods() {
         "sign should work as expected."
     );
 }
+
+#[test]
+fn swizzle() {
+    let mut vector = Vector4i::new(1, 2, 3, 4);
+
+    assert_eq!(
+        vector.wx(),
+        Vector2i::new(4, 1),
+        "Vector4i wx() should narrow to a Vector2i."
+    );
+    assert_eq!(
+        vector.wzy(),
+        Vector3i::new(4, 3, 2),
+        "Vector4i wzy() should narrow to a Vector3i."
+    );
+    assert_eq!(
+        vector.wzyx(),
+        Vector4i::new(4, 3, 2, 1),
+        "Vector4i wzyx() should reverse the components."
+    );
+    assert_eq!(
+        Vector4::new(1.0, 2.0, 3.0, 4.0).xyww(),
+        Vector4::new(1.0, 2.0, 4.0, 4.0),
+        "Vector4 swizzles should allow repeated components."
+    );
+
+    vector.set_wy(&Vector2i::new(7, 8));
+    assert_eq!(
+        vector,
+        Vector4i::new(1, 8, 3, 7),
+        "Vector4i set_wy() should write the components in order."
+    );
+    vector.set_zxw(&Vector3i::new(5, 6, 9));
+    assert_eq!(
+        vector,
+        Vector4i::new(6, 8, 5, 9),
+        "Vector4i set_zxw() should leave the other components untouched."
+    );
+}
+
+#[test]
+fn standard_traits() {
+    use std::collections::{BTreeSet, HashMap};
+
+    let mut vector = Vector4i::new(1, 2, 3, 4);
+
+    assert_eq!(vector[3], 4, "Vector4i indexing should match get().");
+    vector[3] = 7;
+    assert_eq!(vector.w, 7, "Vector4i IndexMut should write the component.");
+    assert_eq!(
+        vector.as_ref(),
+        &[1, 2, 3, 7],
+        "Vector4i should be viewable as an array in x, y, z, w order."
+    );
+    assert_eq!(
+        Vector4i::from((1, 2, 3, 4)),
+        Vector4i::new(1, 2, 3, 4),
+        "Vector4i should convert from a tuple."
+    );
+    let tuple: (int!(), int!(), int!(), int!()) = vector.into();
+    assert_eq!(tuple, (1, 2, 3, 7), "Vector4i should convert into a tuple.");
+    assert_eq!(
+        (1..=4).collect::<Vector4i>(),
+        Vector4i::new(1, 2, 3, 4),
+        "Vector4i should be collectable from its components."
+    );
+    assert_eq!(
+        [Vector4i::ONE, Vector4i::new(1, 2, 3, 4)]
+            .into_iter()
+            .sum::<Vector4i>(),
+        Vector4i::new(2, 3, 4, 5),
+        "Vector4i should sum component-wise."
+    );
+
+    let mut map = HashMap::new();
+    map.insert(Vector4i::new(1, 2, 3, 4), "a");
+    assert_eq!(
+        map.get(&Vector4i::new(1, 2, 3, 4)),
+        Some(&"a"),
+        "Vector4i should be usable as a HashMap key."
+    );
+
+    let sorted: Vec<Vector4i> = BTreeSet::from([
+        Vector4i::new(1, 2, 3, 5),
+        Vector4i::new(0, 9, 9, 9),
+        Vector4i::new(1, 2, 3, 4),
+    ])
+    .into_iter()
+    .collect();
+    assert_eq!(
+        sorted,
+        vec![
+            Vector4i::new(0, 9, 9, 9),
+            Vector4i::new(1, 2, 3, 4),
+            Vector4i::new(1, 2, 3, 5),
+        ],
+        "Vector4i should be ordered component by component."
+    );
+    assert_eq!(
+        Vector4i::new(1, 9, 0, 0).cmp(&Vector4i::new(2, 0, 0, 0)),
+        std::cmp::Ordering::Less,
+        "Vector4i cmp() should agree with the < operator."
+    );
+}
+
+#[test]
+fn overflow_methods() {
+    let big = Vector4i::MAX;
+    let one = Vector4i::ONE;
+    assert_eq!(big.checked_add(&one), None, "checked_add should detect overflow.");
+    assert_eq!(one.checked_add(&one), Some(Vector4i::new(2, 2, 2, 2)), "checked_add should add without overflow.");
+    assert_eq!(big.checked_mul(&Vector4i::new(2, 2, 2, 2)), None, "checked_mul should detect overflow.");
+    assert_eq!(Vector4i::MIN.checked_sub(&one), None, "checked_sub should detect overflow.");
+    assert_eq!(one.checked_div(&Vector4i::ZERO), None, "checked_div should detect a division by zero.");
+    assert_eq!(big.saturating_add(&one), big, "saturating_add should clamp to MAX.");
+    assert_eq!(Vector4i::MIN.saturating_sub(&one), Vector4i::MIN, "saturating_sub should clamp to MIN.");
+    assert_eq!(big.saturating_mul(&-one), -big, "saturating_mul should not clamp without overflow.");
+    assert_eq!(big.wrapping_add(&one), Vector4i::MIN, "wrapping_add should wrap around.");
+    assert_eq!(Vector4i::MIN.wrapping_sub(&one), big, "wrapping_sub should wrap around.");
+    assert_eq!(big.wrapping_mul(&Vector4i::new(2, 2, 2, 2)), Vector4i::new(-2, -2, -2, -2), "wrapping_mul should wrap around.");
+
+    assert_eq!(
+        Vector4i::new(-1, 7, -8, 8).div_euclid(&Vector4i::new(4, 4, 4, 4)),
+        Vector4i::new(-1, 1, -2, 2),
+        "div_euclid should round towards negative infinity."
+    );
+    assert_eq!(
+        Vector4i::new(-1, 7, -8, 8).rem_euclid(&Vector4i::new(4, 4, 4, 4)),
+        Vector4i::new(3, 3, 0, 0),
+        "rem_euclid should never be negative."
+    );
+
+    assert_eq!(
+        Vector4i::new(1_500_000_000, 1_500_000_000, 1_500_000_000, 1_500_000_000).length_squared(),
+        4 * 2_250_000_000_000_000_000,
+        "length_squared should not overflow."
+    );
+    assert_eq!(
+        Vector4i::MIN.distance_squared_to(&Vector4i::ZERO),
+        Vector4i::ZERO.distance_squared_to(&Vector4i::MIN),
+        "distance_squared_to should not overflow."
+    );
+    assert_eq!(
+        Vector4i::new(1_000_000_000, 0, 0, 0).distance_squared_to(&Vector4i::new(-1_000_000_000, 0, 0, 0)),
+        4_000_000_000_000_000_000,
+        "distance_squared_to should not overflow when the difference does."
+    );
+}
+
+#[test]
+fn bitwise_operators() {
+    let vector = Vector4i::new(12, 10, 6, 9);
+    assert_eq!(vector & 0b0101, Vector4i::new(4, 0, 4, 1), "& should work on each component.");
+    assert_eq!(vector | 0b0101, Vector4i::new(13, 15, 7, 13), "| should work on each component.");
+    assert_eq!(vector ^ vector, Vector4i::ZERO, "^ should work on each component.");
+    assert_eq!(vector << 1, vector * 2, "<< should work on each component.");
+    assert_eq!(vector >> Vector4i::new(1, 1, 1, 1), vector / 2, ">> should work on each component.");
+    assert_eq!(Vector4i::new(-8, -8, -8, -8) >> 2, Vector4i::new(-2, -2, -2, -2), ">> should be an arithmetic shift.");
+
+    let mut vector = vector;
+    vector &= Vector4i::ZERO;
+    vector |= 3;
+    vector ^= 1;
+    vector <<= 2;
+    vector >>= 1;
+    assert_eq!(vector, Vector4i::new(4, 4, 4, 4), "Assignment operators should work on each component.");
+}